@@ -0,0 +1,56 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+use vrp_pragmatic::core::rosomaxa::example::{create_rosenbrock_function, VectorObjective, VectorSolution};
+use vrp_pragmatic::core::rosomaxa::population::{Elitism, HeuristicPopulation};
+use vrp_pragmatic::core::utils::Environment;
+
+type VectorPopulation = Elitism<VectorObjective, VectorSolution>;
+
+fn create_population() -> (Arc<VectorObjective>, VectorPopulation) {
+    let objective =
+        Arc::new(VectorObjective::new(create_rosenbrock_function(), Arc::new(|data: &[f64]| data.to_vec())));
+    let population = Elitism::new(objective.clone(), Environment::default().random, 32, 8);
+
+    (objective, population)
+}
+
+fn create_individuals(objective: Arc<VectorObjective>, size: usize) -> Vec<VectorSolution> {
+    (0..size)
+        .map(|idx| {
+            let value = (idx as f64 / size as f64) * 4. - 2.;
+            VectorSolution::new(vec![value, -value], objective.clone())
+        })
+        .collect()
+}
+
+fn add_individuals_incremental_benchmark(c: &mut Criterion) {
+    c.bench_function("add 1000 individuals to elitism population one by one", |b| {
+        b.iter(|| {
+            let (objective, mut population) = create_population();
+            create_individuals(objective, black_box(1000)).into_iter().for_each(|individual| {
+                population.add(individual);
+            });
+
+            population
+        })
+    });
+}
+
+fn add_individuals_batch_benchmark(c: &mut Criterion) {
+    c.bench_function("add 1000 individuals to elitism population at once", |b| {
+        b.iter(|| {
+            let (objective, mut population) = create_population();
+            population.add_all(create_individuals(objective, black_box(1000)));
+
+            population
+        })
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(15);
+    targets = add_individuals_incremental_benchmark,
+              add_individuals_batch_benchmark
+}
+criterion_main!(benches);
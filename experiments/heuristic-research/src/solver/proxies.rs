@@ -150,6 +150,10 @@ where
         self.inner.ranked()
     }
 
+    fn ranked_detailed(&self) -> Vec<(&Self::Individual, usize, f64)> {
+        self.inner.ranked_detailed()
+    }
+
     fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
         self.inner.all()
     }
@@ -29,6 +29,11 @@ pub trait Storage: Display + Send + Sync {
     /// Adds an input to the storage.
     fn add(&mut self, input: Self::Item);
 
+    /// Adds all inputs to the storage at once.
+    fn add_all(&mut self, inputs: Vec<Self::Item>) {
+        inputs.into_iter().for_each(|input| self.add(input));
+    }
+
     /// Returns iterator over all data.
     fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Item> + 'a>;
 
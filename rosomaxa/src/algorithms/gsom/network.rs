@@ -203,9 +203,18 @@ where
 
     /// Trains network on inputs.
     fn train_batch(&mut self, nodes_data: Vec<(Coordinate, f64, I)>, is_new_input: bool) {
-        nodes_data.into_iter().for_each(|(bmu_coord, error, input)| {
-            self.update(&bmu_coord, &input, error, is_new_input);
-            self.nodes.get_mut(&bmu_coord).unwrap().storage.add(input);
+        // NOTE update does not depend on storage, so inputs are added to each node's storage at once
+        //      which avoids repeated sorting of the storage on each input
+        let node_inputs =
+            nodes_data.into_iter().fold(HashMap::<Coordinate, Vec<I>>::new(), |mut acc, (bmu_coord, error, input)| {
+                self.update(&bmu_coord, &input, error, is_new_input);
+                acc.entry(bmu_coord).or_default().push(input);
+
+                acc
+            });
+
+        node_inputs.into_iter().for_each(|(coord, inputs)| {
+            self.nodes.get_mut(&coord).unwrap().storage.add_all(inputs);
         });
     }
 
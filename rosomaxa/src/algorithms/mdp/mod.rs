@@ -15,6 +15,7 @@ use crate::utils::{compare_floats, Random};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::hash::Hash;
+use std::ops::Range;
 
 /// Represents a state in MDP.
 pub trait State: Clone + Hash + Eq + Send + Sync {
@@ -23,6 +24,12 @@ pub trait State: Clone + Hash + Eq + Send + Sync {
 
     /// Returns reward to be in this state.
     fn reward(&self) -> f64;
+
+    /// Returns true if the state is terminal: an episode ends once it is reached. Default is
+    /// true when no actions are associated with the state.
+    fn is_terminal(&self, actions: &ActionEstimates<Self>) -> bool {
+        actions.data().is_empty()
+    }
 }
 
 /// Represents an agent in MDP.
@@ -36,12 +43,60 @@ pub trait Agent<S: State> {
 
     /// Takes the action in the current agent's state. Potentially, changes agent state.
     fn take_action(&mut self, action: &S::Action);
+
+    /// Returns a shaping reward added to the state's reward when learning transition between states.
+    /// To keep the optimal policy unchanged, it should be potential-based, see `potential_based_shaping`.
+    /// Default is zero: no shaping.
+    fn shape(&self, _from: &S, _to: &S) -> f64 {
+        0.
+    }
+}
+
+/// Returns a potential-based shaping reward for transition between states with given potentials:
+/// `gamma * to - from`, where `gamma` is a discount factor of the learning strategy.
+pub fn potential_based_shaping(gamma: f64, from_potential: f64, to_potential: f64) -> f64 {
+    gamma * to_potential - from_potential
+}
+
+/// Discretizes a continuous range into given amount of buckets with equal width and returns bucket
+/// indices. As `f64` is neither `Hash` nor `Eq`, an index can be used as `State::Action` (or be a
+/// part of it) instead of a continuous action parameter. To take an action, an agent maps
+/// the index back to its sub-range using `get_bucket_range`, e.g. takes its middle value.
+pub fn discretize(range: Range<f64>, buckets: usize) -> Vec<usize> {
+    assert!(buckets > 0, "amount of buckets should be positive");
+    assert!(range.start < range.end, "range should not be empty");
+
+    (0..buckets).collect()
+}
+
+/// Returns a sub-range of the bucket with given index created by `discretize`.
+pub fn get_bucket_range(range: Range<f64>, buckets: usize, bucket: usize) -> Range<f64> {
+    assert!(bucket < buckets, "bucket index is out of range");
+
+    let width = (range.end - range.start) / buckets as f64;
+    let start = range.start + width * bucket as f64;
+    // NOTE use the original end for the last bucket to avoid floating point errors
+    let end = if bucket + 1 == buckets { range.end } else { start + width };
+
+    start..end
 }
 
 /// A learning strategy for the MDP.
 pub trait LearningStrategy<S: State> {
-    /// Estimates an action value given received reward, current value, and actions values from the new state.
+    /// Estimates an action value given received reward, current value and actions values from the new state.
     fn value(&self, reward_value: f64, old_value: f64, estimates: &ActionEstimates<S>) -> f64;
+
+    /// Estimates an action value as `value` does, but also gets amount of visits of the state-action
+    /// pair (including the current one). By default, amount of visits is ignored.
+    fn value_with_visits(
+        &self,
+        reward_value: f64,
+        old_value: f64,
+        _visits: usize,
+        estimates: &ActionEstimates<S>,
+    ) -> f64 {
+        self.value(reward_value, old_value, estimates)
+    }
 }
 
 /// A policy strategy for MDP.
@@ -117,11 +172,49 @@ impl<S: State> ActionEstimates<S> {
         self.min.clone()
     }
 
+    /// Returns a max estimate choosing uniformly at random among all actions which estimates are
+    /// within `tolerance` of the max value. Negative or not finite tolerance is ignored.
+    pub fn max_estimate_with(&self, random: &(dyn Random + Send + Sync), tolerance: f64) -> Option<ActionEstimate<S>> {
+        self.select_within(self.max.as_ref(), random, tolerance)
+    }
+
+    /// Returns a min estimate choosing uniformly at random among all actions which estimates are
+    /// within `tolerance` of the min value. Negative or not finite tolerance is ignored.
+    pub fn min_estimate_with(&self, random: &(dyn Random + Send + Sync), tolerance: f64) -> Option<ActionEstimate<S>> {
+        self.select_within(self.min.as_ref(), random, tolerance)
+    }
+
     /// Returns actual action estimates data.
     pub fn data(&self) -> &HashMap<S::Action, f64> {
         &self.estimates
     }
 
+    fn select_within(
+        &self,
+        extreme: Option<&ActionEstimate<S>>,
+        random: &(dyn Random + Send + Sync),
+        tolerance: f64,
+    ) -> Option<ActionEstimate<S>> {
+        let extreme = extreme?;
+        if tolerance < 0. || !tolerance.is_finite() {
+            return Some(extreme.clone());
+        }
+
+        let candidates = self
+            .estimates
+            .iter()
+            .filter(|(_, value)| compare_floats((*value - extreme.1).abs(), tolerance) != Ordering::Greater)
+            .collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            return Some(extreme.clone());
+        }
+
+        let idx = random.uniform_int(0, candidates.len() as i32 - 1) as usize;
+
+        candidates.get(idx).map(|(action, value)| ((*action).clone(), **value))
+    }
+
     fn get_min_max(map: &HashMap<S::Action, f64>) -> (Option<ActionEstimate<S>>, Option<ActionEstimate<S>>) {
         let max = map.iter().max_by(|(_, a), (_, b)| compare_floats(**a, **b)).map(|(a, b)| (a.clone(), *b));
         let min = map.iter().min_by(|(_, a), (_, b)| compare_floats(**a, **b)).map(|(a, b)| (a.clone(), *b));
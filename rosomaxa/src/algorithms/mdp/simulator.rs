@@ -3,14 +3,31 @@
 mod simulator_test;
 
 use super::*;
-use crate::utils::{parallel_into_collect, CollectGroupBy, Parallelism};
+use crate::utils::{parallel_into_collect, CollectGroupBy, Parallelism, Random};
+use std::sync::Arc;
 
 /// A type which keeps track of all state-action estimates.
 pub type StateEstimates<S> = HashMap<S, ActionEstimates<S>>;
 
+/// A type which keeps track of all state-action visits.
+pub type StateVisits<S> = HashMap<S, HashMap<<S as State>::Action, usize>>;
+
+/// Specifies how an episode is terminated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EpisodeTermination {
+    /// An episode has reached a terminal state.
+    Natural,
+    /// An episode is stopped as max steps per episode is reached.
+    StepCapped,
+}
+
 /// A simulator to train agent with multiple episodes.
 pub struct Simulator<S: State> {
     q: StateEstimates<S>,
+    visits: StateVisits<S>,
+    max_steps: Option<usize>,
+    discount: f64,
+    returns: Vec<f64>,
     learning_strategy: Box<dyn LearningStrategy<S> + Send + Sync>,
     policy_strategy: Box<dyn PolicyStrategy<S> + Send + Sync>,
 }
@@ -21,7 +38,15 @@ impl<S: State> Simulator<S> {
         learning_strategy: Box<dyn LearningStrategy<S> + Send + Sync>,
         policy_strategy: Box<dyn PolicyStrategy<S> + Send + Sync>,
     ) -> Self {
-        Self { q: Default::default(), learning_strategy, policy_strategy }
+        Self {
+            q: Default::default(),
+            visits: Default::default(),
+            max_steps: None,
+            discount: 1.,
+            returns: Vec::default(),
+            learning_strategy,
+            policy_strategy,
+        }
     }
 
     /// Return a learned optimal policy for given state.
@@ -39,6 +64,16 @@ impl<S: State> Simulator<S> {
         &self.q
     }
 
+    /// Gets state-action visits.
+    pub fn get_state_visits(&self) -> &StateVisits<S> {
+        &self.visits
+    }
+
+    /// Gets discounted returns of all episodes run so far in order of their completion.
+    pub fn episode_returns(&self) -> &[f64] {
+        self.returns.as_slice()
+    }
+
     /// Sets action estimates for given state.
     pub fn set_action_estimates(&mut self, state: S, estimates: ActionEstimates<S>) {
         self.q.insert(state, estimates);
@@ -54,81 +89,182 @@ impl<S: State> Simulator<S> {
         self.policy_strategy = policy_strategy;
     }
 
-    /// Runs single episode for each of the given agents in parallel.
-    pub fn run_episode<A>(&mut self, mut agent: A, reducer: impl Fn(&S, &[f64]) -> f64) -> A
+    /// Sets max amount of steps per episode, an episode is stopped once it is reached.
+    /// No limit is applied when `None` is passed.
+    pub fn set_max_steps(&mut self, max_steps: Option<usize>) {
+        self.max_steps = max_steps;
+    }
+
+    /// Sets a discount factor used to calculate episode returns. Default is 1.
+    pub fn set_return_discount(&mut self, discount: f64) {
+        self.discount = discount;
+    }
+
+    /// Runs single episode for given agent. Returns the agent and how the episode is terminated.
+    pub fn run_episode<A>(&mut self, mut agent: A, reducer: impl Fn(&S, &[f64]) -> f64) -> (A, EpisodeTermination)
     where
         A: Agent<S> + Send + Sync,
     {
-        let qs = Self::run_episode_isolated(
+        let (qs, visits, termination, episode_return) = Self::run_episode_isolated(
             &mut agent,
             self.learning_strategy.as_ref(),
             self.policy_strategy.as_ref(),
             &self.q,
+            &self.visits,
+            (self.max_steps, self.discount),
         );
 
         self.merge_state_estimates(vec![qs], reducer);
+        self.merge_state_visits(vec![visits]);
+        self.returns.push(episode_return);
 
-        agent
+        (agent, termination)
     }
 
-    /// Runs single episode for each of the given agents in parallel.
+    /// Runs single episode for each of the given agents in parallel. Returns the agents and how
+    /// their episodes are terminated.
     pub fn run_episodes<A>(
         &mut self,
         agents: Vec<A>,
         parallelism: Parallelism,
         reducer: impl Fn(&S, &[f64]) -> f64,
-    ) -> Vec<A>
+    ) -> Vec<(A, EpisodeTermination)>
     where
         A: Agent<S> + Send + Sync,
     {
-        let (agents, qs): (Vec<_>, Vec<_>) =
+        let (agents, results): (Vec<_>, Vec<_>) =
             parallel_into_collect(agents.into_iter().enumerate().collect(), |(idx, mut agent)| {
                 parallelism.thread_pool_execute(idx, || {
-                    let qs = Self::run_episode_isolated(
+                    let (q, visits, termination, episode_return) = Self::run_episode_isolated(
                         &mut agent,
                         self.learning_strategy.as_ref(),
                         self.policy_strategy.as_ref(),
                         &self.q,
+                        &self.visits,
+                        (self.max_steps, self.discount),
                     );
-                    (agent, qs)
+                    ((agent, termination), ((q, visits), episode_return))
                 })
             })
             .into_iter()
             .unzip();
+        let (results, returns): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+        let (qs, visits): (Vec<_>, Vec<_>) = results.into_iter().unzip();
 
         self.merge_state_estimates(qs, reducer);
+        self.merge_state_visits(visits);
+        self.returns.extend(returns);
 
         agents
     }
 
+    /// Evaluates given policy on learned estimates without changing them: runs `episodes` with
+    /// agents created by `env` and returns mean discounted return. States without estimates are
+    /// evaluated on actions provided by the agent. Max steps per episode and return discount
+    /// settings of the simulator are applied.
+    pub fn evaluate_policy<A>(&self, env: impl Fn() -> A, policy: &dyn PolicyStrategy<S>, episodes: usize) -> f64
+    where
+        A: Agent<S>,
+    {
+        if episodes == 0 {
+            return 0.;
+        }
+
+        let total = (0..episodes)
+            .map(|_| {
+                let mut agent = env();
+                let (mut steps, mut episode_return, mut discount_factor) = (0, 0., 1.);
+
+                loop {
+                    let state = agent.get_state().clone();
+                    let actions;
+                    let estimates = if let Some(estimates) = self.q.get(&state) {
+                        estimates
+                    } else {
+                        actions = agent.get_actions(&state);
+                        &actions
+                    };
+
+                    if state.is_terminal(estimates) || self.max_steps.map_or(false, |max_steps| steps >= max_steps) {
+                        break;
+                    }
+
+                    let action = if let Some(action) = policy.select(estimates) { action } else { break };
+
+                    agent.take_action(&action);
+                    steps += 1;
+
+                    episode_return += discount_factor * agent.get_state().reward();
+                    discount_factor *= self.discount;
+                }
+
+                episode_return
+            })
+            .sum::<f64>();
+
+        total / episodes as f64
+    }
+
     /// Runs episode for given agent without changing any state of a simulator.
     fn run_episode_isolated<A>(
         agent: &mut A,
         learning_strategy: &(dyn LearningStrategy<S> + Send + Sync),
         policy_strategy: &(dyn PolicyStrategy<S> + Send + Sync),
         q: &StateEstimates<S>,
-    ) -> StateEstimates<S>
+        visits: &StateVisits<S>,
+        (max_steps, discount): (Option<usize>, f64),
+    ) -> (StateEstimates<S>, StateVisits<S>, EpisodeTermination, f64)
     where
         A: Agent<S> + Send + Sync,
     {
         let mut q_new = StateEstimates::new();
+        let mut visits_new = StateVisits::new();
+        let mut steps = 0;
+        let mut episode_return = 0.;
+        let mut discount_factor = 1.;
 
         loop {
             let old_state = agent.get_state().clone();
             Self::ensure_actions(&mut q_new, q, &old_state, agent);
             let old_estimates = q_new.get(&old_state).unwrap();
 
-            let action = if let Some(action) = policy_strategy.select(old_estimates) { action } else { return q_new };
+            if old_state.is_terminal(old_estimates) {
+                return (q_new, visits_new, EpisodeTermination::Natural, episode_return);
+            }
+
+            if max_steps.map_or(false, |max_steps| steps >= max_steps) {
+                return (q_new, visits_new, EpisodeTermination::StepCapped, episode_return);
+            }
+
+            let action = if let Some(action) = policy_strategy.select(old_estimates) {
+                action
+            } else {
+                return (q_new, visits_new, EpisodeTermination::Natural, episode_return);
+            };
+
+            steps += 1;
 
             agent.take_action(&action);
             let old_value = *old_estimates.data().get(&action).unwrap();
 
             let next_state = agent.get_state();
-            let reward_value = next_state.reward();
+            let reward_value = next_state.reward() + agent.shape(&old_state, next_state);
+
+            // NOTE shaping is a learning aid, so the return is based on environment rewards only
+            episode_return += discount_factor * next_state.reward();
+            discount_factor *= discount;
 
             Self::ensure_actions(&mut q_new, q, next_state, agent);
             let new_estimates = q_new.get(next_state).unwrap();
-            let new_value = learning_strategy.value(reward_value, old_value, new_estimates);
+            // NOTE terminal state has no future value
+            let terminal_estimates = ActionEstimates::default();
+            let new_estimates = if next_state.is_terminal(new_estimates) { &terminal_estimates } else { new_estimates };
+            let action_visits = visits_new.entry(old_state.clone()).or_default().entry(action.clone()).or_insert(0);
+            *action_visits += 1;
+            let total_visits =
+                *action_visits + visits.get(&old_state).and_then(|actions| actions.get(&action)).copied().unwrap_or(0);
+
+            let new_value = learning_strategy.value_with_visits(reward_value, old_value, total_visits, new_estimates);
 
             q_new.entry(old_state.clone()).and_modify(|estimates| {
                 estimates.insert(action.clone(), new_value);
@@ -148,6 +284,13 @@ impl<S: State> Simulator<S> {
         });
     }
 
+    fn merge_state_visits(&mut self, visits: Vec<StateVisits<S>>) {
+        visits.into_iter().flat_map(|visits| visits.into_iter()).for_each(|(state, actions)| {
+            let action_visits = self.visits.entry(state).or_default();
+            actions.into_iter().for_each(|(action, count)| *action_visits.entry(action).or_insert(0) += count);
+        });
+    }
+
     fn ensure_actions(q_new: &mut StateEstimates<S>, q: &StateEstimates<S>, state: &S, agent: &dyn Agent<S>) {
         match (q_new.get(state), q.get(state)) {
             (None, Some(estimates)) => q_new.insert(state.clone(), estimates.clone()),
@@ -157,6 +300,117 @@ impl<S: State> Simulator<S> {
     }
 }
 
+/// Specifies a learning strategy used by simulator built with `SimulatorBuilder`.
+#[derive(Clone, Debug)]
+pub enum Learning {
+    /// A q-learning strategy, see `QLearning`.
+    QLearning {
+        /// A learning rate.
+        alpha: f64,
+        /// A discount factor.
+        gamma: f64,
+        /// A learning rate decay.
+        decay: AlphaDecay,
+    },
+    /// A monte carlo strategy, see `MonteCarlo`.
+    MonteCarlo {
+        /// A learning rate.
+        alpha: f64,
+    },
+}
+
+/// Specifies a policy strategy used by simulator built with `SimulatorBuilder`.
+#[derive(Clone, Debug)]
+pub enum Policy {
+    /// A greedy strategy, see `Greedy`.
+    Greedy,
+    /// An e-greedy strategy, see `EpsilonGreedy`.
+    EpsilonGreedy {
+        /// A probability to select a random action.
+        epsilon: f64,
+    },
+    /// An e-weighted strategy, see `EpsilonWeighted`.
+    EpsilonWeighted {
+        /// A probability to select a random action.
+        epsilon: f64,
+    },
+    /// A softmax strategy, see `Boltzmann`.
+    Boltzmann {
+        /// A positive temperature which controls randomness of selection.
+        temperature: f64,
+    },
+}
+
+/// Provides a way to build a simulator from learning and policy strategy definitions.
+pub struct SimulatorBuilder {
+    learning: Learning,
+    policy: Policy,
+    random: Option<Arc<dyn Random + Send + Sync>>,
+    max_steps: Option<usize>,
+}
+
+impl Default for SimulatorBuilder {
+    fn default() -> Self {
+        Self {
+            learning: Learning::QLearning { alpha: 0.2, gamma: 0.01, decay: AlphaDecay::Constant },
+            policy: Policy::Greedy,
+            random: None,
+            max_steps: None,
+        }
+    }
+}
+
+impl SimulatorBuilder {
+    /// Sets learning strategy.
+    pub fn with_learning(mut self, learning: Learning) -> Self {
+        self.learning = learning;
+        self
+    }
+
+    /// Sets policy strategy.
+    pub fn with_policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets random generator, it is required by policy strategies with random action selection.
+    pub fn with_random(mut self, random: Arc<dyn Random + Send + Sync>) -> Self {
+        self.random = Some(random);
+        self
+    }
+
+    /// Sets max amount of steps per episode.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Builds a simulator.
+    pub fn build<S: State>(self) -> Result<Simulator<S>, String> {
+        let (learning_strategy, discount): (Box<dyn LearningStrategy<S> + Send + Sync>, _) = match self.learning {
+            Learning::QLearning { alpha, gamma, decay } => {
+                (Box::new(QLearning::try_new_with_decay(alpha, gamma, decay)?), gamma)
+            }
+            Learning::MonteCarlo { alpha } => (Box::new(MonteCarlo::try_new(alpha)?), 1.),
+        };
+
+        let get_random = || self.random.clone().ok_or_else(|| format!("policy {:?} requires random", self.policy));
+
+        let policy_strategy: Box<dyn PolicyStrategy<S> + Send + Sync> = match self.policy {
+            Policy::Greedy => Box::<Greedy>::default(),
+            Policy::EpsilonGreedy { epsilon } => Box::new(EpsilonGreedy::new(epsilon, get_random()?)),
+            Policy::EpsilonWeighted { epsilon } => Box::new(EpsilonWeighted::new(epsilon, get_random()?)),
+            Policy::Boltzmann { temperature } => Box::new(Boltzmann::try_new(temperature, get_random()?)?),
+        };
+
+        let mut simulator = Simulator::new(learning_strategy, policy_strategy);
+        simulator.set_max_steps(self.max_steps);
+        simulator.set_return_discount(discount);
+
+        Ok(simulator)
+    }
+}
+
 fn merge_vec_maps<K: Eq + Hash, V, F: FnMut((K, Vec<V>))>(vec_map: Vec<HashMap<K, V>>, merge_func: F) {
     vec_map.into_iter().flat_map(|q| q.into_iter()).collect_group_by().into_iter().for_each(merge_func)
 }
@@ -2,24 +2,95 @@ use super::*;
 use crate::utils::{compare_floats, Random};
 use std::sync::Arc;
 
+/// Specifies how learning rate decays with amount of visits of the state-action pair. A fixed
+/// learning rate can prevent convergence in stochastic environments.
+#[derive(Clone, Copy, Debug)]
+pub enum AlphaDecay {
+    /// Learning rate is not changed.
+    Constant,
+    /// Learning rate is divided by amount of visits.
+    Harmonic,
+    /// Learning rate is multiplied by `rate` on each subsequent visit.
+    Exponential {
+        /// A decay rate in (0, 1] range.
+        rate: f64,
+    },
+    /// Learning rate is divided by `1 + decay * step`, where step is amount of previous visits.
+    Inverse {
+        /// A non-negative decay coefficient.
+        decay: f64,
+    },
+}
+
+impl AlphaDecay {
+    /// Returns learning rate for given initial rate and amount of visits.
+    pub fn get_alpha(&self, alpha: f64, visits: usize) -> f64 {
+        let visits = visits.max(1);
+
+        match self {
+            AlphaDecay::Constant => alpha,
+            AlphaDecay::Harmonic => alpha / visits as f64,
+            AlphaDecay::Exponential { rate } => alpha * rate.powi((visits - 1).min(i32::MAX as usize) as i32),
+            AlphaDecay::Inverse { decay } => alpha / (1. + decay * (visits - 1) as f64),
+        }
+    }
+}
+
 /// Applies q-learning strategy to calculate values for taken actions.
 pub struct QLearning {
     alpha: f64,
     gamma: f64,
+    decay: AlphaDecay,
 }
 
 impl QLearning {
-    /// Creates a new instance of `QLearning`.
+    /// Creates a new instance of `QLearning`. Panics if parameters are invalid, so it is intended
+    /// for constant parameters, use `try_new` otherwise.
     pub fn new(alpha: f64, gamma: f64) -> Self {
-        Self { alpha, gamma }
+        Self::new_with_decay(alpha, gamma, AlphaDecay::Constant)
+    }
+
+    /// Creates a new instance of `QLearning` which learning rate decays with amount of visits.
+    /// Panics if parameters are invalid, so it is intended for constant parameters, use
+    /// `try_new_with_decay` otherwise.
+    pub fn new_with_decay(alpha: f64, gamma: f64, decay: AlphaDecay) -> Self {
+        Self::try_new_with_decay(alpha, gamma, decay).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Tries to create a new instance of `QLearning`. Returns an error if `alpha` is not in (0, 1]
+    /// or `gamma` is not in [0, 1] range.
+    pub fn try_new(alpha: f64, gamma: f64) -> Result<Self, String> {
+        Self::try_new_with_decay(alpha, gamma, AlphaDecay::Constant)
+    }
+
+    /// Tries to create a new instance of `QLearning` which learning rate decays with amount of visits.
+    /// Returns an error if `alpha` is not in (0, 1] or `gamma` is not in [0, 1] range, or decay
+    /// parameters are invalid.
+    pub fn try_new_with_decay(alpha: f64, gamma: f64, decay: AlphaDecay) -> Result<Self, String> {
+        validate_learning_rate(alpha)?;
+        validate_discount_factor(gamma)?;
+        validate_alpha_decay(&decay)?;
+
+        Ok(Self { alpha, gamma, decay })
     }
 }
 
 impl<S: State> LearningStrategy<S> for QLearning {
     fn value(&self, reward_value: f64, old_value: f64, estimates: &ActionEstimates<S>) -> f64 {
+        self.value_with_visits(reward_value, old_value, 1, estimates)
+    }
+
+    fn value_with_visits(
+        &self,
+        reward_value: f64,
+        old_value: f64,
+        visits: usize,
+        estimates: &ActionEstimates<S>,
+    ) -> f64 {
         let next_max = estimates.max.as_ref().map_or(0., |(_, v)| *v);
+        let alpha = self.decay.get_alpha(self.alpha, visits);
 
-        old_value + self.alpha * (reward_value + self.gamma * next_max - old_value)
+        old_value + alpha * (reward_value + self.gamma * next_max - old_value)
     }
 }
 
@@ -29,9 +100,17 @@ pub struct MonteCarlo {
 }
 
 impl MonteCarlo {
-    /// Creates a new instance of `MonteCarlo`.
+    /// Creates a new instance of `MonteCarlo`. Panics if `alpha` is invalid, so it is intended
+    /// for constant parameters, use `try_new` otherwise.
     pub fn new(alpha: f64) -> Self {
-        Self { alpha }
+        Self::try_new(alpha).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Tries to create a new instance of `MonteCarlo`. Returns an error if `alpha` is not in (0, 1] range.
+    pub fn try_new(alpha: f64) -> Result<Self, String> {
+        validate_learning_rate(alpha)?;
+
+        Ok(Self { alpha })
     }
 }
 
@@ -49,9 +128,9 @@ pub struct EpsilonGreedy {
 }
 
 impl EpsilonGreedy {
-    /// Creates a new instance of `EpsilonGreedy`.
+    /// Creates a new instance of `EpsilonGreedy`. Epsilon is clamped into [0, 1] range.
     pub fn new(epsilon: f64, random: Arc<dyn Random + Send + Sync>) -> Self {
-        Self { epsilon, random }
+        Self { epsilon: epsilon.clamp(0., 1.), random }
     }
 }
 
@@ -86,9 +165,9 @@ pub struct EpsilonWeighted {
 }
 
 impl EpsilonWeighted {
-    /// Creates a new instance of `EpsilonWeighted`.
+    /// Creates a new instance of `EpsilonWeighted`. Epsilon is clamped into [0, 1] range.
     pub fn new(epsilon: f64, random: Arc<dyn Random + Send + Sync>) -> Self {
-        Self { epsilon, random }
+        Self { epsilon: epsilon.clamp(0., 1.), random }
     }
 }
 
@@ -105,3 +184,86 @@ impl<S: State> PolicyStrategy<S> for EpsilonWeighted {
         }
     }
 }
+
+/// A Boltzmann (softmax) action selection strategy: an action is selected with probability
+/// proportional to `exp(estimate / temperature)`. Low temperature makes selection close to greedy,
+/// high temperature makes it close to uniform. Unlike `ActionEstimates::weighted`, it supports
+/// negative estimates.
+pub struct Boltzmann {
+    temperature: f64,
+    random: Arc<dyn Random + Send + Sync>,
+}
+
+impl Boltzmann {
+    /// Creates a new instance of `Boltzmann`. Panics if temperature is not positive, so it is intended
+    /// for constant parameters, use `try_new` otherwise.
+    pub fn new(temperature: f64, random: Arc<dyn Random + Send + Sync>) -> Self {
+        Self::try_new(temperature, random).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Tries to create a new instance of `Boltzmann`. Returns an error if temperature is not positive.
+    pub fn try_new(temperature: f64, random: Arc<dyn Random + Send + Sync>) -> Result<Self, String> {
+        if temperature > 0. {
+            Ok(Self { temperature, random })
+        } else {
+            Err(format!("temperature should be positive, got: {temperature}"))
+        }
+    }
+}
+
+impl<S: State> PolicyStrategy<S> for Boltzmann {
+    fn select(&self, estimates: &ActionEstimates<S>) -> Option<S::Action> {
+        let max = estimates.data().values().copied().max_by(|a, b| compare_floats(*a, *b))?;
+
+        // NOTE subtract max estimate to avoid overflow
+        let weights = estimates
+            .data()
+            .iter()
+            .map(|(action, estimate)| (action, ((estimate - max) / self.temperature).exp()))
+            .collect::<Vec<_>>();
+
+        let total = weights.iter().map(|(_, weight)| weight).sum::<f64>();
+        let mut threshold = self.random.uniform_real(0., total);
+
+        weights
+            .iter()
+            .find(|(_, weight)| {
+                threshold -= weight;
+                threshold < 0.
+            })
+            .or_else(|| weights.last())
+            .map(|&(action, _)| action.clone())
+    }
+}
+
+/// Checks that learning rate is in (0, 1] range.
+pub(super) fn validate_learning_rate(alpha: f64) -> Result<(), String> {
+    if alpha > 0. && alpha <= 1. {
+        Ok(())
+    } else {
+        Err(format!("learning rate (alpha) should be in (0, 1] range, got: {alpha}"))
+    }
+}
+
+/// Checks that decay parameters keep learning rate positive and non-increasing: exponential decay
+/// rate should be in (0, 1] range, inverse decay coefficient should be non-negative.
+pub(super) fn validate_alpha_decay(decay: &AlphaDecay) -> Result<(), String> {
+    match *decay {
+        AlphaDecay::Exponential { rate } if !(rate > 0. && rate <= 1.) => {
+            Err(format!("exponential decay rate should be in (0, 1] range, got: {rate}"))
+        }
+        AlphaDecay::Inverse { decay } if !(decay >= 0. && decay.is_finite()) => {
+            Err(format!("inverse decay coefficient should be non-negative, got: {decay}"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks that discount factor is in [0, 1] range, otherwise estimates can diverge.
+pub(super) fn validate_discount_factor(gamma: f64) -> Result<(), String> {
+    if (0. ..=1.).contains(&gamma) {
+        Ok(())
+    } else {
+        Err(format!("discount factor (gamma) should be in [0, 1] range, got: {gamma}"))
+    }
+}
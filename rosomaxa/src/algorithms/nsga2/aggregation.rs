@@ -0,0 +1,77 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/nsga2/aggregation_test.rs"]
+mod aggregation_test;
+
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+/// Specifies a method to aggregate a Pareto front into a single objective vector, e.g. to report
+/// a single KPI from a multi-objective run. All objectives are assumed to be minimized.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FrontAggregation {
+    /// Takes the best value of each objective across the front. The result is an ideal (utopia)
+    /// point which is usually not a member of the front.
+    BestPerObjective,
+    /// Selects a knee point: a front member with the largest trade-off gain. After normalization of
+    /// each objective into [0, 1] range, it is approximated by the member with minimal sum of
+    /// objectives, so it is the farthest one from the hyperplane through extreme points.
+    KneePoint,
+    /// Selects a front member with the smallest euclidean distance to the ideal point in normalized
+    /// objective space.
+    UtopiaDistance,
+}
+
+/// Aggregates a Pareto front, given as a list of objective vectors, into a single objective vector
+/// using given method. Returns an empty vector for an empty front.
+pub fn aggregate_front(front: &[Vec<f64>], method: FrontAggregation) -> Vec<f64> {
+    let (ideal, nadir) = match get_bounds(front) {
+        Some(bounds) => bounds,
+        None => return Vec::default(),
+    };
+
+    let normalize = |values: &[f64]| {
+        values
+            .iter()
+            .zip(ideal.iter().zip(nadir.iter()))
+            .map(|(value, (min, max))| {
+                let range = max - min;
+                if compare_floats(range, 0.) == Ordering::Equal {
+                    0.
+                } else {
+                    (value - min) / range
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let select_member = |score_fn: &dyn Fn(&[f64]) -> f64| {
+        front
+            .iter()
+            .map(|values| (values, score_fn(normalize(values).as_slice())))
+            .min_by(|(_, a), (_, b)| compare_floats(*a, *b))
+            .map(|(values, _)| values.clone())
+            .unwrap_or_default()
+    };
+
+    match method {
+        FrontAggregation::BestPerObjective => ideal,
+        FrontAggregation::KneePoint => select_member(&|normalized| normalized.iter().sum()),
+        FrontAggregation::UtopiaDistance => {
+            select_member(&|normalized| normalized.iter().map(|value| value * value).sum::<f64>().sqrt())
+        }
+    }
+}
+
+/// Returns ideal (best) and nadir (worst) values of each objective across the front.
+fn get_bounds(front: &[Vec<f64>]) -> Option<(Vec<f64>, Vec<f64>)> {
+    let first = front.first()?;
+
+    Some(front.iter().skip(1).fold((first.clone(), first.clone()), |(mut ideal, mut nadir), values| {
+        values.iter().enumerate().for_each(|(idx, &value)| {
+            ideal[idx] = ideal[idx].min(value);
+            nadir[idx] = nadir[idx].max(value);
+        });
+
+        (ideal, nadir)
+    }))
+}
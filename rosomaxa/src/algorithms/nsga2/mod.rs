@@ -27,7 +27,10 @@ mod non_dominated_sort;
 use self::non_dominated_sort::*;
 
 mod nsga2_sort;
-pub use self::nsga2_sort::select_and_rank;
+pub use self::nsga2_sort::{select_and_rank, select_and_rank_with_tie_break};
 
 mod objective;
 pub use self::objective::*;
+
+mod aggregation;
+pub use self::aggregation::*;
@@ -14,7 +14,6 @@ mod non_dominated_sort_test;
 
 use crate::MultiObjective;
 use std::cmp::Ordering;
-use std::collections::HashSet;
 
 type SolutionIdx = usize;
 
@@ -153,13 +152,16 @@ where
             .enumerate()
             .filter(|(_, count)| **count == min)
             .map(|(idx, _)| idx)
-            .collect::<HashSet<_>>();
+            .collect::<Vec<_>>();
 
-        dominated_solutions.iter_mut().enumerate().filter(|(idx, _)| ids.contains(idx)).for_each(|(_, domindated)| {
-            domindated.retain(|idx| !ids.contains(idx));
+        // NOTE ids are sorted, so the front has a deterministic order
+        let contains = |idx: &usize| ids.binary_search(idx).is_ok();
+
+        dominated_solutions.iter_mut().enumerate().filter(|(idx, _)| contains(idx)).for_each(|(_, domindated)| {
+            domindated.retain(|idx| !contains(idx));
         });
 
-        current_front.extend(ids.into_iter());
+        current_front.extend(ids.iter().copied());
     }
 
     Front { dominated_solutions, domination_count, previous_front: Vec::new(), current_front, rank: 0, solutions }
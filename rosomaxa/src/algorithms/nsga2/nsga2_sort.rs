@@ -3,6 +3,8 @@
 mod nsga2_sort_test;
 
 use super::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
 
 /// Select `n` solutions using the approach taken by NSGA2.
 ///
@@ -12,10 +14,25 @@ use super::*;
 /// that does not completely fit into the result set, we sort it's solutions according to their
 /// crowding distance (higher crowding distance is "better"), and prefer those solutions with the
 /// higher crowding distance until we have exactly `n` solutions in the result set.
+///
+/// Solutions of the last front with the same crowding distance are ordered by their index in
+/// `solutions`, so the result is reproducible for the same input.
 pub fn select_and_rank<'a, S: 'a>(
     solutions: &'a [S],
     n: usize,
     multi_objective: &impl MultiObjective<Solution = S>,
+) -> Vec<AssignedCrowdingDistance<'a, S>> {
+    select_and_rank_with_tie_break(solutions, n, multi_objective, &|_, _| Ordering::Equal)
+}
+
+/// Select `n` solutions using the approach taken by NSGA2 as [`select_and_rank`] does, but orders
+/// solutions of the last front with the same crowding distance using `tie_break` function first.
+/// Remaining ties are resolved by solution's index in `solutions`.
+pub fn select_and_rank_with_tie_break<'a, S: 'a>(
+    solutions: &'a [S],
+    n: usize,
+    multi_objective: &impl MultiObjective<Solution = S>,
+    tie_break: &dyn Fn(&S, &S) -> Ordering,
 ) -> Vec<AssignedCrowdingDistance<'a, S>> {
     // cannot select more solutions than we actually have
     let n = solutions.len().min(n);
@@ -34,7 +51,9 @@ pub fn select_and_rank<'a, S: 'a>(
             // distance and take the best solutions until we have "n" solutions in the result
             assigned_crowding.sort_by(|a, b| {
                 debug_assert_eq!(a.rank, b.rank);
-                a.crowding_distance.partial_cmp(&b.crowding_distance).unwrap().reverse()
+                compare_floats(b.crowding_distance, a.crowding_distance)
+                    .then_with(|| tie_break(a.solution, b.solution))
+                    .then_with(|| a.index.cmp(&b.index))
             });
         }
 
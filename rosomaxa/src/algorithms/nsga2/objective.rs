@@ -1,5 +1,29 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/nsga2/objective_test.rs"]
+mod objective_test;
+
 use crate::utils::compare_floats;
 use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Specifies an optimization direction of the objective's fitness value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectiveDirection {
+    /// Lower fitness value is better.
+    Minimize,
+    /// Higher fitness value is better.
+    Maximize,
+}
+
+impl ObjectiveDirection {
+    /// Converts fitness value to the one which is minimized.
+    pub fn to_minimized(&self, value: f64) -> f64 {
+        match self {
+            ObjectiveDirection::Minimize => value,
+            ObjectiveDirection::Maximize => -value,
+        }
+    }
+}
 
 /// An *objective* defines a *total ordering relation* and a *distance metric* on a set of
 /// `solutions`. Given any two solutions, an objective answers the following two questions:
@@ -15,8 +39,8 @@ pub trait Objective {
     /// This answers the question, is solution `a` better, equal or worse than solution `b`,
     /// according to the objective.
     fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
-        let fitness_a = self.fitness(a);
-        let fitness_b = self.fitness(b);
+        let fitness_a = self.direction().to_minimized(self.fitness(a));
+        let fitness_b = self.direction().to_minimized(self.fitness(b));
 
         compare_floats(fitness_a, fitness_b)
     }
@@ -35,6 +59,38 @@ pub trait Objective {
 
     /// An objective fitness value for given `solution`.
     fn fitness(&self, solution: &Self::Solution) -> f64;
+
+    /// An optimization direction of the fitness value. Default is minimization.
+    fn direction(&self) -> ObjectiveDirection {
+        ObjectiveDirection::Minimize
+    }
+}
+
+/// An objective type which can be shared between threads.
+pub type SharedObjective<S> = Arc<dyn Objective<Solution = S> + Send + Sync>;
+
+/// An objective which fitness is a weighted sum of inner objectives' fitness values. Fitness values
+/// of maximized objectives are negated, so the sum is always minimized.
+pub struct WeightedSumObjective<S> {
+    objectives: Vec<(SharedObjective<S>, f64)>,
+}
+
+impl<S> WeightedSumObjective<S> {
+    /// Creates a new instance of `WeightedSumObjective` from objectives with their weights.
+    pub fn new(objectives: Vec<(SharedObjective<S>, f64)>) -> Self {
+        Self { objectives }
+    }
+}
+
+impl<S> Objective for WeightedSumObjective<S> {
+    type Solution = S;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.objectives
+            .iter()
+            .map(|(objective, weight)| weight * objective.direction().to_minimized(objective.fitness(solution)))
+            .sum()
+    }
 }
 
 /// A multi objective.
@@ -56,6 +112,15 @@ pub trait MultiObjective {
 
     /// Returns total number of inner objectives.
     fn size(&self) -> usize;
+
+    /// Returns dominance relationship between two solutions considering all inner objectives:
+    /// `Less` if `a` dominates `b`, `Greater` if `b` dominates `a` and `Equal` if they are
+    /// incomparable or have the same fitness.
+    fn dominance(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        get_dominance(
+            (0..self.size()).map(|idx| self.get_order(a, b, idx).expect("get_order: invalid multi objective")),
+        )
+    }
 }
 
 /// Calculates dominance order of two solutions using multiple objectives.
@@ -64,11 +129,59 @@ pub fn dominance_order<'a, T, O: Objective<Solution = T> + ?Sized + 'a, Iter: It
     b: &'a T,
     objectives: Iter,
 ) -> Ordering {
+    get_dominance(objectives.map(|objective| objective.total_order(a, b)))
+}
+
+/// A multi objective which considers only first `limit` inner objectives for dominance, but still
+/// reports fitness, orders and distances of all of them. With many objectives, almost all solutions
+/// become mutually non-dominated, so limiting them keeps pareto ranking meaningful.
+pub struct LimitedDominanceObjective<O: MultiObjective> {
+    inner: O,
+    limit: usize,
+}
+
+impl<O: MultiObjective> LimitedDominanceObjective<O> {
+    /// Creates a new instance of `LimitedDominanceObjective`.
+    pub fn new(inner: O, limit: usize) -> Self {
+        assert!(limit > 0);
+
+        Self { inner, limit }
+    }
+}
+
+impl<O: MultiObjective> MultiObjective for LimitedDominanceObjective<O> {
+    type Solution = O::Solution;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        get_dominance(
+            (0..self.limit.min(self.inner.size()))
+                .map(|idx| self.inner.get_order(a, b, idx).expect("get_order: invalid multi objective")),
+        )
+    }
+
+    fn fitness<'a>(&'a self, solution: &'a Self::Solution) -> Box<dyn Iterator<Item = f64> + 'a> {
+        self.inner.fitness(solution)
+    }
+
+    fn get_order(&self, a: &Self::Solution, b: &Self::Solution, idx: usize) -> Result<Ordering, String> {
+        self.inner.get_order(a, b, idx)
+    }
+
+    fn get_distance(&self, a: &Self::Solution, b: &Self::Solution, idx: usize) -> Result<f64, String> {
+        self.inner.get_distance(a, b, idx)
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+}
+
+fn get_dominance(orders: impl Iterator<Item = Ordering>) -> Ordering {
     let mut less_cnt = 0;
     let mut greater_cnt = 0;
 
-    for objective in objectives {
-        match objective.total_order(a, b) {
+    for order in orders {
+        match order {
             Ordering::Less => {
                 less_cnt += 1;
             }
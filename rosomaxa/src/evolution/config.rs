@@ -1,8 +1,12 @@
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/config_test.rs"]
+mod config_test;
+
 use crate::evolution::*;
 use crate::hyper::*;
 use crate::termination::*;
 use std::hash::Hash;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// A configuration which controls evolution execution.
 pub struct EvolutionConfig<C, O, S>
@@ -84,6 +88,7 @@ where
 {
     max_generations: Option<usize>,
     max_time: Option<usize>,
+    desired_solutions: usize,
     min_cv: Option<(String, usize, f64, bool, K)>,
     target_proximity: Option<(Vec<f64>, f64)>,
     heuristic: Option<Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>>,
@@ -93,6 +98,8 @@ where
 
     search_operators: Option<HeuristicSearchOperators<C, O, S>>,
     diversify_operators: Option<HeuristicDiversifyOperators<C, O, S>>,
+    heuristic_policy: Option<Arc<Mutex<HeuristicPolicy>>>,
+    heuristic_reset_after: Option<usize>,
 
     objective: Option<Arc<dyn HeuristicObjective<Solution = S>>>,
 
@@ -111,6 +118,7 @@ where
         Self {
             max_generations: None,
             max_time: None,
+            desired_solutions: 1,
             min_cv: None,
             target_proximity: None,
             heuristic: None,
@@ -119,6 +127,8 @@ where
             strategy: None,
             search_operators: None,
             diversify_operators: None,
+            heuristic_policy: None,
+            heuristic_reset_after: None,
             objective: None,
             initial: InitialConfig { operators: vec![], max_size: 4, quota: 0.05, individuals: vec![] },
             processing: ProcessingConfig { context: vec![], solution: vec![] },
@@ -145,6 +155,12 @@ where
         self
     }
 
+    /// Sets amount of top ranked solutions returned by default evolution strategy. Default is 1.
+    pub fn with_desired_solutions(mut self, amount: usize) -> Self {
+        self.desired_solutions = amount;
+        self
+    }
+
     /// Sets variation coefficient termination criteria. Default is None.
     pub fn with_min_cv(mut self, min_cv: Option<(String, usize, f64, bool)>, key: K) -> Self {
         self.min_cv = min_cv.map(|min_cv| (min_cv.0, min_cv.1, min_cv.2, min_cv.3, key));
@@ -230,6 +246,20 @@ where
         self
     }
 
+    /// Sets a shared policy for dynamic heuristic: it is used to warm start operator selection
+    /// and receives learned operator estimates during the search, so it can be saved for next run.
+    pub fn with_heuristic_policy(mut self, policy: Arc<Mutex<HeuristicPolicy>>) -> Self {
+        self.heuristic_policy = Some(policy);
+        self
+    }
+
+    /// Sets amount of consecutive generations without new best known solution after which dynamic
+    /// heuristic resets learned operator estimates to uniform ones.
+    pub fn with_heuristic_reset_after(mut self, generations: Option<usize>) -> Self {
+        self.heuristic_reset_after = generations;
+        self
+    }
+
     /// Gets termination criterias.
     #[allow(clippy::type_complexity)]
     fn get_termination(
@@ -318,14 +348,30 @@ where
                     (logger)("configured to use custom heuristic");
                     heuristic
                 } else {
-                    Box::new(DynamicSelective::new(
+                    let heuristic = DynamicSelective::new(
                         self.search_operators.ok_or_else(|| "missing search operators or heuristic".to_string())?,
                         self.diversify_operators
                             .ok_or_else(|| "missing diversify operators or heuristic".to_string())?,
                         context.environment(),
-                    ))
+                    );
+
+                    let heuristic = if let Some(policy) = self.heuristic_policy {
+                        (logger)("configured to use heuristic policy");
+                        heuristic.with_policy(policy)
+                    } else {
+                        heuristic
+                    };
+
+                    if let Some(generations) = self.heuristic_reset_after {
+                        (logger)(
+                            format!("configured to reset heuristic estimates after {generations} generations").as_str(),
+                        );
+                        Box::new(heuristic.with_reset_after(generations))
+                    } else {
+                        Box::new(heuristic)
+                    }
                 };
-                Box::new(strategies::Iterative::new(heuristic, 1))
+                Box::new(strategies::Iterative::new(heuristic, self.desired_solutions))
             },
             context,
             termination,
@@ -22,6 +22,10 @@ pub struct TelemetryMetrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// Pareto fronts recorded at each generation.
+    pub fronts: ParetoFrontRecorder,
+    /// Best known fitness recorded at each generation.
+    pub trajectory: SearchTrajectory,
 }
 
 /// Represents information about generation.
@@ -56,6 +60,95 @@ pub struct TelemetryPopulation {
     pub individuals: Vec<TelemetryIndividual>,
 }
 
+/// Records a Pareto front (rank-0 individuals) of the population at each generation, e.g. to
+/// animate convergence of a multi-objective search.
+#[derive(Clone, Debug, Default)]
+pub struct ParetoFrontRecorder {
+    fronts: Vec<Vec<Vec<f64>>>,
+}
+
+impl ParetoFrontRecorder {
+    /// Records fitness values of population individuals which are not dominated by any other one.
+    pub fn record<O, S>(&mut self, objective: &O, population: &DynHeuristicPopulation<O, S>)
+    where
+        O: HeuristicObjective<Solution = S>,
+        S: HeuristicSolution,
+    {
+        let individuals = population.all().collect::<Vec<_>>();
+
+        let front = individuals
+            .iter()
+            .filter(|&&a| individuals.iter().all(|&b| objective.dominance(b, a) != Ordering::Less))
+            .map(|individual| individual.fitness().collect::<Vec<_>>())
+            .collect();
+
+        self.fronts.push(front);
+    }
+
+    /// Returns recorded fronts in order of generations.
+    pub fn fronts(&self) -> &[Vec<Vec<f64>>] {
+        self.fronts.as_slice()
+    }
+
+    /// Dumps recorded fronts as json array of fronts where each front is an array of fitness vectors.
+    /// Non finite values are written as `null`.
+    pub fn to_json(&self) -> String {
+        let format_value = |value: &f64| if value.is_finite() { value.to_string() } else { "null".to_string() };
+        let format_array = |items: Vec<String>| format!("[{}]", items.join(","));
+
+        format_array(
+            self.fronts
+                .iter()
+                .map(|front| {
+                    format_array(
+                        front.iter().map(|fitness| format_array(fitness.iter().map(format_value).collect())).collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Represents a state of the search at specific generation.
+#[derive(Clone, Debug)]
+pub struct TrajectoryPoint {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Time since evolution started, in seconds.
+    pub elapsed: f64,
+    /// Fitness values of the best known individual.
+    pub best_fitness: Vec<f64>,
+    /// Amount of individuals in population.
+    pub population_size: usize,
+}
+
+/// Keeps a search trajectory: the best known fitness at each generation, e.g. to analyze convergence
+/// programmatically.
+#[derive(Clone, Debug, Default)]
+pub struct SearchTrajectory {
+    /// Recorded points in order of generations.
+    pub points: Vec<TrajectoryPoint>,
+}
+
+impl SearchTrajectory {
+    /// Records the best individual of the population at given generation. Nothing is recorded if
+    /// population is empty.
+    pub fn record<O, S>(&mut self, generation: usize, elapsed: f64, population: &DynHeuristicPopulation<O, S>)
+    where
+        O: HeuristicObjective<Solution = S>,
+        S: HeuristicSolution,
+    {
+        if let Some((best, _)) = population.ranked().next() {
+            self.points.push(TrajectoryPoint {
+                generation,
+                elapsed,
+                best_fitness: best.fitness().collect(),
+                population_size: population.size(),
+            });
+        }
+    }
+}
+
 /// Specifies a telemetry mode.
 #[derive(Clone)]
 pub enum TelemetryMode {
@@ -117,7 +210,14 @@ where
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: TelemetryMetrics {
+                duration: 0,
+                generations: 0,
+                speed: 0.0,
+                evolution: vec![],
+                fronts: ParetoFrontRecorder::default(),
+                trajectory: SearchTrajectory::default(),
+            },
             mode,
             statistics: Default::default(),
             improvement_tracker: ImprovementTracker::new(1000),
@@ -181,6 +281,11 @@ where
             }
         };
 
+        if track_population.is_some() {
+            self.metrics.fronts.record(objective, population);
+            self.metrics.trajectory.record(generation, self.time.elapsed_secs_as_f64(), population);
+        }
+
         if let Some((best_individual, rank)) = population.ranked().next() {
             let should_log_best = generation % *log_best.unwrap_or(&usize::MAX) == 0;
             let should_log_population = generation % *log_population.unwrap_or(&usize::MAX) == 0;
@@ -11,7 +11,8 @@ use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::Formatter;
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use std::io::{BufRead, Write};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 /// A collection of heuristic search operators with their name and initial weight.
@@ -36,6 +37,56 @@ where
     action_registry: SearchActionRegistry<C, O, S>,
     diversify_operators: HeuristicDiversifyOperators<C, O, S>,
     tracker: HeuristicTracker,
+    policy: Option<Arc<Mutex<HeuristicPolicy>>>,
+    stagnation_limit: Option<usize>,
+    stagnation_generations: usize,
+}
+
+/// Keeps learned estimates of search operators, identified by their names, for the best known and
+/// diverse search states. It can be saved after one run and loaded to warm start another one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HeuristicPolicy {
+    /// Operator estimates in the state with the best known solution.
+    pub best_known: Vec<(String, f64)>,
+    /// Operator estimates in the state with diverse solution.
+    pub diverse: Vec<(String, f64)>,
+}
+
+impl HeuristicPolicy {
+    /// Writes policy as csv lines in `state,name,estimate` format.
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), String> {
+        let best_known = self.best_known.iter().map(|entry| ("best_known", entry));
+        let diverse = self.diverse.iter().map(|entry| ("diverse", entry));
+
+        best_known
+            .chain(diverse)
+            .try_for_each(|(state, (name, estimate))| writeln!(writer, "{state},{name},{estimate}"))
+            .map_err(|err| format!("cannot write heuristic policy: {err}"))
+    }
+
+    /// Reads policy from csv lines in `state,name,estimate` format.
+    pub fn read<R: BufRead>(reader: R) -> Result<Self, String> {
+        reader.lines().try_fold(Self::default(), |mut policy, line| {
+            let line = line.map_err(|err| format!("cannot read heuristic policy: {err}"))?;
+            let line = line.trim();
+            if line.is_empty() {
+                return Ok(policy);
+            }
+
+            let parse_error = || format!("invalid heuristic policy line: '{line}'");
+            let (state, entry) = line.split_once(',').ok_or_else(parse_error)?;
+            let (name, estimate) = entry.rsplit_once(',').ok_or_else(parse_error)?;
+            let estimate = estimate.parse::<f64>().map_err(|_| parse_error())?;
+
+            match state {
+                "best_known" => policy.best_known.push((name.to_string(), estimate)),
+                "diverse" => policy.diverse.push((name.to_string(), estimate)),
+                _ => return Err(parse_error()),
+            }
+
+            Ok(policy)
+        })
+    }
 }
 
 impl<C, O, S> HyperHeuristic for DynamicSelective<C, O, S>
@@ -49,7 +100,7 @@ where
     type Solution = S;
 
     fn search(&mut self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
-        let agent = self.heuristic_simulator.run_episode(
+        let (agent, _) = self.heuristic_simulator.run_episode(
             SearchAgent {
                 heuristic_ctx,
                 original: solution,
@@ -91,7 +142,7 @@ where
             // NOTE use parallelism setting
             .run_episodes(agents, heuristic_ctx.environment().parallelism.clone(), get_state_reducer())
             .into_iter()
-            .filter_map(|agent| agent.solution.map(|solution| (solution, agent.samples)))
+            .filter_map(|(agent, _)| agent.solution.map(|solution| (solution, agent.samples)))
             .fold((Vec::new(), Vec::new()), |mut acc, (solution, runtime)| {
                 acc.0.push(solution);
                 acc.1.extend(runtime.into_iter());
@@ -158,6 +209,66 @@ where
                 overall_telemetry: Default::default(),
                 selection_telemetry: Default::default(),
             },
+            policy: None,
+            stagnation_limit: None,
+            stagnation_generations: 0,
+        }
+    }
+
+    /// Uses a shared heuristic policy: its content is loaded as initial operator estimates (operators
+    /// are matched by name) and it is updated with learned estimates during the search.
+    pub fn with_policy(mut self, policy: Arc<Mutex<HeuristicPolicy>>) -> Self {
+        let loaded = policy.lock().unwrap().clone();
+        let states = [
+            (SearchState::BestKnown(Default::default()), loaded.best_known),
+            (SearchState::Diverse(Default::default()), loaded.diverse),
+        ];
+
+        states.into_iter().filter(|(_, entries)| !entries.is_empty()).for_each(|(state, entries)| {
+            let mut estimates: HashMap<_, _> = self.initial_estimates[&state].data().clone();
+            entries.into_iter().for_each(|(name, estimate)| {
+                if let Some(heuristic_idx) = self.action_registry.heuristics.iter().position(|(_, n, _)| *n == name) {
+                    estimates.insert(SearchAction::Search { heuristic_idx }, estimate);
+                }
+            });
+
+            self.heuristic_simulator.set_action_estimates(state, ActionEstimates::from(estimates));
+        });
+
+        self.policy = Some(policy);
+
+        self
+    }
+
+    /// Resets learned operator estimates to uniform ones after given amount of consecutive generations
+    /// without new best known solution, so the search can re-explore all operators when it is stuck.
+    pub fn with_reset_after(mut self, generations: usize) -> Self {
+        self.stagnation_limit = Some(generations.max(1));
+        self
+    }
+
+    /// Returns the current heuristic policy.
+    pub fn get_policy(&self) -> HeuristicPolicy {
+        let get_entries = |state: SearchState| {
+            let estimates =
+                self.heuristic_simulator.get_state_estimates().get(&state).unwrap_or(&self.initial_estimates[&state]);
+            let mut entries = estimates
+                .data()
+                .iter()
+                .map(|(action, estimate)| match action {
+                    SearchAction::Search { heuristic_idx } => {
+                        (self.action_registry.heuristics[*heuristic_idx].1.clone(), *estimate)
+                    }
+                })
+                .collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            entries
+        };
+
+        HeuristicPolicy {
+            best_known: get_entries(SearchState::BestKnown(Default::default())),
+            diverse: get_entries(SearchState::Diverse(Default::default())),
         }
     }
 
@@ -175,6 +286,9 @@ where
         let termination_estimate = heuristic_ctx.statistics().termination_estimate;
         let random = heuristic_ctx.environment().random.clone();
         let generation = heuristic_ctx.statistics().generation;
+        let is_improved = samples.iter().any(|sample| {
+            matches!(sample.new_state, SearchState::BestMajorImprovement(_) | SearchState::BestMinorImprovement(_))
+        });
 
         self.heuristic_simulator.set_learning_strategy(create_learning_strategy(termination_estimate));
         self.heuristic_simulator.set_policy_strategy(create_policy_strategy(termination_estimate, random));
@@ -193,6 +307,37 @@ where
         self.tracker.observe_all_states(generation, self.heuristic_simulator.get_state_estimates(), |heuristic_idx| {
             self.action_registry.heuristics[heuristic_idx].1.clone()
         });
+
+        self.try_reset_estimates(is_improved);
+
+        if let Some(policy) = self.policy.as_ref() {
+            *policy.lock().unwrap() = self.get_policy();
+        }
+    }
+
+    fn try_reset_estimates(&mut self, is_improved: bool) {
+        let stagnation_limit = if let Some(stagnation_limit) = self.stagnation_limit {
+            stagnation_limit
+        } else {
+            return;
+        };
+
+        self.stagnation_generations = if is_improved { 0 } else { self.stagnation_generations + 1 };
+
+        if self.stagnation_generations < stagnation_limit {
+            return;
+        }
+
+        let uniform = (0..self.action_registry.heuristics.len())
+            .map(|heuristic_idx| (SearchAction::Search { heuristic_idx }, 1.))
+            .collect::<HashMap<_, _>>();
+        let uniform = ActionEstimates::from(uniform);
+
+        [SearchState::BestKnown(Default::default()), SearchState::Diverse(Default::default())]
+            .into_iter()
+            .for_each(|state| self.heuristic_simulator.set_action_estimates(state, uniform.clone()));
+
+        self.stagnation_generations = 0;
     }
 }
 
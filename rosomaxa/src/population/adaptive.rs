@@ -0,0 +1,155 @@
+#[cfg(test)]
+#[path = "../../tests/unit/population/adaptive_test.rs"]
+mod adaptive_test;
+
+use super::*;
+use crate::algorithms::math::get_cv_safe;
+use crate::utils::Random;
+use std::cmp::Ordering;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+/// Specifies configuration of `Adaptive` population.
+#[derive(Clone, Debug)]
+pub struct AdaptiveConfig {
+    /// A minimum population size.
+    pub min_size: usize,
+    /// A maximum population size.
+    pub max_size: usize,
+    /// A diversity below which population shrinks.
+    pub low_diversity: f64,
+    /// A diversity above which population grows.
+    pub high_diversity: f64,
+}
+
+/// A population which wraps `Elitism` and adjusts its maximum size within configured bounds on
+/// each generation: it grows while population diversity is high and shrinks when it collapses.
+/// Diversity is measured as a mean of fitness coefficient of variation over all objectives.
+pub struct Adaptive<O, S>
+where
+    O: HeuristicObjective<Solution = S> + Shuffled,
+    S: HeuristicSolution + DominanceOrdered,
+{
+    inner: Elitism<O, S>,
+    config: AdaptiveConfig,
+    max_population_size: usize,
+}
+
+impl<O, S> HeuristicPopulation for Adaptive<O, S>
+where
+    O: HeuristicObjective<Solution = S> + Shuffled,
+    S: HeuristicSolution + DominanceOrdered,
+{
+    type Objective = O;
+    type Individual = S;
+
+    fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
+        self.inner.add_all(individuals)
+    }
+
+    fn add(&mut self, individual: Self::Individual) -> bool {
+        self.inner.add(individual)
+    }
+
+    fn on_generation(&mut self, statistics: &HeuristicStatistics) {
+        let diversity = self.get_diversity();
+
+        let max_population_size = if diversity > self.config.high_diversity {
+            self.max_population_size + 1
+        } else if diversity < self.config.low_diversity {
+            self.max_population_size.saturating_sub(1)
+        } else {
+            self.max_population_size
+        };
+
+        self.max_population_size = max_population_size.clamp(self.config.min_size, self.config.max_size);
+        self.inner.set_max_population_size(self.max_population_size);
+
+        self.inner.on_generation(statistics);
+    }
+
+    fn cmp(&self, a: &Self::Individual, b: &Self::Individual) -> Ordering {
+        self.inner.cmp(a, b)
+    }
+
+    fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        self.inner.select()
+    }
+
+    fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Self::Individual, usize)> + 'a> {
+        self.inner.ranked()
+    }
+
+    fn ranked_detailed(&self) -> Vec<(&Self::Individual, usize, f64)> {
+        self.inner.ranked_detailed()
+    }
+
+    fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        self.inner.all()
+    }
+
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    fn selection_phase(&self) -> SelectionPhase {
+        self.inner.selection_phase()
+    }
+}
+
+impl<O, S> Adaptive<O, S>
+where
+    O: HeuristicObjective<Solution = S> + Shuffled,
+    S: HeuristicSolution + DominanceOrdered,
+{
+    /// Creates a new instance of `Adaptive` population which starts with minimum size.
+    pub fn new(
+        objective: Arc<O>,
+        random: Arc<dyn Random + Send + Sync>,
+        config: AdaptiveConfig,
+        selection_size: usize,
+    ) -> Result<Self, String> {
+        if config.min_size == 0 || config.min_size > config.max_size {
+            return Err(format!("invalid population size bounds: [{}, {}]", config.min_size, config.max_size));
+        }
+
+        if config.low_diversity > config.high_diversity {
+            return Err("low diversity threshold should not be greater than high one".to_string());
+        }
+
+        let max_population_size = config.min_size;
+        let inner = Elitism::new(objective, random, max_population_size, selection_size);
+
+        Ok(Self { inner, config, max_population_size })
+    }
+
+    /// Returns current maximum population size.
+    pub fn max_population_size(&self) -> usize {
+        self.max_population_size
+    }
+
+    fn get_diversity(&self) -> f64 {
+        let fitness = self.inner.all().map(|individual| individual.fitness().collect::<Vec<_>>()).collect::<Vec<_>>();
+        let objectives = fitness.first().map_or(0, |values| values.len());
+
+        if fitness.len() < 2 || objectives == 0 {
+            return 0.;
+        }
+
+        let total = (0..objectives)
+            .map(|idx| get_cv_safe(fitness.iter().map(|values| values[idx]).collect::<Vec<_>>().as_slice()).abs())
+            .sum::<f64>();
+
+        total / objectives as f64
+    }
+}
+
+impl<O, S> Display for Adaptive<O, S>
+where
+    O: HeuristicObjective<Solution = S> + Shuffled,
+    S: HeuristicSolution + DominanceOrdered,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
@@ -15,6 +15,9 @@ use std::sync::Arc;
 /// A function type to deduplicate individuals.
 pub type DedupFn<O, S> = Box<dyn Fn(&O, &S, &S) -> bool + Send + Sync>;
 
+/// A function type to get a secondary key of individual used as a tie-break in deduplication.
+pub type TieBreakFn<S> = Box<dyn Fn(&S) -> f64 + Send + Sync>;
+
 /// A simple evolution aware implementation of `Population` trait with the the following
 /// characteristics:
 ///
@@ -33,6 +36,7 @@ where
     individuals: Vec<S>,
     speed: Option<HeuristicSpeed>,
     dedup_fn: DedupFn<O, S>,
+    min_improvement: f64,
 }
 
 /// Keeps track of dominance order in the population for certain individual.
@@ -55,6 +59,7 @@ pub struct DominanceOrder {
     orig_index: usize,
     seq_index: usize,
     rank: usize,
+    crowding_distance: f64,
 }
 
 impl<O, S> HeuristicPopulation for Elitism<O, S>
@@ -109,6 +114,23 @@ where
         Box::new(self.individuals.iter().map(|individual| (individual, individual.get_order().rank)))
     }
 
+    fn ranked_detailed(&self) -> Vec<(&Self::Individual, usize, f64)> {
+        let mut individuals = self
+            .individuals
+            .iter()
+            .map(|individual| {
+                let order = individual.get_order();
+                (individual, order.rank, order.crowding_distance)
+            })
+            .collect::<Vec<_>>();
+
+        individuals.sort_by(|(_, a_rank, a_distance), (_, b_rank, b_distance)| {
+            a_rank.cmp(b_rank).then_with(|| compare_floats(*b_distance, *a_distance))
+        });
+
+        individuals
+    }
+
     fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
         Box::new(self.individuals.iter())
     }
@@ -139,17 +161,44 @@ where
             random,
             max_population_size,
             selection_size,
-            Box::new(|_, a, b| {
-                if a.get_order().rank == b.get_order().rank {
-                    // NOTE just using crowding distance here does not work
+            Box::new(|_, a, b| is_same_individual(a, b)),
+        )
+    }
+
+    /// Creates a new instance of `Elitism` seeded with `initial` individuals. They are added the same
+    /// way as with `add_all`, so the worst ranked ones are dropped if max population size is exceeded.
+    pub fn new_with_initial(
+        objective: Arc<O>,
+        random: Arc<dyn Random + Send + Sync>,
+        max_population_size: usize,
+        selection_size: usize,
+        initial: Vec<S>,
+    ) -> Self {
+        let mut population = Self::new(objective, random, max_population_size, selection_size);
+        population.add_all(initial);
 
-                    let fitness_a = a.fitness();
-                    let fitness_b = b.fitness();
+        population
+    }
 
-                    fitness_a.zip(fitness_b).all(|(a, b)| compare_floats(a, b) == Ordering::Equal)
-                } else {
-                    false
-                }
+    /// Creates a new instance of `Elitism` which keeps individuals with the same rank and fitness
+    /// if they have different secondary key returned by `tie_break_fn`.
+    pub fn new_with_tie_break(
+        objective: Arc<O>,
+        random: Arc<dyn Random + Send + Sync>,
+        max_population_size: usize,
+        selection_size: usize,
+        tie_break_fn: TieBreakFn<S>,
+    ) -> Self
+    where
+        S: 'static,
+    {
+        Self::new_with_dedup(
+            objective,
+            random,
+            max_population_size,
+            selection_size,
+            Box::new(move |_, a, b| {
+                is_same_individual(a, b) && compare_floats(tie_break_fn(a), tie_break_fn(b)) == Ordering::Equal
             }),
         )
     }
@@ -160,7 +209,8 @@ where
     {
         let best_known_fitness = self.individuals.first().map(|i| i.fitness().collect());
 
-        self.individuals.extend(iter);
+        let individuals = iter.filter(|individual| !self.is_marginal_improvement(individual)).collect::<Vec<_>>();
+        self.individuals.extend(individuals);
 
         self.sort();
         self.ensure_max_population_size();
@@ -176,7 +226,30 @@ where
         dedup_fn: DedupFn<O, S>,
     ) -> Self {
         assert!(max_population_size > 0);
-        Self { objective, random, selection_size, max_population_size, individuals: vec![], speed: None, dedup_fn }
+        Self {
+            objective,
+            random,
+            selection_size,
+            max_population_size,
+            individuals: vec![],
+            speed: None,
+            dedup_fn,
+            min_improvement: 0.,
+        }
+    }
+
+    /// Sets maximum population size and shrinks population if necessary.
+    pub fn set_max_population_size(&mut self, max_population_size: usize) {
+        assert!(max_population_size > 0);
+        self.max_population_size = max_population_size;
+        self.ensure_max_population_size();
+    }
+
+    /// Sets minimum improvement of fitness values: a new individual which is better than an existing
+    /// one, but does not improve any of its fitness values by more than `min_improvement`, is rejected.
+    pub fn set_min_improvement(&mut self, min_improvement: f64) {
+        assert!(min_improvement >= 0.);
+        self.min_improvement = min_improvement;
     }
 
     /// Shuffles objective function.
@@ -199,7 +272,12 @@ where
         let best_order = select_and_rank(self.individuals.as_slice(), self.individuals.len(), objective.as_ref())
             .into_iter()
             .zip(0..)
-            .map(|(acc, idx)| DominanceOrder { orig_index: acc.index, seq_index: idx, rank: acc.rank })
+            .map(|(acc, idx)| DominanceOrder {
+                orig_index: acc.index,
+                seq_index: idx,
+                rank: acc.rank,
+                crowding_distance: acc.crowding_distance,
+            })
             .collect::<Vec<_>>();
 
         assert_eq!(self.individuals.len(), best_order.len());
@@ -209,6 +287,17 @@ where
         self.individuals.dedup_by(|a, b| (self.dedup_fn)(&objective, a, b));
     }
 
+    fn is_marginal_improvement(&self, individual: &S) -> bool {
+        self.min_improvement > 0.
+            && self.individuals.iter().any(|existing| {
+                self.objective.total_order(individual, existing) == Ordering::Less
+                    && individual
+                        .fitness()
+                        .zip(existing.fitness())
+                        .all(|(new, old)| compare_floats(old - new, self.min_improvement) != Ordering::Greater)
+            })
+    }
+
     fn ensure_max_population_size(&mut self) {
         if self.individuals.len() > self.max_population_size {
             self.individuals.truncate(self.max_population_size);
@@ -247,3 +336,19 @@ where
         write!(f, "[{fitness}]")
     }
 }
+
+fn is_same_individual<S>(a: &S, b: &S) -> bool
+where
+    S: HeuristicSolution + DominanceOrdered,
+{
+    if a.get_order().rank == b.get_order().rank {
+        // NOTE just using crowding distance here does not work
+
+        let fitness_a = a.fitness();
+        let fitness_b = b.fitness();
+
+        fitness_a.zip(fitness_b).all(|(a, b)| compare_floats(a, b) == Ordering::Equal)
+    } else {
+        false
+    }
+}
@@ -1,5 +1,9 @@
 //! Specifies population types.
 
+mod adaptive;
+pub use self::adaptive::Adaptive;
+pub use self::adaptive::AdaptiveConfig;
+
 mod elitism;
 pub use self::elitism::DominanceOrder;
 pub use self::elitism::DominanceOrdered;
@@ -9,6 +13,9 @@ pub use self::elitism::Shuffled;
 mod greedy;
 pub use self::greedy::Greedy;
 
+mod niche;
+pub use self::niche::NichePopulation;
+
 mod rosomaxa;
 pub use self::rosomaxa::Rosomaxa;
 pub use self::rosomaxa::RosomaxaConfig;
@@ -57,6 +64,15 @@ pub trait HeuristicPopulation: Display + Send + Sync {
     /// Returns subset of individuals within their rank sorted according their quality.
     fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Self::Individual, usize)> + 'a>;
 
+    /// Returns individuals with their rank and crowding distance sorted by rank ascending and
+    /// crowding distance descending. Populations which do not track crowding distance report zero.
+    fn ranked_detailed(&self) -> Vec<(&Self::Individual, usize, f64)> {
+        let mut individuals = self.ranked().map(|(individual, rank)| (individual, rank, 0.)).collect::<Vec<_>>();
+        individuals.sort_by_key(|(_, rank, _)| *rank);
+
+        individuals
+    }
+
     /// Returns all individuals in arbitrary order.
     fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a>;
 
@@ -65,4 +81,31 @@ pub trait HeuristicPopulation: Display + Send + Sync {
 
     /// Returns a current selection phase.
     fn selection_phase(&self) -> SelectionPhase;
+
+    /// Returns individuals from the best rank which have the minimum value of each fitness dimension,
+    /// e.g. the cheapest one and the one with the least amount of unassigned jobs.
+    fn extremes(&self) -> Vec<Self::Individual> {
+        let best = self.ranked().filter(|(_, rank)| *rank == 0).map(|(individual, _)| individual).collect::<Vec<_>>();
+        let dimensions = best.first().map_or(0, |individual| individual.fitness().count());
+
+        (0..dimensions)
+            .filter_map(|idx| {
+                let get_value = |individual: &&Self::Individual| individual.fitness().nth(idx).unwrap_or(f64::MAX);
+
+                best.iter()
+                    .copied()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| compare_floats(get_value(a), get_value(b)))
+                    .map(|(position, _)| position)
+            })
+            .fold(Vec::<usize>::new(), |mut positions, position| {
+                if !positions.contains(&position) {
+                    positions.push(position);
+                }
+                positions
+            })
+            .into_iter()
+            .map(|position| best[position].deep_copy())
+            .collect()
+    }
 }
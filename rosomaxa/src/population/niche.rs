@@ -0,0 +1,150 @@
+#[cfg(test)]
+#[path = "../../tests/unit/population/niche_test.rs"]
+mod niche_test;
+
+use super::*;
+use crate::utils::Random;
+use crate::HeuristicStatistics;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Formatter;
+use std::iter::{empty, once};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A population which keeps the best individual per niche (similar to MAP-Elites algorithm):
+/// objective space is split into a grid of cells with equal size using bounds of each fitness
+/// dimension. Fitness values outside of the bounds are assigned to the border cells.
+pub struct NichePopulation<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    objective: Arc<O>,
+    random: Arc<dyn Random + Send + Sync>,
+    bounds: Vec<Range<f64>>,
+    cells: usize,
+    selection_size: usize,
+    niches: HashMap<Vec<usize>, S>,
+}
+
+impl<O, S> HeuristicPopulation for NichePopulation<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Objective = O;
+    type Individual = S;
+
+    fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
+        #[allow(clippy::unnecessary_fold)]
+        individuals.into_iter().fold(false, |acc, individual| self.add(individual) || acc)
+    }
+
+    fn add(&mut self, individual: Self::Individual) -> bool {
+        let is_best_known =
+            self.best().is_none_or(|best| self.objective.total_order(&individual, best) == Ordering::Less);
+
+        let niche = self.get_niche(&individual);
+        if let Some(existing) = self.niches.get(&niche) {
+            if self.objective.total_order(existing, &individual) != Ordering::Greater {
+                return false;
+            }
+        }
+
+        self.niches.insert(niche, individual);
+
+        is_best_known
+    }
+
+    fn on_generation(&mut self, _: &HeuristicStatistics) {}
+
+    fn cmp(&self, a: &Self::Individual, b: &Self::Individual) -> Ordering {
+        self.objective.total_order(a, b)
+    }
+
+    fn select<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        let best = if let Some(best) = self.best() { best } else { return Box::new(empty()) };
+        let individuals = self.niches.values().collect::<Vec<_>>();
+
+        Box::new(
+            once(best).chain(
+                (1..self.selection_size)
+                    .map(move |_| individuals[self.random.uniform_int(0, individuals.len() as i32 - 1) as usize]),
+            ),
+        )
+    }
+
+    fn ranked<'a>(&'a self) -> Box<dyn Iterator<Item = (&Self::Individual, usize)> + 'a> {
+        let mut individuals = self.niches.values().collect::<Vec<_>>();
+        individuals.sort_by(|a, b| self.objective.total_order(a, b));
+
+        Box::new(individuals.into_iter().enumerate().map(|(rank, individual)| (individual, rank)))
+    }
+
+    fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
+        Box::new(self.niches.values())
+    }
+
+    fn size(&self) -> usize {
+        self.niches.len()
+    }
+
+    fn selection_phase(&self) -> SelectionPhase {
+        SelectionPhase::Exploration
+    }
+}
+
+impl<O, S> Display for NichePopulation<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = if let Some(best) = self.best() {
+            best.fitness().map(|v| format!("{v:.7}")).collect::<Vec<_>>().join(",")
+        } else {
+            "".to_string()
+        };
+
+        write!(f, "[niches: {}, best: [{values}]]", self.niches.len())
+    }
+}
+
+impl<O, S> NichePopulation<O, S>
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `NichePopulation` which splits each fitness dimension specified
+    /// by `bounds` into `cells` of equal size.
+    pub fn new(
+        objective: Arc<O>,
+        random: Arc<dyn Random + Send + Sync>,
+        bounds: Vec<Range<f64>>,
+        cells: usize,
+        selection_size: usize,
+    ) -> Self {
+        assert!(!bounds.is_empty());
+        assert!(bounds.iter().all(|range| range.start < range.end));
+        assert!(cells > 0);
+
+        Self { objective, random, bounds, cells, selection_size, niches: HashMap::default() }
+    }
+
+    fn best(&self) -> Option<&S> {
+        self.niches.values().min_by(|a, b| self.objective.total_order(a, b))
+    }
+
+    fn get_niche(&self, individual: &S) -> Vec<usize> {
+        self.bounds
+            .iter()
+            .zip(individual.fitness())
+            .map(|(range, value)| {
+                let ratio = (value - range.start) / (range.end - range.start);
+
+                (ratio * self.cells as f64).floor().clamp(0., (self.cells - 1) as f64) as usize
+            })
+            .collect()
+    }
+}
@@ -82,6 +82,18 @@ where
     fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
         // NOTE avoid extra deep copy
         let best_known = self.elite.ranked().map(|(i, _)| i).next();
+
+        // NOTE individuals are not kept outside of elite in exploitation phase, so they can be moved
+        if let RosomaxaPhases::Exploitation { .. } = &self.phase {
+            let elite = individuals
+                .into_iter()
+                .filter(|individual| self.is_comparable_with_best_known(individual, best_known))
+                .map(init_individual)
+                .collect::<Vec<_>>();
+
+            return self.elite.add_all(elite);
+        }
+
         let elite = individuals
             .iter()
             .filter(|individual| self.is_comparable_with_best_known(individual, best_known))
@@ -105,11 +117,14 @@ where
     fn add(&mut self, individual: Self::Individual) -> bool {
         let best_known = self.elite.ranked().map(|(i, _)| i).next();
         let individual = init_individual(individual);
-        let is_improved = if self.is_comparable_with_best_known(&individual, best_known) {
-            self.elite.add(individual.deep_copy())
-        } else {
-            false
-        };
+        let is_comparable = self.is_comparable_with_best_known(&individual, best_known);
+
+        // NOTE individual is not kept outside of elite in exploitation phase, so it can be moved
+        if let RosomaxaPhases::Exploitation { .. } = &self.phase {
+            return is_comparable && self.elite.add(individual);
+        }
+
+        let is_improved = if is_comparable { self.elite.add(individual.deep_copy()) } else { false };
 
         match &mut self.phase {
             RosomaxaPhases::Initial { solutions: individuals } => individuals.push(individual),
@@ -167,6 +182,10 @@ where
         self.elite.ranked()
     }
 
+    fn ranked_detailed(&self) -> Vec<(&Self::Individual, usize, f64)> {
+        self.elite.ranked_detailed()
+    }
+
     fn all<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Individual> + 'a> {
         match &self.phase {
             RosomaxaPhases::Exploration { network, .. } => {
@@ -504,6 +523,10 @@ where
         self.population.add(input);
     }
 
+    fn add_all(&mut self, inputs: Vec<Self::Item>) {
+        self.population.add_all(inputs);
+    }
+
     fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = &Self::Item> + 'a> {
         Box::new(self.population.ranked().map(|(r, _)| r))
     }
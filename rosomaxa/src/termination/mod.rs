@@ -16,6 +16,12 @@ pub trait Termination {
 
     /// Returns a relative estimation till termination. Value is in the `[0, 1]` range.
     fn estimate(&self, heuristic_ctx: &Self::Context) -> f64;
+
+    /// Returns true if termination has a known end, so its estimation reflects actual progress.
+    /// Criteria based on convergence cannot tell in advance when they are met.
+    fn is_estimable(&self) -> bool {
+        true
+    }
 }
 
 mod min_variation;
@@ -68,4 +74,8 @@ where
     fn estimate(&self, heuristic_ctx: &Self::Context) -> f64 {
         self.terminations.iter().map(|t| t.estimate(heuristic_ctx)).max_by(compare_floats_refs).unwrap_or(0.)
     }
+
+    fn is_estimable(&self) -> bool {
+        self.terminations.iter().any(|t| t.is_estimable())
+    }
 }
@@ -54,4 +54,8 @@ where
     fn estimate(&self, _: &Self::Context) -> f64 {
         0.
     }
+
+    fn is_estimable(&self) -> bool {
+        false
+    }
 }
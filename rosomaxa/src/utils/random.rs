@@ -5,6 +5,9 @@ mod random_test;
 use rand::prelude::*;
 use rand::Error;
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
 
 /// Provides the way to use randomized values in generic way.
 pub trait Random {
@@ -27,11 +30,64 @@ pub trait Random {
 
     /// Returns RNG.
     fn get_rng(&self) -> RandomGen;
+
+    /// Returns a seed used to initialize random generator, if it is known.
+    fn get_seed(&self) -> Option<u64> {
+        None
+    }
+
+    /// Forks an independent random generator which can be used in parallel, e.g. by an operator
+    /// running on its own thread. By default, a seed of the fork is drawn from this generator.
+    fn fork(&self) -> Box<dyn Random + Send + Sync> {
+        Box::new(DefaultRandom::new_with_seed(self.get_rng().gen()))
+    }
+
+    /// Creates a random generator for a named consumer, e.g. a search operator, which should not
+    /// share its sequence with other consumers. By default, it is the same as `fork`.
+    fn substream(&self, _name: &str) -> Box<dyn Random + Send + Sync> {
+        self.fork()
+    }
+}
+
+/// A default random implementation. It is always initialized with a seed: either given one or
+/// generated randomly, so it can be reported and used to repeat the run. Please note, that results
+/// are repeatable only when random values are consumed in the same order, e.g. within single thread.
+pub struct DefaultRandom {
+    id: usize,
+    seed: u64,
+    forks: AtomicU64,
+    threads: AtomicU64,
+    alive: Arc<()>,
+}
+
+impl DefaultRandom {
+    /// Creates an instance of `DefaultRandom` initialized with given seed.
+    pub fn new_with_seed(seed: u64) -> Self {
+        let id = NEXT_INSTANCE_ID.fetch_add(1, Ordering::Relaxed);
+
+        Self { id, seed, forks: AtomicU64::new(0), threads: AtomicU64::new(0), alive: Arc::new(()) }
+    }
+
+    /// Creates an instance of `DefaultRandom` for a parallel worker with given index. Its seed is
+    /// derived from the seed of this instance and the worker index, so the runs are repeatable at
+    /// fixed amount of workers regardless of the order in which they are started.
+    pub fn new_for_worker(&self, worker_idx: usize) -> Self {
+        Self::new_with_seed(derive_seed(self.seed, worker_idx as u64))
+    }
 }
 
-/// A default random implementation.
-#[derive(Default)]
-pub struct DefaultRandom {}
+impl Default for DefaultRandom {
+    fn default() -> Self {
+        Self::new_with_seed(thread_rng().gen())
+    }
+}
+
+impl Drop for DefaultRandom {
+    fn drop(&mut self) {
+        // NOTE generators used by other threads are removed lazily once they add a new one
+        let _ = SEEDED_RNGS.try_with(|rngs| rngs.borrow_mut().remove(&self.id));
+    }
+}
 
 impl Random for DefaultRandom {
     fn uniform_int(&self, min: i32, max: i32) -> i32 {
@@ -71,68 +127,136 @@ impl Random for DefaultRandom {
     }
 
     fn get_rng(&self) -> RandomGen {
-        RandomGen::new_randomized()
+        SEEDED_RNGS.with(|rngs| {
+            let mut rngs = rngs.borrow_mut();
+            if !rngs.contains_key(&self.id) {
+                add_thread_rng(&mut rngs, self);
+            }
+        });
+
+        RandomGen { mode: RandomGenMode::Seeded { id: self.id, seed: self.seed } }
+    }
+
+    fn get_seed(&self) -> Option<u64> {
+        Some(self.seed)
+    }
+
+    /// Forks a generator which seed is derived from the seed of this instance and the amount of
+    /// previous forks, so forks are repeatable regardless of how many values were consumed from
+    /// this instance. Fork indices start after worker ones, so all derived seeds are distinct.
+    fn fork(&self) -> Box<dyn Random + Send + Sync> {
+        let fork_idx = self.forks.fetch_add(1, Ordering::Relaxed);
+
+        Box::new(Self::new_with_seed(derive_seed(self.seed, FIRST_FORK_IDX + fork_idx)))
+    }
+
+    /// Creates a sub-stream which seed is derived from the seed of this instance and the name only,
+    /// so its sequence is not affected by how many other sub-streams or forks were created before.
+    fn substream(&self, name: &str) -> Box<dyn Random + Send + Sync> {
+        Box::new(Self::new_with_seed(derive_seed(self.seed, hash_name(name))))
     }
 }
 
+/// An index used to derive a seed of the first fork: it is above any reasonable amount of workers.
+const FIRST_FORK_IDX: u64 = 1 << 32;
+
+/// Keeps the next id of `DefaultRandom` instance.
+static NEXT_INSTANCE_ID: AtomicUsize = AtomicUsize::new(0);
+
 thread_local! {
     /// Random generator seeded from thread_rng to make runs non-repeatable.
     static RANDOMIZED_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_rng(thread_rng()).expect("cannot get RNG from thread rng"));
 
     /// Random generator seeded with 0 SmallRng to make runs repeatable.
     static REPEATABLE_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(0));
+
+    /// Random generators of `DefaultRandom` instances used within the thread with a liveness
+    /// token of their instance.
+    static SEEDED_RNGS: RefCell<HashMap<usize, (Weak<()>, SmallRng)>> = RefCell::new(HashMap::new());
 }
 
 /// Provides underlying random generator API.
 #[derive(Clone, Debug)]
 pub struct RandomGen {
-    use_repeatable: bool,
+    mode: RandomGenMode,
+}
+
+#[derive(Clone, Debug)]
+enum RandomGenMode {
+    Repeatable,
+    Randomized,
+    Seeded { id: usize, seed: u64 },
 }
 
 impl RandomGen {
     /// Creates an instance of `RandomGen` using random generator with fixed seed.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { mode: RandomGenMode::Repeatable }
     }
 
     /// Creates an instance of `RandomGen` using random generator with randomized seed.
     pub fn new_randomized() -> Self {
-        Self { use_repeatable: false }
+        Self { mode: RandomGenMode::Randomized }
+    }
+
+    fn with_rng<R>(&self, func: impl FnOnce(&mut SmallRng) -> R) -> R {
+        // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
+        match &self.mode {
+            RandomGenMode::Seeded { id, seed } => SEEDED_RNGS.with(|rngs| {
+                // NOTE generator is added by the instance, it can be missing only when `RandomGen`
+                // is moved to another thread, then the thread uses its own generator for the seed
+                let mut rngs = rngs.borrow_mut();
+                let (_, rng) = rngs.entry(*id).or_insert_with(|| (Weak::new(), SmallRng::seed_from_u64(*seed)));
+
+                func(rng)
+            }),
+            RandomGenMode::Repeatable => REPEATABLE_RNG.with(|rng| func(&mut rng.borrow_mut())),
+            RandomGenMode::Randomized => RANDOMIZED_RNG.with(|rng| func(&mut rng.borrow_mut())),
+        }
     }
 }
 
+/// Adds a random generator of the instance for the current thread: each thread gets its own sequence
+/// derived from the seed and the order in which the thread has started to use the instance.
+/// Generators of already dropped instances are removed.
+fn add_thread_rng(rngs: &mut HashMap<usize, (Weak<()>, SmallRng)>, random: &DefaultRandom) {
+    rngs.retain(|_, (alive, _)| alive.strong_count() > 0);
+
+    let thread_idx = random.threads.fetch_add(1, Ordering::Relaxed);
+
+    rngs.insert(
+        random.id,
+        (Arc::downgrade(&random.alive), SmallRng::seed_from_u64(derive_seed(random.seed, thread_idx))),
+    );
+}
+
+/// Derives a seed of a child random generator from the parent seed and the child index. As the
+/// multiplier is odd, different indices always produce different seeds.
+fn derive_seed(seed: u64, idx: u64) -> u64 {
+    seed ^ idx.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+/// Hashes a name using FNV-1a: unlike std hashers, its result is guaranteed to be the same across
+/// platforms and compiler versions.
+fn hash_name(name: &str) -> u64 {
+    name.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3))
+}
+
 impl RngCore for RandomGen {
     fn next_u32(&mut self) -> u32 {
-        // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u32())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u32())
-        }
+        self.with_rng(|rng| rng.next_u32())
     }
 
     fn next_u64(&mut self) -> u64 {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u64())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u64())
-        }
+        self.with_rng(|rng| rng.next_u64())
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.fill_bytes(dest))
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.try_fill_bytes(dest))
     }
 }
 
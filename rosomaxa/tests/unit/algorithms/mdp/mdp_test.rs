@@ -1,5 +1,7 @@
 use super::*;
+use crate::utils::DefaultRandom;
 use crate::Environment;
+use std::sync::Arc;
 
 #[derive(Clone, Hash, Eq, PartialEq)]
 struct TestAction {
@@ -50,3 +52,327 @@ fn can_select_action_with_weights() {
     });
     assert!(result);
 }
+
+parameterized_test! {can_validate_learning_parameters, (alpha, gamma, is_valid), {
+    can_validate_learning_parameters_impl(alpha, gamma, is_valid);
+}}
+
+can_validate_learning_parameters! {
+    case01_valid: (0.2, 0.01, true),
+    case02_valid_bounds: (1., 0., true),
+    case03_valid_bounds: (1., 1., true),
+    case04_zero_alpha: (0., 0.5, false),
+    case05_big_alpha: (1.1, 0.5, false),
+    case06_negative_gamma: (0.5, -0.1, false),
+    case07_big_gamma: (0.5, 1.1, false),
+}
+
+fn can_validate_learning_parameters_impl(alpha: f64, gamma: f64, is_valid: bool) {
+    let q_learning = SimulatorBuilder::default()
+        .with_learning(Learning::QLearning { alpha, gamma, decay: AlphaDecay::Constant })
+        .build::<TestState>();
+    let monte_carlo = SimulatorBuilder::default().with_learning(Learning::MonteCarlo { alpha }).build::<TestState>();
+
+    assert_eq!(q_learning.is_ok(), is_valid);
+    assert_eq!(QLearning::try_new(alpha, gamma).is_ok(), is_valid);
+    assert_eq!(std::panic::catch_unwind(|| QLearning::new(alpha, gamma)).is_ok(), is_valid);
+    assert_eq!(monte_carlo.is_ok(), validate_learning_rate(alpha).is_ok());
+    assert_eq!(MonteCarlo::try_new(alpha).is_ok(), validate_learning_rate(alpha).is_ok());
+    assert_eq!(std::panic::catch_unwind(|| MonteCarlo::new(alpha)).is_ok(), validate_learning_rate(alpha).is_ok());
+}
+
+parameterized_test! {can_validate_alpha_decay, (decay, is_valid), {
+    can_validate_alpha_decay_impl(decay, is_valid);
+}}
+
+can_validate_alpha_decay! {
+    case01_constant: (AlphaDecay::Constant, true),
+    case02_harmonic: (AlphaDecay::Harmonic, true),
+    case03_exponential_valid: (AlphaDecay::Exponential { rate: 0.5 }, true),
+    case04_exponential_bound: (AlphaDecay::Exponential { rate: 1. }, true),
+    case05_exponential_big_rate: (AlphaDecay::Exponential { rate: 1.1 }, false),
+    case06_exponential_zero_rate: (AlphaDecay::Exponential { rate: 0. }, false),
+    case07_exponential_negative_rate: (AlphaDecay::Exponential { rate: -0.5 }, false),
+    case08_inverse_valid: (AlphaDecay::Inverse { decay: 0.5 }, true),
+    case09_inverse_zero: (AlphaDecay::Inverse { decay: 0. }, true),
+    case10_inverse_negative: (AlphaDecay::Inverse { decay: -1. }, false),
+    case11_inverse_nan: (AlphaDecay::Inverse { decay: f64::NAN }, false),
+}
+
+fn can_validate_alpha_decay_impl(decay: AlphaDecay, is_valid: bool) {
+    let simulator = SimulatorBuilder::default()
+        .with_learning(Learning::QLearning { alpha: 0.5, gamma: 0.5, decay })
+        .build::<TestState>();
+
+    assert_eq!(validate_alpha_decay(&decay).is_ok(), is_valid);
+    assert_eq!(simulator.is_ok(), is_valid);
+    assert_eq!(QLearning::try_new_with_decay(0.5, 0.5, decay).is_ok(), is_valid);
+    assert_eq!(std::panic::catch_unwind(|| QLearning::new_with_decay(0.5, 0.5, decay)).is_ok(), is_valid);
+}
+
+#[test]
+fn can_keep_exponentially_decayed_learning_rate_for_large_visits() {
+    let decay = AlphaDecay::Exponential { rate: 0.5 };
+
+    let alpha = decay.get_alpha(0.5, usize::MAX);
+
+    assert_eq!(alpha, 0.);
+}
+
+#[test]
+fn can_shrink_learning_rate_with_inverse_decay() {
+    let decay = AlphaDecay::Inverse { decay: 0.5 };
+
+    let alphas = (1..=4).map(|visits| decay.get_alpha(0.6, visits)).collect::<Vec<_>>();
+
+    alphas.iter().zip([0.6, 0.4, 0.3, 0.24]).for_each(|(&actual, expected)| {
+        assert!((actual - expected).abs() < 1E-9, "actual: {actual}, expected: {expected}")
+    });
+}
+
+#[test]
+fn can_converge_with_inverse_decay_on_alternating_rewards() {
+    let strategy = QLearning::new_with_decay(1., 0., AlphaDecay::Inverse { decay: 1. });
+    let estimates = ActionEstimates::<TestState>::default();
+
+    let values = (1..=1000).fold(vec![0.], |mut values, visits| {
+        let reward = if visits % 2 == 0 { 2. } else { 0. };
+        let old_value = *values.last().unwrap();
+        values.push(LearningStrategy::<TestState>::value_with_visits(&strategy, reward, old_value, visits, &estimates));
+        values
+    });
+
+    let last = values.last().copied().unwrap();
+    let last_change = (last - values[values.len() - 2]).abs();
+    assert!((last - 1.).abs() < 0.01, "unexpected value: {last}");
+    assert!(last_change < 0.01, "unexpected change: {last_change}");
+}
+
+#[test]
+fn can_ignore_visits_in_default_value_with_visits() {
+    let strategy = MonteCarlo::new(0.5);
+    let estimates = ActionEstimates::<TestState>::default();
+
+    let value = LearningStrategy::<TestState>::value(&strategy, 2., 1., &estimates);
+    let value_with_visits = LearningStrategy::<TestState>::value_with_visits(&strategy, 2., 1., 10, &estimates);
+
+    assert_eq!(value, 1.5);
+    assert_eq!(value_with_visits, value);
+}
+
+#[test]
+fn can_discretize_range_into_buckets() {
+    let range = 1.0..2.5;
+
+    let buckets = discretize(range.clone(), 3);
+
+    assert_eq!(buckets, vec![0, 1, 2]);
+    let bounds = buckets.into_iter().map(|bucket| get_bucket_range(range.clone(), 3, bucket)).collect::<Vec<_>>();
+    assert_eq!(bounds, vec![1.0..1.5, 1.5..2.0, 2.0..2.5]);
+}
+
+fn create_test_estimates() -> ActionEstimates<TestState> {
+    let mut estimates = ActionEstimates::<TestState>::default();
+    (0..6).for_each(|marker| estimates.insert(TestAction { marker }, marker as f64));
+    estimates.recalculate_min_max();
+
+    estimates
+}
+
+fn get_selected_markers(
+    strategy: &dyn PolicyStrategy<TestState>,
+    estimates: &ActionEstimates<TestState>,
+) -> Vec<usize> {
+    (0..100).map(|_| strategy.select(estimates).unwrap().marker).collect()
+}
+
+parameterized_test! {can_select_max_action_with_zero_epsilon, epsilon, {
+    can_select_max_action_with_zero_epsilon_impl(epsilon);
+}}
+
+can_select_max_action_with_zero_epsilon! {
+    case01_zero: 0.,
+    case02_negative_clamped: -0.5,
+}
+
+fn can_select_max_action_with_zero_epsilon_impl(epsilon: f64) {
+    let estimates = create_test_estimates();
+    let strategy = EpsilonGreedy::new(epsilon, Arc::new(DefaultRandom::new_with_seed(42)));
+
+    let markers = get_selected_markers(&strategy, &estimates);
+
+    assert!(markers.iter().all(|marker| *marker == 5));
+}
+
+#[test]
+fn can_select_different_actions_with_epsilon_one() {
+    let estimates = create_test_estimates();
+    let strategy = EpsilonGreedy::new(1., Arc::new(DefaultRandom::new_with_seed(42)));
+
+    let markers = get_selected_markers(&strategy, &estimates);
+
+    assert!(markers.iter().any(|marker| *marker != markers[0]));
+}
+
+#[test]
+fn can_return_none_for_empty_estimates() {
+    let estimates = ActionEstimates::<TestState>::default();
+    let random = Arc::new(DefaultRandom::new_with_seed(42));
+
+    assert!(PolicyStrategy::<TestState>::select(&EpsilonGreedy::new(0.5, random.clone()), &estimates).is_none());
+    assert!(PolicyStrategy::<TestState>::select(&EpsilonWeighted::new(0.5, random), &estimates).is_none());
+}
+
+parameterized_test! {can_break_ties_of_extreme_estimates_randomly, (is_max, estimates, tolerance), {
+    can_break_ties_of_extreme_estimates_randomly_impl(is_max, estimates, tolerance);
+}}
+
+can_break_ties_of_extreme_estimates_randomly! {
+    case_01_max_exact: (true, vec![1., 1., 1., 0.5], 0.),
+    case_02_max_tolerance: (true, vec![1., 0.999, 0.9995, 0.5], 0.01),
+    case_03_min_exact: (false, vec![-1., -1., -1., 0.5], 0.),
+    case_04_min_tolerance: (false, vec![-1., -0.999, -0.9995, 0.5], 0.01),
+}
+
+fn can_break_ties_of_extreme_estimates_randomly_impl(is_max: bool, values: Vec<f64>, tolerance: f64) {
+    let samples = 3000;
+    let random = DefaultRandom::new_with_seed(42);
+    let mut estimates = ActionEstimates::<TestState>::default();
+    values.iter().enumerate().for_each(|(marker, &value)| estimates.insert(TestAction { marker }, value));
+    estimates.recalculate_min_max();
+
+    let counts = (0..samples).fold(vec![0; values.len()], |mut acc, _| {
+        let (action, _) = if is_max {
+            estimates.max_estimate_with(&random, tolerance)
+        } else {
+            estimates.min_estimate_with(&random, tolerance)
+        }
+        .unwrap();
+        acc[action.marker] += 1;
+        acc
+    });
+
+    assert_eq!(counts[3], 0);
+    counts.iter().take(3).for_each(|&count| {
+        assert!((count as f64 / samples as f64 - 1. / 3.).abs() < 0.05, "unexpected counts: {counts:?}");
+    });
+}
+
+parameterized_test! {can_fallback_to_extreme_estimate_with_invalid_tolerance, tolerance, {
+    can_fallback_to_extreme_estimate_with_invalid_tolerance_impl(tolerance);
+}}
+
+can_fallback_to_extreme_estimate_with_invalid_tolerance! {
+    case_01_negative: -0.1,
+    case_02_nan: f64::NAN,
+    case_03_infinite: f64::INFINITY,
+}
+
+fn can_fallback_to_extreme_estimate_with_invalid_tolerance_impl(tolerance: f64) {
+    let random = DefaultRandom::new_with_seed(42);
+    let mut estimates = ActionEstimates::<TestState>::default();
+    vec![0.5, 1., 0.2]
+        .into_iter()
+        .enumerate()
+        .for_each(|(marker, value)| estimates.insert(TestAction { marker }, value));
+    estimates.recalculate_min_max();
+
+    (0..100).for_each(|_| {
+        assert_eq!(estimates.max_estimate_with(&random, tolerance).map(|(action, _)| action.marker), Some(1));
+        assert_eq!(estimates.min_estimate_with(&random, tolerance).map(|(action, _)| action.marker), Some(2));
+    });
+}
+
+#[test]
+fn can_return_none_for_empty_estimates_with_tie_break() {
+    let random = DefaultRandom::new_with_seed(42);
+    let estimates = ActionEstimates::<TestState>::default();
+
+    assert!(estimates.max_estimate_with(&random, 0.).is_none());
+    assert!(estimates.min_estimate_with(&random, 0.).is_none());
+}
+
+fn get_boltzmann_frequencies(temperature: f64, estimates: &ActionEstimates<TestState>, samples: usize) -> Vec<f64> {
+    let strategy = Boltzmann::new(temperature, Arc::new(DefaultRandom::new_with_seed(42)));
+
+    (0..samples)
+        .fold(vec![0; estimates.data().len()], |mut acc, _| {
+            acc[PolicyStrategy::<TestState>::select(&strategy, estimates).unwrap().marker] += 1;
+            acc
+        })
+        .into_iter()
+        .map(|count| count as f64 / samples as f64)
+        .collect()
+}
+
+#[test]
+fn can_select_actions_with_softmax_distribution() {
+    let mut estimates = ActionEstimates::<TestState>::default();
+    estimates.insert(TestAction { marker: 0 }, -2.);
+    estimates.insert(TestAction { marker: 1 }, -1.);
+    estimates.insert(TestAction { marker: 2 }, 0.5);
+    estimates.recalculate_min_max();
+    let temperature = 2.;
+    let weights = [-2_f64, -1., 0.5].iter().map(|estimate| (estimate / temperature).exp()).collect::<Vec<_>>();
+    let total = weights.iter().sum::<f64>();
+
+    let frequencies = get_boltzmann_frequencies(temperature, &estimates, 10000);
+
+    weights.iter().zip(frequencies.iter()).for_each(|(weight, frequency)| {
+        let expected = weight / total;
+        assert!((expected - frequency).abs() < 0.02, "expected: {expected}, actual: {frequency}");
+    });
+}
+
+parameterized_test! {can_use_temperature_to_control_boltzmann_selection, (temperature, expected), {
+    can_use_temperature_to_control_boltzmann_selection_impl(temperature, expected);
+}}
+
+can_use_temperature_to_control_boltzmann_selection! {
+    case01_low_temperature_greedy: (0.001, vec![0., 0., 1.]),
+    case02_high_temperature_uniform: (1E6, vec![1. / 3., 1. / 3., 1. / 3.]),
+}
+
+fn can_use_temperature_to_control_boltzmann_selection_impl(temperature: f64, expected: Vec<f64>) {
+    let mut estimates = ActionEstimates::<TestState>::default();
+    estimates.insert(TestAction { marker: 0 }, -1000.);
+    estimates.insert(TestAction { marker: 1 }, 0.);
+    estimates.insert(TestAction { marker: 2 }, 1000.);
+    estimates.recalculate_min_max();
+
+    let frequencies = get_boltzmann_frequencies(temperature, &estimates, 10000);
+
+    expected.iter().zip(frequencies.iter()).for_each(|(expected, frequency)| {
+        assert!((expected - frequency).abs() < 0.02, "expected: {expected}, actual: {frequency}");
+    });
+}
+
+#[test]
+fn can_return_none_for_empty_estimates_with_boltzmann() {
+    let strategy = Boltzmann::new(1., Arc::new(DefaultRandom::new_with_seed(42)));
+
+    assert!(PolicyStrategy::<TestState>::select(&strategy, &ActionEstimates::default()).is_none());
+}
+
+parameterized_test! {can_validate_boltzmann_temperature, (temperature, is_valid), {
+    can_validate_boltzmann_temperature_impl(temperature, is_valid);
+}}
+
+can_validate_boltzmann_temperature! {
+    case01_positive: (0.5, true),
+    case02_zero: (0., false),
+    case03_negative: (-1., false),
+    case04_nan: (f64::NAN, false),
+}
+
+fn can_validate_boltzmann_temperature_impl(temperature: f64, is_valid: bool) {
+    let random = Arc::new(DefaultRandom::new_with_seed(42));
+    let simulator = SimulatorBuilder::default()
+        .with_policy(Policy::Boltzmann { temperature })
+        .with_random(random.clone())
+        .build::<TestState>();
+
+    assert_eq!(Boltzmann::try_new(temperature, random.clone()).is_ok(), is_valid);
+    assert_eq!(simulator.is_ok(), is_valid);
+    assert_eq!(std::panic::catch_unwind(|| Boltzmann::new(temperature, random)).is_ok(), is_valid);
+}
@@ -1,5 +1,7 @@
 use super::*;
 use crate::helpers::utils::create_test_random;
+use crate::utils::DefaultRandom;
+use std::iter::once;
 use std::ops::Range;
 use std::sync::{Arc, RwLock};
 
@@ -188,3 +190,385 @@ fn can_run_grid_episodes_impl(
         assert_eq!((dx, dy), (e_dx, e_dy));
     }
 }
+
+#[test]
+fn can_build_simulator_and_train_agent() {
+    let mut simulator = SimulatorBuilder::default()
+        .with_learning(Learning::QLearning { alpha: 0.2, gamma: 0.01, decay: AlphaDecay::Constant })
+        .with_policy(Policy::Greedy)
+        .build::<GridState>()
+        .unwrap();
+
+    run_simulator(&mut simulator, 1000, 1, false, |counter| create_agent(GridState::OnGrid { x: 0, y: 0 }, counter));
+
+    let (GridAction::Move { dx, dy }, _) = simulator.get_optimal_policy(&GridState::OnGrid { x: 2, y: 3 }).unwrap();
+    assert_eq!((dx, dy), (1, 0));
+}
+
+#[test]
+fn can_build_simulator_with_random_policy_only_when_random_is_set() {
+    let builder = || SimulatorBuilder::default().with_policy(Policy::EpsilonGreedy { epsilon: 0.1 });
+
+    assert!(builder().build::<GridState>().is_err());
+    assert!(builder().with_random(create_test_random()).build::<GridState>().is_ok());
+}
+
+// NOTE with a small discount factor, shaping rewards bumping into a wall far from the terminal more
+// than a step costs, so an agent learns to stay at the wall instead of moving to the terminal
+const GAMMA: f64 = 0.9;
+
+struct ShapedGridAgent(GridAgent);
+
+impl Agent<GridState> for ShapedGridAgent {
+    fn get_state(&self) -> &GridState {
+        self.0.get_state()
+    }
+
+    fn get_actions(&self, state: &GridState) -> ActionEstimates<GridState> {
+        self.0.get_actions(state)
+    }
+
+    fn take_action(&mut self, action: &<GridState as State>::Action) {
+        self.0.take_action(action)
+    }
+
+    fn shape(&self, from: &GridState, to: &GridState) -> f64 {
+        let get_potential = |state: &GridState| match state {
+            GridState::OnGrid { x, y } => -((self.0.terminal.0 - x).abs() + (self.0.terminal.1 - y).abs()) as f64,
+            GridState::Terminal => 0.,
+        };
+
+        potential_based_shaping(GAMMA, get_potential(from), get_potential(to))
+    }
+}
+
+#[test]
+fn can_converge_to_the_same_policy_with_shaped_rewards() {
+    let create_simulator = || {
+        let random = Arc::new(DefaultRandom::new_with_seed(0));
+        Simulator::<GridState>::new(Box::new(QLearning::new(0.2, GAMMA)), Box::new(EpsilonGreedy::new(0.1, random)))
+    };
+    // NOTE episodes start from every cell to visit all checked states
+    let starts = (0..4).flat_map(|x| (0..4).map(move |y| (x, y))).filter(|&start| start != (3, 3)).collect::<Vec<_>>();
+    let new_agent = |(x, y): (i32, i32)| create_agent(GridState::OnGrid { x, y }, Arc::new(RwLock::new(vec![])));
+    let (mut unshaped, mut shaped) = (create_simulator(), create_simulator());
+
+    (0..5000).map(|idx| starts[idx % starts.len()]).for_each(|start| {
+        unshaped.run_episode(new_agent(start), |_, values| values[0]);
+        shaped.run_episode(ShapedGridAgent(new_agent(start)), |_, values| values[0]);
+    });
+
+    let get_move = |simulator: &Simulator<GridState>, x: i32, y: i32| {
+        let (GridAction::Move { dx, dy }, _) = simulator.get_optimal_policy(&GridState::OnGrid { x, y }).unwrap();
+        (dx, dy)
+    };
+    for &((x, y), expected) in
+        &[((2, 3), (1, 0)), ((1, 3), (1, 0)), ((0, 3), (1, 0)), ((3, 2), (0, 1)), ((3, 1), (0, 1)), ((3, 0), (0, 1))]
+    {
+        assert_eq!(get_move(&unshaped, x, y), expected);
+        assert_eq!(get_move(&shaped, x, y), expected);
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+enum CoinState {
+    Start,
+    End { reward: i32 },
+}
+
+impl State for CoinState {
+    type Action = ();
+
+    fn reward(&self) -> f64 {
+        match self {
+            CoinState::Start => 0.,
+            CoinState::End { reward } => *reward as f64,
+        }
+    }
+}
+
+struct CoinAgent {
+    state: CoinState,
+    random: Arc<dyn Random + Send + Sync>,
+}
+
+impl Agent<CoinState> for CoinAgent {
+    fn get_state(&self) -> &CoinState {
+        &self.state
+    }
+
+    fn get_actions(&self, state: &CoinState) -> ActionEstimates<CoinState> {
+        match state {
+            CoinState::Start => ActionEstimates::from(once(((), 0.)).collect::<HashMap<_, _>>()),
+            CoinState::End { .. } => ActionEstimates::default(),
+        }
+    }
+
+    fn take_action(&mut self, _: &()) {
+        self.state = CoinState::End { reward: if self.random.is_head_not_tails() { 10 } else { 0 } };
+    }
+}
+
+fn get_estimate_spread(decay: AlphaDecay) -> f64 {
+    let random = create_test_random();
+    let mut simulator = Simulator::new(Box::new(QLearning::new_with_decay(0.5, 0., decay)), Box::<Greedy>::default());
+
+    let estimates = (0..400)
+        .map(|_| {
+            simulator.run_episode(CoinAgent { state: CoinState::Start, random: random.clone() }, |_, values| values[0]);
+            *simulator.get_state_estimates().get(&CoinState::Start).unwrap().data().get(&()).unwrap()
+        })
+        .skip(200)
+        .collect::<Vec<_>>();
+
+    let (min, max) =
+        estimates.iter().fold((f64::MAX, f64::MIN), |(min, max), value| (min.min(*value), max.max(*value)));
+
+    max - min
+}
+
+#[test]
+fn can_converge_tighter_with_decaying_alpha_in_stochastic_environment() {
+    let constant = get_estimate_spread(AlphaDecay::Constant);
+    let harmonic = get_estimate_spread(AlphaDecay::Harmonic);
+    let inverse = get_estimate_spread(AlphaDecay::Inverse { decay: 0.5 });
+
+    assert!(harmonic < constant, "harmonic: {harmonic}, constant: {constant}");
+    assert!(inverse < constant, "inverse: {inverse}, constant: {constant}");
+}
+
+#[test]
+fn can_count_state_action_visits() {
+    let random = create_test_random();
+    let mut simulator = Simulator::new(Box::new(QLearning::new(0.5, 0.)), Box::<Greedy>::default());
+
+    (0..3).for_each(|_| {
+        simulator.run_episode(CoinAgent { state: CoinState::Start, random: random.clone() }, |_, values| values[0]);
+    });
+
+    assert_eq!(simulator.get_state_visits().get(&CoinState::Start).and_then(|actions| actions.get(&())), Some(&3));
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct ChainState(i32);
+
+impl State for ChainState {
+    type Action = ();
+
+    fn reward(&self) -> f64 {
+        1.
+    }
+
+    fn is_terminal(&self, _: &ActionEstimates<Self>) -> bool {
+        self.0 == 2
+    }
+}
+
+struct ChainAgent {
+    state: ChainState,
+    steps: usize,
+}
+
+impl Agent<ChainState> for ChainAgent {
+    fn get_state(&self) -> &ChainState {
+        &self.state
+    }
+
+    fn get_actions(&self, _: &ChainState) -> ActionEstimates<ChainState> {
+        // NOTE terminal state still has an action with non-zero estimate
+        ActionEstimates::from(once(((), 100.)).collect::<HashMap<_, _>>())
+    }
+
+    fn take_action(&mut self, _: &()) {
+        self.steps += 1;
+        self.state = ChainState(self.state.0 + 1);
+    }
+}
+
+#[test]
+fn can_stop_episode_and_skip_updates_at_terminal_state() {
+    let mut simulator = Simulator::new(Box::new(QLearning::new(1., 1.)), Box::<Greedy>::default());
+
+    let (agent, termination) =
+        simulator.run_episode(ChainAgent { state: ChainState(0), steps: 0 }, |_, values| values[0]);
+
+    let get_estimate = |state: i32| {
+        simulator.get_state_estimates().get(&ChainState(state)).and_then(|estimates| estimates.data().get(&())).copied()
+    };
+    assert_eq!(termination, EpisodeTermination::Natural);
+    assert_eq!(agent.steps, 2);
+    assert_eq!(agent.state.0, 2);
+    assert_eq!(get_estimate(1), Some(1.));
+    assert_eq!(get_estimate(2), Some(100.));
+    assert!(simulator.get_state_visits().get(&ChainState(2)).is_none());
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct CycleState(bool);
+
+impl State for CycleState {
+    type Action = ();
+
+    fn reward(&self) -> f64 {
+        -1.
+    }
+}
+
+struct CycleAgent {
+    state: CycleState,
+    steps: usize,
+}
+
+impl Agent<CycleState> for CycleAgent {
+    fn get_state(&self) -> &CycleState {
+        &self.state
+    }
+
+    fn get_actions(&self, _: &CycleState) -> ActionEstimates<CycleState> {
+        ActionEstimates::from(once(((), 0.)).collect::<HashMap<_, _>>())
+    }
+
+    fn take_action(&mut self, _: &()) {
+        self.steps += 1;
+        self.state = CycleState(!self.state.0);
+    }
+}
+
+#[test]
+fn can_stop_episode_at_max_steps_in_cyclic_environment() {
+    let mut simulator = SimulatorBuilder::default().with_max_steps(5).build::<CycleState>().unwrap();
+
+    let results = simulator.run_episodes(
+        (0..2).map(|_| CycleAgent { state: CycleState(false), steps: 0 }).collect(),
+        Parallelism::default(),
+        |_, values| values[0],
+    );
+
+    assert_eq!(results.len(), 2);
+    results.iter().for_each(|(agent, termination)| {
+        assert_eq!(*termination, EpisodeTermination::StepCapped);
+        assert_eq!(agent.steps, 5);
+    });
+    let visits = simulator.get_state_visits().values().flat_map(|actions| actions.values()).sum::<usize>();
+    assert_eq!(visits, 10);
+}
+
+#[test]
+fn can_track_discounted_episode_returns() {
+    let mut simulator = SimulatorBuilder::default()
+        .with_learning(Learning::QLearning { alpha: 0.5, gamma: 0.5, decay: AlphaDecay::Constant })
+        .with_max_steps(3)
+        .build::<CycleState>()
+        .unwrap();
+
+    (0..2).for_each(|_| {
+        simulator.run_episode(CycleAgent { state: CycleState(false), steps: 0 }, |_, values| values[0]);
+    });
+
+    // NOTE each step gives -1: -1 + 0.5 * -1 + 0.25 * -1
+    assert_eq!(simulator.episode_returns(), &[-1.75, -1.75]);
+}
+
+#[test]
+fn can_evaluate_trained_policy_against_random_policy() {
+    let start = GridState::OnGrid { x: 0, y: 0 };
+    let mut simulator = SimulatorBuilder::default().build::<GridState>().unwrap();
+    run_simulator(&mut simulator, 1000, 1, false, |counter| create_agent(start.clone(), counter));
+    simulator.set_max_steps(Some(100));
+    simulator.set_return_discount(1.);
+    let env = || create_agent(start.clone(), Arc::new(RwLock::new(vec![])));
+
+    let trained = simulator.evaluate_policy(env, &Greedy, 10);
+    let random = simulator.evaluate_policy(env, &EpsilonGreedy::new(1., create_test_random()), 100);
+
+    // NOTE optimal path has 5 moves on grid and one move to terminal state
+    assert_eq!(trained, 5.);
+    assert!(trained > random, "trained: {trained}, random: {random}");
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+enum ChoiceState {
+    Start,
+    End(bool),
+}
+
+impl State for ChoiceState {
+    type Action = bool;
+
+    fn reward(&self) -> f64 {
+        match self {
+            ChoiceState::Start => 0.,
+            ChoiceState::End(is_right) => {
+                if *is_right {
+                    2.
+                } else {
+                    1.
+                }
+            }
+        }
+    }
+
+    fn is_terminal(&self, _: &ActionEstimates<Self>) -> bool {
+        matches!(self, ChoiceState::End(_))
+    }
+}
+
+struct ChoiceAgent {
+    state: ChoiceState,
+}
+
+impl Agent<ChoiceState> for ChoiceAgent {
+    fn get_state(&self) -> &ChoiceState {
+        &self.state
+    }
+
+    fn get_actions(&self, _: &ChoiceState) -> ActionEstimates<ChoiceState> {
+        ActionEstimates::from(vec![(false, 0.), (true, 0.)].into_iter().collect::<HashMap<_, _>>())
+    }
+
+    fn take_action(&mut self, action: &bool) {
+        self.state = ChoiceState::End(*action);
+    }
+}
+
+fn create_choice_simulator() -> Simulator<ChoiceState> {
+    SimulatorBuilder::default()
+        .with_learning(Learning::QLearning { alpha: 0.5, gamma: 0., decay: AlphaDecay::Constant })
+        .with_policy(Policy::EpsilonGreedy { epsilon: 0.5 })
+        .with_random(Arc::new(DefaultRandom::new_with_seed(42)))
+        .build()
+        .unwrap()
+}
+
+fn get_choice_estimates(simulator: &Simulator<ChoiceState>) -> Vec<f64> {
+    let estimates = simulator.get_state_estimates().get(&ChoiceState::Start).unwrap().data();
+
+    vec![*estimates.get(&false).unwrap(), *estimates.get(&true).unwrap()]
+}
+
+#[test]
+fn can_converge_to_the_same_policy_with_parallel_and_sequential_training() {
+    let create_agent = || ChoiceAgent { state: ChoiceState::Start };
+    let mut sequential = create_choice_simulator();
+    let mut parallel = create_choice_simulator();
+
+    (0..100).for_each(|_| {
+        sequential.run_episode(create_agent(), |_, values| values[0]);
+    });
+    // NOTE averaging includes unchanged estimates of agents which have not taken the action, so
+    // parallel training needs more rounds to converge
+    (0..100).for_each(|_| {
+        parallel.run_episodes((0..5).map(|_| create_agent()).collect(), Parallelism::default(), |_, values| {
+            values.iter().sum::<f64>() / values.len() as f64
+        });
+    });
+
+    assert_eq!(sequential.get_optimal_policy(&ChoiceState::Start).map(|(action, _)| action), Some(true));
+    assert_eq!(parallel.get_optimal_policy(&ChoiceState::Start).map(|(action, _)| action), Some(true));
+    get_choice_estimates(&sequential).into_iter().zip(get_choice_estimates(&parallel)).zip([1., 2.]).for_each(
+        |((sequential, parallel), expected)| {
+            assert!((sequential - expected).abs() < 0.05);
+            assert!((parallel - expected).abs() < 0.05);
+        },
+    );
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+fn create_front() -> Vec<Vec<f64>> {
+    vec![vec![0., 10.], vec![0.5, 6.], vec![4., 4.], vec![10., 0.]]
+}
+
+parameterized_test! {can_aggregate_front, (method, expected), {
+    can_aggregate_front_impl(method, expected);
+}}
+
+can_aggregate_front! {
+    case_01_best_per_objective: (FrontAggregation::BestPerObjective, vec![0., 0.]),
+    case_02_knee_point: (FrontAggregation::KneePoint, vec![0.5, 6.]),
+    case_03_utopia_distance: (FrontAggregation::UtopiaDistance, vec![4., 4.]),
+}
+
+fn can_aggregate_front_impl(method: FrontAggregation, expected: Vec<f64>) {
+    let result = aggregate_front(create_front().as_slice(), method);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_return_front_member_for_knee_point() {
+    let front = create_front();
+
+    let result = aggregate_front(front.as_slice(), FrontAggregation::KneePoint);
+
+    assert!(front.contains(&result));
+}
+
+#[test]
+fn can_handle_degenerate_fronts() {
+    assert!(aggregate_front(&[], FrontAggregation::KneePoint).is_empty());
+    assert_eq!(aggregate_front(&[vec![1., 2.]], FrontAggregation::UtopiaDistance), vec![1., 2.]);
+    assert_eq!(aggregate_front(&[vec![1., 2.], vec![1., 1.]], FrontAggregation::KneePoint), vec![1., 1.]);
+}
@@ -91,3 +91,60 @@ fn can_use_select_and_rank_with_non_transient_relationship_by_hierarchical_objec
 
     assert_eq!(ranked.len(), solutions.len())
 }
+
+parameterized_test! {can_return_one_entry_per_solution, solutions, {
+    can_return_one_entry_per_solution_impl(solutions);
+}}
+
+can_return_one_entry_per_solution! {
+    case01_single: &[vec![1., 2.]],
+    case02_identical_pair: &[vec![1., 2.], vec![1., 2.]],
+}
+
+fn can_return_one_entry_per_solution_impl(solutions: &[Vec<f64>]) {
+    let mo = SliceMultiObjective::new(vec![
+        Arc::new(SliceDimensionObjective::new(0)),
+        Arc::new(SliceDimensionObjective::new(1)),
+    ]);
+
+    let ranked = select_and_rank(solutions, solutions.len(), &mo);
+
+    assert_eq!(ranked.len(), solutions.len());
+    assert!(ranked.iter().all(|assigned| assigned.rank == 0));
+    let mut indices = ranked.iter().map(|assigned| assigned.index).collect::<Vec<_>>();
+    indices.sort();
+    assert_eq!(indices, (0..solutions.len()).collect::<Vec<_>>());
+}
+
+fn create_tied_population() -> (Vec<Vec<f64>>, SliceHierarchicalObjective) {
+    // NOTE the third dimension is an id which is not a part of objective
+    let population = (0..20).map(|idx| vec![1., 1., idx as f64]).collect::<Vec<_>>();
+    let mo = SliceHierarchicalObjective::new(
+        vec![Arc::new(SliceDimensionObjective::new(0)), Arc::new(SliceDimensionObjective::new(1))],
+        vec![],
+    );
+
+    (population, mo)
+}
+
+#[test]
+fn can_use_select_and_rank_with_reproducible_order_of_ties() {
+    let (population, mo) = create_tied_population();
+    let get_indices = || select_and_rank(&population, 10, &mo).iter().map(|s| s.index).collect::<Vec<_>>();
+
+    let results = (0..10).map(|_| get_indices()).collect::<Vec<_>>();
+
+    assert!(results.windows(2).all(|pair| pair[0] == pair[1]));
+    // NOTE the first and the last solutions are extremes with infinite crowding distance
+    assert_eq!(results[0], vec![0, 19, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn can_use_select_and_rank_with_custom_tie_break() {
+    let (population, mo) = create_tied_population();
+    let tie_break = |a: &Vec<f64>, b: &Vec<f64>| compare_floats(b[2], a[2]);
+
+    let results = select_and_rank_with_tie_break(&population, 5, &mo, &tie_break);
+
+    assert_eq!(results.iter().map(|s| s.index).collect::<Vec<_>>(), vec![19, 0, 18, 17, 16]);
+}
@@ -0,0 +1,95 @@
+use super::*;
+use crate::algorithms::nsga2::select_and_rank;
+use crate::helpers::algorithms::nsga2::*;
+use std::sync::Arc;
+
+fn create_multi_objective(dimensions: usize) -> SliceHierarchicalObjective {
+    SliceHierarchicalObjective::new(
+        (0..dimensions).map(|dimension| Arc::new(SliceDimensionObjective::new(dimension)) as SliceObjective).collect(),
+        vec![],
+    )
+}
+
+fn get_ranks(solutions: &[Vec<f64>], objective: &impl MultiObjective<Solution = Vec<f64>>) -> Vec<usize> {
+    let mut ranks = select_and_rank(solutions, solutions.len(), objective)
+        .into_iter()
+        .map(|assigned| (assigned.index, assigned.rank))
+        .collect::<Vec<_>>();
+    ranks.sort_by_key(|(index, _)| *index);
+
+    ranks.into_iter().map(|(_, rank)| rank).collect()
+}
+
+#[test]
+fn can_limit_objectives_considered_for_dominance() {
+    let solutions =
+        vec![vec![1., 1., 5., 5., 5.], vec![2., 2., 4., 4., 4.], vec![3., 3., 3., 3., 1.], vec![4., 4., 1., 2., 3.]];
+    let limited = LimitedDominanceObjective::new(create_multi_objective(5), 2);
+
+    let full_ranks = get_ranks(&solutions, &create_multi_objective(5));
+    let limited_ranks = get_ranks(&solutions, &limited);
+
+    assert_eq!(full_ranks, vec![0, 0, 0, 0]);
+    assert_eq!(limited_ranks, vec![0, 1, 2, 3]);
+    assert_eq!(limited.size(), 5);
+    assert_eq!(limited.fitness(&solutions[3]).collect::<Vec<_>>(), solutions[3]);
+    assert_eq!(limited.get_order(&solutions[2], &solutions[3], 4), Ok(Ordering::Less));
+}
+
+parameterized_test! {can_get_dominance_of_two_solutions, (a, b, expected), {
+    can_get_dominance_of_two_solutions_impl(a, b, expected);
+}}
+
+can_get_dominance_of_two_solutions! {
+    case_01_strict_dominance: (vec![1., 2.], vec![2., 3.], Ordering::Less),
+    case_02_weak_dominance: (vec![1., 2.], vec![1., 3.], Ordering::Less),
+    case_03_reverse_dominance: (vec![2., 3.], vec![1., 2.], Ordering::Greater),
+    case_04_incomparable: (vec![1., 3.], vec![2., 2.], Ordering::Equal),
+    case_05_same: (vec![1., 2.], vec![1., 2.], Ordering::Equal),
+}
+
+fn can_get_dominance_of_two_solutions_impl(a: Vec<f64>, b: Vec<f64>, expected: Ordering) {
+    let objective = create_multi_objective(2);
+
+    assert_eq!(objective.dominance(&a, &b), expected);
+}
+
+struct RewardObjective;
+
+impl Objective for RewardObjective {
+    type Solution = Vec<f64>;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution[1]
+    }
+
+    fn direction(&self) -> ObjectiveDirection {
+        ObjectiveDirection::Maximize
+    }
+}
+
+parameterized_test! {can_use_objective_direction_in_weighted_sum, (a, b, expected), {
+    can_use_objective_direction_in_weighted_sum_impl(a, b, expected);
+}}
+
+can_use_objective_direction_in_weighted_sum! {
+    case01_same_cost_more_reward: (vec![10., 8.], vec![10., 5.], Ordering::Less),
+    case02_same_cost_less_reward: (vec![10., 5.], vec![10., 8.], Ordering::Greater),
+    case03_more_cost_much_more_reward: (vec![12., 8.], vec![10., 5.], Ordering::Less),
+    case04_less_cost_less_reward: (vec![10., 5.], vec![11., 6.], Ordering::Equal),
+}
+
+fn can_use_objective_direction_in_weighted_sum_impl(a: Vec<f64>, b: Vec<f64>, expected: Ordering) {
+    let objective = WeightedSumObjective::new(vec![
+        (Arc::new(SliceDimensionObjective::new(0)) as SharedObjective<Vec<f64>>, 1.),
+        (Arc::new(RewardObjective) as SharedObjective<Vec<f64>>, 1.),
+    ]);
+
+    assert_eq!(objective.total_order(&a, &b), expected);
+}
+
+#[test]
+fn can_prefer_higher_fitness_of_maximized_objective() {
+    assert_eq!(RewardObjective.total_order(&vec![0., 2.], &vec![0., 1.]), Ordering::Less);
+    assert_eq!(SliceDimensionObjective::new(1).total_order(&vec![0., 2.], &vec![0., 1.]), Ordering::Greater);
+}
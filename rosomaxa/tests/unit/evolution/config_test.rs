@@ -0,0 +1,57 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::helpers::example::create_example_objective;
+use crate::utils::Environment;
+use crate::{get_default_population, get_default_selection_size, TelemetryMode};
+
+struct NoopHeuristicOperator;
+
+impl HeuristicSearchOperator for NoopHeuristicOperator {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        solution.deep_copy()
+    }
+}
+
+parameterized_test! {can_configure_heuristic_reset_after, (reset_after, expected), {
+    can_configure_heuristic_reset_after_impl(reset_after, expected);
+}}
+
+can_configure_heuristic_reset_after! {
+    case_01_not_set: (None, false),
+    case_02_set: (Some(10), true),
+}
+
+fn can_configure_heuristic_reset_after_impl(reset_after: Option<usize>, expected: bool) {
+    let messages = Arc::new(Mutex::new(Vec::<String>::new()));
+    let environment = Arc::new(Environment {
+        logger: Arc::new({
+            let messages = messages.clone();
+            move |msg: &str| messages.lock().unwrap().push(msg.to_string())
+        }),
+        ..Environment::default()
+    });
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let population = get_default_population(objective.clone(), environment.clone(), selection_size);
+    let context = VectorContext::new(objective.clone(), population, TelemetryMode::None, environment);
+
+    let config = EvolutionConfigBuilder::default()
+        .with_objective(objective)
+        .with_context(context)
+        .with_search_operators(vec![(Arc::new(NoopHeuristicOperator), "noop".to_string(), 1.)])
+        .with_diversify_operators(vec![])
+        .with_heuristic_reset_after(reset_after)
+        .build();
+
+    assert!(config.is_ok());
+    let is_configured = messages
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|msg| msg == "configured to reset heuristic estimates after 10 generations");
+    assert_eq!(is_configured, expected);
+}
@@ -1,6 +1,7 @@
 use super::*;
 use crate::example::*;
 use crate::helpers::example::create_example_objective;
+use crate::population::Elitism;
 use crate::utils::compare_floats;
 use crate::{get_default_population, get_default_selection_size};
 use std::cmp::Ordering;
@@ -41,3 +42,85 @@ fn can_update_statistic() {
     telemetry.on_generation(objective, population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+fn is_dominated(a: &[f64], b: &[f64]) -> bool {
+    b.iter().zip(a.iter()).all(|(b, a)| b <= a) && b.iter().zip(a.iter()).any(|(b, a)| b < a)
+}
+
+#[test]
+fn can_record_pareto_front_per_generation() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let mut population: Box<DynHeuristicPopulation<VectorObjective, VectorSolution>> =
+        Box::new(Elitism::new(objective.clone(), environment.random.clone(), 8, 2));
+    let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 100 });
+    let generations = 5;
+
+    (0..generations).for_each(|idx| {
+        population.add_all(vec![
+            VectorSolution::new(vec![idx as f64, 1.], objective.clone()),
+            VectorSolution::new(vec![1., idx as f64], objective.clone()),
+        ]);
+        telemetry.on_generation(objective.as_ref(), population.as_ref(), 0., Timer::start(), false);
+    });
+
+    let metrics = telemetry.take_metrics().expect("no metrics");
+    let fronts = metrics.fronts.fronts();
+    assert_eq!(fronts.len(), generations);
+    fronts.iter().for_each(|front| {
+        assert!(!front.is_empty());
+        front.iter().for_each(|a| assert!(front.iter().all(|b| !is_dominated(a, b))));
+    });
+}
+
+#[test]
+fn can_dump_pareto_fronts_to_json() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let mut population: Box<DynHeuristicPopulation<VectorObjective, VectorSolution>> =
+        Box::new(Elitism::new(objective.clone(), environment.random.clone(), 8, 2));
+    let mut recorder = ParetoFrontRecorder::default();
+
+    recorder.record(objective.as_ref(), population.as_ref());
+    population.add(VectorSolution::new(vec![1., 1.], objective.clone()));
+    recorder.record(objective.as_ref(), population.as_ref());
+
+    assert_eq!(recorder.to_json(), "[[],[[0]]]");
+}
+
+#[test]
+fn can_record_search_trajectory_per_generation() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let mut population: Box<DynHeuristicPopulation<VectorObjective, VectorSolution>> =
+        Box::new(Elitism::new(objective.clone(), environment.random.clone(), 8, 2));
+    let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 100 });
+    let generations = 5;
+
+    (0..generations).for_each(|idx| {
+        population.add(VectorSolution::new(vec![1. + (generations - idx) as f64 / 10., 1.], objective.clone()));
+        telemetry.on_generation(objective.as_ref(), population.as_ref(), 0., Timer::start(), true);
+    });
+
+    let points = telemetry.take_metrics().expect("no metrics").trajectory.points;
+    assert_eq!(points.len(), generations);
+    assert_eq!(points.iter().map(|point| point.generation).collect::<Vec<_>>(), (0..generations).collect::<Vec<_>>());
+    assert_eq!(points.iter().map(|point| point.population_size).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    points.windows(2).for_each(|pair| {
+        assert_eq!(compare_floats(pair[1].best_fitness[0], pair[0].best_fitness[0]), Ordering::Less);
+        assert!(pair[1].elapsed >= pair[0].elapsed);
+    });
+}
+
+#[test]
+fn can_skip_search_trajectory_without_metrics() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let population: Box<DynHeuristicPopulation<VectorObjective, VectorSolution>> =
+        Box::new(Elitism::new(objective.clone(), environment.random.clone(), 8, 2));
+    let mut telemetry = Telemetry::new(TelemetryMode::None);
+
+    telemetry.on_generation(objective.as_ref(), population.as_ref(), 0., Timer::start(), true);
+
+    assert!(telemetry.take_metrics().is_none());
+}
@@ -1,7 +1,10 @@
 use super::*;
 use crate::example::{VectorContext, VectorObjective, VectorSolution};
-use crate::helpers::example::{create_default_heuristic_context, create_example_objective};
+use crate::helpers::example::{
+    create_default_heuristic_context, create_example_objective, create_heuristic_context_with_solutions,
+};
 use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
 parameterized_test! {can_evaluate_state_reward, (ratio, value, expected), {
     can_evaluate_state_reward_impl(ratio, value, expected);
@@ -109,3 +112,108 @@ fn can_display_heuristic_info() {
 
     assert!(!formatted.is_empty());
 }
+
+#[test]
+fn can_write_and_read_heuristic_policy() {
+    let policy = HeuristicPolicy {
+        best_known: vec![("first".to_string(), 1.5), ("second".to_string(), -2.)],
+        diverse: vec![("first".to_string(), 0.)],
+    };
+    let mut buffer = Vec::new();
+
+    policy.write(&mut buffer).unwrap();
+    let result = HeuristicPolicy::read(buffer.as_slice()).unwrap();
+
+    assert_eq!(result, policy);
+    assert!(HeuristicPolicy::read("best_known,first".as_bytes()).is_err());
+    assert!(HeuristicPolicy::read("unknown,first,1".as_bytes()).is_err());
+}
+
+parameterized_test! {can_warm_start_operator_selection_from_policy, (policy, is_second_preferred), {
+    can_warm_start_operator_selection_from_policy_impl(policy, is_second_preferred);
+}}
+
+can_warm_start_operator_selection_from_policy! {
+    case_01_cold_start: (None, false),
+    case_02_warm_start: (Some(vec![("first".to_string(), 1.), ("second".to_string(), 1000.)]), true),
+}
+
+fn can_warm_start_operator_selection_from_policy_impl(
+    best_known: Option<Vec<(String, f64)>>,
+    is_second_preferred: bool,
+) {
+    struct CountingHeuristicOperator {
+        counter: Arc<AtomicUsize>,
+    }
+    impl HeuristicSearchOperator for CountingHeuristicOperator {
+        type Context = VectorContext;
+        type Objective = VectorObjective;
+        type Solution = VectorSolution;
+
+        fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+            self.counter.fetch_add(1, AtomicOrdering::Relaxed);
+            solution.deep_copy()
+        }
+    }
+    let environment = Environment::default();
+    let (first, second) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+    let heuristic = DynamicSelective::<VectorContext, VectorObjective, VectorSolution>::new(
+        vec![
+            (Arc::new(CountingHeuristicOperator { counter: first.clone() }), "first".to_string(), 1.),
+            (Arc::new(CountingHeuristicOperator { counter: second.clone() }), "second".to_string(), 1.),
+        ],
+        vec![],
+        &environment,
+    );
+    let policy = Arc::new(Mutex::new(HeuristicPolicy { best_known: best_known.unwrap_or_default(), diverse: vec![] }));
+    let mut heuristic = heuristic.with_policy(policy.clone());
+    let solution = VectorSolution::new(vec![0., 0.], create_example_objective());
+
+    heuristic.search_many(&create_default_heuristic_context(), (0..100).map(|_| &solution).collect());
+
+    let (first, second) = (first.load(AtomicOrdering::Relaxed), second.load(AtomicOrdering::Relaxed));
+    assert_eq!(first + second, 100);
+    assert_eq!(second > 75, is_second_preferred);
+    assert_eq!(policy.lock().unwrap().best_known.len(), 2);
+}
+
+#[test]
+fn can_reset_operator_estimates_to_uniform_after_stagnation() {
+    struct StagnatingHeuristicOperator;
+    impl HeuristicSearchOperator for StagnatingHeuristicOperator {
+        type Context = VectorContext;
+        type Objective = VectorObjective;
+        type Solution = VectorSolution;
+
+        fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+            solution.deep_copy()
+        }
+    }
+    let is_uniform = |entries: &[(String, f64)]| entries.iter().all(|(_, estimate)| *estimate == 1.);
+    let environment = Environment::default();
+    let mut heuristic = DynamicSelective::<VectorContext, VectorObjective, VectorSolution>::new(
+        vec![
+            (Arc::new(StagnatingHeuristicOperator), "first".to_string(), 1.),
+            (Arc::new(StagnatingHeuristicOperator), "second".to_string(), 1000.),
+        ],
+        vec![],
+        &environment,
+    )
+    .with_reset_after(3);
+    let heuristic_ctx = create_heuristic_context_with_solutions(vec![vec![0., 0.]]);
+    let solution = VectorSolution::new(vec![0., 0.], create_example_objective());
+
+    for _ in 0..2 {
+        heuristic.search_many(&heuristic_ctx, (0..10).map(|_| &solution).collect());
+        let policy = heuristic.get_policy();
+        assert!(!is_uniform(policy.best_known.as_slice()));
+        assert!(!is_uniform(policy.diverse.as_slice()));
+    }
+
+    heuristic.search_many(&heuristic_ctx, (0..10).map(|_| &solution).collect());
+
+    let policy = heuristic.get_policy();
+    assert_eq!(policy.best_known.len(), 2);
+    assert!(is_uniform(policy.best_known.as_slice()));
+    assert!(is_uniform(policy.diverse.as_slice()));
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::example::create_example_objective;
+use crate::utils::Environment;
+
+fn create_population(initial: &[Vec<f64>]) -> Adaptive<VectorObjective, VectorSolution> {
+    let objective = create_example_objective();
+    let config = AdaptiveConfig { min_size: 2, max_size: 4, low_diversity: 0.05, high_diversity: 0.5 };
+    let mut population = Adaptive::new(objective.clone(), Environment::default().random, config, 1).unwrap();
+
+    population.add_all(initial.iter().map(|data| VectorSolution::new(data.clone(), objective.clone())).collect());
+
+    population
+}
+
+#[test]
+fn can_grow_and_shrink_depending_on_diversity() {
+    let objective = create_example_objective();
+    let create_solution = |data: Vec<f64>| VectorSolution::new(data, objective.clone());
+    let mut population = create_population(&[vec![0., 0.], vec![0.5, 0.5]]);
+    assert_eq!(population.max_population_size(), 2);
+
+    // NOTE fitness values are 1, 6.5, 58.5: diversity is high
+    population.on_generation(&HeuristicStatistics::default());
+    population.add(create_solution(vec![-0.5, -0.5]));
+    population.on_generation(&HeuristicStatistics::default());
+    assert_eq!(population.max_population_size(), 4);
+    assert_eq!(population.size(), 3);
+
+    // NOTE fitness values are close to 1: diversity collapses
+    population.add_all(vec![
+        create_solution(vec![0.01, 0.]),
+        create_solution(vec![0.02, 0.]),
+        create_solution(vec![0.03, 0.]),
+    ]);
+    assert_eq!(population.size(), 4);
+    population.on_generation(&HeuristicStatistics::default());
+    assert_eq!(population.max_population_size(), 3);
+    assert_eq!(population.size(), 3);
+}
+
+#[test]
+fn can_validate_config() {
+    let create = |min_size, max_size, low_diversity, high_diversity| {
+        let config = AdaptiveConfig { min_size, max_size, low_diversity, high_diversity };
+        Adaptive::<VectorObjective, VectorSolution>::new(
+            create_example_objective(),
+            Environment::default().random,
+            config,
+            1,
+        )
+        .is_ok()
+    };
+
+    assert!(create(1, 2, 0.1, 0.2));
+    assert!(!create(0, 2, 0.1, 0.2));
+    assert!(!create(3, 2, 0.1, 0.2));
+    assert!(!create(1, 2, 0.3, 0.2));
+}
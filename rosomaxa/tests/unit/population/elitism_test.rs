@@ -68,6 +68,59 @@ fn can_maintain_diversity_with_one_objective() {
     assert_eq!(get_all_fitness(&population), &[0., 1., 6.5, 58.5])
 }
 
+#[test]
+fn can_use_tie_break_to_keep_individuals_with_same_fitness() {
+    let (objective, mut population) = create_objective_population(4, 1);
+    let mut tie_break_population = Elitism::new_with_tie_break(
+        objective.clone(),
+        Environment::default().random,
+        4,
+        1,
+        Box::new(|solution: &VectorSolution| solution.data[0]),
+    );
+    // NOTE both individuals have the same fitness and crowding distance
+    let create_individuals = || {
+        vec![VectorSolution::new(vec![0., 0.], objective.clone()), VectorSolution::new(vec![2., 4.], objective.clone())]
+    };
+
+    population.add_all(create_individuals());
+    tie_break_population.add_all(create_individuals());
+
+    assert_eq!(get_all_fitness(&population), &[1.]);
+    assert_eq!(get_all_fitness(&tie_break_population), &[1., 1.]);
+}
+
+#[test]
+fn can_reject_individual_with_improvement_below_threshold() {
+    let (objective, mut population) = create_objective_population(4, 1);
+    population.set_min_improvement(0.1);
+
+    assert!(population.add(VectorSolution::new(vec![0., 0.], objective.clone())));
+    // NOTE fitness is ~0.96, so improvement is below threshold
+    assert!(!population.add(VectorSolution::new(vec![0.02, 0.], objective.clone())));
+    assert_eq!(get_all_fitness(&population), &[1.]);
+
+    assert!(population.add(VectorSolution::new(vec![1., 1.], objective)));
+    assert_eq!(get_all_fitness(&population), &[0., 1.]);
+}
+
+#[test]
+fn can_create_population_with_initial_individuals() {
+    let objective = create_example_objective();
+    let initial = vec![
+        VectorSolution::new(vec![0.5, 0.5], objective.clone()),
+        VectorSolution::new(vec![-0.5, -0.5], objective.clone()),
+        VectorSolution::new(vec![0., 0.], objective.clone()),
+    ];
+
+    let population = Elitism::new_with_initial(objective, Environment::default().random, 2, 1, initial);
+
+    assert_eq!(
+        population.ranked().map(|(individual, rank)| (individual.fitness(), rank)).collect::<Vec<_>>(),
+        &[(1., 0), (6.5, 1)]
+    );
+}
+
 #[test]
 fn can_check_improvement() {
     let (objective, mut population) = create_objective_population(4, 1);
@@ -117,3 +170,149 @@ fn can_handle_empty() {
 
     assert!(population.select().next().is_none());
 }
+
+struct ParetoSolution {
+    data: Vec<f64>,
+    order: DominanceOrder,
+}
+
+impl HeuristicSolution for ParetoSolution {
+    fn fitness<'a>(&'a self) -> Box<dyn Iterator<Item = f64> + 'a> {
+        Box::new(self.data.iter().cloned())
+    }
+
+    fn deep_copy(&self) -> Self {
+        Self { data: self.data.clone(), order: self.order.clone() }
+    }
+}
+
+impl DominanceOrdered for ParetoSolution {
+    fn get_order(&self) -> &DominanceOrder {
+        &self.order
+    }
+
+    fn set_order(&mut self, order: DominanceOrder) {
+        self.order = order
+    }
+}
+
+struct ParetoObjective;
+
+impl HeuristicObjective for ParetoObjective {}
+
+impl MultiObjective for ParetoObjective {
+    type Solution = ParetoSolution;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        let orders = (0..self.size()).map(|idx| compare_floats(a.data[idx], b.data[idx])).collect::<Vec<_>>();
+
+        match (orders.contains(&Ordering::Less), orders.contains(&Ordering::Greater)) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+
+    fn fitness<'a>(&'a self, solution: &'a Self::Solution) -> Box<dyn Iterator<Item = f64> + 'a> {
+        solution.fitness()
+    }
+
+    fn get_order(&self, a: &Self::Solution, b: &Self::Solution, idx: usize) -> Result<Ordering, String> {
+        Ok(compare_floats(a.data[idx], b.data[idx]))
+    }
+
+    fn get_distance(&self, a: &Self::Solution, b: &Self::Solution, idx: usize) -> Result<f64, String> {
+        Ok((a.data[idx] - b.data[idx]).abs())
+    }
+
+    fn size(&self) -> usize {
+        2
+    }
+}
+
+impl Shuffled for ParetoObjective {
+    fn get_shuffled(&self, _: &(dyn Random + Send + Sync)) -> Self {
+        Self
+    }
+}
+
+#[test]
+fn can_get_extremes_from_best_rank() {
+    let mut population = Elitism::<_, _>::new(Arc::new(ParetoObjective), Environment::default().random, 8, 2);
+    population.add_all(
+        vec![vec![1., 5.], vec![2., 2.], vec![5., 1.], vec![6., 6.], vec![0., 10.]]
+            .into_iter()
+            .map(|data| ParetoSolution { data, order: DominanceOrder::default() })
+            .collect(),
+    );
+
+    let mut extremes = population.extremes().into_iter().map(|individual| individual.data).collect::<Vec<_>>();
+    extremes.sort_by(|a, b| compare_floats(a[0], b[0]));
+
+    assert_eq!(extremes, vec![vec![0., 10.], vec![5., 1.]]);
+}
+
+#[test]
+fn can_get_ranked_detailed_sorted_by_rank_and_crowding_distance() {
+    let mut population = Elitism::<_, _>::new(Arc::new(ParetoObjective), Environment::default().random, 8, 2);
+    population.add_all(
+        vec![vec![1., 5.], vec![2., 2.], vec![6., 6.], vec![5., 1.], vec![0., 10.]]
+            .into_iter()
+            .map(|data| ParetoSolution { data, order: DominanceOrder::default() })
+            .collect(),
+    );
+
+    let ranked = population.ranked_detailed();
+
+    assert_eq!(ranked.len(), 5);
+    assert!(ranked.windows(2).all(|pair| match pair {
+        [(_, a_rank, a_distance), (_, b_rank, b_distance)] => {
+            a_rank < b_rank || (a_rank == b_rank && a_distance >= b_distance)
+        }
+        _ => unreachable!(),
+    }));
+    assert_eq!(ranked.iter().map(|(_, rank, _)| *rank).collect::<Vec<_>>(), vec![0, 0, 0, 0, 1]);
+    assert!(ranked[..2].iter().all(|(_, _, distance)| *distance == f64::INFINITY));
+    assert!(ranked[2..4].iter().all(|(_, _, distance)| distance.is_finite()));
+    assert_eq!(ranked[4].0.data, vec![6., 6.]);
+}
+
+#[test]
+fn can_keep_order_and_ranks_after_sort() {
+    let mut population = Elitism::<_, _>::new(Arc::new(ParetoObjective), Environment::default().random, 8, 2);
+    population.add_all(
+        vec![vec![1., 5.], vec![2., 2.], vec![6., 6.], vec![5., 1.], vec![0., 10.]]
+            .into_iter()
+            .map(|data| ParetoSolution { data, order: DominanceOrder::default() })
+            .collect(),
+    );
+
+    let ranked = population.ranked().map(|(individual, rank)| (individual.data.clone(), rank)).collect::<Vec<_>>();
+
+    // NOTE individuals within the front are sorted by the last objective when crowding distance is assigned
+    assert_eq!(
+        ranked,
+        vec![(vec![5., 1.], 0), (vec![2., 2.], 0), (vec![1., 5.], 0), (vec![0., 10.], 0), (vec![6., 6.], 1)]
+    );
+}
+
+#[test]
+fn can_produce_same_population_with_batch_and_incremental_add() {
+    let objective = create_example_objective();
+    let create_individuals = || {
+        vec![vec![0.5, 0.5], vec![0., 0.], vec![1., 1.], vec![0.5, 0.5], vec![-0.5, -0.5], vec![2., 2.], vec![0., 0.]]
+            .into_iter()
+            .map(|data| VectorSolution::new(data, objective.clone()))
+            .collect::<Vec<_>>()
+    };
+    let (_, mut batch) = create_objective_population(4, 1);
+    let (_, mut incremental) = create_objective_population(4, 1);
+
+    batch.add_all(create_individuals());
+    create_individuals().into_iter().for_each(|individual| {
+        incremental.add(individual);
+    });
+
+    assert_eq!(get_all_fitness(&batch), get_all_fitness(&incremental));
+    assert_eq!(get_all_fitness(&batch), &[0., 1., 6.5, 58.5]);
+}
@@ -0,0 +1,57 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::example::create_example_objective;
+use crate::utils::Environment;
+
+fn create_population(objective: Arc<VectorObjective>) -> NichePopulation<VectorObjective, VectorSolution> {
+    NichePopulation::new(objective, Environment::default().random, vec![0. ..100.], 4, 2)
+}
+
+fn get_all_fitness(population: &NichePopulation<VectorObjective, VectorSolution>) -> Vec<f64> {
+    population.ranked().map(|(individual, _)| individual.fitness()).collect()
+}
+
+#[test]
+fn can_keep_best_individual_per_niche() {
+    let objective = create_example_objective();
+    let mut population = create_population(objective.clone());
+    let mut add = |data: Vec<f64>| population.add(VectorSolution::new(data, objective.clone()));
+
+    assert!(add(vec![0.5, 0.5]));
+    assert!(add(vec![0., 0.]));
+    assert!(!add(vec![-0.5, -0.5]));
+    assert!(!add(vec![-1., -1.]));
+    assert!(!add(vec![2., 2.]));
+    assert!(!add(vec![0.5, 0.5]));
+
+    // NOTE 6.5 and 1. share the first niche, 404. and 401. share the last one (out of bounds)
+    assert_eq!(population.size(), 3);
+    assert_eq!(get_all_fitness(&population), vec![1., 58.5, 401.]);
+}
+
+#[test]
+fn can_select_best_individual_first() {
+    let objective = create_example_objective();
+    let mut population = create_population(objective.clone());
+    assert_eq!(population.select().count(), 0);
+
+    population.add_all(vec![
+        VectorSolution::new(vec![-0.5, -0.5], objective.clone()),
+        VectorSolution::new(vec![0., 0.], objective),
+    ]);
+
+    let selected = population.select().map(|individual| individual.fitness()).collect::<Vec<_>>();
+    assert_eq!(selected.len(), 2);
+    assert_eq!(selected[0], 1.);
+}
+
+#[test]
+fn can_format_population() {
+    let objective = create_example_objective();
+    let mut population = create_population(objective.clone());
+    assert_eq!(format!("{population}"), "[niches: 0, best: []]");
+
+    population.add(VectorSolution::new(vec![0., 0.], objective));
+
+    assert_eq!(format!("{population}"), "[niches: 1, best: [1.0000000]]");
+}
@@ -69,6 +69,26 @@ fn can_select_individuals_in_different_phases() {
     assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
 }
 
+#[test]
+fn can_add_individuals_to_elite_in_exploitation_phase() {
+    let (objective, mut rosomaxa) = create_rosomaxa(10);
+    (0..10).for_each(|idx| {
+        rosomaxa.add_all(vec![VectorSolution::new(vec![-1., -1.], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.75, idx))
+    });
+    rosomaxa.update_phase(&create_statistics(0.95, 10));
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploitation);
+
+    assert!(rosomaxa.add(VectorSolution::new(vec![2., 2.], objective.clone())));
+    assert!(!rosomaxa.add(VectorSolution::new(vec![-2., -2.], objective.clone())));
+    assert!(rosomaxa.add_all(vec![
+        VectorSolution::new(vec![0.5, 0.5], objective.clone()),
+        VectorSolution::new(vec![-2., -2.], objective),
+    ]));
+
+    assert_eq!(rosomaxa.ranked().next().map(|(individual, _)| individual.fitness()), Some(6.5));
+}
+
 #[test]
 fn can_optimize_network() {
     let termination_estimate = 0.75;
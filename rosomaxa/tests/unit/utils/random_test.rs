@@ -20,3 +20,135 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+fn get_sequence(random: &DefaultRandom) -> Vec<i32> {
+    (0..10).map(|_| random.uniform_int(0, 1000)).collect()
+}
+
+#[test]
+fn can_repeat_sequence_with_the_same_seed() {
+    let first = get_sequence(&DefaultRandom::new_with_seed(42));
+    let second = get_sequence(&DefaultRandom::new_with_seed(42));
+    let third = get_sequence(&DefaultRandom::new_with_seed(43));
+
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+}
+
+#[test]
+fn can_repeat_real_and_int_sequences_with_the_same_seed() {
+    let get_mixed_sequence = |random: &DefaultRandom| {
+        (0..10).map(|_| (random.uniform_real(0., 1.), random.uniform_int(0, 1000))).collect::<Vec<_>>()
+    };
+
+    let first = get_mixed_sequence(&DefaultRandom::new_with_seed(42));
+    let second = get_mixed_sequence(&DefaultRandom::new_with_seed(42));
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn can_derive_worker_sequences_deterministically() {
+    let first = DefaultRandom::new_with_seed(42);
+    let second = DefaultRandom::new_with_seed(42);
+
+    let first_worker = get_sequence(&first.new_for_worker(1));
+    let second_worker = get_sequence(&second.new_for_worker(1));
+    let other_worker = get_sequence(&first.new_for_worker(2));
+
+    assert_eq!(first_worker, second_worker);
+    assert_ne!(first_worker, other_worker);
+    assert_ne!(first_worker, get_sequence(&DefaultRandom::new_with_seed(42)));
+}
+
+#[test]
+fn can_report_seed() {
+    assert_eq!(DefaultRandom::new_with_seed(42).get_seed(), Some(42));
+    assert!(DefaultRandom::default().get_seed().is_some());
+}
+
+#[test]
+fn can_repeat_sequence_with_reported_seed() {
+    let random = DefaultRandom::default();
+    let original = get_sequence(&random);
+
+    let repeated = get_sequence(&DefaultRandom::new_with_seed(random.get_seed().unwrap()));
+
+    assert_eq!(original, repeated);
+}
+
+#[test]
+fn can_use_different_sequences_in_different_threads() {
+    let random = std::sync::Arc::new(DefaultRandom::new_with_seed(42));
+    let main_sequence = get_sequence(&random);
+
+    let thread_sequence = std::thread::spawn({
+        let random = random.clone();
+        move || get_sequence(&random)
+    })
+    .join()
+    .unwrap();
+
+    assert_ne!(main_sequence, thread_sequence);
+}
+
+#[test]
+fn can_remove_thread_generators_of_dropped_instances() {
+    let random = DefaultRandom::new_with_seed(42);
+    let _ = get_sequence(&random);
+    // NOTE instance is dropped on another thread, so its generator is kept here until a new one is added
+    std::thread::spawn(move || drop(random)).join().unwrap();
+
+    let other = DefaultRandom::new_with_seed(43);
+    let _ = get_sequence(&other);
+
+    let ids = SEEDED_RNGS.with(|rngs| rngs.borrow().keys().copied().collect::<Vec<_>>());
+    assert_eq!(ids, vec![other.id]);
+}
+
+fn get_forked_sequences(parent: &DefaultRandom) -> Vec<Vec<i32>> {
+    (0..3)
+        .map(|_| {
+            let fork = parent.fork();
+            (0..10).map(|_| fork.uniform_int(0, 1000)).collect()
+        })
+        .collect()
+}
+
+#[test]
+fn can_fork_reproducible_and_distinct_streams() {
+    let parent = DefaultRandom::new_with_seed(42);
+    let other = DefaultRandom::new_with_seed(42);
+    // NOTE consumption of parent values should not affect forks
+    let parent_sequence = get_sequence(&other);
+
+    let first = get_forked_sequences(&parent);
+    let second = get_forked_sequences(&other);
+
+    assert_eq!(first, second);
+    assert!(first.iter().all(|sequence| *sequence != parent_sequence));
+    assert!(first.iter().enumerate().all(|(idx, sequence)| first.iter().skip(idx + 1).all(|other| other != sequence)));
+    assert!(first.iter().all(|sequence| *sequence != get_sequence(&parent.new_for_worker(1))));
+}
+
+fn get_substream_sequence(parent: &DefaultRandom, name: &str) -> Vec<i32> {
+    let substream = parent.substream(name);
+    (0..10).map(|_| substream.uniform_int(0, 1000)).collect()
+}
+
+#[test]
+fn can_keep_substream_sequence_when_other_substreams_are_added() {
+    let parent = DefaultRandom::new_with_seed(42);
+    let other = DefaultRandom::new_with_seed(42);
+    // NOTE an unused consumer is added before the existing one, parent values are consumed as well
+    let _ = other.substream("unused");
+    let _ = other.fork();
+    let _ = get_sequence(&other);
+
+    let first = get_substream_sequence(&parent, "cheapest");
+    let second = get_substream_sequence(&other, "cheapest");
+
+    assert_eq!(first, second);
+    assert_ne!(first, get_substream_sequence(&parent, "regret"));
+    assert_ne!(first, get_sequence(&DefaultRandom::new_with_seed(42)));
+}
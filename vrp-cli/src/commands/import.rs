@@ -14,7 +14,13 @@ pub const OUT_RESULT_ARG_NAME: &str = "out-result";
 pub fn get_import_app() -> Command {
     Command::new("import")
         .about("Provides the way to import problem from various formats")
-        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["csv"]).index(1))
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .value_parser(["csv", "geojson"])
+                .index(1),
+        )
         .arg(
             Arg::new(INPUT_ARG_NAME)
                 .help("Sets input files which contains a VRP definition")
@@ -42,6 +42,7 @@ const PARALLELISM_ARG_NAME: &str = "parallelism";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
+const SEED_ARG_NAME: &str = "seed";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
@@ -71,6 +72,7 @@ type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, Soluti
 fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) {
     if cfg!(feature = "scientific-format") {
         use vrp_scientific::common::read_init_solution;
+        use vrp_scientific::golden::GoldenProblem;
         use vrp_scientific::lilim::{LilimProblem, LilimSolution};
         use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
 
@@ -81,14 +83,16 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
             (
                 ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
                     assert!(matrices.is_none());
-                    BufReader::new(problem).read_solomon(is_rounded)
+                    BufReader::new(problem).read_solomon(is_rounded).map_err(|err| err.to_string())
                 })),
                 InitSolutionReader(Box::new({
                     let random = random.clone();
-                    move |file, problem| read_init_solution(BufReader::new(file), problem, random.clone())
+                    move |file, problem| {
+                        read_init_solution(BufReader::new(file), problem, random.clone()).map_err(|err| err.to_string())
+                    }
                 })),
                 SolutionWriter(Box::new(|_, solution, cost, _, mut writer, _| {
-                    (&solution, cost).write_solomon(&mut writer)
+                    (&solution, cost).write_solomon(&mut writer).map_err(|err| err.to_string())
                 })),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
@@ -98,11 +102,30 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
             (
                 ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
                     assert!(matrices.is_none());
-                    BufReader::new(problem).read_lilim(is_rounded)
+                    BufReader::new(problem).read_lilim(is_rounded).map_err(|err| err.to_string())
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
                 SolutionWriter(Box::new(|_, solution, cost, _, mut writer, _| {
-                    (&solution, cost).write_lilim(&mut writer)
+                    (&solution, cost).write_lilim(&mut writer).map_err(|err| err.to_string())
+                })),
+                LocationWriter(Box::new(|_, _| unimplemented!())),
+            ),
+        );
+        formats.insert(
+            "golden",
+            (
+                ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                    assert!(matrices.is_none());
+                    BufReader::new(problem).read_golden(is_rounded).map_err(|err| err.to_string())
+                })),
+                InitSolutionReader(Box::new({
+                    let random = random.clone();
+                    move |file, problem| {
+                        read_init_solution(BufReader::new(file), problem, random.clone()).map_err(|err| err.to_string())
+                    }
+                })),
+                SolutionWriter(Box::new(|_, solution, cost, _, mut writer, _| {
+                    (&solution, cost).write_tsplib(&mut writer).map_err(|err| err.to_string())
                 })),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
@@ -112,13 +135,13 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
             (
                 ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
                     assert!(matrices.is_none());
-                    BufReader::new(problem).read_tsplib(is_rounded)
+                    BufReader::new(problem).read_tsplib(is_rounded).map_err(|err| err.to_string())
                 })),
                 InitSolutionReader(Box::new(move |file, problem| {
-                    read_init_solution(BufReader::new(file), problem, random.clone())
+                    read_init_solution(BufReader::new(file), problem, random.clone()).map_err(|err| err.to_string())
                 })),
                 SolutionWriter(Box::new(|_, solution, cost, _, mut writer, _| {
-                    (&solution, cost).write_tsplib(&mut writer)
+                    (&solution, cost).write_tsplib(&mut writer).map_err(|err| err.to_string())
                 })),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
@@ -184,7 +207,7 @@ pub fn get_solve_app() -> Command {
             Arg::new(FORMAT_ARG_NAME)
                 .help("Specifies the problem type")
                 .required(true)
-                .value_parser(["solomon", "lilim", "tsplib", "pragmatic"])
+                .value_parser(["solomon", "lilim", "tsplib", "golden", "pragmatic"])
                 .index(1),
         )
         .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
@@ -313,6 +336,12 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(SEED_ARG_NAME)
+                .help("Specifies a random seed to make solver run repeatable.")
+                .long(SEED_ARG_NAME)
+                .required(false)
+        )
 }
 
 /// Runs solver commands.
@@ -464,6 +493,10 @@ fn get_init_size(matches: &ArgMatches) -> Result<Option<usize>, String> {
 fn get_environment(matches: &ArgMatches, max_time: Option<usize>) -> Result<Arc<Environment>, String> {
     let quota = Some(create_interruption_quota(max_time));
     let is_experimental = matches.get_one::<bool>(EXPERIMENTAL_ARG_NAME).copied().unwrap_or(false);
+    let random: Arc<dyn Random + Send + Sync> = match parse_int_value::<u64>(matches, SEED_ARG_NAME, "random seed")? {
+        Some(seed) => Arc::new(DefaultRandom::new_with_seed(seed)),
+        None => Arc::new(DefaultRandom::default()),
+    };
 
     matches
         .get_one::<String>(PARALLELISM_ARG_NAME)
@@ -477,18 +510,12 @@ fn get_environment(matches: &ArgMatches, max_time: Option<usize>) -> Result<Arc<
                 } else {
                     Arc::new(|_: &str| {})
                 };
-                Ok(Arc::new(Environment::new(
-                    Arc::new(DefaultRandom::default()),
-                    quota.clone(),
-                    parallelism,
-                    logger,
-                    is_experimental,
-                )))
+                Ok(Arc::new(Environment::new(random.clone(), quota.clone(), parallelism, logger, is_experimental)))
             } else {
                 Err("cannot parse parallelism parameter".to_string())
             }
         })
-        .unwrap_or_else(|| Ok(Arc::new(Environment { quota, is_experimental, ..Environment::default() })))
+        .unwrap_or_else(|| Ok(Arc::new(Environment { random, quota, is_experimental, ..Environment::default() })))
 }
 
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
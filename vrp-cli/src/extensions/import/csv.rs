@@ -124,7 +124,12 @@ mod actual {
                             latest: None,
                             location: depot_location.clone(),
                         },
-                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: vehicle.tw_end,
+                            location: depot_location,
+                            dwell: None,
+                        }),
                         dispatch: None,
                         breaks: None,
                         reloads: None,
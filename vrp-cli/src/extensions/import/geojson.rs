@@ -0,0 +1,143 @@
+//! Import from a GeoJSON format logic.
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/import/geojson_test.rs"]
+mod geojson_test;
+
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::io::{BufReader, Read};
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::{FormatError, Location};
+
+#[derive(Debug, Deserialize)]
+struct GeoJsonFeatureCollection {
+    features: Vec<GeoJsonFeature>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeoJsonFeature {
+    geometry: GeoJsonGeometry,
+    properties: GeoJsonProperties,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum GeoJsonGeometry {
+    Point { coordinates: (f64, f64) },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum GeoJsonProperties {
+    Job { id: String, demand: i32, duration: usize, tw_start: Option<String>, tw_end: Option<String> },
+    Depot { id: String, capacity: i32, tw_start: String, tw_end: String, amount: usize, profile: String },
+}
+
+fn create_job(id: String, location: Location, demand: i32, duration: usize, times: Option<Vec<String>>) -> Job {
+    let tasks = Some(vec![JobTask {
+        places: vec![JobPlace {
+            location,
+            duration: duration as f64 * 60.,
+            times: times.map(|tw| vec![tw]),
+            tag: None,
+        }],
+        demand: if demand != 0 { Some(vec![demand.abs()]) } else { None },
+        order: None,
+    }]);
+
+    let (pickups, deliveries, services) = match demand {
+        demand if demand > 0 => (tasks, None, None),
+        demand if demand < 0 => (None, tasks, None),
+        _ => (None, None, tasks),
+    };
+
+    Job {
+        id,
+        pickups,
+        deliveries,
+        replacements: None,
+        services,
+        skills: None,
+        value: None,
+        group: None,
+        compatibility: None,
+    }
+}
+
+fn create_vehicle(
+    id: String,
+    location: Location,
+    capacity: i32,
+    tw_start: String,
+    tw_end: String,
+    amount: usize,
+    profile: String,
+) -> VehicleType {
+    VehicleType {
+        type_id: id,
+        vehicle_ids: (1..=amount).map(|seq| format!("{profile}_{seq}")).collect(),
+        profile: VehicleProfile { matrix: profile, scale: None },
+        costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+        shifts: vec![VehicleShift {
+            start: ShiftStart { earliest: tw_start, latest: None, location: location.clone() },
+            end: Some(ShiftEnd { earliest: None, latest: tw_end, location, dwell: None }),
+            dispatch: None,
+            breaks: None,
+            reloads: None,
+        }],
+        capacity: vec![capacity],
+        skills: None,
+        limits: None,
+    }
+}
+
+fn create_format_error(details: String) -> FormatError {
+    FormatError::new_with_details(
+        "E0000".to_string(),
+        "cannot read geojson".to_string(),
+        "check that input is a feature collection of points with job or depot properties".to_string(),
+        details,
+    )
+}
+
+/// Reads problem from GeoJSON format: a feature collection of points where each point has
+/// either a job or a depot properties.
+pub fn read_geojson_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, FormatError> {
+    let collection: GeoJsonFeatureCollection =
+        serde_json::from_reader(reader).map_err(|err| create_format_error(err.to_string()))?;
+
+    let (jobs, vehicles) =
+        collection.features.into_iter().fold((vec![], vec![]), |(mut jobs, mut vehicles), feature| {
+            // NOTE GeoJSON keeps coordinates in longitude, latitude order
+            let GeoJsonGeometry::Point { coordinates: (lng, lat) } = feature.geometry;
+            let location = Location::Coordinate { lat, lng };
+
+            match feature.properties {
+                GeoJsonProperties::Job { id, demand, duration, tw_start, tw_end } => {
+                    let times = tw_start.zip(tw_end).map(|(start, end)| vec![start, end]);
+                    jobs.push(create_job(id, location, demand, duration, times));
+                }
+                GeoJsonProperties::Depot { id, capacity, tw_start, tw_end, amount, profile } => {
+                    vehicles.push(create_vehicle(id, location, capacity, tw_start, tw_end, amount, profile));
+                }
+            }
+
+            (jobs, vehicles)
+        });
+
+    if jobs.is_empty() || vehicles.is_empty() {
+        return Err(create_format_error("geojson should have at least one job and one depot".to_string()));
+    }
+
+    let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
+
+    Ok(Problem {
+        plan: Plan { jobs, relations: None, clustering: None },
+        fleet: Fleet {
+            vehicles,
+            profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+            resources: None,
+        },
+        objectives: None,
+    })
+}
@@ -3,6 +3,9 @@
 mod csv;
 pub use self::csv::*;
 
+mod geojson;
+pub use self::geojson::*;
+
 use std::io::{BufReader, Read};
 use vrp_pragmatic::format::problem::Problem;
 
@@ -15,6 +18,10 @@ pub fn import_problem<R: Read>(input_format: &str, readers: Option<Vec<BufReader
             read_csv_problem(jobs, vehicles).map_err(|err| format!("cannot read csv: {err}"))
         }
         ("csv", _) => Err("csv format expects two files with jobs and vehicles as an input".to_string()),
+        ("geojson", Some(mut readers)) if readers.len() == 1 => {
+            read_geojson_problem(readers.swap_remove(0)).map_err(|err| format!("cannot read geojson: {err}"))
+        }
+        ("geojson", _) => Err("geojson format expects one file with jobs and depots as an input".to_string()),
         _ => Err(format!("unknown format: '{input_format}'")),
     }
 }
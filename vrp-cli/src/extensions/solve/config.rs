@@ -22,6 +22,7 @@ use vrp_core::rosomaxa::utils::*;
 use vrp_core::solver::search::*;
 use vrp_core::solver::RecreateInitialOperator;
 use vrp_core::solver::*;
+use vrp_pragmatic::format::problem::RelaxationStep;
 
 /// An algorithm configuration.
 #[derive(Clone, Default, Deserialize, Debug)]
@@ -36,6 +37,8 @@ pub struct Config {
     pub environment: Option<EnvironmentConfig>,
     /// Specifies telemetry configuration.
     pub telemetry: Option<TelemetryConfig>,
+    /// Specifies constraint relaxation steps applied one by one when no feasible solution is found.
+    pub relaxation_ladder: Option<Vec<RelaxationStep>>,
 }
 
 /// An evolution configuration.
@@ -303,6 +306,9 @@ pub enum RecreateMethod {
     /// Insertion with regret method.
     #[serde(rename(deserialize = "regret"))]
     Regret { weight: usize, start: usize, end: usize },
+    /// Beam search insertion method.
+    #[serde(rename(deserialize = "beam"))]
+    Beam { weight: usize, width: usize },
 }
 
 /// A local search configuration.
@@ -318,11 +324,17 @@ pub enum LocalOperatorType {
     #[serde(rename(deserialize = "inter-route-random"))]
     InterRouteRandom { weight: usize, noise: NoiseConfig },
 
+    #[serde(rename(deserialize = "inter-route-neighbor"))]
+    InterRouteNeighbor { weight: usize, k: usize, noise: NoiseConfig },
+
     #[serde(rename(deserialize = "intra-route-random"))]
     IntraRouteRandom { weight: usize, noise: NoiseConfig },
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "reverse-segment"))]
+    ReverseSegment { weight: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -592,6 +604,7 @@ fn create_recreate_method(
             let noise = Noise::new_with_addition(*probability, (*min, *max), random.clone());
             (Arc::new(RecreateWithPerturbation::new(noise, random.clone())), *weight)
         }
+        RecreateMethod::Beam { weight, width } => (Arc::new(BeamInsertion::new(*width)), *weight),
     }
 }
 
@@ -614,7 +627,7 @@ fn create_operator(
             )
         }
         SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
-            let operator = create_local_search(times, inners, environment.random.clone());
+            let operator = create_local_search(&problem, times, inners, environment.random.clone());
             (Arc::new(LocalSearch::new(operator)), create_operator_probability(probability, environment.random.clone()))
         }
         SearchOperatorType::Decomposition { routes, repeat, probability } => {
@@ -697,6 +710,7 @@ fn create_ruin_method(
 }
 
 fn create_local_search(
+    problem: &Problem,
     times: &MinMaxConfig,
     inners: &[LocalOperatorType],
     random: Arc<dyn Random + Send + Sync>,
@@ -711,10 +725,15 @@ fn create_local_search(
             LocalOperatorType::InterRouteRandom { weight, noise } => {
                 (Arc::new(ExchangeInterRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
+            LocalOperatorType::InterRouteNeighbor { weight, k, noise } => (
+                Arc::new(ExchangeInterRouteNeighbor::new(problem, *k, noise.probability, noise.min, noise.max)),
+                *weight,
+            ),
             LocalOperatorType::IntraRouteRandom { weight, noise } => {
                 (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
             LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::ReverseSegment { weight } => (Arc::new(ReverseSegment::default()), *weight),
         })
         .collect::<Vec<_>>();
 
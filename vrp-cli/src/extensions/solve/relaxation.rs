@@ -0,0 +1,57 @@
+//! Contains logic to retry solving with progressively relaxed constraints.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/relaxation_test.rs"]
+mod relaxation_test;
+
+use super::config::{create_builder_from_config, Config};
+use std::sync::Arc;
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::TelemetryMetrics;
+use vrp_pragmatic::format::problem::{relax_problem, Matrix, PragmaticProblem, Problem, RelaxationStep};
+
+/// A best effort solution found for a (possibly) relaxed problem.
+pub struct RelaxedSolution {
+    /// A problem which was used to find the solution.
+    pub problem: Arc<CoreProblem>,
+    /// A solution found.
+    pub solution: Solution,
+    /// A solution cost.
+    pub cost: f64,
+    /// Telemetry metrics of the last solver run.
+    pub metrics: Option<TelemetryMetrics>,
+    /// Relaxation steps applied to the original problem, in order of their application.
+    pub relaxations: Vec<RelaxationStep>,
+}
+
+/// Solves the problem. If a solution has unassigned jobs, retries with constraints relaxed
+/// cumulatively by steps from `relaxation_ladder` config until a feasible solution is found or
+/// no steps are left. Each attempt uses the same solver configuration, including termination.
+pub fn solve_with_relaxation(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    config: &Config,
+) -> Result<RelaxedSolution, String> {
+    let mut steps = config.relaxation_ladder.iter().flatten();
+    let mut relaxations = Vec::default();
+    let mut problem = problem;
+
+    loop {
+        let core_problem =
+            Arc::new((problem.clone(), matrices.clone()).read_pragmatic().map_err(|errs| errs.to_string())?);
+
+        let (solution, cost, metrics) = create_builder_from_config(core_problem.clone(), Default::default(), config)
+            .and_then(|builder| builder.build())
+            .map(|evolution_config| Solver::new(core_problem.clone(), evolution_config))
+            .and_then(|solver| solver.solve())?;
+
+        match steps.next() {
+            Some(step) if !solution.unassigned.is_empty() => {
+                problem = relax_problem(&problem, step);
+                relaxations.push(step.clone());
+            }
+            _ => return Ok(RelaxedSolution { problem: core_problem, solution, cost, metrics, relaxations }),
+        }
+    }
+}
@@ -49,7 +49,7 @@ fn can_solve_solomon_problem_with_generation_limit() {
 
 #[test]
 fn can_require_problem_path() {
-    for format in &["pragmatic", "solomon", "lilim", "tsplib"] {
+    for format in &["pragmatic", "solomon", "lilim", "tsplib", "golden"] {
         get_solve_app().try_get_matches_from(vec!["solve", format]).unwrap_err();
     }
 }
@@ -116,6 +116,24 @@ fn can_specify_parallelism() {
     }
 }
 
+#[test]
+fn can_specify_seed() {
+    for (params, result) in vec![
+        (vec!["--seed", "42"], Ok(Some(42_u64))),
+        (vec!["--seed", "42", "--parallelism", "3,1"], Ok(Some(42_u64))),
+        (
+            vec!["--seed", "abc"],
+            Err("cannot get integer value, error: 'invalid digit found in string': 'random seed'".to_string()),
+        ),
+    ] {
+        let matches = get_solomon_matches(params.as_slice());
+
+        let seed = get_environment(&matches, None).map(|e| e.random.get_seed());
+
+        assert_eq!(seed, result);
+    }
+}
+
 #[test]
 fn can_use_init_size() {
     for (params, result) in vec![
@@ -0,0 +1,64 @@
+use super::*;
+use crate::extensions::import::import_problem;
+use std::fs::File;
+
+const GEOJSON_PROBLEM_PATH: &str = "../examples/data/geojson/simple.problem.geojson";
+
+#[test]
+fn can_read_geojson_problem() {
+    let result =
+        read_geojson_problem(BufReader::new(File::open(GEOJSON_PROBLEM_PATH).unwrap())).expect("cannot read geojson");
+
+    assert_eq!(result.plan.jobs.len(), 3);
+    let job1 = result.plan.jobs.iter().find(|job| job.id == "job1").unwrap();
+    let place = &job1.pickups.as_ref().unwrap().first().unwrap().places[0];
+    assert_eq!(place.location.to_lat_lng(), (52.52599, 13.45413));
+    assert_eq!(place.duration, 300.);
+    assert_eq!(place.times, Some(vec![vec!["2020-07-04T08:00:00Z".to_string(), "2020-07-04T12:00:00Z".to_string()]]));
+    let job2 = result.plan.jobs.iter().find(|job| job.id == "job2").unwrap();
+    assert_eq!(job2.deliveries.as_ref().unwrap()[0].demand, Some(vec![1]));
+    let job3 = result.plan.jobs.iter().find(|job| job.id == "job3").unwrap();
+    assert!(job3.services.is_some());
+
+    assert_eq!(result.fleet.vehicles.len(), 1);
+    let vehicle = &result.fleet.vehicles[0];
+    assert_eq!(vehicle.type_id, "vehicle1");
+    assert_eq!(vehicle.vehicle_ids, vec!["car_1".to_string(), "car_2".to_string()]);
+    assert_eq!(vehicle.capacity, vec![10]);
+    assert_eq!(vehicle.shifts[0].start.location.to_lat_lng(), (52.4664, 13.4023));
+    assert_eq!(result.fleet.profiles.len(), 1);
+}
+
+#[test]
+fn can_propagate_format_error() {
+    let invalid_geojson = r#"{ "type": "FeatureCollection", "features": [{ "type": "Feature" }] }"#;
+
+    let result = read_geojson_problem(BufReader::new(invalid_geojson.as_bytes())).expect_err("Should return error!");
+
+    assert_eq!(result.code, "E0000");
+    assert_eq!(result.cause, "cannot read geojson");
+    assert!(result.details.is_some());
+
+    let result = import_problem("geojson", Some(vec![BufReader::new(invalid_geojson.as_bytes())]))
+        .expect_err("Should return error!");
+
+    assert!(result.starts_with("cannot read geojson: E0000"));
+}
+
+parameterized_test! {can_handle_invalid_input_amount, input_size, {
+        can_handle_invalid_input_amount_impl(input_size);
+}}
+
+can_handle_invalid_input_amount! {
+        case01: None,
+        case02: Some(0),
+        case03: Some(2),
+}
+
+fn can_handle_invalid_input_amount_impl(input_size: Option<usize>) {
+    let result =
+        import_problem("geojson", input_size.map(|size| (0..size).map(|_| BufReader::new("".as_bytes())).collect()))
+            .expect_err("Should return error!");
+
+    assert_eq!(result, "geojson format expects one file with jobs and depots as an input");
+}
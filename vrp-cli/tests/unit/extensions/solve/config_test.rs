@@ -97,7 +97,7 @@ fn can_read_full_config() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 6);
                 }
                 _ => unreachable!(),
             }
@@ -142,6 +142,7 @@ fn can_configure_telemetry_metrics() {
             progress: None,
             metrics: Some(MetricsConfig { enabled: true, track_population: Some(10) }),
         }),
+        relaxation_ladder: None,
     };
 
     let (_, _, metrics) = create_builder_from_config(create_example_problem(), Vec::default(), &config)
@@ -0,0 +1,64 @@
+use super::*;
+use crate::extensions::solve::config::TerminationConfig;
+use crate::helpers::generate::{create_empty_plan, create_test_job, create_test_vehicle_type};
+use vrp_pragmatic::format::problem::{Fleet, JobTask, MatrixProfile, Plan};
+
+fn create_test_problem(demand: i32) -> Problem {
+    let mut job = create_test_job(1., 0.);
+    job.pickups = job
+        .pickups
+        .map(|tasks| tasks.into_iter().map(|task| JobTask { demand: Some(vec![demand]), ..task }).collect::<Vec<_>>());
+
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            resources: None,
+        },
+        objectives: None,
+    }
+}
+
+fn create_test_config(relaxation_ladder: Option<Vec<RelaxationStep>>) -> Config {
+    Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        relaxation_ladder,
+        ..Config::default()
+    }
+}
+
+fn get_relaxation_ladder() -> Vec<RelaxationStep> {
+    vec![RelaxationStep::WidenTimeWindows { seconds: 3600. }, RelaxationStep::ScaleCapacity { factor: 2. }]
+}
+
+#[test]
+fn can_report_relaxations_applied_for_infeasible_problem() {
+    let problem = create_test_problem(15);
+
+    let result = solve_with_relaxation(problem, None, &create_test_config(Some(get_relaxation_ladder()))).unwrap();
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.len(), 1);
+    assert_eq!(result.relaxations, get_relaxation_ladder());
+}
+
+#[test]
+fn can_return_best_effort_solution_when_ladder_is_exhausted() {
+    let problem = create_test_problem(25);
+
+    let result = solve_with_relaxation(problem, None, &create_test_config(Some(get_relaxation_ladder()))).unwrap();
+
+    assert_eq!(result.solution.unassigned.len(), 1);
+    assert_eq!(result.relaxations, get_relaxation_ladder());
+}
+
+#[test]
+fn can_skip_relaxation_for_feasible_problem() {
+    let problem = create_test_problem(5);
+
+    let result = solve_with_relaxation(problem, None, &create_test_config(Some(get_relaxation_ladder()))).unwrap();
+
+    assert!(result.solution.unassigned.is_empty());
+    assert!(result.relaxations.is_empty());
+}
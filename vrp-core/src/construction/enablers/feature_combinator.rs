@@ -77,6 +77,10 @@ impl FeatureConstraint for CombinedFeatureConstraint {
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
         merge_with_constraints(&self.constraints, source, candidate)
     }
+
+    fn depends_on_other_routes(&self) -> bool {
+        self.constraints.iter().any(|constraint| constraint.depends_on_other_routes())
+    }
 }
 
 pub(crate) fn accept_insertion_with_states(
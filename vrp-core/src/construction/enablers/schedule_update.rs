@@ -1,6 +1,6 @@
 use crate::construction::heuristics::RouteContext;
 use crate::models::common::{Distance, Schedule, Timestamp};
-use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::problem::{ActivityCost, EndDwellDimension, TransportCost, TravelTime};
 use crate::models::StateKey;
 use crate::models::OP_START_MSG;
 
@@ -76,8 +76,9 @@ fn update_states(
 ) {
     // update latest arrival and waiting states of non-terminate (jobs) activities
     let actor = route_ctx.route().actor.clone();
+    let end_dwell = actor.detail.end.as_ref().and_then(|_| actor.vehicle.dimens.get_end_dwell().cloned()).unwrap_or(0.);
     let init = (
-        actor.detail.time.end,
+        actor.detail.time.end - end_dwell,
         actor
             .detail
             .end
@@ -0,0 +1,123 @@
+//! Provides a way to keep jobs with the same affinity (e.g. the same customer site) in one route.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/affinity_test.rs"]
+mod affinity_test;
+
+use super::*;
+use hashbrown::{HashMap, HashSet};
+
+const AFFINITY_DIMENSION_KEY: &str = "affinity";
+
+/// A trait to get or set job affinity: jobs sharing the same affinity key are preferred to be
+/// served by the same route.
+pub trait AffinityDimension {
+    /// Sets affinity key.
+    fn set_affinity(&mut self, affinity: &str) -> &mut Self;
+    /// Gets affinity key.
+    fn get_affinity(&self) -> Option<&String>;
+}
+
+impl AffinityDimension for Dimensions {
+    fn set_affinity(&mut self, affinity: &str) -> &mut Self {
+        self.set_value(AFFINITY_DIMENSION_KEY, affinity.to_string());
+        self
+    }
+
+    fn get_affinity(&self) -> Option<&String> {
+        self.get_value(AFFINITY_DIMENSION_KEY)
+    }
+}
+
+/// Creates an affinity feature as soft objective. An insertion of the job into the route which
+/// already serves its affinity group is rewarded, an insertion which splits the group between
+/// routes is penalized. Solution fitness is the total amount of extra routes used by affinity groups.
+pub fn create_affinity_feature(name: &str) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(AffinityObjective { state_key: AFFINITY_KEY })
+        .with_state(AffinityState { state_keys: vec![AFFINITY_KEY] })
+        .build()
+}
+
+struct AffinityObjective {
+    state_key: StateKey,
+}
+
+impl Objective for AffinityObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.state.get(&self.state_key).and_then(|s| s.downcast_ref::<Cost>()).copied().unwrap_or_default()
+    }
+}
+
+impl FeatureObjective for AffinityObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                let affinity = if let Some(affinity) = get_affinity(job) { affinity } else { return Cost::default() };
+
+                if is_affinity_served_by_route(route_ctx, affinity) {
+                    -1.
+                } else {
+                    solution_ctx.routes.iter().filter(|other| is_affinity_served_by_route(other, affinity)).count()
+                        as Cost
+                }
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct AffinityState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for AffinityState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let affinities =
+            route_ctx.route().tour.jobs().filter_map(|job| get_affinity(&job).cloned()).collect::<HashSet<_>>();
+
+        route_ctx.state_mut().put_route_state(AFFINITY_KEY, affinities);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+
+        let route_counts = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.state().get_route_state::<HashSet<String>>(AFFINITY_KEY))
+            .flat_map(|affinities| affinities.iter())
+            .fold(HashMap::<&String, usize>::new(), |mut acc, affinity| {
+                *acc.entry(affinity).or_default() += 1;
+                acc
+            });
+
+        let fitness = route_counts.values().map(|count| count - 1).sum::<usize>() as Cost;
+
+        solution_ctx.state.insert(AFFINITY_KEY, Arc::new(fitness));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_affinity(job: &Job) -> Option<&String> {
+    job.as_single().and_then(|single| single.dimens.get_affinity())
+}
+
+fn is_affinity_served_by_route(route_ctx: &RouteContext, affinity: &String) -> bool {
+    match route_ctx.state().get_route_state::<HashSet<String>>(AFFINITY_KEY) {
+        Some(affinities) => affinities.contains(affinity),
+        None => route_ctx.route().tour.jobs().any(|job| get_affinity(&job) == Some(affinity)),
+    }
+}
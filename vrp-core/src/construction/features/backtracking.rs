@@ -0,0 +1,145 @@
+//! Provides a way to penalize backtracking: routes which repeatedly move away from and then back
+//! toward the depot.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/backtracking_test.rs"]
+mod backtracking_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+use std::cmp::Ordering;
+
+/// Creates a feature which penalizes direction reversals relative to the depot (tour start) as soft
+/// objective. A vehicle is expected to move outward and then return, so each turn away from the
+/// depot after approaching it is counted as a backtrack and charged with `penalty` cost. A route
+/// which moves outward monotonically and then returns has no backtracks.
+pub fn create_backtracking_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    penalty: Cost,
+) -> Result<Feature, String> {
+    if penalty < 0. {
+        return Err("backtracking penalty should not be negative".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(BacktrackingObjective { transport: transport.clone(), penalty })
+        .with_state(BacktrackingState { transport, penalty, state_keys: vec![BACKTRACKING_KEY] })
+        .build()
+}
+
+struct BacktrackingObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    penalty: Cost,
+}
+
+impl Objective for BacktrackingObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .state
+            .get(&BACKTRACKING_KEY)
+            .and_then(|s| s.downcast_ref::<Cost>())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl FeatureObjective for BacktrackingObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let route = route_ctx.route();
+                let get_distance = |activity: &Activity| get_depot_distance(self.transport.as_ref(), route, activity);
+
+                // NOTE backtracks depend only on neighboring legs, so it is enough to check a window around insertion
+                let before = activity_ctx.index.checked_sub(1).and_then(|index| route.tour.get(index));
+                let after = route.tour.get(activity_ctx.index + 2);
+
+                let old_turns = count_turns(
+                    before
+                        .into_iter()
+                        .chain(Some(activity_ctx.prev))
+                        .chain(activity_ctx.next)
+                        .chain(after)
+                        .map(get_distance),
+                );
+                let new_turns = count_turns(
+                    before
+                        .into_iter()
+                        .chain(Some(activity_ctx.prev))
+                        .chain(Some(activity_ctx.target))
+                        .chain(activity_ctx.next)
+                        .chain(after)
+                        .map(get_distance),
+                );
+
+                (new_turns as Cost - old_turns as Cost) * self.penalty
+            }
+        }
+    }
+}
+
+struct BacktrackingState {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    penalty: Cost,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for BacktrackingState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let route = route_ctx.route();
+        let turns = count_turns(
+            route.tour.all_activities().map(|activity| get_depot_distance(self.transport.as_ref(), route, activity)),
+        );
+
+        route_ctx.state_mut().put_route_state(BACKTRACKING_KEY, turns as Cost * self.penalty);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+
+        let total = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.state().get_route_state::<Cost>(BACKTRACKING_KEY))
+            .sum::<Cost>();
+
+        solution_ctx.state.insert(BACKTRACKING_KEY, Arc::new(total));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_depot_distance(transport: &(dyn TransportCost + Send + Sync), route: &Route, activity: &Activity) -> Distance {
+    route.tour.start().map_or(Distance::default(), |start| {
+        transport.distance_approx(&route.actor.vehicle.profile, start.place.location, activity.place.location)
+    })
+}
+
+/// Counts turns away from the depot after approaching it. Legs which keep the same distance to
+/// the depot do not change the direction.
+fn count_turns(distances: impl Iterator<Item = Distance>) -> usize {
+    let (_, _, turns) =
+        distances.fold((None, false, 0), |(last, is_inward, turns): (Option<Distance>, bool, usize), distance| {
+            match last.map(|last| compare_floats(distance, last)) {
+                Some(Ordering::Less) => (Some(distance), true, turns),
+                Some(Ordering::Greater) => (Some(distance), false, turns + usize::from(is_inward)),
+                _ => (Some(distance), is_inward, turns),
+            }
+        });
+
+    turns
+}
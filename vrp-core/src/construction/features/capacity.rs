@@ -1,4 +1,7 @@
 //! Provides feature to add capacity limitation on a vehicle.
+//!
+//! Parts of a split delivery job (see `split_delivery_job`) are accounted as regular jobs with
+//! static delivery demand, so parts served by the same route share its capacity.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/capacity_test.rs"]
@@ -10,6 +13,7 @@ use crate::models::problem::Single;
 use crate::models::solution::Activity;
 use rosomaxa::prelude::Objective;
 use std::iter::once;
+use std::marker::PhantomData;
 use std::slice::Iter;
 use std::sync::Arc;
 
@@ -37,6 +41,54 @@ pub fn create_capacity_limit_feature<T: LoadOps>(name: &str, code: ViolationCode
         .build()
 }
 
+/// Creates a soft capacity feature as an objective: loading a vehicle beyond `threshold` (a ratio of
+/// its capacity) is penalized proportionally to the exceeded ratio using `penalty_rate`. It relies on
+/// route state maintained by the capacity limit feature, so it should be used together with one.
+pub fn create_soft_capacity_feature<T: LoadOps>(
+    name: &str,
+    threshold: f64,
+    penalty_rate: f64,
+) -> Result<Feature, String> {
+    if !(threshold > 0. && threshold <= 1.) {
+        return Err(format!("soft capacity threshold should be in (0, 1] range, got: {threshold}"));
+    }
+
+    if penalty_rate < 0. {
+        return Err(format!("soft capacity penalty rate should not be negative, got: {penalty_rate}"));
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(SoftCapacityObjective::<T> { threshold, penalty_rate, phantom: Default::default() })
+        .build()
+}
+
+/// Returns ids of jobs which demand does not fit into capacity of any vehicle in the fleet. Such jobs
+/// cannot be assigned unless their demand is split (see `split_delivery_job`), so they can be
+/// reported before solving instead of being left unassigned after the search. A multi job is
+/// considered as oversized when any of its sub jobs is oversized.
+pub fn detect_oversized_jobs<T: LoadOps>(problem: &Problem) -> Vec<String> {
+    let capacities =
+        problem.fleet.vehicles.iter().filter_map(|vehicle| vehicle.dimens.get_capacity()).collect::<Vec<&T>>();
+
+    let is_oversized = |single: &Arc<Single>| {
+        get_buffered_demand::<T>(&single.dimens).map_or(false, |demand| {
+            let size = (demand.pickup.0 + demand.pickup.1).max_load(demand.delivery.0 + demand.delivery.1);
+            !capacities.iter().any(|capacity| capacity.can_fit(&size))
+        })
+    };
+
+    problem
+        .jobs
+        .all()
+        .filter(|job| match job {
+            Job::Single(single) => is_oversized(single),
+            Job::Multi(multi) => multi.jobs.iter().any(is_oversized),
+        })
+        .filter_map(|job| job.dimens().get_id().cloned())
+        .collect()
+}
+
 struct CapacityConstraint<T: LoadOps> {
     code: ViolationCode,
     multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
@@ -70,6 +122,13 @@ impl<T: LoadOps> FeatureConstraint for CapacityConstraint<T> {
                         let mut dimens = s_source.dimens.clone();
                         dimens.set_demand(new_demand);
 
+                        let source_stock: Option<&T> = s_source.dimens.get_safety_stock();
+                        let candidate_stock: Option<&T> = s_candidate.dimens.get_safety_stock();
+                        if source_stock.is_some() || candidate_stock.is_some() {
+                            let get_stock = |stock: Option<&T>| stock.copied().unwrap_or_default();
+                            dimens.set_safety_stock(get_stock(source_stock) + get_stock(candidate_stock));
+                        }
+
                         Ok(Job::Single(Arc::new(Single { places: s_source.places.clone(), dimens })))
                     }
                 }
@@ -94,11 +153,19 @@ impl<T: LoadOps> CapacityConstraint<T> {
         };
 
         let can_handle = match job {
-            Job::Single(job) => {
-                can_handle_demand_on_intervals(route_ctx, self.multi_trip.as_ref(), job.dimens.get_demand(), None)
-            }
+            Job::Single(job) => can_handle_demand_on_intervals(
+                route_ctx,
+                self.multi_trip.as_ref(),
+                get_buffered_demand(&job.dimens).as_ref(),
+                None,
+            ),
             Job::Multi(job) => job.jobs.iter().any(|job| {
-                can_handle_demand_on_intervals(route_ctx, self.multi_trip.as_ref(), job.dimens.get_demand(), None)
+                can_handle_demand_on_intervals(
+                    route_ctx,
+                    self.multi_trip.as_ref(),
+                    get_buffered_demand(&job.dimens).as_ref(),
+                    None,
+                )
             }),
         };
 
@@ -132,6 +199,7 @@ impl<T: LoadOps> CapacityConstraint<T> {
         };
 
         let demand = get_demand(activity_ctx.target);
+        let demand = demand.as_ref();
 
         let violation = if activity_ctx.target.retrieve_job().map_or(false, |job| job.as_multi().is_some()) {
             // NOTE multi job has dynamic demand which can go in another interval
@@ -195,6 +263,59 @@ impl<T: LoadOps> FeatureObjective for CapacityObjective<T> {
     }
 }
 
+struct SoftCapacityObjective<T: LoadOps> {
+    threshold: f64,
+    penalty_rate: f64,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> SoftCapacityObjective<T> {
+    fn get_penalty(&self, load_ratio: f64) -> Cost {
+        (load_ratio - self.threshold).max(0.) * self.penalty_rate
+    }
+
+    fn get_route_load_ratio(route_ctx: &RouteContext) -> f64 {
+        route_ctx.state().get_route_state::<f64>(MAX_LOAD_KEY).cloned().unwrap_or(0.)
+    }
+
+    fn get_job_load(job: &Job) -> T {
+        let get_single_load = |single: &Single| {
+            get_buffered_demand(&single.dimens)
+                .map_or(T::default(), |demand: Demand<T>| demand.pickup.0 + demand.delivery.0 + demand.pickup.1)
+        };
+
+        match job {
+            Job::Single(single) => get_single_load(single),
+            Job::Multi(multi) => multi.jobs.iter().fold(T::default(), |acc, single| acc + get_single_load(single)),
+        }
+    }
+}
+
+impl<T: LoadOps> Objective for SoftCapacityObjective<T> {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|route_ctx| self.get_penalty(Self::get_route_load_ratio(route_ctx))).sum()
+    }
+}
+
+impl<T: LoadOps> FeatureObjective for SoftCapacityObjective<T> {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let capacity: Option<&T> = route_ctx.route().actor.vehicle.dimens.get_capacity();
+                let capacity = if let Some(capacity) = capacity { capacity } else { return Cost::default() };
+
+                let current = Self::get_route_load_ratio(route_ctx);
+                let estimated = current + Self::get_job_load(job).ratio(capacity);
+
+                self.get_penalty(estimated) - self.get_penalty(current)
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
 struct CapacityState<T: LoadOps> {
     multi_trip: Arc<dyn MultiTrip<Constraint = T> + Send + Sync>,
     context_transition: Box<dyn JobContextTransition + Send + Sync>,
@@ -395,6 +516,14 @@ fn can_handle_demand_on_intervals<T: LoadOps>(
         })
 }
 
-fn get_demand<T: LoadOps>(activity: &Activity) -> Option<&Demand<T>> {
-    activity.job.as_ref().and_then(|job| job.dimens.get_demand())
+fn get_demand<T: LoadOps>(activity: &Activity) -> Option<Demand<T>> {
+    activity.job.as_ref().and_then(|job| get_buffered_demand(&job.dimens))
+}
+
+/// Returns job demand with safety stock applied, if it is specified.
+fn get_buffered_demand<T: LoadOps>(dimens: &Dimensions) -> Option<Demand<T>> {
+    let demand: &Demand<T> = dimens.get_demand()?;
+    let stock: Option<&T> = dimens.get_safety_stock();
+
+    Some(stock.map_or_else(|| demand.clone(), |stock| demand.with_safety_stock(*stock)))
 }
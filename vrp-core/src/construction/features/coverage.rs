@@ -0,0 +1,126 @@
+//! Provides a way to maximize geographic coverage, e.g. for surveillance or sampling routing.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/coverage_test.rs"]
+mod coverage_test;
+
+use super::*;
+use hashbrown::HashSet;
+
+type Cell = (i64, i64);
+
+/// Creates a feature to maximize amount of distinct grid cells visited by all routes as soft objective.
+/// `coordinates` maps location index to a point on the plane, locations without coordinates are
+/// ignored. The plane is split into square cells with `resolution` side length. Solution fitness is
+/// a negated amount of distinct visited cells, so a solution which covers more cells is preferred
+/// regardless of traveled distance.
+pub fn create_coverage_feature(name: &str, coordinates: Vec<(f64, f64)>, resolution: f64) -> Result<Feature, String> {
+    if resolution <= 0. {
+        return Err("coverage grid resolution should be positive".to_string());
+    }
+
+    let grid = Arc::new(Grid { coordinates, resolution });
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(CoverageObjective { grid: grid.clone() })
+        .with_state(CoverageState { grid, state_keys: vec![COVERAGE_KEY] })
+        .build()
+}
+
+struct Grid {
+    coordinates: Vec<(f64, f64)>,
+    resolution: f64,
+}
+
+impl Grid {
+    fn get_cell(&self, location: Location) -> Option<Cell> {
+        self.coordinates
+            .get(location)
+            .map(|(x, y)| ((x / self.resolution).floor() as i64, (y / self.resolution).floor() as i64))
+    }
+
+    fn get_route_cells<'a>(&'a self, route_ctx: &'a RouteContext) -> impl Iterator<Item = Cell> + 'a {
+        route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .filter(|activity| activity.job.is_some())
+            .filter_map(|activity| self.get_cell(activity.place.location))
+    }
+}
+
+struct CoverageObjective {
+    grid: Arc<Grid>,
+}
+
+impl Objective for CoverageObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.state.get(&COVERAGE_KEY).and_then(|s| s.downcast_ref::<Cost>()).copied().unwrap_or_default()
+    }
+}
+
+impl FeatureObjective for CoverageObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let cell = if let Some(cell) = self.grid.get_cell(activity_ctx.target.place.location) {
+                    cell
+                } else {
+                    return Cost::default();
+                };
+
+                let is_covered = match route_ctx.state().get_route_state::<HashSet<Cell>>(COVERAGE_KEY) {
+                    Some(cells) => cells.contains(&cell),
+                    None => self.grid.get_route_cells(route_ctx).any(|covered| covered == cell),
+                };
+
+                if is_covered {
+                    Cost::default()
+                } else {
+                    -1.
+                }
+            }
+        }
+    }
+}
+
+struct CoverageState {
+    grid: Arc<Grid>,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for CoverageState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let cells = self.grid.get_route_cells(route_ctx).collect::<HashSet<_>>();
+
+        route_ctx.state_mut().put_route_state(COVERAGE_KEY, cells);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+
+        let fitness = -(solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.state().get_route_state::<HashSet<Cell>>(COVERAGE_KEY))
+            .flat_map(|cells| cells.iter())
+            .collect::<HashSet<_>>()
+            .len() as Cost);
+
+        solution_ctx.state.insert(COVERAGE_KEY, Arc::new(fitness));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
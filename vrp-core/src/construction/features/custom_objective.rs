@@ -0,0 +1,44 @@
+//! Provides the way to use a custom objective defined by a closure.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/custom_objective_test.rs"]
+mod custom_objective_test;
+
+use super::*;
+
+/// Specifies a function which returns fitness of the solution: the lower, the better.
+pub type ClosureObjectiveFn = Arc<dyn Fn(&SolutionContext, &Problem) -> f64 + Send + Sync>;
+
+/// Creates a feature with a custom objective, e.g. to combine multiple KPIs in one value, without
+/// implementing objective traits.
+pub fn create_custom_objective_feature(name: &str, fitness_fn: ClosureObjectiveFn) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(ClosureObjective::new(fitness_fn)).build()
+}
+
+/// An objective which delegates fitness calculation to a closure. As the closure can be evaluated
+/// only on a complete solution, insertion cost is not estimated, so the objective guides only the
+/// selection of solutions.
+pub struct ClosureObjective {
+    fitness_fn: ClosureObjectiveFn,
+}
+
+impl ClosureObjective {
+    /// Creates a new instance of `ClosureObjective`.
+    pub fn new(fitness_fn: ClosureObjectiveFn) -> Self {
+        Self { fitness_fn }
+    }
+}
+
+impl Objective for ClosureObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        (self.fitness_fn)(&solution.solution, solution.problem.as_ref())
+    }
+}
+
+impl FeatureObjective for ClosureObjective {
+    fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+        Cost::default()
+    }
+}
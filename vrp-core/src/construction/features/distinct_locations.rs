@@ -0,0 +1,95 @@
+//! Provides a way to minimize amount of distinct locations visited by the fleet, e.g. when visiting
+//! a new site has some overhead.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/distinct_locations_test.rs"]
+mod distinct_locations_test;
+
+use super::*;
+use hashbrown::HashSet;
+
+/// Creates a feature to minimize total amount of distinct locations visited by all routes as soft
+/// objective. An insertion of the job activity at location already visited by the route is preferred
+/// over insertion at a new location, so revisiting planned locations is rewarded when the job has
+/// alternative places. Solution fitness is the amount of distinct job locations across all routes.
+pub fn create_minimize_distinct_locations_feature(name: &str) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(DistinctLocationsObjective { state_key: DISTINCT_LOCATIONS_KEY })
+        .with_state(DistinctLocationsState { state_keys: vec![DISTINCT_LOCATIONS_KEY] })
+        .build()
+}
+
+struct DistinctLocationsObjective {
+    state_key: StateKey,
+}
+
+impl Objective for DistinctLocationsObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.state.get(&self.state_key).and_then(|s| s.downcast_ref::<Cost>()).copied().unwrap_or_default()
+    }
+}
+
+impl FeatureObjective for DistinctLocationsObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let location = activity_ctx.target.place.location;
+
+                let is_visited = match route_ctx.state().get_route_state::<HashSet<Location>>(self.state_key) {
+                    Some(locations) => locations.contains(&location),
+                    None => get_job_locations(route_ctx).any(|visited| visited == location),
+                };
+
+                if is_visited {
+                    Cost::default()
+                } else {
+                    1.
+                }
+            }
+        }
+    }
+}
+
+struct DistinctLocationsState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for DistinctLocationsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let locations = get_job_locations(route_ctx).collect::<HashSet<_>>();
+
+        route_ctx.state_mut().put_route_state(DISTINCT_LOCATIONS_KEY, locations);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+
+        let fitness = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.state().get_route_state::<HashSet<Location>>(DISTINCT_LOCATIONS_KEY))
+            .flat_map(|locations| locations.iter())
+            .collect::<HashSet<_>>()
+            .len() as Cost;
+
+        solution_ctx.state.insert(DISTINCT_LOCATIONS_KEY, Arc::new(fitness));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_job_locations(route_ctx: &RouteContext) -> impl Iterator<Item = Location> + '_ {
+    route_ctx.route().tour.all_activities().filter(|activity| activity.job.is_some()).map(|a| a.place.location)
+}
@@ -5,13 +5,14 @@
 mod fleet_usage_test;
 
 use super::*;
+use hashbrown::HashSet;
 
 /// Creates a feature to minimize used fleet size (affects amount of tours in solution).
 pub fn create_minimize_tours_feature(name: &str) -> Result<Feature, String> {
     FeatureBuilder::default()
         .with_name(name)
         .with_objective(FleetUsageObjective {
-            route_estimate_fn: Box::new(|route_ctx| if route_ctx.route().tour.job_count() == 0 { 1. } else { 0. }),
+            route_estimate_fn: Box::new(|_, route_ctx| if route_ctx.route().tour.job_count() == 0 { 1. } else { 0. }),
             solution_estimate_fn: Box::new(|solution_ctx| solution_ctx.routes.iter().len() as Cost),
         })
         .build()
@@ -22,18 +23,70 @@ pub fn create_maximize_tours_feature(name: &str) -> Result<Feature, String> {
     FeatureBuilder::default()
         .with_name(name)
         .with_objective(FleetUsageObjective {
-            route_estimate_fn: Box::new(|route_ctx| if route_ctx.route().tour.job_count() == 0 { -1. } else { 0. }),
+            route_estimate_fn: Box::new(|_, route_ctx| if route_ctx.route().tour.job_count() == 0 { -1. } else { 0. }),
             solution_estimate_fn: Box::new(|solution_ctx| -1. * solution_ctx.routes.iter().len() as Cost),
         })
         .build()
 }
 
+/// Creates a feature to keep used fleet size close to the target (affects amount of tours in solution).
+pub fn create_target_tours_feature(name: &str, target: usize) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(FleetUsageObjective {
+            route_estimate_fn: Box::new(move |solution_ctx, route_ctx| {
+                match (route_ctx.route().tour.job_count() == 0, solution_ctx.routes.len() < target) {
+                    (true, true) => -1.,
+                    (true, false) => 1.,
+                    _ => 0.,
+                }
+            }),
+            solution_estimate_fn: Box::new(move |solution_ctx| solution_ctx.routes.len().abs_diff(target) as Cost),
+        })
+        .build()
+}
+
+/// Creates a feature to keep used fleet size within a soft target: the first `target` tours are free
+/// and each extra tour is penalized with a cost which escalates by `increment` for every next one,
+/// so the k-th extra tour costs `penalty + (k - 1) * increment`. The penalty is expressed in cost
+/// units, so the feature is supposed to be on the same level as transport costs to let the solver
+/// use extra vehicles only when routing savings exceed their penalty.
+pub fn create_soft_fleet_size_feature(
+    name: &str,
+    target: usize,
+    penalty: Cost,
+    increment: Cost,
+) -> Result<Feature, String> {
+    if penalty < 0. || increment < 0. {
+        return Err("fleet size penalty and its increment should not be negative".to_string());
+    }
+
+    let get_extra_penalty = move |extra: usize| penalty + (extra as Cost) * increment;
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(FleetUsageObjective {
+            route_estimate_fn: Box::new(move |solution_ctx, route_ctx| {
+                let routes = solution_ctx.routes.len();
+                if route_ctx.route().tour.job_count() == 0 && routes >= target {
+                    get_extra_penalty(routes - target)
+                } else {
+                    0.
+                }
+            }),
+            solution_estimate_fn: Box::new(move |solution_ctx| {
+                (0..solution_ctx.routes.len().saturating_sub(target)).map(get_extra_penalty).sum()
+            }),
+        })
+        .build()
+}
+
 /// Creates a feature to tries to minimize arrival time of used fleet.
 pub fn create_minimize_arrival_time_feature(name: &str) -> Result<Feature, String> {
     FeatureBuilder::default()
         .with_name(name)
         .with_objective(FleetUsageObjective {
-            route_estimate_fn: Box::new(|route_ctx| route_ctx.route().actor.detail.time.start),
+            route_estimate_fn: Box::new(|_, route_ctx| route_ctx.route().actor.detail.time.start),
             solution_estimate_fn: Box::new(|solution_ctx| {
                 if solution_ctx.routes.is_empty() {
                     0.
@@ -52,8 +105,27 @@ pub fn create_minimize_arrival_time_feature(name: &str) -> Result<Feature, Strin
         .build()
 }
 
+/// Creates a feature to solve a problem with a fixed set of pre-opened routes: one route per each
+/// vehicle with given id. The solver only assigns and sequences jobs among these routes and never
+/// opens a route for other vehicles or a second route (e.g. another shift) of the same vehicle.
+/// This is a hard constraint. As actors of the same group are interchangeable for the registry, the
+/// given vehicles should not share a group with other vehicles.
+pub fn create_fixed_routes_feature(
+    name: &str,
+    vehicle_ids: HashSet<String>,
+    code: ViolationCode,
+) -> Result<Feature, String> {
+    if vehicle_ids.is_empty() {
+        return Err("fixed routes: at least one vehicle id should be specified".to_string());
+    }
+
+    FeatureBuilder::default().with_name(name).with_constraint(FixedRoutesConstraint { vehicle_ids, code }).build()
+}
+
+type RouteEstimateFn = Box<dyn Fn(&SolutionContext, &RouteContext) -> Cost + Send + Sync>;
+
 struct FleetUsageObjective {
-    route_estimate_fn: Box<dyn Fn(&RouteContext) -> Cost + Send + Sync>,
+    route_estimate_fn: RouteEstimateFn,
     solution_estimate_fn: Box<dyn Fn(&SolutionContext) -> Cost + Send + Sync>,
 }
 
@@ -68,8 +140,45 @@ impl Objective for FleetUsageObjective {
 impl FeatureObjective for FleetUsageObjective {
     fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
         match move_ctx {
-            MoveContext::Route { route_ctx, .. } => (self.route_estimate_fn)(route_ctx),
+            MoveContext::Route { solution_ctx, route_ctx, .. } => (self.route_estimate_fn)(solution_ctx, route_ctx),
             _ => Cost::default(),
         }
     }
 }
+
+struct FixedRoutesConstraint {
+    vehicle_ids: HashSet<String>,
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for FixedRoutesConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } if !route_ctx.route().tour.has_jobs() => {
+                let vehicle = &route_ctx.route().actor.vehicle;
+
+                let is_fixed = vehicle.dimens.get_id().map_or(false, |id| self.vehicle_ids.contains(id));
+                let is_opened = solution_ctx
+                    .routes
+                    .iter()
+                    .filter(|other| other.route().tour.has_jobs())
+                    .any(|other| Arc::ptr_eq(&other.route().actor.vehicle, vehicle));
+
+                if !is_fixed || is_opened {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+
+    fn depends_on_other_routes(&self) -> bool {
+        true
+    }
+}
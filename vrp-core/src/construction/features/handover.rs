@@ -0,0 +1,156 @@
+//! Provides a way to minimize amount of vehicle-to-vehicle handovers of goods in cross-dock scenarios.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/handover_test.rs"]
+mod handover_test;
+
+use super::*;
+use hashbrown::HashMap;
+
+const CONSIGNMENT_DIMENSION_KEY: &str = "consignment";
+
+/// A trait to get or set consignment: an id of goods which are moved through the network of
+/// transfer nodes by a chain of jobs.
+pub trait HandoverDimension {
+    /// Sets consignment.
+    fn set_consignment(&mut self, consignment: &str) -> &mut Self;
+    /// Gets consignment.
+    fn get_consignment(&self) -> Option<&String>;
+}
+
+impl HandoverDimension for Dimensions {
+    fn set_consignment(&mut self, consignment: &str) -> &mut Self {
+        self.set_value(CONSIGNMENT_DIMENSION_KEY, consignment.to_string());
+        self
+    }
+
+    fn get_consignment(&self) -> Option<&String> {
+        self.get_value(CONSIGNMENT_DIMENSION_KEY)
+    }
+}
+
+/// Creates a feature to minimize total amount of vehicle-to-vehicle handovers. Goods are modeled
+/// as a consignment: a chain of jobs which share the same consignment dimension, e.g. pickup at origin,
+/// drop at transfer node, pickup at the same transfer node and delivery at destination. Jobs of
+/// the consignment are ordered by their arrival time and each change of a serving vehicle between
+/// two consecutive jobs is counted as a handover at transfer node.
+///
+/// The order of consignment's jobs is not enforced by this feature: use job precedence feature
+/// for that purpose.
+pub fn create_handover_feature(name: &str) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(HandoverObjective {})
+        .with_state(HandoverState { state_keys: vec![HANDOVER_KEY] })
+        .build()
+}
+
+/// Keeps consignment and arrival time of route's consignment jobs in tour order.
+type ConsignmentVisits = Vec<(String, Timestamp)>;
+
+struct HandoverObjective {}
+
+impl Objective for HandoverObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let visits = solution.solution.routes.iter().enumerate().fold(
+            HashMap::<String, Vec<(Timestamp, usize)>>::new(),
+            |mut acc, (route_idx, route_ctx)| {
+                get_consignment_visits(route_ctx).into_iter().for_each(|(consignment, arrival)| {
+                    acc.entry(consignment).or_default().push((arrival, route_idx));
+                });
+                acc
+            },
+        );
+
+        visits
+            .into_values()
+            .map(|mut visits| {
+                // NOTE sort is stable, so visits of the same route keep tour order
+                visits.sort_by(|(a, _), (b, _)| compare_floats(*a, *b));
+                visits.windows(2).filter(|pair| pair[0].1 != pair[1].1).count()
+            })
+            .sum::<usize>() as f64
+    }
+}
+
+impl FeatureObjective for HandoverObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                let consignment = if let Some(consignment) = get_consignment(job) {
+                    consignment
+                } else {
+                    return Cost::default();
+                };
+
+                let is_served_by = |route_ctx: &RouteContext| is_consignment_served_by_route(route_ctx, consignment);
+
+                if !is_served_by(route_ctx) && solution_ctx.routes.iter().any(is_served_by) {
+                    1.
+                } else {
+                    Cost::default()
+                }
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct HandoverState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for HandoverState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let visits = create_consignment_visits(route_ctx);
+
+        route_ctx.state_mut().put_route_state(HANDOVER_KEY, visits);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_consignment(job: &Job) -> Option<&String> {
+    job.as_single().and_then(|single| single.dimens.get_consignment())
+}
+
+fn create_consignment_visits(route_ctx: &RouteContext) -> ConsignmentVisits {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .filter_map(|activity| {
+            let consignment = activity.job.as_ref().and_then(|single| single.dimens.get_consignment())?;
+            Some((consignment.clone(), activity.schedule.arrival))
+        })
+        .collect()
+}
+
+fn get_consignment_visits(route_ctx: &RouteContext) -> ConsignmentVisits {
+    route_ctx
+        .state()
+        .get_route_state::<ConsignmentVisits>(HANDOVER_KEY)
+        .cloned()
+        .unwrap_or_else(|| create_consignment_visits(route_ctx))
+}
+
+fn is_consignment_served_by_route(route_ctx: &RouteContext, consignment: &String) -> bool {
+    match route_ctx.state().get_route_state::<ConsignmentVisits>(HANDOVER_KEY) {
+        Some(visits) => visits.iter().any(|(other, _)| other == consignment),
+        None => route_ctx.route().tour.jobs().any(|job| get_consignment(&job) == Some(consignment)),
+    }
+}
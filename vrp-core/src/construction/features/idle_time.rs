@@ -0,0 +1,263 @@
+//! Provides the way to charge vehicle idle time (waiting at stops) separately from travel cost.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/idle_time_test.rs"]
+mod idle_time_test;
+
+use super::*;
+use crate::models::solution::Activity;
+
+/// Creates a feature to minimize idle time of the vehicles: waiting before time window start
+/// at each stop is charged with `per_idle_time` cost.
+pub fn create_minimize_idle_time_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    per_idle_time: Cost,
+) -> Result<Feature, String> {
+    if per_idle_time < 0. {
+        return Err("idle time cost should not be negative".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(IdleTimeObjective { transport, activity, per_idle_time })
+        .build()
+}
+
+/// Creates a feature to prefer working in a contiguous time block: the largest idle gap (waiting
+/// before time window start) within each route is charged with `per_idle_time` cost, so a few short
+/// waits are preferred over a single long one.
+pub fn create_minimize_max_idle_gap_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    per_idle_time: Cost,
+) -> Result<Feature, String> {
+    if per_idle_time < 0. {
+        return Err("idle time cost should not be negative".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MaxIdleGapObjective { transport, activity, per_idle_time })
+        .build()
+}
+
+/// Creates a feature to limit total idle time of the whole fleet as hard constraint: an insertion
+/// is rejected if it makes total waiting before time window start over all routes to exceed
+/// `max_idle_time`. Schedules of activities are expected to be updated by transport feature, so
+/// it should be specified before this one.
+pub fn create_idle_time_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    max_idle_time: Duration,
+) -> Result<Feature, String> {
+    if max_idle_time < 0. {
+        return Err("max idle time should not be negative".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(IdleTimeLimitConstraint { code, transport, activity, max_idle_time })
+        .with_state(IdleTimeLimitState { state_keys: vec![IDLE_TIME_KEY] })
+        .build()
+}
+
+struct IdleTimeObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    per_idle_time: Cost,
+}
+
+impl IdleTimeObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        estimate_idle_time(self.transport.as_ref(), self.activity.as_ref(), route_ctx, activity_ctx)
+            * self.per_idle_time
+    }
+}
+
+impl Objective for IdleTimeObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let total_idle = solution.solution.routes.iter().map(get_route_idle_time).sum::<Duration>();
+
+        total_idle * self.per_idle_time
+    }
+}
+
+impl FeatureObjective for IdleTimeObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+struct MaxIdleGapObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    per_idle_time: Cost,
+}
+
+impl Objective for MaxIdleGapObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let total_gap = solution.solution.routes.iter().map(get_route_max_idle_gap).sum::<Duration>();
+
+        total_gap * self.per_idle_time
+    }
+}
+
+impl FeatureObjective for MaxIdleGapObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let (target_idle, next_idle) =
+                    estimate_idle_times(self.transport.as_ref(), self.activity.as_ref(), route_ctx, activity_ctx);
+
+                // NOTE a decrease of the max gap, when it is at the next activity, is ignored
+                (target_idle.max(next_idle) - get_route_max_idle_gap(route_ctx)).max(0.) * self.per_idle_time
+            }
+        }
+    }
+}
+
+/// Keeps idle time of the route and idle time of all other routes in the solution.
+type IdleTimes = (Duration, Duration);
+
+struct IdleTimeLimitConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    max_idle_time: Duration,
+}
+
+impl FeatureConstraint for IdleTimeLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let (route_idle, others_idle) =
+                    route_ctx.state().get_route_state::<IdleTimes>(IDLE_TIME_KEY).copied().unwrap_or_default();
+                let delta =
+                    estimate_idle_time(self.transport.as_ref(), self.activity.as_ref(), route_ctx, activity_ctx);
+
+                if route_idle + others_idle + delta > self.max_idle_time {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct IdleTimeLimitState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for IdleTimeLimitState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        // NOTE idle time of other routes is not known here, it is restored when solution state is accepted
+        let others_idle =
+            route_ctx.state().get_route_state::<IdleTimes>(IDLE_TIME_KEY).map_or(0., |(_, others_idle)| *others_idle);
+        let route_idle = get_route_idle_time(route_ctx);
+
+        route_ctx.state_mut().put_route_state::<IdleTimes>(IDLE_TIME_KEY, (route_idle, others_idle));
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let route_idles = solution_ctx.routes.iter().map(get_route_idle_time).collect::<Vec<_>>();
+        let total_idle = route_idles.iter().sum::<Duration>();
+
+        solution_ctx.routes.iter_mut().zip(route_idles).for_each(|(route_ctx, route_idle)| {
+            route_ctx.state_mut().put_route_state::<IdleTimes>(IDLE_TIME_KEY, (route_idle, total_idle - route_idle));
+        });
+
+        // NOTE routes which are not used yet have no idle time on their own, but see the one of the solution
+        solution_ctx
+            .registry
+            .update_routes_state(|state| state.put_route_state::<IdleTimes>(IDLE_TIME_KEY, (0., total_idle)));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Estimates a change of idle time caused by the insertion of the target activity.
+fn estimate_idle_time(
+    transport: &(dyn TransportCost + Send + Sync),
+    activity: &(dyn ActivityCost + Send + Sync),
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+) -> Duration {
+    let (target_idle, next_idle) = estimate_idle_times(transport, activity, route_ctx, activity_ctx);
+
+    // NOTE consider only the next activity, a change of idle time further in the tour is ignored
+    let next_idle_delta = activity_ctx.next.map_or(0., |next| next_idle - get_idle_time(next, next.schedule.arrival));
+
+    target_idle + next_idle_delta
+}
+
+/// Estimates idle time at the target activity and at the next one after the target is inserted.
+fn estimate_idle_times(
+    transport: &(dyn TransportCost + Send + Sync),
+    activity: &(dyn ActivityCost + Send + Sync),
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+) -> (Duration, Duration) {
+    let route = route_ctx.route();
+    let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+    let arrival = prev.schedule.departure
+        + transport.duration(
+            route,
+            prev.place.location,
+            target.place.location,
+            TravelTime::Departure(prev.schedule.departure),
+        );
+    let target_idle = get_idle_time(target, arrival);
+
+    let next_idle = activity_ctx.next.map_or(0., |next| {
+        let departure = activity.estimate_departure(route, target, arrival);
+        let next_arrival = departure
+            + transport.duration(route, target.place.location, next.place.location, TravelTime::Departure(departure));
+
+        get_idle_time(next, next_arrival)
+    });
+
+    (target_idle, next_idle)
+}
+
+fn get_route_idle_time(route_ctx: &RouteContext) -> Duration {
+    route_ctx.route().tour.all_activities().map(|activity| get_idle_time(activity, activity.schedule.arrival)).sum()
+}
+
+fn get_route_max_idle_gap(route_ctx: &RouteContext) -> Duration {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .map(|activity| get_idle_time(activity, activity.schedule.arrival))
+        .fold(0., Duration::max)
+}
+
+fn get_idle_time(activity: &Activity, arrival: Timestamp) -> Duration {
+    (activity.place.time.start - arrival).max(0.)
+}
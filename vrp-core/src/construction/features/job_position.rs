@@ -0,0 +1,95 @@
+//! Provides a way to require job activity to be the first or the last one in the tour, e.g. when
+//! a key should be picked up before visiting any other customer.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_position_test.rs"]
+mod job_position_test;
+
+use super::*;
+use crate::models::solution::Activity;
+
+const JOB_POSITION_DIMENSION_KEY: &str = "job_position";
+
+/// Specifies a required position of job activity in the tour.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JobPosition {
+    /// Job activity should be served right after departure.
+    MustBeFirst,
+    /// Job activity should be served right before arrival or at the end of open tour.
+    MustBeLast,
+}
+
+/// A trait to get or set a required position of the job activity in the tour.
+pub trait JobPositionDimension {
+    /// Sets job position.
+    fn set_job_position(&mut self, position: JobPosition) -> &mut Self;
+    /// Gets job position.
+    fn get_job_position(&self) -> Option<JobPosition>;
+}
+
+impl JobPositionDimension for Dimensions {
+    fn set_job_position(&mut self, position: JobPosition) -> &mut Self {
+        self.set_value(JOB_POSITION_DIMENSION_KEY, position);
+        self
+    }
+
+    fn get_job_position(&self) -> Option<JobPosition> {
+        self.get_value(JOB_POSITION_DIMENSION_KEY).copied()
+    }
+}
+
+/// Creates a job position feature as hard constraint. A job activity with position specified on
+/// its dimensions is served only as the first or the last job activity in the tour, other job
+/// activities cannot be inserted before (after) it. For multi job, the position is specified on
+/// dimensions of its sub jobs.
+pub fn create_job_position_feature(name: &str, code: ViolationCode) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_constraint(JobPositionConstraint { code }).build()
+}
+
+struct JobPositionConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for JobPositionConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { activity_ctx, .. } => {
+                let is_first = activity_ctx.prev.job.is_none();
+                let is_last = activity_ctx.next.map_or(true, |next| next.job.is_none());
+
+                // NOTE fail when no further position in the tour can be valid, skip otherwise
+                match get_job_position(activity_ctx.target) {
+                    Some(JobPosition::MustBeFirst) if !is_first => return ConstraintViolation::fail(self.code),
+                    Some(JobPosition::MustBeLast) if !is_last => return ConstraintViolation::skip(self.code),
+                    _ => {}
+                }
+
+                if get_job_position(activity_ctx.prev) == Some(JobPosition::MustBeLast) {
+                    return ConstraintViolation::fail(self.code);
+                }
+
+                if activity_ctx.next.and_then(get_job_position) == Some(JobPosition::MustBeFirst) {
+                    return ConstraintViolation::skip(self.code);
+                }
+
+                None
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_position =
+            |job: &Job| job.as_single().map_or(false, |single| single.dimens.get_job_position().is_some());
+
+        if has_position(&source) || has_position(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+fn get_job_position(activity: &Activity) -> Option<JobPosition> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_position())
+}
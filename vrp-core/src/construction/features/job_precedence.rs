@@ -0,0 +1,296 @@
+//! Provides a way to enforce a partial order between jobs: a job should be visited before another one.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_precedence_test.rs"]
+mod job_precedence_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+use hashbrown::{HashMap, HashSet};
+
+/// Creates a job precedence feature as hard constraint. Each precedence is a pair of job ids where
+/// the first job should be visited before the second one. Precedences are transitive, so they define
+/// a partial order over jobs which should not contain cycles.
+///
+/// Jobs can be served by any route: when they share a route, the predecessor should be placed before
+/// the successor in the tour, otherwise, the predecessor should be departed before the successor is
+/// reached. An unassigned predecessor does not prevent its successors from being served.
+///
+/// Schedules of activities are expected to be updated by transport feature, so it should be
+/// specified before this one.
+pub fn create_job_precedence_feature(
+    name: &str,
+    code: ViolationCode,
+    precedences: &[(String, String)],
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    let relations = Arc::new(PrecedenceRelations::new(precedences)?);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(JobPrecedenceConstraint { code, relations: relations.clone(), transport, activity })
+        .with_state(JobPrecedenceState { relations, state_keys: vec![JOB_PRECEDENCE_KEY] })
+        .build()
+}
+
+struct PrecedenceRelations {
+    predecessors: HashMap<String, HashSet<String>>,
+    successors: HashMap<String, HashSet<String>>,
+}
+
+impl PrecedenceRelations {
+    fn new(precedences: &[(String, String)]) -> Result<Self, String> {
+        let direct =
+            precedences.iter().try_fold(HashMap::<String, HashSet<String>>::new(), |mut acc, (from, to)| {
+                if from == to {
+                    return Err(format!("job '{from}' cannot precede itself"));
+                }
+
+                acc.entry(from.clone()).or_default().insert(to.clone());
+                Ok(acc)
+            })?;
+
+        let successors = direct
+            .keys()
+            .map(|job_id| {
+                let mut reachable = HashSet::new();
+                let mut pending = direct.get(job_id).into_iter().flatten().collect::<Vec<_>>();
+
+                while let Some(next) = pending.pop() {
+                    if reachable.insert(next.clone()) {
+                        pending.extend(direct.get(next).into_iter().flatten());
+                    }
+                }
+
+                if reachable.contains(job_id) {
+                    Err(format!("precedences should not contain cycles, job '{job_id}' precedes itself"))
+                } else {
+                    Ok((job_id.clone(), reachable))
+                }
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        let predecessors = successors.iter().fold(HashMap::<String, HashSet<String>>::new(), |mut acc, (from, tos)| {
+            tos.iter().for_each(|to| {
+                acc.entry(to.clone()).or_default().insert(from.clone());
+            });
+            acc
+        });
+
+        Ok(Self { predecessors, successors })
+    }
+
+    fn contains(&self, job_id: &String) -> bool {
+        self.predecessors.contains_key(job_id) || self.successors.contains_key(job_id)
+    }
+
+    fn get_predecessors(&self, job_id: &String) -> impl Iterator<Item = &String> + '_ {
+        self.predecessors.get(job_id).into_iter().flatten()
+    }
+
+    fn get_successors(&self, job_id: &String) -> impl Iterator<Item = &String> + '_ {
+        self.successors.get(job_id).into_iter().flatten()
+    }
+}
+
+/// Keeps track where and when a job in precedence relation is served.
+struct JobSchedule {
+    actor: Arc<Actor>,
+    first_index: usize,
+    last_index: usize,
+    arrival: Timestamp,
+    departure: Timestamp,
+}
+
+type JobSchedules = HashMap<String, JobSchedule>;
+
+struct JobPrecedenceConstraint {
+    code: ViolationCode,
+    relations: Arc<PrecedenceRelations>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl JobPrecedenceConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let job_id = get_job_id(activity_ctx.target)?;
+        let schedules = route_ctx.state().get_route_state::<Arc<JobSchedules>>(JOB_PRECEDENCE_KEY)?;
+
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let index = activity_ctx.index;
+        let is_same_route = |schedule: &JobSchedule| Arc::ptr_eq(&schedule.actor, &route.actor);
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        let is_successor_violated =
+            self.relations.get_successors(&job_id).filter_map(|id| schedules.get(id)).any(|schedule| {
+                if is_same_route(schedule) {
+                    schedule.first_index <= index
+                } else {
+                    departure > schedule.arrival
+                }
+            });
+
+        if is_successor_violated {
+            return ConstraintViolation::fail(self.code);
+        }
+
+        let is_predecessor_violated =
+            self.relations.get_predecessors(&job_id).filter_map(|id| schedules.get(id)).any(|schedule| {
+                if is_same_route(schedule) {
+                    schedule.last_index > index
+                } else {
+                    arrival < schedule.departure
+                }
+            });
+
+        if is_predecessor_violated || self.is_shift_violated(route, activity_ctx, departure, schedules) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        None
+    }
+
+    /// Checks whether the insertion delays a predecessor in the route too much for its successors
+    /// in other routes. As delay cannot grow along the tour, a delay at next activity is used as an
+    /// upper bound.
+    fn is_shift_violated(
+        &self,
+        route: &Route,
+        activity_ctx: &ActivityContext,
+        departure: Timestamp,
+        schedules: &JobSchedules,
+    ) -> bool {
+        let (target, next) = match activity_ctx.next {
+            Some(next) => (activity_ctx.target, next),
+            None => return false,
+        };
+
+        let arrival_at_next = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let shift = arrival_at_next - next.schedule.arrival;
+
+        if shift <= 0. {
+            return false;
+        }
+
+        schedules
+            .iter()
+            .filter(|(_, schedule)| {
+                Arc::ptr_eq(&schedule.actor, &route.actor) && schedule.last_index > activity_ctx.index
+            })
+            .any(|(job_id, schedule)| {
+                self.relations
+                    .get_successors(job_id)
+                    .filter_map(|id| schedules.get(id))
+                    .filter(|other| !Arc::ptr_eq(&other.actor, &route.actor))
+                    .any(|other| schedule.departure + shift > other.arrival)
+            })
+    }
+}
+
+impl FeatureConstraint for JobPrecedenceConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_relations = |job: &Job| matches!(job.dimens().get_id(), Some(id) if self.relations.contains(id));
+
+        if has_relations(&source) || has_relations(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct JobPrecedenceState {
+    relations: Arc<PrecedenceRelations>,
+    state_keys: Vec<StateKey>,
+}
+
+impl JobPrecedenceState {
+    fn collect_schedules(&self, route_ctx: &RouteContext, schedules: &mut JobSchedules) {
+        let route = route_ctx.route();
+
+        route.tour.all_activities().enumerate().for_each(|(idx, activity)| {
+            let job_id = match get_job_id(activity) {
+                Some(job_id) if self.relations.contains(&job_id) => job_id,
+                _ => return,
+            };
+
+            schedules
+                .entry(job_id)
+                .and_modify(|schedule| {
+                    schedule.last_index = idx;
+                    schedule.departure = activity.schedule.departure;
+                })
+                .or_insert_with(|| JobSchedule {
+                    actor: route.actor.clone(),
+                    first_index: idx,
+                    last_index: idx,
+                    arrival: activity.schedule.arrival,
+                    departure: activity.schedule.departure,
+                });
+        });
+    }
+}
+
+impl FeatureState for JobPrecedenceState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        // NOTE schedules of jobs in other routes are not known here, they are restored when
+        // solution state is accepted
+        let mut schedules = JobSchedules::default();
+        self.collect_schedules(route_ctx, &mut schedules);
+
+        route_ctx.state_mut().put_route_state(JOB_PRECEDENCE_KEY, Arc::new(schedules));
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let schedules = Arc::new(solution_ctx.routes.iter().fold(JobSchedules::default(), |mut acc, route_ctx| {
+            self.collect_schedules(route_ctx, &mut acc);
+            acc
+        }));
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state(JOB_PRECEDENCE_KEY, schedules.clone());
+        });
+
+        // NOTE routes which are not used yet have to know schedules of the solution too
+        solution_ctx.registry.update_routes_state(|state| state.put_route_state(JOB_PRECEDENCE_KEY, schedules.clone()));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_job_id(activity: &Activity) -> Option<String> {
+    activity.retrieve_job().and_then(|job| job.dimens().get_id().cloned())
+}
@@ -0,0 +1,203 @@
+//! Provides the way to minimize amount of jobs served after their due time and their tardiness.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/late_jobs_test.rs"]
+mod late_jobs_test;
+
+use super::*;
+use crate::models::solution::Activity;
+
+const DUE_TIME_DIMENSION_KEY: &str = "due_time";
+const JOB_PRIORITY_DIMENSION_KEY: &str = "job_priority";
+
+/// A trait to get or set job due time: a soft deadline which, unlike time window end, can be
+/// violated at the price of making the job late.
+pub trait DueTimeDimension {
+    /// Sets due time.
+    fn set_due_time(&mut self, due_time: Timestamp) -> &mut Self;
+    /// Gets due time.
+    fn get_due_time(&self) -> Option<&Timestamp>;
+}
+
+impl DueTimeDimension for Dimensions {
+    fn set_due_time(&mut self, due_time: Timestamp) -> &mut Self {
+        self.set_value(DUE_TIME_DIMENSION_KEY, due_time);
+        self
+    }
+
+    fn get_due_time(&self) -> Option<&Timestamp> {
+        self.get_value(DUE_TIME_DIMENSION_KEY)
+    }
+}
+
+/// A trait to get or set job priority: a weight of job's tardiness.
+pub trait JobPriorityDimension {
+    /// Sets job priority.
+    fn set_job_priority(&mut self, priority: f64) -> &mut Self;
+    /// Gets job priority.
+    fn get_job_priority(&self) -> Option<&f64>;
+}
+
+impl JobPriorityDimension for Dimensions {
+    fn set_job_priority(&mut self, priority: f64) -> &mut Self {
+        self.set_value(JOB_PRIORITY_DIMENSION_KEY, priority);
+        self
+    }
+
+    fn get_job_priority(&self) -> Option<&f64> {
+        self.get_value(JOB_PRIORITY_DIMENSION_KEY)
+    }
+}
+
+/// Creates a feature to minimize amount of late jobs: a job is late when vehicle arrives after
+/// its due time or after its time window end if the window is soft. Only the count matters, so
+/// one very late job is preferred over a few slightly late ones. Each sub job of a multi job is
+/// counted separately.
+pub fn create_minimize_late_jobs_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(LateJobCountObjective { transport, activity }).build()
+}
+
+/// Creates a feature to minimize total weighted tardiness: a sum over jobs of their priority multiplied
+/// by the time vehicle arrives after their due time (or soft time window end). Unlike late jobs count,
+/// the magnitude of lateness matters, so a slightly late job with high priority can be penalized more
+/// than a very late job with low priority. A job without priority is weighted by one.
+pub fn create_minimize_weighted_tardiness_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(WeightedTardinessObjective { transport, activity }).build()
+}
+
+struct LateJobCountObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl Objective for LateJobCountObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        get_total_late_cost(solution, get_late_count)
+    }
+}
+
+impl FeatureObjective for LateJobCountObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => estimate_late_cost(
+                self.transport.as_ref(),
+                self.activity.as_ref(),
+                route_ctx,
+                activity_ctx,
+                get_late_count,
+            ),
+        }
+    }
+}
+
+struct WeightedTardinessObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl Objective for WeightedTardinessObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        get_total_late_cost(solution, get_weighted_tardiness)
+    }
+}
+
+impl FeatureObjective for WeightedTardinessObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => estimate_late_cost(
+                self.transport.as_ref(),
+                self.activity.as_ref(),
+                route_ctx,
+                activity_ctx,
+                get_weighted_tardiness,
+            ),
+        }
+    }
+}
+
+/// Specifies a function which returns a cost of serving activity at given arrival time.
+type LateCostFn = fn(&Activity, Timestamp) -> Cost;
+
+fn get_total_late_cost(solution: &InsertionContext, late_cost_fn: LateCostFn) -> Cost {
+    solution
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+        .map(|activity| late_cost_fn(activity, activity.schedule.arrival))
+        .sum()
+}
+
+fn estimate_late_cost(
+    transport: &(dyn TransportCost + Send + Sync),
+    activity: &(dyn ActivityCost + Send + Sync),
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+    late_cost_fn: LateCostFn,
+) -> Cost {
+    let route = route_ctx.route();
+    let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+    let arrival = prev.schedule.departure
+        + transport.duration(
+            route,
+            prev.place.location,
+            target.place.location,
+            TravelTime::Departure(prev.schedule.departure),
+        );
+
+    // NOTE consider only the next activity, a change of lateness further in the tour is ignored
+    let next_delta = activity_ctx.next.map_or(0., |next| {
+        let departure = activity.estimate_departure(route, target, arrival);
+        let next_arrival = departure
+            + transport.duration(route, target.place.location, next.place.location, TravelTime::Departure(departure));
+
+        late_cost_fn(next, next_arrival) - late_cost_fn(next, next.schedule.arrival)
+    });
+
+    late_cost_fn(target, arrival) + next_delta
+}
+
+fn get_late_count(activity: &Activity, arrival: Timestamp) -> Cost {
+    let is_late = get_due_time(activity).map_or(false, |due_time| arrival > due_time);
+
+    if is_late {
+        1.
+    } else {
+        0.
+    }
+}
+
+fn get_weighted_tardiness(activity: &Activity, arrival: Timestamp) -> Cost {
+    activity.job.as_ref().map_or(0., |single| {
+        let tardiness = get_due_time(activity).map_or(0., |due_time| (arrival - due_time).max(0.));
+        let priority = single.dimens.get_job_priority().copied().unwrap_or(1.);
+
+        priority * tardiness
+    })
+}
+
+/// Returns job's due time. A job with soft time window, but without explicit due time, is due at
+/// the end of its time window.
+fn get_due_time(activity: &Activity) -> Option<Timestamp> {
+    activity.job.as_ref().and_then(|single| {
+        single.dimens.get_due_time().copied().or_else(|| match single.dimens.get_window_hardness() {
+            Some(WindowHardness::Soft) => Some(activity.place.time.end),
+            _ => None,
+        })
+    })
+}
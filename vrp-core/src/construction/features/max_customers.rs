@@ -0,0 +1,78 @@
+//! Provides a way to limit amount of distinct customers served by a route.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/max_customers_test.rs"]
+mod max_customers_test;
+
+use super::*;
+use hashbrown::HashSet;
+
+const CUSTOMER_DIMENSION_KEY: &str = "customer";
+
+/// A trait to get or set an id of the customer who owns the job.
+pub trait CustomerDimension {
+    /// Sets customer id.
+    fn set_customer(&mut self, customer: &str) -> &mut Self;
+    /// Gets customer id.
+    fn get_customer(&self) -> Option<&String>;
+}
+
+impl CustomerDimension for Dimensions {
+    fn set_customer(&mut self, customer: &str) -> &mut Self {
+        self.set_value(CUSTOMER_DIMENSION_KEY, customer.to_string());
+        self
+    }
+
+    fn get_customer(&self) -> Option<&String> {
+        self.get_value(CUSTOMER_DIMENSION_KEY)
+    }
+}
+
+/// Creates a limit for amount of distinct customers served by a tour: multiple jobs of the same
+/// customer count as one. Jobs without customer id are not counted.
+/// This is a hard constraint.
+pub fn create_max_customers_per_route_feature(
+    name: &str,
+    code: ViolationCode,
+    limit_fn: ActivitySizeResolver,
+) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_constraint(MaxCustomersPerRouteConstraint { code, limit_fn }).build()
+}
+
+struct MaxCustomersPerRouteConstraint {
+    code: ViolationCode,
+    limit_fn: ActivitySizeResolver,
+}
+
+impl FeatureConstraint for MaxCustomersPerRouteConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let customer = job.dimens().get_customer()?;
+                let limit = (self.limit_fn)(route_ctx.route().actor.as_ref())?;
+
+                let customers = route_ctx
+                    .route()
+                    .tour
+                    .jobs()
+                    .filter_map(|job| job.dimens().get_customer().cloned())
+                    .collect::<HashSet<_>>();
+
+                if customers.contains(customer) || customers.len() < limit {
+                    ConstraintViolation::success()
+                } else {
+                    ConstraintViolation::fail(self.code)
+                }
+            }
+            MoveContext::Activity { .. } => ConstraintViolation::success(),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if source.dimens().get_customer() == candidate.dimens().get_customer() {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Provides a way to limit time between pickup and delivery of the same job (e.g. dial-a-ride problem).
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/max_ride_time_test.rs"]
+mod max_ride_time_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+
+const MAX_RIDE_TIME_DIMENSION_KEY: &str = "max_ride_time";
+
+/// A trait to get or set max ride time of multi job: max duration between departure from its
+/// first activity and arrival at its last activity in the tour.
+pub trait MaxRideTimeDimension {
+    /// Sets max ride time.
+    fn set_max_ride_time(&mut self, max_ride_time: Duration) -> &mut Self;
+    /// Gets max ride time.
+    fn get_max_ride_time(&self) -> Option<Duration>;
+}
+
+impl MaxRideTimeDimension for Dimensions {
+    fn set_max_ride_time(&mut self, max_ride_time: Duration) -> &mut Self {
+        self.set_value(MAX_RIDE_TIME_DIMENSION_KEY, max_ride_time);
+        self
+    }
+
+    fn get_max_ride_time(&self) -> Option<Duration> {
+        self.get_value(MAX_RIDE_TIME_DIMENSION_KEY).copied()
+    }
+}
+
+/// Creates a max ride time feature as hard constraint. Pickup and delivery of the multi job can
+/// have independent time windows, but time elapsed between them should not exceed the max ride time
+/// specified on multi job's dimensions. Jobs without max ride time are not affected.
+///
+/// Schedules of activities are expected to be updated by transport feature, so it should be
+/// specified before this one.
+pub fn create_max_ride_time_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(MaxRideTimeConstraint { code, transport, activity })
+        .with_state(MaxRideTimeState { state_keys: vec![MAX_RIDE_TIME_KEY] })
+        .build()
+}
+
+/// Keeps track of multi job's ride in the tour.
+struct RideSchedule {
+    first_index: usize,
+    last_index: usize,
+    departure: Timestamp,
+    arrival: Timestamp,
+    max_ride_time: Duration,
+}
+
+struct MaxRideTimeConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl MaxRideTimeConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+
+        if let Some((multi, max_ride_time)) = get_ride_job(target) {
+            // NOTE already inserted activities of the same job are served before the target
+            let ride_start = route
+                .tour
+                .all_activities()
+                .take(activity_ctx.index + 1)
+                .filter(|activity| activity.has_same_job(&multi))
+                .map(|activity| activity.schedule.departure)
+                .next();
+
+            // NOTE arrival cannot become earlier at later positions, so there is no need to continue
+            if ride_start.map_or(false, |departure| arrival - departure > max_ride_time) {
+                return ConstraintViolation::fail(self.code);
+            }
+        }
+
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        if self.is_shift_violated(route_ctx, activity_ctx, departure) {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        None
+    }
+
+    /// Checks whether the insertion delays arrival at the end of other rides too much. As delay
+    /// cannot grow along the tour, a delay at next activity is used as an upper bound.
+    fn is_shift_violated(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        departure: Timestamp,
+    ) -> bool {
+        let route = route_ctx.route();
+        let (target, next) = match activity_ctx.next {
+            Some(next) => (activity_ctx.target, next),
+            None => return false,
+        };
+
+        let schedules = match route_ctx.state().get_route_state::<Vec<RideSchedule>>(MAX_RIDE_TIME_KEY) {
+            Some(schedules) => schedules,
+            None => return false,
+        };
+
+        let arrival_at_next = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let shift = arrival_at_next - next.schedule.arrival;
+
+        if shift <= 0. {
+            return false;
+        }
+
+        schedules
+            .iter()
+            .filter(|schedule| schedule.first_index <= activity_ctx.index && activity_ctx.index < schedule.last_index)
+            .any(|schedule| schedule.arrival + shift - schedule.departure > schedule.max_ride_time)
+    }
+}
+
+impl FeatureConstraint for MaxRideTimeConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_ride_time = |job: &Job| job.dimens().get_max_ride_time().is_some();
+
+        if has_ride_time(&source) || has_ride_time(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+struct MaxRideTimeState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for MaxRideTimeState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let schedules = collect_ride_schedules(route_ctx.route());
+
+        route_ctx.state_mut().put_route_state(MAX_RIDE_TIME_KEY, schedules);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn collect_ride_schedules(route: &Route) -> Vec<RideSchedule> {
+    route
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter_map(|(idx, activity)| {
+            get_ride_job(activity).map(|(job, max_ride_time)| (idx, activity, job, max_ride_time))
+        })
+        .fold(Vec::<(Job, RideSchedule)>::new(), |mut acc, (idx, activity, job, max_ride_time)| {
+            match acc.iter_mut().find(|(other, _)| activity.has_same_job(other)) {
+                Some((_, schedule)) => {
+                    schedule.last_index = idx;
+                    schedule.arrival = activity.schedule.arrival;
+                }
+                None => acc.push((
+                    job,
+                    RideSchedule {
+                        first_index: idx,
+                        last_index: idx,
+                        departure: activity.schedule.departure,
+                        arrival: activity.schedule.arrival,
+                        max_ride_time,
+                    },
+                )),
+            }
+
+            acc
+        })
+        .into_iter()
+        .map(|(_, schedule)| schedule)
+        .filter(|schedule| schedule.first_index < schedule.last_index)
+        .collect()
+}
+
+fn get_ride_job(activity: &Activity) -> Option<(Job, Duration)> {
+    let single = activity.job.as_ref()?;
+    let multi = Multi::roots(single)?;
+    let max_ride_time = multi.dimens.get_max_ride_time()?;
+
+    Some((Job::Multi(multi), max_ride_time))
+}
@@ -8,21 +8,81 @@ use rosomaxa::prelude::*;
 use std::slice::Iter;
 use std::sync::Arc;
 
+mod affinity;
+pub use self::affinity::*;
+
+mod backtracking;
+pub use self::backtracking::*;
+
 mod capacity;
 pub use self::capacity::*;
 
+mod coverage;
+pub use self::coverage::*;
+
+mod custom_objective;
+pub use self::custom_objective::*;
+
+mod distinct_locations;
+pub use self::distinct_locations::*;
+
 mod fleet_usage;
 pub use self::fleet_usage::*;
 
+mod handover;
+pub use self::handover::*;
+
+mod idle_time;
+pub use self::idle_time::*;
+
+mod job_position;
+pub use self::job_position::*;
+
+mod job_precedence;
+pub use self::job_precedence::*;
+
+mod late_jobs;
+pub use self::late_jobs::*;
+
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
+mod max_customers;
+pub use self::max_customers::*;
+
+mod max_ride_time;
+pub use self::max_ride_time::*;
+
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
+mod neighbor_distance;
+pub use self::neighbor_distance::*;
+
+mod periodic_visits;
+pub use self::periodic_visits::*;
+
+mod return_time_spread;
+pub use self::return_time_spread::*;
+
+mod route_crossings;
+pub use self::route_crossings::*;
+
+mod scheduled_weights;
+pub use self::scheduled_weights::*;
+
+mod service_tiers;
+pub use self::service_tiers::*;
+
 mod shared_resource;
 pub use self::shared_resource::*;
 
+mod split_delivery;
+pub use self::split_delivery::*;
+
+mod stop_density;
+pub use self::stop_density::*;
+
 mod total_value;
 pub use self::total_value::*;
 
@@ -41,6 +101,9 @@ pub use self::transport::*;
 mod work_balance;
 pub use self::work_balance::*;
 
+mod zone_routes;
+pub use self::zone_routes::*;
+
 /// A key which tracks latest arrival.
 pub const LATEST_ARRIVAL_KEY: i32 = 1;
 /// A key which tracks waiting time.
@@ -71,3 +134,36 @@ pub const BALANCE_ACTIVITY_KEY: i32 = 21;
 pub const BALANCE_DISTANCE_KEY: i32 = 22;
 /// A key for balancing duration.
 pub const BALANCE_DURATION_KEY: i32 = 23;
+/// A key for balancing depot workload.
+pub const BALANCE_DEPOT_KEY: i32 = 24;
+/// A key for peak concurrent load of the fleet.
+pub const PEAK_CONCURRENT_LOAD_KEY: i32 = 25;
+
+/// A key which tracks split delivery groups served by the route.
+pub const SPLIT_DELIVERY_KEY: i32 = 30;
+/// A key which tracks schedules of jobs in precedence relation.
+pub const JOB_PRECEDENCE_KEY: i32 = 31;
+/// A key which tracks periodic visit groups served by the route.
+pub const PERIODIC_VISITS_KEY: i32 = 32;
+/// A key which tracks affinity groups served by the route.
+pub const AFFINITY_KEY: i32 = 33;
+/// A key which tracks penalized route crossings.
+pub const ROUTE_CROSSINGS_KEY: i32 = 34;
+/// A key which tracks rides of jobs with max ride time.
+pub const MAX_RIDE_TIME_KEY: i32 = 35;
+/// A key which tracks idle time of the route and the rest of the fleet.
+pub const IDLE_TIME_KEY: i32 = 36;
+/// A key which tracks distinct locations visited by the route.
+pub const DISTINCT_LOCATIONS_KEY: i32 = 37;
+/// A key which tracks zones served by the route.
+pub const ZONE_ROUTES_KEY: i32 = 38;
+/// A key which tracks consignments served by the route.
+pub const HANDOVER_KEY: i32 = 39;
+/// A key which tracks penalized backtracks of the route.
+pub const BACKTRACKING_KEY: i32 = 40;
+/// A key which tracks grid cells covered by the route.
+pub const COVERAGE_KEY: i32 = 41;
+/// A key which tracks generation used to get scheduled objective weights.
+pub const WEIGHT_GENERATION_KEY: i32 = 42;
+/// A key which tracks generation at which the solution is produced.
+pub const SOLUTION_GENERATION_KEY: i32 = 43;
@@ -0,0 +1,84 @@
+//! Provides a way to prevent long detours by limiting how far a job can be inserted from its nearest neighbor.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/neighbor_distance_test.rs"]
+mod neighbor_distance_test;
+
+use super::*;
+
+/// Creates a feature which rejects job activity insertions when the distance from the job to all its
+/// route neighbors exceeds `ratio` multiplied by the distance to the job's nearest neighbor in the problem.
+///
+/// Only route neighbors which are jobs are considered: vehicle's start and end are ignored, so
+/// the first job can always be inserted into an empty route. Please note that a job which shares
+/// location with its nearest neighbor can be inserted only next to a job at the same location.
+pub fn create_neighbor_distance_limit_feature(
+    name: &str,
+    jobs: Arc<Jobs>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    ratio: f64,
+    code: ViolationCode,
+) -> Result<Feature, String> {
+    if ratio < 1. {
+        return Err("neighbor distance ratio should not be less than 1".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(NeighborDistanceConstraint { jobs, transport, ratio, code })
+        .build()
+}
+
+struct NeighborDistanceConstraint {
+    jobs: Arc<Jobs>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    ratio: f64,
+    code: ViolationCode,
+}
+
+impl NeighborDistanceConstraint {
+    /// Returns the distance from job's location to its nearest neighbor.
+    fn get_nearest_distance(&self, profile: &Profile, job: &Job, location: Location) -> Option<Distance> {
+        let (neighbor, _) = self.jobs.neighbors(profile, job, Timestamp::default()).next()?;
+
+        get_job_locations(neighbor)
+            .flatten()
+            .map(|other| self.transport.distance_approx(profile, location, other))
+            .min_by(|a, b| compare_floats(*a, *b))
+    }
+}
+
+impl FeatureConstraint for NeighborDistanceConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let job = activity_ctx.target.retrieve_job()?;
+                let profile = &route_ctx.route().actor.vehicle.profile;
+                let location = activity_ctx.target.place.location;
+
+                let limit = self.get_nearest_distance(profile, &job, location)? * self.ratio;
+
+                let prev = Some(activity_ctx.prev)
+                    .filter(|prev| prev.job.is_some())
+                    .map(|prev| self.transport.distance_approx(profile, prev.place.location, location));
+                let next = activity_ctx
+                    .next
+                    .filter(|next| next.job.is_some())
+                    .map(|next| self.transport.distance_approx(profile, location, next.place.location));
+
+                let is_far = |distance: Option<Distance>| distance.map_or(true, |distance| distance > limit);
+
+                if (prev.is_some() || next.is_some()) && is_far(prev) && is_far(next) {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
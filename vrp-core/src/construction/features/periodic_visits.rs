@@ -0,0 +1,151 @@
+//! Provides a way to visit the same customer multiple times over a planning horizon (periodic VRP).
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/periodic_visits_test.rs"]
+mod periodic_visits_test;
+
+use super::*;
+use hashbrown::HashSet;
+
+const VISIT_GROUP_DIMENSION_KEY: &str = "visit_group";
+
+/// A trait to get or set visit group: an id of the original job which requires multiple visits.
+pub trait PeriodicVisitDimension {
+    /// Sets visit group.
+    fn set_visit_group(&mut self, group: &str) -> &mut Self;
+    /// Gets visit group.
+    fn get_visit_group(&self) -> Option<&String>;
+}
+
+impl PeriodicVisitDimension for Dimensions {
+    fn set_visit_group(&mut self, group: &str) -> &mut Self {
+        self.set_value(VISIT_GROUP_DIMENSION_KEY, group.to_string());
+        self
+    }
+
+    fn get_visit_group(&self) -> Option<&String> {
+        self.get_value(VISIT_GROUP_DIMENSION_KEY)
+    }
+}
+
+/// Creates `visits` copies of the job which should be served on different days. Each visit keeps
+/// original job's places and dimensions, gets its own id and shares the same visit group.
+pub fn create_periodic_visit_jobs(job: &Arc<Single>, visits: usize) -> Result<Vec<Job>, String> {
+    if visits == 0 {
+        return Err("amount of visits should be positive".to_string());
+    }
+
+    let group = job.dimens.get_id().ok_or_else(|| "periodic visit job should have an id".to_string())?.clone();
+
+    Ok((0..visits)
+        .map(|idx| {
+            let mut dimens = job.dimens.clone();
+            dimens.set_id(format!("{group}_{idx}").as_str()).set_visit_group(group.as_str());
+
+            Job::Single(Arc::new(Single { places: job.places.clone(), dimens }))
+        })
+        .collect())
+}
+
+/// Creates a periodic visits feature as hard constraint. A multi-day horizon is modeled by vehicle
+/// shifts: visits of the same group should be served by routes which shift starts are at least
+/// `min_spacing` apart, so two visits cannot share a route.
+pub fn create_periodic_visits_feature(
+    name: &str,
+    code: ViolationCode,
+    min_spacing: Duration,
+) -> Result<Feature, String> {
+    if min_spacing <= 0. {
+        return Err("min spacing between visits should be positive".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(PeriodicVisitsConstraint { code, min_spacing })
+        .with_state(PeriodicVisitsState { state_keys: vec![PERIODIC_VISITS_KEY] })
+        .build()
+}
+
+struct PeriodicVisitsConstraint {
+    code: ViolationCode,
+    min_spacing: Duration,
+}
+
+impl FeatureConstraint for PeriodicVisitsConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                let group = get_visit_group(job)?;
+                let shift_start = route_ctx.route().actor.detail.time.start;
+
+                let is_too_close = |other: &RouteContext| {
+                    (other.route().actor.detail.time.start - shift_start).abs() < self.min_spacing
+                };
+
+                let has_conflict = is_group_served_by_route(route_ctx, group)
+                    || solution_ctx
+                        .routes
+                        .iter()
+                        .filter(|other| is_group_served_by_route(other, group))
+                        .any(is_too_close);
+
+                if has_conflict {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if get_visit_group(&source).is_some() || get_visit_group(&candidate).is_some() {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn depends_on_other_routes(&self) -> bool {
+        true
+    }
+}
+
+struct PeriodicVisitsState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for PeriodicVisitsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let groups =
+            route_ctx.route().tour.jobs().filter_map(|job| get_visit_group(&job).cloned()).collect::<HashSet<_>>();
+
+        route_ctx.state_mut().put_route_state(PERIODIC_VISITS_KEY, groups);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_visit_group(job: &Job) -> Option<&String> {
+    job.as_single().and_then(|single| single.dimens.get_visit_group())
+}
+
+fn is_group_served_by_route(route_ctx: &RouteContext, group: &String) -> bool {
+    match route_ctx.state().get_route_state::<HashSet<String>>(PERIODIC_VISITS_KEY) {
+        Some(groups) => groups.contains(group),
+        None => route_ctx.route().tour.jobs().any(|job| get_visit_group(&job) == Some(group)),
+    }
+}
@@ -0,0 +1,56 @@
+//! Provides a way to spread return times of routes, e.g. to avoid congestion at depot.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/return_time_spread_test.rs"]
+mod return_time_spread_test;
+
+use super::*;
+
+/// Creates a feature which minimizes congestion of route returns: vehicles are penalized for
+/// returning within the given time interval from each other.
+pub fn create_return_time_spread_feature(name: &str, interval: Duration) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_objective(ReturnTimeSpreadObjective::new(interval)?).build()
+}
+
+/// An objective which keeps route return times apart. Its fitness is a total shortage of time
+/// between consecutive route returns comparing to the desired interval, so it is zero when all
+/// vehicles return at least `interval` apart.
+/// NOTE: as return times are known only for complete routes, insertion cost is not estimated, so
+/// the objective guides only the selection of solutions.
+pub struct ReturnTimeSpreadObjective {
+    interval: Duration,
+}
+
+impl ReturnTimeSpreadObjective {
+    /// Creates a new instance of `ReturnTimeSpreadObjective`.
+    pub fn new(interval: Duration) -> Result<Self, String> {
+        if interval <= 0. {
+            return Err("return time interval should be positive".to_string());
+        }
+
+        Ok(Self { interval })
+    }
+}
+
+impl Objective for ReturnTimeSpreadObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let mut return_times = solution
+            .solution
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.route().tour.end())
+            .map(|end| end.schedule.arrival)
+            .collect::<Vec<_>>();
+        return_times.sort_by(|a, b| compare_floats(*a, *b));
+
+        return_times.windows(2).map(|pair| (self.interval - (pair[1] - pair[0])).max(0.)).sum()
+    }
+}
+
+impl FeatureObjective for ReturnTimeSpreadObjective {
+    fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+        Cost::default()
+    }
+}
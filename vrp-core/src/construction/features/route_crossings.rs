@@ -0,0 +1,142 @@
+//! Provides a way to penalize self-intersections of the routes to produce visually clean tours.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/route_crossings_test.rs"]
+mod route_crossings_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+
+type Point = (f64, f64);
+type Segment = (Point, Point);
+
+/// Creates a feature which penalizes crossings of segments within the same route as soft objective.
+/// `coordinates` maps location index to a point on the plane, locations without coordinates are
+/// ignored. Each crossing is charged with `penalty` cost.
+pub fn create_route_crossings_feature(name: &str, coordinates: Vec<Point>, penalty: Cost) -> Result<Feature, String> {
+    if penalty < 0. {
+        return Err("route crossing penalty should not be negative".to_string());
+    }
+
+    let coordinates = Arc::new(coordinates);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(RouteCrossingsObjective { coordinates: coordinates.clone(), penalty })
+        .with_state(RouteCrossingsState { coordinates, penalty, state_keys: vec![ROUTE_CROSSINGS_KEY] })
+        .build()
+}
+
+struct RouteCrossingsObjective {
+    coordinates: Arc<Vec<Point>>,
+    penalty: Cost,
+}
+
+impl Objective for RouteCrossingsObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .state
+            .get(&ROUTE_CROSSINGS_KEY)
+            .and_then(|s| s.downcast_ref::<Cost>())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl FeatureObjective for RouteCrossingsObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let get_point = |activity: &Activity| self.coordinates.get(activity.place.location).copied();
+
+                let (prev, target) = (get_point(activity_ctx.prev), get_point(activity_ctx.target));
+                let next = activity_ctx.next.and_then(get_point);
+
+                // NOTE a leg between prev and next activities is replaced by two new legs
+                let other_segments = get_segments(route_ctx.route(), self.coordinates.as_slice())
+                    .filter(|(leg_idx, _)| *leg_idx != activity_ctx.index)
+                    .map(|(_, segment)| segment)
+                    .collect::<Vec<_>>();
+
+                let count_crossings = |segment: Option<Segment>| {
+                    segment.map_or(0., |segment| {
+                        other_segments.iter().filter(|other| is_crossing(&segment, other)).count() as f64
+                    })
+                };
+
+                let new_crossings = count_crossings(prev.zip(target)) + count_crossings(target.zip(next));
+                let old_crossings = count_crossings(prev.zip(next));
+
+                (new_crossings - old_crossings) * self.penalty
+            }
+        }
+    }
+}
+
+struct RouteCrossingsState {
+    coordinates: Arc<Vec<Point>>,
+    penalty: Cost,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for RouteCrossingsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let segments = get_segments(route_ctx.route(), self.coordinates.as_slice())
+            .map(|(_, segment)| segment)
+            .collect::<Vec<_>>();
+
+        let crossings = segments
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, segment)| segments.iter().skip(idx + 1).map(move |other| (segment, other)))
+            .filter(|(segment, other)| is_crossing(segment, other))
+            .count();
+
+        route_ctx.state_mut().put_route_state(ROUTE_CROSSINGS_KEY, crossings as Cost * self.penalty);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+
+        let total = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| route_ctx.state().get_route_state::<Cost>(ROUTE_CROSSINGS_KEY))
+            .sum::<Cost>();
+
+        solution_ctx.state.insert(ROUTE_CROSSINGS_KEY, Arc::new(total));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+/// Returns segments of the route with their leg index, legs without known coordinates are skipped.
+fn get_segments<'a>(route: &'a Route, coordinates: &'a [Point]) -> impl Iterator<Item = (usize, Segment)> + 'a {
+    route.tour.legs().filter_map(move |(items, leg_idx)| match items {
+        [from, to] => coordinates
+            .get(from.place.location)
+            .copied()
+            .zip(coordinates.get(to.place.location).copied())
+            .map(|segment| (leg_idx, segment)),
+        _ => None,
+    })
+}
+
+/// Checks whether two segments properly intersect, segments which share an end are not crossing.
+fn is_crossing((p1, p2): &Segment, (q1, q2): &Segment) -> bool {
+    let orientation = |a: &Point, b: &Point, c: &Point| (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+
+    orientation(p1, p2, q1) * orientation(p1, p2, q2) < 0. && orientation(q1, q2, p1) * orientation(q1, q2, p2) < 0.
+}
@@ -0,0 +1,167 @@
+//! Provides a way to combine objectives using weights which change over generations, e.g. to
+//! diversify search at the beginning and intensify it at the end.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/scheduled_weights_test.rs"]
+mod scheduled_weights_test;
+
+use super::*;
+
+/// Specifies objective weights which are linearly interpolated from initial to target values
+/// within given amount of generations. Weights are taken for the generation at which the solution
+/// is produced, see `SolutionContext::get_generation`.
+pub struct WeightSchedule {
+    initial: Vec<f64>,
+    target: Vec<f64>,
+    last_generation: usize,
+}
+
+impl WeightSchedule {
+    /// Creates a new instance of `WeightSchedule`.
+    pub fn new(initial: Vec<f64>, target: Vec<f64>, last_generation: usize) -> Result<Self, String> {
+        if initial.is_empty() || initial.len() != target.len() {
+            return Err("initial and target weights should have the same non-zero size".to_string());
+        }
+
+        if initial.iter().chain(target.iter()).any(|weight| *weight < 0.) {
+            return Err("weights should not be negative".to_string());
+        }
+
+        if last_generation == 0 {
+            return Err("last generation should be positive".to_string());
+        }
+
+        Ok(Self { initial, target, last_generation })
+    }
+
+    /// Returns weights effective at given generation.
+    pub fn get_weights_at(&self, generation: usize) -> Vec<f64> {
+        let ratio = generation.min(self.last_generation) as f64 / self.last_generation as f64;
+
+        self.initial
+            .iter()
+            .zip(self.target.iter())
+            .map(|(initial, target)| initial + (target - initial) * ratio)
+            .collect()
+    }
+
+    fn size(&self) -> usize {
+        self.initial.len()
+    }
+}
+
+/// Creates a feature which objective is a weighted sum of given features' objectives with weights
+/// taken from the schedule. Constraints and states of given features are kept as they are.
+pub fn create_scheduled_weights_feature(
+    name: &str,
+    features: &[Feature],
+    schedule: Arc<WeightSchedule>,
+) -> Result<Feature, String> {
+    let objectives = features
+        .iter()
+        .map(|feature| {
+            feature.objective.clone().ok_or_else(|| format!("feature '{}' has no objective to weight", feature.name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if objectives.len() != schedule.size() {
+        return Err(format!(
+            "amount of weights ({}) should match amount of objectives ({})",
+            schedule.size(),
+            objectives.len()
+        ));
+    }
+
+    let weighted = FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(ScheduledWeightsObjective { objectives, schedule })
+        .with_state(ScheduledWeightsState { state_keys: vec![WEIGHT_GENERATION_KEY] })
+        .build()?;
+
+    let features = features
+        .iter()
+        .map(|feature| Feature { objective: None, ..feature.clone() })
+        .chain(std::iter::once(weighted))
+        .collect::<Vec<_>>();
+
+    FeatureBuilder::combine(name, features.as_slice())
+}
+
+/// Returns generation of the solution used to get scheduled weights.
+fn get_solution_generation(solution_ctx: &SolutionContext) -> usize {
+    solution_ctx.get_generation().unwrap_or_default()
+}
+
+struct ScheduledWeightsObjective {
+    objectives: Vec<Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>>,
+    schedule: Arc<WeightSchedule>,
+}
+
+impl ScheduledWeightsObjective {
+    fn get_weighted(
+        &self,
+        generation: usize,
+        value_fn: impl Fn(&dyn FeatureObjective<Solution = InsertionContext>) -> f64,
+    ) -> f64 {
+        self.schedule
+            .get_weights_at(generation)
+            .into_iter()
+            .zip(self.objectives.iter())
+            .map(|(weight, objective)| weight * value_fn(objective.as_ref()))
+            .sum()
+    }
+}
+
+impl Objective for ScheduledWeightsObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.get_weighted(get_solution_generation(&solution.solution), |objective| objective.fitness(solution))
+    }
+}
+
+impl FeatureObjective for ScheduledWeightsObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        let generation = match move_ctx {
+            MoveContext::Route { solution_ctx, .. } => get_solution_generation(solution_ctx),
+            MoveContext::Activity { route_ctx, .. } => {
+                route_ctx.state().get_route_state::<usize>(WEIGHT_GENERATION_KEY).copied().unwrap_or_default()
+            }
+        };
+
+        self.get_weighted(generation, |objective| objective.estimate(move_ctx))
+    }
+}
+
+struct ScheduledWeightsState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for ScheduledWeightsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        // NOTE activity estimates have no access to the solution, so its generation is copied to routes
+        let generation = get_solution_generation(solution_ctx);
+
+        solution_ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| route_ctx.state().get_route_state::<usize>(WEIGHT_GENERATION_KEY) != Some(&generation))
+            .for_each(|route_ctx| {
+                let is_stale = route_ctx.is_stale();
+                route_ctx.state_mut().put_route_state(WEIGHT_GENERATION_KEY, generation);
+                route_ctx.mark_stale(is_stale);
+            });
+
+        solution_ctx.registry.update_routes_state(|state| state.put_route_state(WEIGHT_GENERATION_KEY, generation));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
@@ -0,0 +1,77 @@
+//! Provides a way to prioritize serving high-value customers (e.g. premium service tier).
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/service_tiers_test.rs"]
+mod service_tiers_test;
+
+use super::*;
+
+const SERVICE_TIER_DIMENSION_KEY: &str = "service_tier";
+
+/// A trait to get or set job service tier: lower tier index means more important customer.
+pub trait ServiceTierDimension {
+    /// Sets service tier.
+    fn set_service_tier(&mut self, tier: usize) -> &mut Self;
+    /// Gets service tier.
+    fn get_service_tier(&self) -> Option<usize>;
+}
+
+impl ServiceTierDimension for Dimensions {
+    fn set_service_tier(&mut self, tier: usize) -> &mut Self {
+        self.set_value(SERVICE_TIER_DIMENSION_KEY, tier);
+        self
+    }
+
+    fn get_service_tier(&self) -> Option<usize> {
+        self.get_value(SERVICE_TIER_DIMENSION_KEY).copied()
+    }
+}
+
+/// Creates a feature which maximizes amount of served jobs weighted by their service tier as soft
+/// objective. `tier_weights` specifies weight of each tier by its index, jobs without tier or with
+/// tier outside of weights are not taken into account.
+pub fn create_maximize_served_tiers_feature(name: &str, tier_weights: Vec<f64>) -> Result<Feature, String> {
+    if tier_weights.iter().any(|&weight| weight < 0.) {
+        return Err("service tier weights should not be negative".to_string());
+    }
+
+    FeatureBuilder::default().with_name(name).with_objective(ServedTiersObjective { tier_weights }).build()
+}
+
+struct ServedTiersObjective {
+    tier_weights: Vec<f64>,
+}
+
+impl ServedTiersObjective {
+    fn get_weight(&self, job: &Job) -> Cost {
+        job.as_single()
+            .and_then(|single| single.dimens.get_service_tier())
+            .and_then(|tier| self.tier_weights.get(tier))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl Objective for ServedTiersObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        // NOTE negate served weights as fitness is minimized
+        -solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+            .map(|job| self.get_weight(&job))
+            .sum::<Cost>()
+    }
+}
+
+impl FeatureObjective for ServedTiersObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { job, .. } => -self.get_weight(job),
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
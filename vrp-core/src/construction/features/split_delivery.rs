@@ -0,0 +1,164 @@
+//! Provides a way to serve a large delivery demand partially by multiple routes.
+//!
+//! Splitting is done before solving: each part is a regular delivery job, so capacity feature
+//! accounts parts as any other jobs and sums demand of all parts served by the same route. As a
+//! limitation, sizes of parts are fixed and are not adjusted to the vehicle's remaining capacity.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/split_delivery_test.rs"]
+mod split_delivery_test;
+
+use super::*;
+use hashbrown::{HashMap, HashSet};
+
+const SPLIT_GROUP_DIMENSION_KEY: &str = "split_group";
+
+/// A trait to get or set split delivery group: an id of the original job which demand is split.
+pub trait SplitDeliveryDimension {
+    /// Sets split group.
+    fn set_split_group(&mut self, group: &str) -> &mut Self;
+    /// Gets split group.
+    fn get_split_group(&self) -> Option<&String>;
+}
+
+impl SplitDeliveryDimension for Dimensions {
+    fn set_split_group(&mut self, group: &str) -> &mut Self {
+        self.set_value(SPLIT_GROUP_DIMENSION_KEY, group.to_string());
+        self
+    }
+
+    fn get_split_group(&self) -> Option<&String> {
+        self.get_value(SPLIT_GROUP_DIMENSION_KEY)
+    }
+}
+
+/// Splits a delivery job into parts with static delivery demand not exceeding `max_part_size`.
+/// Each part keeps original job's places and dimensions, gets its own id and shares the same
+/// split group, so the sum of parts' demand is equal to the original demand. A job without static
+/// delivery demand or with demand which fits into one part is returned as is.
+pub fn split_delivery_job(job: &Arc<Single>, max_part_size: i32) -> Result<Vec<Job>, String> {
+    if max_part_size <= 0 {
+        return Err("max part size should be positive".to_string());
+    }
+
+    let demand: Option<&Demand<SingleDimLoad>> = job.dimens.get_demand();
+    let total = match demand {
+        Some(demand) if demand.pickup.0.value == 0 && demand.pickup.1.value == 0 && demand.delivery.1.value == 0 => {
+            demand.delivery.0.value
+        }
+        _ => 0,
+    };
+
+    if total <= max_part_size {
+        return Ok(vec![Job::Single(job.clone())]);
+    }
+
+    let group = job.dimens.get_id().ok_or_else(|| "split delivery job should have an id".to_string())?.clone();
+    let parts = (total + max_part_size - 1) / max_part_size;
+
+    Ok((0..parts)
+        .map(|idx| {
+            let size = if idx < parts - 1 { max_part_size } else { total - max_part_size * (parts - 1) };
+
+            let mut dimens = job.dimens.clone();
+            dimens.set_id(format!("{group}_{idx}").as_str()).set_split_group(group.as_str()).set_demand(Demand {
+                pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+                delivery: (SingleDimLoad::new(size), SingleDimLoad::default()),
+            });
+
+            Job::Single(Arc::new(Single { places: job.places.clone(), dimens }))
+        })
+        .collect())
+}
+
+/// Creates a split delivery feature as an objective which prefers fewer splits: it counts how many
+/// extra routes are used to serve parts of the same split group.
+pub fn create_split_delivery_feature(name: &str) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(SplitDeliveryObjective {})
+        .with_state(SplitDeliveryState { state_keys: vec![SPLIT_DELIVERY_KEY] })
+        .build()
+}
+
+struct SplitDeliveryObjective {}
+
+impl Objective for SplitDeliveryObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let route_groups = solution.solution.routes.iter().map(get_route_groups);
+
+        let groups = route_groups.fold(HashMap::<String, usize>::new(), |mut acc, groups| {
+            groups.into_iter().for_each(|group| *acc.entry(group).or_insert(0) += 1);
+            acc
+        });
+
+        groups.values().map(|routes| routes - 1).sum::<usize>() as f64
+    }
+}
+
+impl FeatureObjective for SplitDeliveryObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                let group = if let Some(group) = get_split_group(job) { group } else { return Cost::default() };
+
+                let is_served_by = |route_ctx: &RouteContext| is_group_served_by_route(route_ctx, group);
+
+                if !is_served_by(route_ctx) && solution_ctx.routes.iter().any(is_served_by) {
+                    1.
+                } else {
+                    Cost::default()
+                }
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct SplitDeliveryState {
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for SplitDeliveryState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let groups =
+            route_ctx.route().tour.jobs().filter_map(|job| get_split_group(&job).cloned()).collect::<HashSet<_>>();
+
+        route_ctx.state_mut().put_route_state(SPLIT_DELIVERY_KEY, groups);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_split_group(job: &Job) -> Option<&String> {
+    job.as_single().and_then(|single| single.dimens.get_split_group())
+}
+
+fn get_route_groups(route_ctx: &RouteContext) -> HashSet<String> {
+    route_ctx
+        .state()
+        .get_route_state::<HashSet<String>>(SPLIT_DELIVERY_KEY)
+        .cloned()
+        .unwrap_or_else(|| route_ctx.route().tour.jobs().filter_map(|job| get_split_group(&job).cloned()).collect())
+}
+
+fn is_group_served_by_route(route_ctx: &RouteContext, group: &String) -> bool {
+    match route_ctx.state().get_route_state::<HashSet<String>>(SPLIT_DELIVERY_KEY) {
+        Some(groups) => groups.contains(group),
+        None => route_ctx.route().tour.jobs().any(|job| get_split_group(&job) == Some(group)),
+    }
+}
@@ -0,0 +1,78 @@
+//! Provides the way to avoid routes with too densely packed stops.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/stop_density_test.rs"]
+mod stop_density_test;
+
+use super::*;
+
+/// Creates a feature to penalize routes which have more stops than allowed by stop density
+/// threshold: a vehicle is expected to serve at most `max_stops` per `period` of its available
+/// time (shift time window). Each stop over the allowed amount is charged with `per_excess_stop`
+/// cost, so the stops are spread more evenly between the vehicles.
+pub fn create_stop_density_feature(
+    name: &str,
+    max_stops: f64,
+    period: Duration,
+    per_excess_stop: Cost,
+) -> Result<Feature, String> {
+    if max_stops <= 0. || period <= 0. {
+        return Err("stop density threshold should be positive".to_string());
+    }
+
+    if per_excess_stop < 0. {
+        return Err("excess stop cost should not be negative".to_string());
+    }
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(StopDensityObjective { max_stops, period, per_excess_stop })
+        .build()
+}
+
+struct StopDensityObjective {
+    max_stops: f64,
+    period: Duration,
+    per_excess_stop: Cost,
+}
+
+impl StopDensityObjective {
+    fn get_excess_stops(&self, route_ctx: &RouteContext, stops: usize) -> f64 {
+        let available_time = route_ctx.route().actor.detail.time.duration();
+        let allowed_stops = self.max_stops * available_time / self.period;
+
+        (stops as f64 - allowed_stops).max(0.)
+    }
+}
+
+impl Objective for StopDensityObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| self.get_excess_stops(route_ctx, route_ctx.route().tour.job_activity_count()))
+            .sum::<f64>()
+            * self.per_excess_stop
+    }
+}
+
+impl FeatureObjective for StopDensityObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let stops = route_ctx.route().tour.job_activity_count();
+                let job_stops = match job {
+                    Job::Single(_) => 1,
+                    Job::Multi(multi) => multi.jobs.len(),
+                };
+
+                (self.get_excess_stops(route_ctx, stops + job_stops) - self.get_excess_stops(route_ctx, stops))
+                    * self.per_excess_stop
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
@@ -5,14 +5,17 @@
 mod tour_limits_test;
 
 use super::*;
-use crate::models::common::{Distance, Duration, Timestamp};
+use crate::models::common::{Distance, Duration, Location, Timestamp};
 use crate::models::problem::{Actor, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
+use std::cmp::Ordering;
 
 /// A function which returns activity size limit for given actor.
 pub type ActivitySizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 /// A function to resolve travel limit.
 pub type TravelLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
+/// A function which returns max amount of routes dispatched from given depot (start location).
+pub type DepotRouteLimitFn = Arc<dyn Fn(Location) -> Option<usize> + Send + Sync>;
 
 /// Creates a limit for activity amount in a tour.
 /// This is a hard constraint.
@@ -27,6 +30,18 @@ pub fn create_activity_limit_feature(
         .build()
 }
 
+/// Creates a limit for amount of routes dispatched from the same depot: a depot is a start location
+/// of the actor. Once the limit is reached, remaining jobs can be served only by already used routes
+/// of the depot or by vehicles from other depots.
+/// This is a hard constraint.
+pub fn create_depot_route_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    limit_fn: DepotRouteLimitFn,
+) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_constraint(DepotRouteLimitConstraint { code, limit_fn }).build()
+}
+
 /// Creates a travel limits such as distance and/or duration.
 /// This is a hard constraint.
 pub fn create_travel_limit_feature(
@@ -50,6 +65,38 @@ pub fn create_travel_limit_feature(
         .build()
 }
 
+/// Creates a feature to minimize total overtime across the fleet as an objective. Overtime of a tour
+/// is its duration above the regular duration returned by `regular_duration_fn` for the tour's actor.
+/// Tour durations are expected to be updated by transport feature.
+pub fn create_minimize_overtime_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    regular_duration_fn: TravelLimitFn<Duration>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(TotalOvertimeObjective { transport, regular_duration_fn })
+        .build()
+}
+
+/// Creates a limit for elapsed time of a tour measured from departure at its start to arrival at
+/// its last activity, independently from absolute time windows. Unlike tour duration limit, the
+/// elapsed time is calculated by rescheduling the rest of the tour, so extra waiting caused by
+/// insertion further in the tour is taken into account.
+/// This is a hard constraint.
+pub fn create_max_elapsed_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    elapsed_limit_fn: TravelLimitFn<Duration>,
+) -> Result<Feature, String> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(MaxElapsedConstraint { code, transport, activity, elapsed_limit_fn })
+        .build()
+}
+
 struct ActivityLimitConstraint {
     code: ViolationCode,
     limit_fn: ActivitySizeResolver,
@@ -83,64 +130,52 @@ impl FeatureConstraint for ActivityLimitConstraint {
     }
 }
 
-struct TravelLimitConstraint {
-    distance_code: ViolationCode,
-    duration_code: ViolationCode,
-    transport: Arc<dyn TransportCost + Send + Sync>,
-    tour_distance_limit_fn: TravelLimitFn<Distance>,
-    tour_duration_limit_fn: TravelLimitFn<Duration>,
+struct DepotRouteLimitConstraint {
+    code: ViolationCode,
+    limit_fn: DepotRouteLimitFn,
 }
 
-impl TravelLimitConstraint {
-    fn calculate_travel(&self, route: &Route, activity_ctx: &ActivityContext) -> (Distance, Duration) {
-        let prev = activity_ctx.prev;
-        let tar = activity_ctx.target;
-        let next = activity_ctx.next;
+impl FeatureConstraint for DepotRouteLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } if !route_ctx.route().tour.has_jobs() => {
+                let depot = get_depot(route_ctx.route().actor.as_ref())?;
+                let limit = (self.limit_fn)(depot)?;
 
-        let prev_dep = prev.schedule.departure;
+                let used = solution_ctx
+                    .routes
+                    .iter()
+                    .filter(|other| other.route().tour.has_jobs())
+                    .filter(|other| get_depot(other.route().actor.as_ref()) == Some(depot))
+                    .count();
 
-        let (prev_to_tar_dis, prev_to_tar_dur) = self.calculate_leg_travel_info(route, prev, tar, prev_dep);
-        if next.is_none() {
-            return (prev_to_tar_dis, prev_to_tar_dur);
+                if used >= limit {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    ConstraintViolation::success()
+                }
+            }
+            _ => ConstraintViolation::success(),
         }
-
-        let next = next.unwrap();
-        let tar_dep = prev_dep + prev_to_tar_dur;
-
-        let (prev_to_next_dis, prev_to_next_dur) = self.calculate_leg_travel_info(route, prev, next, prev_dep);
-        let (tar_to_next_dis, tar_to_next_dur) = self.calculate_leg_travel_info(route, tar, next, tar_dep);
-
-        (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
     }
 
-    fn calculate_leg_travel_info(
-        &self,
-        route: &Route,
-        first: &Activity,
-        second: &Activity,
-        departure: Timestamp,
-    ) -> (Distance, Duration) {
-        let first_to_second_dis = self.transport.distance(
-            route,
-            first.place.location,
-            second.place.location,
-            TravelTime::Departure(departure),
-        );
-        let first_to_second_dur = self.transport.duration(
-            route,
-            first.place.location,
-            second.place.location,
-            TravelTime::Departure(departure),
-        );
-
-        let second_arr = departure + first_to_second_dur;
-        let second_wait = (second.place.time.start - second_arr).max(0.);
-        let second_dep = second_arr + second_wait + second.place.duration;
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
 
-        (first_to_second_dis, second_dep - departure)
+    fn depends_on_other_routes(&self) -> bool {
+        true
     }
 }
 
+struct TravelLimitConstraint {
+    distance_code: ViolationCode,
+    duration_code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    tour_distance_limit_fn: TravelLimitFn<Distance>,
+    tour_duration_limit_fn: TravelLimitFn<Duration>,
+}
+
 impl FeatureConstraint for TravelLimitConstraint {
     fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         match move_ctx {
@@ -150,7 +185,8 @@ impl FeatureConstraint for TravelLimitConstraint {
                 let tour_duration_limit = (self.tour_duration_limit_fn)(route_ctx.route().actor.as_ref());
 
                 if tour_distance_limit.is_some() || tour_duration_limit.is_some() {
-                    let (change_distance, change_duration) = self.calculate_travel(route_ctx.route(), activity_ctx);
+                    let (change_distance, change_duration) =
+                        calculate_travel(self.transport.as_ref(), route_ctx.route(), activity_ctx);
 
                     if let Some(distance_limit) = tour_distance_limit {
                         let curr_dis = route_ctx.state().get_route_state(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
@@ -179,6 +215,69 @@ impl FeatureConstraint for TravelLimitConstraint {
     }
 }
 
+struct MaxElapsedConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    elapsed_limit_fn: TravelLimitFn<Duration>,
+}
+
+impl MaxElapsedConstraint {
+    fn get_elapsed(&self, route: &Route, activity_ctx: &ActivityContext) -> Duration {
+        let get_schedule = |from: &Activity, departure: Timestamp, to: &Activity| {
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    from.place.location,
+                    to.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            (arrival, self.activity.estimate_departure(route, to, arrival))
+        };
+
+        let prev = activity_ctx.prev;
+        let start_departure = route.tour.start().map_or(prev.schedule.departure, |start| start.schedule.departure);
+
+        let (mut arrival, mut departure) = get_schedule(prev, prev.schedule.departure, activity_ctx.target);
+        let mut from = activity_ctx.target;
+
+        for to in route.tour.all_activities().skip(activity_ctx.index + 1) {
+            (arrival, departure) = get_schedule(from, departure, to);
+
+            // NOTE the rest of the tour is not affected when its schedule is not shifted
+            if compare_floats(arrival, to.schedule.arrival) == Ordering::Equal {
+                return route.tour.end().map_or(arrival, |end| end.schedule.arrival) - start_departure;
+            }
+
+            from = to;
+        }
+
+        arrival - start_departure
+    }
+}
+
+impl FeatureConstraint for MaxElapsedConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let limit = (self.elapsed_limit_fn)(route_ctx.route().actor.as_ref())?;
+
+                if self.get_elapsed(route_ctx.route(), activity_ctx) > limit {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
 struct TravelLimitState {
     tour_duration_limit_fn: TravelLimitFn<Duration>,
     state_keys: Vec<StateKey>,
@@ -199,3 +298,94 @@ impl FeatureState for TravelLimitState {
         self.state_keys.iter()
     }
 }
+
+struct TotalOvertimeObjective {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    regular_duration_fn: TravelLimitFn<Duration>,
+}
+
+impl TotalOvertimeObjective {
+    fn get_overtime(&self, actor: &Actor, duration: Duration) -> Duration {
+        (self.regular_duration_fn)(actor).map_or(0., |regular| (duration - regular).max(0.))
+    }
+
+    fn get_route_duration(route_ctx: &RouteContext) -> Duration {
+        route_ctx.state().get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.)
+    }
+}
+
+impl Objective for TotalOvertimeObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| self.get_overtime(route_ctx.route().actor.as_ref(), Self::get_route_duration(route_ctx)))
+            .sum()
+    }
+}
+
+impl FeatureObjective for TotalOvertimeObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let actor = route_ctx.route().actor.as_ref();
+                let current = Self::get_route_duration(route_ctx);
+                let (_, change_duration) = calculate_travel(self.transport.as_ref(), route_ctx.route(), activity_ctx);
+
+                self.get_overtime(actor, current + change_duration) - self.get_overtime(actor, current)
+            }
+        }
+    }
+}
+
+fn calculate_travel(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    activity_ctx: &ActivityContext,
+) -> (Distance, Duration) {
+    let prev = activity_ctx.prev;
+    let tar = activity_ctx.target;
+    let next = activity_ctx.next;
+
+    let prev_dep = prev.schedule.departure;
+
+    let (prev_to_tar_dis, prev_to_tar_dur) = calculate_leg_travel_info(transport, route, prev, tar, prev_dep);
+    if next.is_none() {
+        return (prev_to_tar_dis, prev_to_tar_dur);
+    }
+
+    let next = next.unwrap();
+    let tar_dep = prev_dep + prev_to_tar_dur;
+
+    let (prev_to_next_dis, prev_to_next_dur) = calculate_leg_travel_info(transport, route, prev, next, prev_dep);
+    let (tar_to_next_dis, tar_to_next_dur) = calculate_leg_travel_info(transport, route, tar, next, tar_dep);
+
+    (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
+}
+
+fn calculate_leg_travel_info(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    first: &Activity,
+    second: &Activity,
+    departure: Timestamp,
+) -> (Distance, Duration) {
+    let first_to_second_dis =
+        transport.distance(route, first.place.location, second.place.location, TravelTime::Departure(departure));
+    let first_to_second_dur =
+        transport.duration(route, first.place.location, second.place.location, TravelTime::Departure(departure));
+
+    let second_arr = departure + first_to_second_dur;
+    let second_wait = (second.place.time.start - second_arr).max(0.);
+    let second_dep = second_arr + second_wait + second.place.duration;
+
+    (first_to_second_dis, second_dep - departure)
+}
+
+fn get_depot(actor: &Actor) -> Option<Location> {
+    actor.detail.start.as_ref().map(|start| start.location)
+}
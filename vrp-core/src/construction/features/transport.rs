@@ -6,10 +6,43 @@ mod transport_test;
 
 use super::*;
 use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
-use crate::models::common::Timestamp;
-use crate::models::problem::{ActivityCost, Single, TransportCost, TravelTime};
+use crate::models::common::{TimeWindow, Timestamp};
+use crate::models::problem::{
+    ActivityCost, Single, TransportCost, TravelTime, WindowHardness, WindowHardnessDimension,
+};
 use crate::models::solution::Activity;
 
+const VEHICLE_PREFERENCE_DIMENSION_KEY: &str = "vehicle_preference";
+
+/// Specifies a vehicle preferred to serve the job, e.g. due to driver familiarity, and a penalty
+/// applied when the job is served by any other vehicle.
+#[derive(Clone, Debug)]
+pub struct VehiclePreference {
+    /// An id of the preferred vehicle.
+    pub vehicle_id: String,
+    /// A penalty (in cost units) for serving the job by a different vehicle.
+    pub penalty: Cost,
+}
+
+/// A trait to get or set job's vehicle preference.
+pub trait VehiclePreferenceDimension {
+    /// Sets vehicle preference.
+    fn set_vehicle_preference(&mut self, preference: VehiclePreference) -> &mut Self;
+    /// Gets vehicle preference.
+    fn get_vehicle_preference(&self) -> Option<&VehiclePreference>;
+}
+
+impl VehiclePreferenceDimension for Dimensions {
+    fn set_vehicle_preference(&mut self, preference: VehiclePreference) -> &mut Self {
+        self.set_value(VEHICLE_PREFERENCE_DIMENSION_KEY, preference);
+        self
+    }
+
+    fn get_vehicle_preference(&self) -> Option<&VehiclePreference> {
+        self.get_value(VEHICLE_PREFERENCE_DIMENSION_KEY)
+    }
+}
+
 // TODO
 //  remove get_total_cost, get_route_costs, get_max_cost methods from contexts
 //  add validation rule which ensures usage of only one of these methods.
@@ -26,10 +59,80 @@ pub fn create_minimize_transport_costs_feature(
         transport,
         activity,
         time_window_code,
+        None,
+        false,
         Box::new(|insertion_ctx| insertion_ctx.solution.get_total_cost()),
     )
 }
 
+/// Creates a travel costs feature which considers distance and duration for minimization and grants
+/// a bonus (negative cost) for each tour completed before its shift end. The bonus is proportional
+/// to how early the tour is completed using `bonus_per_time` and capped by `max_bonus`.
+/// NOTE: any used vehicle can get the bonus, so fixed costs should be high enough to outweigh it.
+pub fn create_minimize_transport_costs_with_early_completion_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    time_window_code: ViolationCode,
+    bonus_per_time: Cost,
+    max_bonus: Cost,
+) -> Result<Feature, String> {
+    if bonus_per_time < 0. || max_bonus < 0. {
+        return Err("early completion bonus should not be negative".to_string());
+    }
+
+    let completion_bonus = CompletionBonus { bonus_per_time, max_bonus };
+
+    create_feature(
+        name,
+        transport,
+        activity,
+        time_window_code,
+        Some(completion_bonus.clone()),
+        false,
+        Box::new(move |insertion_ctx| {
+            insertion_ctx.solution.get_total_cost()
+                - insertion_ctx
+                    .solution
+                    .routes
+                    .iter()
+                    .map(|route_ctx| completion_bonus.get_route_bonus(route_ctx))
+                    .sum::<Cost>()
+        }),
+    )
+}
+
+/// Creates a travel costs feature which considers distance and duration for minimization and adds
+/// a penalty for each job served by a vehicle different from its preferred one (see
+/// `VehiclePreferenceDimension`), so a job is moved to the preferred vehicle unless the detour
+/// costs more than the penalty. Jobs without preference are not penalized.
+pub fn create_minimize_transport_costs_with_vehicle_preference_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    time_window_code: ViolationCode,
+) -> Result<Feature, String> {
+    create_feature(
+        name,
+        transport,
+        activity,
+        time_window_code,
+        None,
+        true,
+        Box::new(|insertion_ctx| {
+            insertion_ctx.solution.get_total_cost()
+                + insertion_ctx
+                    .solution
+                    .routes
+                    .iter()
+                    .flat_map(|route_ctx| {
+                        route_ctx.route().tour.jobs().map(move |job| get_vehicle_preference_penalty(route_ctx, &job))
+                    })
+                    .sum::<Cost>()
+        }),
+    )
+}
+
 /// Creates a travel costs feature which considers duration for minimization as global objective.
 /// NOTE: distance costs is still considered on local level.
 pub fn create_minimize_duration_feature(
@@ -43,6 +146,8 @@ pub fn create_minimize_duration_feature(
         transport,
         activity,
         time_window_code,
+        None,
+        false,
         Box::new(|insertion_ctx| {
             insertion_ctx.solution.routes.iter().fold(Cost::default(), move |acc, route_ctx| {
                 acc + route_ctx.state().get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.)
@@ -64,6 +169,8 @@ pub fn create_minimize_distance_feature(
         transport,
         activity,
         time_window_code,
+        None,
+        false,
         Box::new(|insertion_ctx| {
             insertion_ctx.solution.routes.iter().fold(Cost::default(), move |acc, route_ctx| {
                 acc + route_ctx.state().get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.)
@@ -77,6 +184,8 @@ fn create_feature(
     transport: Arc<dyn TransportCost + Send + Sync>,
     activity: Arc<dyn ActivityCost + Send + Sync>,
     time_window_code: ViolationCode,
+    completion_bonus: Option<CompletionBonus>,
+    has_vehicle_preference: bool,
     fitness_fn: Box<dyn Fn(&InsertionContext) -> f64 + Send + Sync>,
 ) -> Result<Feature, String> {
     FeatureBuilder::default()
@@ -87,7 +196,13 @@ fn create_feature(
             activity: activity.clone(),
         })
         .with_state(TransportState::new(transport.clone(), activity.clone()))
-        .with_objective(TransportObjective { activity, transport, fitness_fn })
+        .with_objective(TransportObjective {
+            activity,
+            transport,
+            completion_bonus,
+            has_vehicle_preference,
+            fitness_fn,
+        })
         .build()
 }
 
@@ -101,11 +216,15 @@ impl TransportConstraint {
     fn evaluate_job(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
         let date = route_ctx.route().tour.start().unwrap().schedule.departure;
         let check_single = |single: &Arc<Single>| {
-            single
-                .places
-                .iter()
-                .flat_map(|place| place.times.iter())
-                .any(|time| time.intersects(date, &route_ctx.route().actor.detail.time))
+            let is_soft = single.dimens.get_window_hardness() == Some(&WindowHardness::Soft);
+
+            single.places.iter().flat_map(|place| place.times.iter()).any(|time| {
+                let time = time.to_time_window(date);
+                // NOTE soft time window can be violated, so only its start matters
+                let time = if is_soft { TimeWindow::new(time.start, f64::MAX) } else { time };
+
+                time.intersects(&route_ctx.route().actor.detail.time)
+            })
         };
 
         let has_time_intersection = match job {
@@ -148,11 +267,15 @@ impl TransportConstraint {
             }
             (
                 next.place.location,
-                *route_ctx.state().get_activity_state(LATEST_ARRIVAL_KEY, next).unwrap_or(&next.place.time.end),
+                route_ctx
+                    .state()
+                    .get_activity_state(LATEST_ARRIVAL_KEY, next)
+                    .copied()
+                    .unwrap_or_else(|| next.latest_start()),
             )
         } else {
             // open vrp
-            (target.place.location, target.place.time.end.min(actor.detail.time.end))
+            (target.place.location, target.latest_start().min(actor.detail.time.end))
         };
 
         let arr_time_at_next = departure
@@ -182,7 +305,7 @@ impl TransportConstraint {
             );
 
         let latest_arr_time_at_target =
-            target.place.time.end.min(self.activity.estimate_arrival(route, target, latest_departure_at_target));
+            target.latest_start().min(self.activity.estimate_arrival(route, target, latest_departure_at_target));
 
         if arr_time_at_target > latest_arr_time_at_target {
             return ConstraintViolation::skip(self.code);
@@ -227,16 +350,23 @@ impl FeatureConstraint for TransportConstraint {
 struct TransportObjective {
     activity: Arc<dyn ActivityCost + Send + Sync>,
     transport: Arc<dyn TransportCost + Send + Sync>,
+    completion_bonus: Option<CompletionBonus>,
+    has_vehicle_preference: bool,
     fitness_fn: Box<dyn Fn(&InsertionContext) -> f64 + Send + Sync>,
 }
 
 impl TransportObjective {
-    fn estimate_route(&self, route_ctx: &RouteContext) -> f64 {
-        if route_ctx.route().tour.has_jobs() {
+    fn estimate_route(&self, route_ctx: &RouteContext, job: &Job) -> f64 {
+        let preference_penalty =
+            if self.has_vehicle_preference { get_vehicle_preference_penalty(route_ctx, job) } else { 0. };
+
+        let fixed_costs = if route_ctx.route().tour.has_jobs() {
             0.
         } else {
             route_ctx.route().actor.driver.costs.fixed + route_ctx.route().actor.vehicle.costs.fixed
-        }
+        };
+
+        fixed_costs + preference_penalty
     }
 
     fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
@@ -257,7 +387,8 @@ impl TransportObjective {
 
         // no jobs yet or open vrp.
         if !route_ctx.route().tour.has_jobs() || next.is_none() {
-            return new_costs;
+            let completion = next.map_or(dep_time_left, |_| dep_time_right);
+            return new_costs + self.estimate_bonus_loss(route_ctx, completion);
         }
 
         let next = next.unwrap();
@@ -271,7 +402,17 @@ impl TransportObjective {
 
         let old_costs = tp_cost_old + act_cost_old + waiting_cost;
 
-        new_costs - old_costs
+        // NOTE delay is propagated till the tour end unless it is absorbed by waiting
+        let delay = (dep_time_right - dep_time_old - waiting_time).max(0.);
+        let completion = route_ctx.route().tour.end().map_or(0., |end| end.schedule.departure) + delay;
+
+        new_costs - old_costs + self.estimate_bonus_loss(route_ctx, completion)
+    }
+
+    fn estimate_bonus_loss(&self, route_ctx: &RouteContext, new_completion: Timestamp) -> Cost {
+        self.completion_bonus.as_ref().map_or(Cost::default(), |completion_bonus| {
+            completion_bonus.get_route_bonus(route_ctx) - completion_bonus.get_bonus(route_ctx, new_completion)
+        })
     }
 
     fn analyze_route_leg(
@@ -288,11 +429,32 @@ impl TransportObjective {
         let departure = self.activity.estimate_departure(route, end, arrival);
 
         let transport_cost =
-            self.transport.cost(route, start.place.location, end.place.location, TravelTime::Departure(time));
+            self.transport.cost(route, start.place.location, end.place.location, TravelTime::Departure(time))
+                - self.get_free_return_cost(route_ctx, start, end, time);
         let activity_cost = self.activity.cost(route, end, arrival);
 
         (transport_cost, activity_cost, departure)
     }
+
+    /// Returns distance cost of the leg if it is a return leg of the vehicle which returns for free.
+    fn get_free_return_cost(
+        &self,
+        route_ctx: &RouteContext,
+        start: &Activity,
+        end: &Activity,
+        time: Timestamp,
+    ) -> Cost {
+        let route = route_ctx.route();
+        let actor = route.actor.as_ref();
+
+        // NOTE only end of the tour has no job
+        if end.job.is_some() || !actor.vehicle.dimens.get_free_return().cloned().unwrap_or(false) {
+            return Cost::default();
+        }
+
+        self.transport.distance(route, start.place.location, end.place.location, TravelTime::Departure(time))
+            * (actor.driver.costs.per_distance + actor.vehicle.costs.per_distance)
+    }
 }
 
 impl Objective for TransportObjective {
@@ -306,12 +468,48 @@ impl Objective for TransportObjective {
 impl FeatureObjective for TransportObjective {
     fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
         match move_ctx {
-            MoveContext::Route { route_ctx, .. } => self.estimate_route(route_ctx),
+            MoveContext::Route { route_ctx, job, .. } => self.estimate_route(route_ctx, job),
             MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
         }
     }
 }
 
+fn get_vehicle_preference_penalty(route_ctx: &RouteContext, job: &Job) -> Cost {
+    job.dimens().get_vehicle_preference().map_or(Cost::default(), |preference| {
+        let vehicle_id = route_ctx.route().actor.vehicle.dimens.get_id();
+
+        if vehicle_id.map_or(false, |id| *id == preference.vehicle_id) {
+            Cost::default()
+        } else {
+            preference.penalty
+        }
+    })
+}
+
+#[derive(Clone)]
+struct CompletionBonus {
+    bonus_per_time: Cost,
+    max_bonus: Cost,
+}
+
+impl CompletionBonus {
+    fn get_bonus(&self, route_ctx: &RouteContext, completion: Timestamp) -> Cost {
+        let shift_end = route_ctx.route().actor.detail.time.end;
+
+        ((shift_end - completion).max(0.) * self.bonus_per_time).min(self.max_bonus)
+    }
+
+    fn get_route_bonus(&self, route_ctx: &RouteContext) -> Cost {
+        let tour = &route_ctx.route().tour;
+
+        if tour.has_jobs() {
+            tour.end().map_or(Cost::default(), |end| self.get_bonus(route_ctx, end.schedule.departure))
+        } else {
+            Cost::default()
+        }
+    }
+}
+
 struct TransportState {
     schedule_state_keys: ScheduleStateKeys,
     transport: Arc<dyn TransportCost + Send + Sync>,
@@ -1,7 +1,12 @@
 //! Provides features to balance work.
 
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/work_balance_test.rs"]
+mod work_balance_test;
+
 use super::*;
 use crate::models::common::{CapacityDimension, LoadOps};
+use hashbrown::HashMap;
 use rosomaxa::algorithms::math::get_cv_safe;
 use std::cmp::Ordering;
 
@@ -73,6 +78,102 @@ pub fn create_distance_balanced_feature(name: &str, threshold: Option<f64>) -> R
     create_transport_balanced_feature(name, threshold, TOTAL_DISTANCE_KEY, BALANCE_DISTANCE_KEY)
 }
 
+/// Specifies a workload measure used to balance work across depots.
+#[derive(Clone, Copy, Debug)]
+pub enum DepotWorkload {
+    /// Amount of job activities served by tours started at the depot.
+    Jobs,
+    /// Total distance travelled by tours started at the depot.
+    Distance,
+}
+
+/// Creates a feature which balances workload across depots, where a depot is a start location of
+/// the vehicle. Depots of all vehicles in the fleet are considered, so an unused depot has zero workload.
+pub fn create_depot_balanced_feature(
+    name: &str,
+    threshold: Option<f64>,
+    workload: DepotWorkload,
+) -> Result<Feature, String> {
+    let get_workload = move |route_ctx: &RouteContext| match workload {
+        DepotWorkload::Jobs => route_ctx.route().tour.job_activity_count() as f64,
+        DepotWorkload::Distance => route_ctx.state().get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.),
+    };
+
+    let route_estimate_fn = Arc::new(get_workload);
+    let solution_estimate_fn = Arc::new(move |solution_ctx: &SolutionContext| {
+        let get_depot = |actor: &Actor| actor.detail.start.as_ref().map(|start| start.location);
+
+        let mut depots = solution_ctx
+            .registry
+            .resources()
+            .all()
+            .filter_map(|actor| get_depot(actor.as_ref()))
+            .map(|depot| (depot, 0.))
+            .collect::<HashMap<_, _>>();
+
+        solution_ctx.routes.iter().for_each(|route_ctx| {
+            if let Some(depot) = get_depot(route_ctx.route().actor.as_ref()) {
+                *depots.entry(depot).or_insert(0.) += get_workload(route_ctx);
+            }
+        });
+
+        get_cv_safe(depots.into_values().collect::<Vec<_>>().as_slice())
+    });
+
+    create_feature(name, threshold, BALANCE_DEPOT_KEY, route_estimate_fn, solution_estimate_fn)
+}
+
+/// Creates a feature which minimizes the peak load carried by the whole fleet at the same time.
+/// A tour keeps the load of an activity from its arrival till arrival at the next activity, the
+/// peak is measured as a ratio to total capacity of all available vehicles. It relies on activity
+/// states maintained by the capacity feature, so it should be used together with one.
+pub fn create_peak_concurrent_load_feature<T: LoadOps>(name: &str, threshold: Option<f64>) -> Result<Feature, String> {
+    let route_estimate_fn = Arc::new(|route_ctx: &RouteContext| {
+        route_ctx.state().get_route_state::<f64>(MAX_LOAD_KEY).cloned().unwrap_or(0.)
+    });
+    let solution_estimate_fn = Arc::new(|solution_ctx: &SolutionContext| {
+        let capacity = solution_ctx
+            .registry
+            .resources()
+            .all()
+            .filter_map(|actor| actor.vehicle.dimens.get_capacity().copied())
+            .fold(T::default(), |acc, capacity: T| acc + capacity);
+
+        get_peak_concurrent_load::<T>(solution_ctx).ratio(&capacity)
+    });
+
+    create_feature(name, threshold, PEAK_CONCURRENT_LOAD_KEY, route_estimate_fn, solution_estimate_fn)
+}
+
+fn get_peak_concurrent_load<T: LoadOps>(solution_ctx: &SolutionContext) -> T {
+    let mut events = Vec::default();
+
+    solution_ctx.routes.iter().for_each(|route_ctx| {
+        let tour = &route_ctx.route().tour;
+        tour.all_activities().zip(tour.all_activities().skip(1)).for_each(|(activity, next)| {
+            let load =
+                route_ctx.state().get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity).copied().unwrap_or_default();
+
+            if load.is_not_empty() {
+                events.push((activity.schedule.arrival, true, load));
+                events.push((next.schedule.arrival, false, load));
+            }
+        });
+    });
+
+    // NOTE at the same time, unloading goes first, so adjacent intervals are not overlapping
+    events.sort_by(|(a_time, a_is_load, _), (b_time, b_is_load, _)| {
+        compare_floats(*a_time, *b_time).then(a_is_load.cmp(b_is_load))
+    });
+
+    let (_, peak) = events.into_iter().fold((T::default(), T::default()), |(current, peak), (_, is_load, load)| {
+        let current = if is_load { current + load } else { current - load };
+        (current, peak.max_load(current))
+    });
+
+    peak
+}
+
 fn create_transport_balanced_feature(
     name: &str,
     threshold: Option<f64>,
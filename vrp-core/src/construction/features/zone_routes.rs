@@ -0,0 +1,151 @@
+//! Provides a way to keep amount of routes serving each zone close to its target.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/zone_routes_test.rs"]
+mod zone_routes_test;
+
+use super::*;
+use hashbrown::{HashMap, HashSet};
+
+const ZONE_DIMENSION_KEY: &str = "zone";
+
+/// A trait to get or set job zone.
+pub trait ZoneDimension {
+    /// Sets zone.
+    fn set_zone(&mut self, zone: &str) -> &mut Self;
+    /// Gets zone.
+    fn get_zone(&self) -> Option<&String>;
+}
+
+impl ZoneDimension for Dimensions {
+    fn set_zone(&mut self, zone: &str) -> &mut Self {
+        self.set_value(ZONE_DIMENSION_KEY, zone.to_string());
+        self
+    }
+
+    fn get_zone(&self) -> Option<&String> {
+        self.get_value(ZONE_DIMENSION_KEY)
+    }
+}
+
+/// Creates a feature which penalizes deviation of amount of routes serving a zone from its target.
+/// Each target is a pair of zone and desired amount of routes. A route serves the zone if it serves
+/// at least one job of the zone, zones without a target are ignored. Solution fitness is the total
+/// absolute deviation from all targets.
+pub fn create_zone_route_target_feature(name: &str, targets: &[(String, usize)]) -> Result<Feature, String> {
+    if targets.is_empty() {
+        return Err("zone route targets should not be empty".to_string());
+    }
+
+    let zone_targets = targets.iter().cloned().collect::<HashMap<_, _>>();
+    if zone_targets.len() != targets.len() {
+        return Err("zone route targets should have unique zones".to_string());
+    }
+
+    let zone_targets = Arc::new(zone_targets);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(ZoneRoutesObjective { zone_targets: zone_targets.clone() })
+        .with_state(ZoneRoutesState { zone_targets, state_keys: vec![ZONE_ROUTES_KEY] })
+        .build()
+}
+
+struct ZoneRoutesObjective {
+    zone_targets: Arc<HashMap<String, usize>>,
+}
+
+impl Objective for ZoneRoutesObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .state
+            .get(&ZONE_ROUTES_KEY)
+            .and_then(|s| s.downcast_ref::<Cost>())
+            .copied()
+            .unwrap_or_else(|| get_total_deviation(&solution.solution, self.zone_targets.as_ref()))
+    }
+}
+
+impl FeatureObjective for ZoneRoutesObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                let (zone, target) = if let Some(zone_target) =
+                    get_zone(job).and_then(|zone| self.zone_targets.get(zone).map(|target| (zone, *target)))
+                {
+                    zone_target
+                } else {
+                    return Cost::default();
+                };
+
+                if is_zone_served_by_route(route_ctx, zone) {
+                    return Cost::default();
+                }
+
+                // NOTE a new route for the zone either reduces or increases deviation by one
+                let count = solution_ctx.routes.iter().filter(|other| is_zone_served_by_route(other, zone)).count();
+                if count < target {
+                    -1.
+                } else {
+                    1.
+                }
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct ZoneRoutesState {
+    zone_targets: Arc<HashMap<String, usize>>,
+    state_keys: Vec<StateKey>,
+}
+
+impl FeatureState for ZoneRoutesState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let zones = route_ctx.route().tour.jobs().filter_map(|job| get_zone(&job).cloned()).collect::<HashSet<_>>();
+
+        route_ctx.state_mut().put_route_state(ZONE_ROUTES_KEY, zones);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+
+        let fitness = get_total_deviation(solution_ctx, self.zone_targets.as_ref());
+
+        solution_ctx.state.insert(ZONE_ROUTES_KEY, Arc::new(fitness));
+    }
+
+    fn state_keys(&self) -> Iter<StateKey> {
+        self.state_keys.iter()
+    }
+}
+
+fn get_zone(job: &Job) -> Option<&String> {
+    job.as_single().and_then(|single| single.dimens.get_zone())
+}
+
+fn is_zone_served_by_route(route_ctx: &RouteContext, zone: &String) -> bool {
+    match route_ctx.state().get_route_state::<HashSet<String>>(ZONE_ROUTES_KEY) {
+        Some(zones) => zones.contains(zone),
+        None => route_ctx.route().tour.jobs().any(|job| get_zone(&job) == Some(zone)),
+    }
+}
+
+fn get_total_deviation(solution_ctx: &SolutionContext, zone_targets: &HashMap<String, usize>) -> Cost {
+    zone_targets
+        .iter()
+        .map(|(zone, target)| {
+            let count = solution_ctx.routes.iter().filter(|route_ctx| is_zone_served_by_route(route_ctx, zone)).count();
+            (count as Cost - *target as Cost).abs()
+        })
+        .sum()
+}
@@ -2,12 +2,12 @@
 #[path = "../../../tests/unit/construction/heuristics/context_test.rs"]
 mod context_test;
 
-use crate::construction::features::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::features::{SOLUTION_GENERATION_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
 use crate::construction::heuristics::factories::*;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, Distance};
 use crate::models::problem::*;
 use crate::models::solution::*;
-use crate::models::GoalContext;
+use crate::models::{GoalContext, ViolationCode};
 use crate::models::{Problem, Solution};
 use crate::utils::short_type_name;
 use hashbrown::{HashMap, HashSet};
@@ -18,7 +18,7 @@ use std::any::Any;
 use std::fmt::{Debug, Formatter};
 use std::hash::BuildHasherDefault;
 use std::ops::Deref;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 /// A context which contains information needed for heuristic and metaheuristic.
 pub struct InsertionContext {
@@ -126,23 +126,34 @@ pub struct SolutionContext {
 impl SolutionContext {
     /// Gets total cost of the solution.
     pub fn get_total_cost(&self) -> Cost {
-        let get_cost = |costs: &Costs, distance: f64, duration: f64| {
-            costs.fixed
-                + costs.per_distance * distance
-                // NOTE this is incorrect when timing costs are different: fitness value will be
-                // different from actual cost. However we accept this so far as it is simpler for
-                // implementation and pragmatic format does not expose this feature
-                // .
-                // TODO calculate actual cost
-                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+        self.get_cost_components().total()
+    }
+
+    /// Gets total cost of the solution split into monetary and time components.
+    pub fn get_cost_components(&self) -> CostComponents {
+        let get_cost = |costs: &Costs, distance: f64, duration: f64| CostComponents {
+            monetary: costs.fixed + costs.per_distance * distance,
+            // NOTE this is incorrect when timing costs are different: fitness value will be
+            // different from actual cost. However we accept this so far as it is simpler for
+            // implementation and pragmatic format does not expose this feature
+            // .
+            // TODO calculate actual cost
+            time: costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration,
         };
 
-        self.routes.iter().fold(Cost::default(), |acc, route_ctx| {
+        self.routes.iter().fold(CostComponents::default(), |acc, route_ctx| {
             let actor = &route_ctx.route.actor;
-            let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+            let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.)
+                - get_free_return_distance(route_ctx);
             let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-            acc + get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+            let (vehicle, driver) =
+                (get_cost(&actor.vehicle.costs, distance, duration), get_cost(&actor.driver.costs, distance, duration));
+
+            CostComponents {
+                monetary: acc.monetary + vehicle.monetary + driver.monetary,
+                time: acc.time + vehicle.time + driver.time,
+            }
         })
     }
 
@@ -173,6 +184,16 @@ impl SolutionContext {
         self.keep_routes(&|route_ctx| route_ctx.route().tour.has_jobs())
     }
 
+    /// Gets generation at which the solution is produced by the search, if known.
+    pub fn get_generation(&self) -> Option<usize> {
+        self.state.get(&SOLUTION_GENERATION_KEY).and_then(|s| s.downcast_ref::<usize>()).copied()
+    }
+
+    /// Sets generation at which the solution is produced by the search.
+    pub fn set_generation(&mut self, generation: usize) {
+        self.state.insert(SOLUTION_GENERATION_KEY, Arc::new(generation));
+    }
+
     /// Creates a deep copy of `SolutionContext`.
     pub fn deep_copy(&self) -> Self {
         Self {
@@ -187,6 +208,29 @@ impl SolutionContext {
     }
 }
 
+/// Returns distance of the route's return leg if the vehicle returns for free, otherwise zero.
+fn get_free_return_distance(route_ctx: &RouteContext) -> Distance {
+    let route = route_ctx.route();
+
+    if !route.actor.vehicle.dimens.get_free_return().cloned().unwrap_or(false) {
+        return Distance::default();
+    }
+
+    match (route.tour.end(), route.tour.total()) {
+        (Some(end), total) if end.job.is_none() && total > 1 => {
+            let get_distance = |activity: Option<&Activity>| {
+                activity
+                    .and_then(|activity| route_ctx.state.get_activity_state::<f64>(TOTAL_DISTANCE_KEY, activity))
+                    .cloned()
+                    .unwrap_or(0.)
+            };
+
+            get_distance(Some(end)) - get_distance(route.tour.get(total - 2))
+        }
+        _ => Distance::default(),
+    }
+}
+
 impl Debug for SolutionContext {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(short_type_name::<Self>())
@@ -209,10 +253,28 @@ impl From<SolutionContext> for Solution {
                 .map(|(job, code)| (job.clone(), code.clone()))
                 .chain(solution_ctx.required.iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)))
                 .collect(),
+            seed: None,
         }
     }
 }
 
+/// Specifies total cost of the solution split into components with different units: monetary cost
+/// and time cost. Both are expressed in cost units and combined with equal weights for ranking.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CostComponents {
+    /// Monetary cost: fixed costs of used vehicles and drivers and costs of traveled distance.
+    pub monetary: Cost,
+    /// Time cost: costs of the time spent by vehicles and drivers on the routes.
+    pub time: Cost,
+}
+
+impl CostComponents {
+    /// Returns combined cost used for ranking.
+    pub fn total(&self) -> Cost {
+        self.monetary + self.time
+    }
+}
+
 /// Specifies insertion context for route.
 pub struct RouteContext {
     route: Route,
@@ -248,7 +310,7 @@ impl RouteContext {
 
     /// Creates a new instance of `RouteContext` with arguments provided.
     pub fn new_with_state(route: Route, state: RouteState) -> Self {
-        RouteContext { route, state, cache: RouteCache { is_stale: true } }
+        RouteContext { route, state, cache: RouteCache { is_stale: true, feasibility: Default::default() } }
     }
 
     /// Creates a deep copy of `RouteContext`.
@@ -256,7 +318,11 @@ impl RouteContext {
         let new_route = Route { actor: self.route.actor.clone(), tour: self.route.tour.deep_copy() };
         let new_state = RouteState::from_other_and_tours(&self.state, &self.route.tour, &new_route.tour);
 
-        RouteContext { route: new_route, state: new_state, cache: RouteCache { is_stale: self.cache.is_stale } }
+        RouteContext {
+            route: new_route,
+            state: new_state,
+            cache: RouteCache { is_stale: self.cache.is_stale, feasibility: Default::default() },
+        }
     }
 
     /// Returns a reference to route.
@@ -297,9 +363,30 @@ impl RouteContext {
     }
 
     /// Marks context stale or resets the flag.
+    /// Marking context as stale invalidates its feasibility cache.
     pub(crate) fn mark_stale(&mut self, is_stale: bool) {
+        if is_stale {
+            self.clear_feasibility_cache();
+        }
+
         self.cache.is_stale = is_stale;
     }
+
+    /// Returns a cached route level constraint violation code of the job, if it is known
+    /// that the job cannot be inserted into the unchanged route.
+    pub(crate) fn get_cached_violation(&self, job: &Job) -> Option<ViolationCode> {
+        self.cache.feasibility.read().unwrap().get(job).copied()
+    }
+
+    /// Caches route level constraint violation code of the job for the unchanged route.
+    pub(crate) fn cache_violation(&self, job: &Job, code: ViolationCode) {
+        self.cache.feasibility.write().unwrap().insert(job.clone(), code);
+    }
+
+    /// Clears feasibility cache.
+    pub(crate) fn clear_feasibility_cache(&mut self) {
+        self.cache.feasibility.get_mut().unwrap().clear();
+    }
 }
 
 impl PartialEq<RouteContext> for RouteContext {
@@ -450,6 +537,8 @@ impl RouteState {
 
 struct RouteCache {
     is_stale: bool,
+    /// Keeps route level constraint violations of jobs evaluated against the route in its current state.
+    feasibility: RwLock<HashMap<Job, ViolationCode>>,
 }
 
 /// A wrapper around route context modifier function.
@@ -529,6 +618,21 @@ impl RegistryContext {
         self.registry.free_actor(&route.route.actor)
     }
 
+    /// Clears feasibility cache of routes available for insertion.
+    pub(crate) fn clear_feasibility_cache(&mut self) {
+        self.index.values_mut().for_each(|route_ctx| route_ctx.clear_feasibility_cache());
+    }
+
+    /// Modifies state of routes available for insertion, e.g. to propagate solution wide data
+    /// to routes which are not used yet. Keeps stale flag of the routes unchanged.
+    pub fn update_routes_state(&mut self, func: impl Fn(&mut RouteState)) {
+        self.index.values_mut().for_each(|route_ctx| {
+            let is_stale = route_ctx.is_stale();
+            func(route_ctx.state_mut());
+            route_ctx.mark_stale(is_stale);
+        });
+    }
+
     /// Creates a deep copy of `RegistryContext`.
     pub fn deep_copy(&self) -> Self {
         Self {
@@ -5,11 +5,12 @@ mod evaluators_test;
 use std::sync::Arc;
 
 use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Distance, IdDimension};
 use crate::models::problem::{Job, Multi, Single};
 use crate::models::solution::{Activity, Leg, Place};
-use crate::models::{ConstraintViolation, GoalContext};
+use crate::models::{ConstraintViolation, GoalContext, Problem, Solution};
 use crate::utils::Either;
-use rosomaxa::utils::unwrap_from_result;
+use rosomaxa::utils::{unwrap_from_result, Environment};
 
 /// Specifies an evaluation context data.
 pub struct EvaluationContext<'a> {
@@ -53,11 +54,19 @@ pub fn eval_job_insertion_in_route(
 
     let goal = &insertion_ctx.problem.goal;
 
-    if let Some(violation) = goal.evaluate(&MoveContext::route(&insertion_ctx.solution, route_ctx, eval_ctx.job)) {
+    // NOTE route level constraints are checked only once while route stays unchanged
+    let violation_code = route_ctx.get_cached_violation(eval_ctx.job).or_else(|| {
+        goal.evaluate(&MoveContext::route(&insertion_ctx.solution, route_ctx, eval_ctx.job)).map(|violation| {
+            route_ctx.cache_violation(eval_ctx.job, violation.code);
+            violation.code
+        })
+    });
+
+    if let Some(code) = violation_code {
         return eval_ctx.result_selector.select_insertion(
             insertion_ctx,
             alternative,
-            InsertionResult::make_failure_with_code(violation.code, true, Some(eval_ctx.job.clone())),
+            InsertionResult::make_failure_with_code(code, true, Some(eval_ctx.job.clone())),
         );
     }
 
@@ -95,6 +104,214 @@ pub fn eval_job_constraint_in_route(
     }
 }
 
+/// Calculates a marginal cost of serving a new job: a change of total solution cost after the job's
+/// cheapest feasible insertion into existing or new route without re-optimizing the solution.
+/// Returns `None` if the job cannot be inserted, use `try_marginal_cost` to get a reason.
+pub fn marginal_cost(problem: Arc<Problem>, solution: &Solution, job: &Job) -> Option<Cost> {
+    try_marginal_cost(problem, solution, job).ok()
+}
+
+/// Calculates a marginal cost of serving a new job as `marginal_cost`, but returns a violation of
+/// the binding constraint if the job cannot be inserted. When the job is rejected by multiple routes
+/// for different reasons, a violation reported by the last evaluated route is returned.
+pub fn try_marginal_cost(problem: Arc<Problem>, solution: &Solution, job: &Job) -> Result<Cost, ConstraintViolation> {
+    let mut insertion_ctx = create_insertion_ctx_from_solution(problem, solution);
+    let success = eval_cheapest_insertion(&insertion_ctx, job)?;
+
+    let original_cost = insertion_ctx.solution.get_total_cost();
+    apply_insertion_success(&mut insertion_ctx, success);
+
+    Ok(insertion_ctx.solution.get_total_cost() - original_cost)
+}
+
+/// Inserts a new job into existing solution with minimal disruption: the job is placed at its
+/// cheapest feasible position in existing or new route, other routes are kept as is. When
+/// `reopt_radius` is specified, the route with the new job and routes having job activities within
+/// given distance from it are re-sequenced; a re-sequenced route is kept only if its cost is not worse.
+/// Routes with locked jobs are never re-sequenced. Returns a violation of the binding constraint
+/// if the job cannot be inserted.
+pub fn insert_job(
+    problem: Arc<Problem>,
+    solution: &Solution,
+    job: &Job,
+    reopt_radius: Option<Distance>,
+) -> Result<Solution, ConstraintViolation> {
+    let mut insertion_ctx = create_insertion_ctx_from_solution(problem, solution);
+    let success = eval_cheapest_insertion(&insertion_ctx, job)?;
+    apply_insertion_success(&mut insertion_ctx, success);
+
+    if let Some(radius) = reopt_radius {
+        get_affected_route_indices(&insertion_ctx, job, radius)
+            .into_iter()
+            .for_each(|route_idx| resequence_route(&mut insertion_ctx, route_idx));
+
+        insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+    }
+
+    Ok(insertion_ctx.solution.into())
+}
+
+/// Removes a job with given id from existing solution, e.g. when an order is canceled. Activities
+/// of the job are removed from its route and the route schedule is recalculated, so the route
+/// reconnects neighbors of removed activities; the route is dropped when it has no jobs left.
+/// Other routes are kept as is. Returns an error if the job is not present in the solution.
+pub fn remove_job(problem: Arc<Problem>, solution: &Solution, job_id: &str) -> Result<Solution, String> {
+    let has_id = |job: &Job| job.dimens().get_id().map_or(false, |id| id == job_id);
+
+    let job = solution
+        .routes
+        .iter()
+        .flat_map(|route| route.tour.jobs())
+        .chain(solution.unassigned.iter().map(|(job, _)| job.clone()))
+        .find(has_id)
+        .ok_or_else(|| format!("cannot find job with id '{job_id}' in the solution"))?;
+
+    let mut insertion_ctx = create_insertion_ctx_from_solution(problem, solution);
+
+    if let Some(route_ctx) =
+        insertion_ctx.solution.routes.iter_mut().find(|route_ctx| route_ctx.route().tour.contains(&job))
+    {
+        route_ctx.route_mut().tour.remove(&job);
+        insertion_ctx.problem.goal.accept_route_state(route_ctx);
+    }
+
+    insertion_ctx.solution.unassigned.remove(&job);
+    insertion_ctx.restore();
+
+    Ok(insertion_ctx.solution.into())
+}
+
+fn create_insertion_ctx_from_solution(problem: Arc<Problem>, solution: &Solution) -> InsertionContext {
+    let solution = Solution {
+        registry: solution.registry.deep_copy(),
+        routes: solution.routes.iter().map(|route| route.deep_copy()).collect(),
+        unassigned: solution.unassigned.clone(),
+        seed: solution.seed,
+    };
+
+    InsertionContext::new_from_solution(problem, (solution, None), Arc::new(Environment::default()))
+}
+
+fn eval_cheapest_insertion(
+    insertion_ctx: &InsertionContext,
+    job: &Job,
+) -> Result<InsertionSuccess, ConstraintViolation> {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        goal: &insertion_ctx.problem.goal,
+        job,
+        leg_selection: &leg_selection,
+        result_selector: &result_selector,
+    };
+
+    let result =
+        insertion_ctx.solution.routes.iter().chain(insertion_ctx.solution.registry.next_route()).fold(
+            InsertionResult::make_failure(),
+            |acc, route_ctx| {
+                eval_job_insertion_in_route(insertion_ctx, &eval_ctx, route_ctx, InsertionPosition::Any, acc)
+            },
+        );
+
+    match result {
+        InsertionResult::Success(success) => Ok(success),
+        InsertionResult::Failure(failure) => {
+            Err(ConstraintViolation { code: failure.constraint, stopped: failure.stopped })
+        }
+    }
+}
+
+/// Returns indices of the route which serves the job and routes with job activities in given radius.
+fn get_affected_route_indices(insertion_ctx: &InsertionContext, job: &Job, radius: Distance) -> Vec<usize> {
+    let routes = &insertion_ctx.solution.routes;
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    let locations = routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.job_activities(job))
+        .map(|activity| activity.place.location)
+        .collect::<Vec<_>>();
+
+    routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| {
+            let route = route_ctx.route();
+            let profile = &route.actor.vehicle.profile;
+
+            route.tour.contains(job)
+                || route.tour.all_activities().filter(|activity| activity.job.is_some()).any(|activity| {
+                    locations.iter().any(|&location| {
+                        transport.distance_approx(profile, location, activity.place.location) <= radius
+                    })
+                })
+        })
+        .map(|(route_idx, _)| route_idx)
+        .collect()
+}
+
+/// Removes all jobs from the route and inserts them back one by one at their cheapest positions.
+/// The original route is restored if some job cannot be inserted or the total cost is increased.
+fn resequence_route(insertion_ctx: &mut InsertionContext, route_idx: usize) {
+    let route_ctx = &insertion_ctx.solution.routes[route_idx];
+    // NOTE keep the order of jobs in the tour to have deterministic result
+    let jobs = route_ctx.route().tour.all_activities().filter_map(|activity| activity.retrieve_job()).fold(
+        Vec::<Job>::default(),
+        |mut jobs, job| {
+            if !jobs.contains(&job) {
+                jobs.push(job);
+            }
+            jobs
+        },
+    );
+
+    if jobs.iter().any(|job| insertion_ctx.solution.locked.contains(job)) {
+        return;
+    }
+
+    let original_route = route_ctx.deep_copy();
+    let original_cost = insertion_ctx.solution.get_total_cost();
+
+    let route_ctx = &mut insertion_ctx.solution.routes[route_idx];
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    insertion_ctx.problem.goal.accept_route_state(route_ctx);
+
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    let is_inserted = jobs.iter().all(|job| {
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        let result = eval_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            &insertion_ctx.solution.routes[route_idx],
+            InsertionPosition::Any,
+            InsertionResult::make_failure(),
+        );
+
+        match result {
+            InsertionResult::Success(success) => {
+                apply_insertion_success(insertion_ctx, success);
+                true
+            }
+            InsertionResult::Failure(_) => false,
+        }
+    });
+
+    if !is_inserted || insertion_ctx.solution.get_total_cost() > original_cost {
+        insertion_ctx.solution.routes[route_idx] = original_route;
+        insertion_ctx.problem.goal.accept_route_state(&mut insertion_ctx.solution.routes[route_idx]);
+    }
+}
+
 pub(crate) fn eval_single_constraint_in_route(
     insertion_ctx: &InsertionContext,
     eval_ctx: &EvaluationContext,
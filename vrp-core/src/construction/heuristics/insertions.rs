@@ -366,12 +366,21 @@ impl TryFrom<InsertionResult> for InsertionSuccess {
 pub(crate) fn prepare_insertion_ctx(insertion_ctx: &mut InsertionContext) {
     insertion_ctx.solution.required.extend(insertion_ctx.solution.unassigned.iter().map(|(job, _)| job.clone()));
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+    clear_feasibility_cache(insertion_ctx);
 }
 
 pub(crate) fn finalize_insertion_ctx(insertion_ctx: &mut InsertionContext) {
     finalize_unassigned(insertion_ctx, UnassignmentInfo::Unknown);
 
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+    clear_feasibility_cache(insertion_ctx);
+}
+
+/// NOTE route level constraints can depend on solution state, so feasibility cache is not kept
+/// between insertion heuristic runs.
+fn clear_feasibility_cache(insertion_ctx: &mut InsertionContext) {
+    insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| route_ctx.clear_feasibility_cache());
+    insertion_ctx.solution.registry.clear_feasibility_cache();
 }
 
 pub(crate) fn apply_insertion_success(insertion_ctx: &mut InsertionContext, success: InsertionSuccess) {
@@ -0,0 +1,93 @@
+//! Provides a way to analyse problem and solution, e.g. to estimate difficulty of the problem
+//! instance or utilization of the solution's routes.
+
+mod problem;
+pub use self::problem::{
+    compute_savings, instance_difficulty, merge_problems, min_vehicles_lower_bound, DifficultyScore,
+};
+
+mod solution;
+pub use self::solution::{RouteFeasibility, RouteUtilization};
+
+use crate::models::common::{CapacityDimension, Demand, DemandDimension, Dimensions, MultiDimLoad, SingleDimLoad};
+use crate::models::solution::Route;
+
+fn get_load_values(dimens: &Dimensions) -> Option<Vec<i32>> {
+    let single: Option<&SingleDimLoad> = dimens.get_capacity();
+    let multi: Option<&MultiDimLoad> = dimens.get_capacity();
+
+    single.map(|load| vec![load.value]).or_else(|| multi.map(|load| load.load[..load.size].to_vec()))
+}
+
+fn get_static_demand_values(dimens: &Dimensions) -> Option<(Vec<i32>, Vec<i32>)> {
+    get_demand_values(dimens).map(|[static_delivery, static_pickup, ..]| (static_delivery, static_pickup))
+}
+
+/// Returns static delivery, static pickup, dynamic delivery and dynamic pickup values of the demand.
+fn get_demand_values(dimens: &Dimensions) -> Option<[Vec<i32>; 4]> {
+    let single: Option<&Demand<SingleDimLoad>> = dimens.get_demand();
+    let multi: Option<&Demand<MultiDimLoad>> = dimens.get_demand();
+    let to_values = |load: &MultiDimLoad| load.load[..load.size].to_vec();
+
+    single
+        .map(|demand| {
+            [
+                vec![demand.delivery.0.value],
+                vec![demand.pickup.0.value],
+                vec![demand.delivery.1.value],
+                vec![demand.pickup.1.value],
+            ]
+        })
+        .or_else(|| {
+            multi.map(|demand| {
+                [
+                    to_values(&demand.delivery.0),
+                    to_values(&demand.pickup.0),
+                    to_values(&demand.delivery.1),
+                    to_values(&demand.pickup.1),
+                ]
+            })
+        })
+}
+
+/// Returns the highest load of each dimension along the route.
+fn get_peak_load(route: &Route) -> Vec<i32> {
+    let demands = route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.job.as_ref())
+        .filter_map(|single| get_demand_values(&single.dimens))
+        .collect::<Vec<_>>();
+
+    let size = demands.iter().flat_map(|values| values.iter().map(|values| values.len())).max().unwrap_or(0);
+    let apply = |load: &mut Vec<i32>, values: &[i32], sign: i32| {
+        load.iter_mut().zip(values.iter()).for_each(|(load, value)| *load += sign * value);
+    };
+
+    // NOTE static deliveries are loaded at the tour start
+    let initial = demands.iter().fold(vec![0; size], |mut load, [static_delivery, ..]| {
+        apply(&mut load, static_delivery, 1);
+        load
+    });
+
+    demands
+        .iter()
+        .fold((initial.clone(), initial), |(mut load, mut peak), [static_delivery, static_pickup, delivery, pickup]| {
+            apply(&mut load, static_delivery, -1);
+            apply(&mut load, delivery, -1);
+            apply(&mut load, static_pickup, 1);
+            apply(&mut load, pickup, 1);
+            peak.iter_mut().zip(load.iter()).for_each(|(peak, load)| *peak = (*peak).max(*load));
+
+            (load, peak)
+        })
+        .1
+}
+
+fn get_ratio(value: f64, total: f64) -> f64 {
+    if total > 0. {
+        (value / total).clamp(0., 1.)
+    } else {
+        0.
+    }
+}
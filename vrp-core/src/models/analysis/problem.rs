@@ -0,0 +1,336 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/analysis/problem_test.rs"]
+mod problem_test;
+
+use super::*;
+use crate::models::common::{IdDimension, Location, TimeWindow};
+use crate::models::problem::*;
+use crate::models::Problem;
+use hashbrown::{HashMap, HashSet};
+use rosomaxa::prelude::compare_floats;
+use std::io::Write;
+use std::sync::Arc;
+
+impl Problem {
+    /// Writes transport matrices used by the solver in csv format with one row per profile and
+    /// location pair: `profile,from,to,distance,duration`. Only job and vehicle locations are
+    /// exported, values are time independent and include profile specific scale.
+    pub fn export_matrices<W: Write>(&self, writer: &mut W) -> Result<(), String> {
+        let locations = self.get_locations();
+
+        writeln!(writer, "profile,from,to,distance,duration").map_err(|err| err.to_string())?;
+
+        self.fleet.profiles.iter().try_for_each(|profile| {
+            locations.iter().flat_map(|&from| locations.iter().map(move |&to| (from, to))).try_for_each(|(from, to)| {
+                let distance = self.transport.distance_approx(profile, from, to);
+                let duration = self.transport.duration_approx(profile, from, to);
+
+                writeln!(writer, "{},{from},{to},{distance},{duration}", profile.index).map_err(|err| err.to_string())
+            })
+        })
+    }
+
+    fn get_locations(&self) -> Vec<Location> {
+        let job_locations = self.jobs.all().flat_map(|job| get_job_locations(&job).flatten().collect::<Vec<_>>());
+        let vehicle_locations = self
+            .fleet
+            .vehicles
+            .iter()
+            .flat_map(|vehicle| vehicle.details.iter())
+            .flat_map(|detail| detail.start.iter().chain(detail.end.iter()).map(|place| place.location));
+
+        let mut locations =
+            job_locations.chain(vehicle_locations).collect::<HashSet<_>>().into_iter().collect::<Vec<_>>();
+        locations.sort_unstable();
+
+        locations
+    }
+}
+
+/// Merges two problems into one by union of their jobs and vehicles, e.g. to combine sub-problems
+/// after decomposition. Both problems should share the same goal, transport and activity costs, and
+/// their job and vehicle ids should not collide. Drivers with the same id and costs are deduplicated,
+/// as fleet supports only one driver, problems with different drivers cannot be merged.
+pub fn merge_problems(a: &Problem, b: &Problem) -> Result<Problem, String> {
+    let is_same = |left: *const (), right: *const ()| std::ptr::eq(left, right);
+
+    if !is_same(Arc::as_ptr(&a.transport) as *const (), Arc::as_ptr(&b.transport) as *const ())
+        || !is_same(Arc::as_ptr(&a.activity) as *const (), Arc::as_ptr(&b.activity) as *const ())
+    {
+        return Err("cannot merge problems with incompatible costs".to_string());
+    }
+
+    if !Arc::ptr_eq(&a.goal, &b.goal) {
+        return Err("cannot merge problems with different goals".to_string());
+    }
+
+    let vehicles = a.fleet.vehicles.iter().chain(b.fleet.vehicles.iter()).cloned().collect::<Vec<_>>();
+    check_unique_ids(vehicles.iter().map(|vehicle| vehicle.dimens.get_id()), "vehicle")?;
+
+    let jobs = a.jobs.all().chain(b.jobs.all()).collect::<Vec<_>>();
+    check_unique_ids(jobs.iter().map(|job| job.dimens().get_id()), "job")?;
+
+    let drivers =
+        a.fleet.drivers.iter().chain(b.fleet.drivers.iter()).fold(Vec::<Arc<Driver>>::new(), |mut acc, driver| {
+            if !acc.iter().any(|other| is_same_driver(other, driver)) {
+                acc.push(driver.clone());
+            }
+            acc
+        });
+
+    // NOTE fleet supports only one driver at the moment
+    if drivers.len() != 1 {
+        return Err("cannot merge problems with different drivers".to_string());
+    }
+
+    let fleet = Fleet::new(
+        drivers,
+        vehicles,
+        Box::new(|actors| {
+            let details = actors.iter().map(|actor| actor.detail.clone()).collect::<HashSet<_>>();
+            let details = details.into_iter().zip(0_usize..).collect::<HashMap<_, _>>();
+            let groups = actors
+                .iter()
+                .map(|actor| (actor.clone(), *details.get(&actor.detail).unwrap()))
+                .collect::<HashMap<_, _>>();
+
+            Box::new(move |actor| *groups.get(actor).unwrap())
+        }),
+    );
+    let jobs = Jobs::new(&fleet, jobs, &a.transport);
+
+    Ok(Problem {
+        fleet: Arc::new(fleet),
+        jobs: Arc::new(jobs),
+        locks: a.locks.iter().chain(b.locks.iter()).cloned().collect(),
+        goal: a.goal.clone(),
+        activity: a.activity.clone(),
+        transport: a.transport.clone(),
+        extras: a.extras.clone(),
+    })
+}
+
+/// Computes Clarke-Wright savings for each pair of jobs: a saving of serving two jobs in one route
+/// instead of two separate routes, `s(i, j) = d(i, depot) + d(depot, j) - d(i, j)`. A depot is a start
+/// location of the first vehicle and distances are estimated for its profile. Each pair is returned
+/// once with job ids ordered in a direction with a higher saving. Pairs are sorted by savings in
+/// descending order. Only jobs with id and location are considered: the first location is used.
+pub fn compute_savings(problem: &Problem) -> Vec<(String, String, f64)> {
+    let depot = problem.fleet.vehicles.first().and_then(|vehicle| {
+        vehicle.details.first().and_then(|detail| detail.start.as_ref()).map(|start| (&vehicle.profile, start.location))
+    });
+    let (profile, depot) = if let Some(depot) = depot { depot } else { return vec![] };
+
+    let jobs = problem
+        .jobs
+        .all()
+        .filter_map(|job| job.dimens().get_id().cloned().zip(get_job_locations(&job).flatten().next()))
+        .collect::<Vec<_>>();
+    let distance = |from: Location, to: Location| problem.transport.distance_approx(profile, from, to);
+    let saving = |from: Location, to: Location| distance(from, depot) + distance(depot, to) - distance(from, to);
+
+    let mut savings = jobs
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, (id_i, loc_i))| {
+            jobs.iter().skip(idx + 1).map(move |(id_j, loc_j)| {
+                let (forward, backward) = (saving(*loc_i, *loc_j), saving(*loc_j, *loc_i));
+                if forward >= backward {
+                    (id_i.clone(), id_j.clone(), forward)
+                } else {
+                    (id_j.clone(), id_i.clone(), backward)
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    savings.sort_by(|(a_i, a_j, a), (b_i, b_j, b)| compare_floats(*b, *a).then_with(|| (a_i, a_j).cmp(&(b_i, b_j))));
+
+    savings
+}
+
+/// Computes a lower bound on amount of tours needed to serve all jobs using a bin packing relaxation:
+/// capacity of each vehicle is relaxed to the largest one in the fleet, static deliveries (pickups)
+/// of all jobs should fit into vehicles at tour start (end), and two jobs with demand above a half of
+/// the capacity cannot share a tour. Vehicle reloads are not considered. Supports single and multi
+/// dimensional loads, zero is returned when the fleet has no capacity specified.
+pub fn min_vehicles_lower_bound(problem: &Problem) -> usize {
+    let capacity = problem.fleet.vehicles.iter().filter_map(|vehicle| get_load_values(&vehicle.dimens)).fold(
+        Vec::<i32>::new(),
+        |mut capacity, values| {
+            capacity.resize(capacity.len().max(values.len()), 0);
+            capacity.iter_mut().zip(values).for_each(|(max, value)| *max = (*max).max(value));
+            capacity
+        },
+    );
+
+    let demands = problem
+        .jobs
+        .all()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .filter_map(|single| get_static_demand_values(&single.dimens))
+        .collect::<Vec<_>>();
+
+    capacity
+        .iter()
+        .enumerate()
+        .filter(|(_, capacity)| **capacity > 0)
+        .map(|(idx, &capacity)| {
+            let get_value = |values: &Vec<i32>| values.get(idx).copied().unwrap_or(0).max(0);
+            let total_bound = |values: Vec<i32>| (values.iter().sum::<i32>() as usize).div_ceil(capacity as usize);
+            let large_bound = |values: &[i32]| values.iter().filter(|&&value| value * 2 > capacity).count();
+
+            let (deliveries, pickups): (Vec<_>, Vec<_>) =
+                demands.iter().map(|(delivery, pickup)| (get_value(delivery), get_value(pickup))).unzip();
+
+            large_bound(deliveries.as_slice())
+                .max(large_bound(pickups.as_slice()))
+                .max(total_bound(deliveries))
+                .max(total_bound(pickups))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Specifies a heuristic difficulty score of the problem instance within its components.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DifficultyScore {
+    /// A total score: job count component scaled by the sum of other components increased by one.
+    pub total: f64,
+    /// A job count component: a decimal logarithm of the amount of jobs increased by one.
+    pub job_count: f64,
+    /// Time window tightness in [0, 1] range: one minus the average share of the planning horizon
+    /// covered by job's time windows.
+    pub time_window_tightness: f64,
+    /// Capacity tightness in [0, 1] range: a ratio of total static demand to total fleet capacity,
+    /// so the less capacity slack, the higher the value. The most loaded dimension is used.
+    pub capacity_tightness: f64,
+    /// Geographic spread in [0, 1] range: an average travel duration from the depot to jobs relative
+    /// to the planning horizon.
+    pub geographic_spread: f64,
+}
+
+/// Computes a heuristic difficulty score of the problem from its features: job count, time window
+/// tightness, capacity slack and geographic spread. The planning horizon is a union of actors' time
+/// windows and a depot is a start location of the first vehicle. The score is intended to compare
+/// instances for benchmarking purpose and does not predict the solver's runtime.
+pub fn instance_difficulty(problem: &Problem) -> DifficultyScore {
+    let singles = problem
+        .jobs
+        .all()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    let horizon =
+        problem.fleet.actors.iter().map(|actor| &actor.detail.time).fold(None, |acc: Option<TimeWindow>, time| {
+            Some(acc.map_or(time.clone(), |acc| TimeWindow::new(acc.start.min(time.start), acc.end.max(time.end))))
+        });
+    let horizon_duration = horizon.as_ref().map_or(0., |horizon| horizon.duration());
+
+    let average =
+        |values: Vec<f64>| if values.is_empty() { 0. } else { values.iter().sum::<f64>() / values.len() as f64 };
+
+    let time_window_tightness = horizon.as_ref().map_or(0., |horizon| {
+        average(
+            singles
+                .iter()
+                .map(|single| {
+                    let covered = single
+                        .places
+                        .iter()
+                        .map(|place| {
+                            if place.times.is_empty() {
+                                return horizon_duration;
+                            }
+
+                            place
+                                .times
+                                .iter()
+                                .map(|span| span.to_time_window(horizon.start))
+                                .map(|tw| (tw.end.min(horizon.end) - tw.start.max(horizon.start)).max(0.))
+                                .sum::<f64>()
+                        })
+                        .fold(0., f64::max);
+
+                    1. - get_ratio(covered, horizon_duration)
+                })
+                .collect(),
+        )
+    });
+
+    let capacity = problem.fleet.vehicles.iter().filter_map(|vehicle| get_load_values(&vehicle.dimens)).fold(
+        Vec::<i32>::new(),
+        |mut capacity, values| {
+            capacity.resize(capacity.len().max(values.len()), 0);
+            capacity.iter_mut().zip(values).for_each(|(total, value)| *total += value);
+            capacity
+        },
+    );
+    let demand = singles.iter().filter_map(|single| get_static_demand_values(&single.dimens)).fold(
+        Vec::<i32>::new(),
+        |mut demand, (delivery, pickup)| {
+            demand.resize(demand.len().max(delivery.len()).max(pickup.len()), 0);
+            demand.iter_mut().zip(delivery).for_each(|(total, value)| *total += value.max(0));
+            demand.iter_mut().zip(pickup).for_each(|(total, value)| *total += value.max(0));
+            demand
+        },
+    );
+    let capacity_tightness = capacity
+        .iter()
+        .enumerate()
+        .map(|(idx, &capacity)| get_ratio(demand.get(idx).copied().unwrap_or(0) as f64, capacity as f64))
+        .fold(0., f64::max);
+
+    let depot = problem.fleet.vehicles.first().and_then(|vehicle| {
+        vehicle.details.first().and_then(|detail| detail.start.as_ref()).map(|start| (&vehicle.profile, start.location))
+    });
+    let geographic_spread = depot.map_or(0., |(profile, depot)| {
+        let durations = singles
+            .iter()
+            .filter_map(|single| single.places.iter().filter_map(|place| place.location).next())
+            .map(|location| problem.transport.duration_approx(profile, depot, location))
+            .collect();
+
+        get_ratio(average(durations), horizon_duration)
+    });
+
+    let job_count = (problem.jobs.size() as f64 + 1.).log10();
+
+    DifficultyScore {
+        total: job_count * (1. + time_window_tightness + capacity_tightness + geographic_spread),
+        job_count,
+        time_window_tightness,
+        capacity_tightness,
+        geographic_spread,
+    }
+}
+
+/// Checks whether drivers are the same or have the same id and costs.
+fn is_same_driver(left: &Arc<Driver>, right: &Arc<Driver>) -> bool {
+    let (lc, rc) = (&left.costs, &right.costs);
+
+    Arc::ptr_eq(left, right)
+        || (left.dimens.get_id() == right.dimens.get_id()
+            && lc.fixed == rc.fixed
+            && lc.per_distance == rc.per_distance
+            && lc.per_driving_time == rc.per_driving_time
+            && lc.per_waiting_time == rc.per_waiting_time
+            && lc.per_service_time == rc.per_service_time)
+}
+
+fn check_unique_ids<'a>(ids: impl Iterator<Item = Option<&'a String>>, kind: &str) -> Result<(), String> {
+    let mut unique = HashSet::new();
+    ids.flatten().try_for_each(|id| {
+        if unique.insert(id) {
+            Ok(())
+        } else {
+            Err(format!("cannot merge problems: duplicate {kind} id '{id}'"))
+        }
+    })
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/analysis/solution_test.rs"]
+mod solution_test;
+
+use super::*;
+use crate::construction::heuristics::{ActivityContext, InsertionContext, MoveContext};
+use crate::models::common::{Distance, IdDimension};
+use crate::models::problem::{Job, TravelTime};
+use crate::models::{Problem, Solution, ViolationCode};
+use rosomaxa::prelude::{Environment, HeuristicSolution};
+use std::sync::Arc;
+
+impl Solution {
+    /// Checks feasibility of each route against problem's constraints: every job is removed from
+    /// its route and evaluated back at its original position. The first violated constraint is
+    /// reported as a binding one.
+    pub fn route_feasibility(&self, problem: Arc<Problem>) -> Vec<RouteFeasibility> {
+        let solution = Solution {
+            registry: self.registry.deep_copy(),
+            routes: self.routes.iter().map(|route| route.deep_copy()).collect(),
+            unassigned: self.unassigned.clone(),
+            seed: self.seed,
+        };
+        let insertion_ctx =
+            InsertionContext::new_from_solution(problem, (solution, None), Arc::new(Environment::default()));
+
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .map(|(route_idx, route_ctx)| {
+                let violation = route_ctx
+                    .route()
+                    .tour
+                    .jobs()
+                    .find_map(|job| evaluate_job_in_route(&insertion_ctx, route_idx, &job));
+
+                RouteFeasibility {
+                    vehicle_id: route_ctx.route().actor.vehicle.dimens.get_id().cloned(),
+                    is_feasible: violation.is_none(),
+                    violation,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns utilization of each route: peak load relative to vehicle's capacity, route duration
+    /// relative to vehicle's shift time and traveled distance. Ratios are in [0, 1] range and zero
+    /// is reported when vehicle has no capacity or its shift has zero duration. Static demand is
+    /// assumed to be loaded at the tour start, so vehicle reloads are not considered.
+    pub fn utilization(&self, problem: &Problem) -> Vec<RouteUtilization> {
+        self.routes
+            .iter()
+            .map(|route| {
+                let peak_load = get_peak_load(route);
+                let capacity = get_load_values(&route.actor.vehicle.dimens)
+                    .map(|capacity| {
+                        capacity
+                            .iter()
+                            .enumerate()
+                            .map(|(idx, &capacity)| {
+                                get_ratio(peak_load.get(idx).copied().unwrap_or(0) as f64, capacity as f64)
+                            })
+                            .fold(0., f64::max)
+                    })
+                    .unwrap_or(0.);
+
+                let duration = route
+                    .tour
+                    .start()
+                    .zip(route.tour.end())
+                    .map_or(0., |(start, end)| end.schedule.departure - start.schedule.departure);
+
+                let distance = route
+                    .tour
+                    .all_activities()
+                    .zip(route.tour.all_activities().skip(1))
+                    .map(|(from, to)| {
+                        let departure = TravelTime::Departure(from.schedule.departure);
+                        problem.transport.distance(route, from.place.location, to.place.location, departure)
+                    })
+                    .sum();
+
+                RouteUtilization {
+                    vehicle_id: route.actor.vehicle.dimens.get_id().cloned(),
+                    capacity,
+                    time: get_ratio(duration, route.actor.detail.time.duration()),
+                    distance,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the max uniform demand increase (in percents) which keeps every route within its
+    /// vehicle's capacity, e.g. `25.` means that all demands can grow by a quarter. A route which
+    /// already exceeds capacity has zero margin and infinity is returned when no route carries load.
+    /// Static demand is assumed to be loaded at the tour start, so vehicle reloads are not considered.
+    pub fn robustness_margin(&self) -> f64 {
+        self.routes
+            .iter()
+            .flat_map(|route| {
+                let peak_load = get_peak_load(route);
+                let capacity = get_load_values(&route.actor.vehicle.dimens).unwrap_or_default();
+
+                peak_load.into_iter().enumerate().filter(|(_, peak)| *peak > 0).map(move |(idx, peak)| {
+                    let capacity = capacity.get(idx).copied().unwrap_or(0) as f64;
+                    ((capacity / peak as f64 - 1.) * 100.).max(0.)
+                })
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns ids of jobs served by the vehicle with given id in the order of their activities in
+    /// the tour or `None` if the vehicle is not used. A job with multiple activities, e.g. pickup
+    /// and delivery, is listed once per activity.
+    pub fn vehicle_manifest(&self, vehicle_id: &str) -> Option<Vec<String>> {
+        let route = self
+            .routes
+            .iter()
+            .find(|route| route.actor.vehicle.dimens.get_id().map_or(false, |id| id == vehicle_id))?;
+
+        let job_ids = route
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.retrieve_job())
+            .filter_map(|job| job.dimens().get_id().cloned())
+            .collect::<Vec<_>>();
+
+        if job_ids.is_empty() {
+            None
+        } else {
+            Some(job_ids)
+        }
+    }
+}
+
+/// Specifies utilization of the route in the solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteUtilization {
+    /// An id of the vehicle used by the route.
+    pub vehicle_id: Option<String>,
+    /// Peak load relative to vehicle's capacity: the most utilized dimension is used for multi
+    /// dimensional load.
+    pub capacity: f64,
+    /// Route duration relative to vehicle's shift time.
+    pub time: f64,
+    /// Total distance traveled by the route.
+    pub distance: Distance,
+}
+
+/// Specifies feasibility of the route in the solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteFeasibility {
+    /// An id of the vehicle used by the route.
+    pub vehicle_id: Option<String>,
+    /// True if all jobs of the route satisfy problem's constraints.
+    pub is_feasible: bool,
+    /// A code of the binding constraint if the route is infeasible.
+    pub violation: Option<ViolationCode>,
+}
+
+fn evaluate_job_in_route(insertion_ctx: &InsertionContext, route_idx: usize, job: &Job) -> Option<ViolationCode> {
+    let original_route_ctx = insertion_ctx.solution.routes.get(route_idx)?;
+    let index = original_route_ctx.route().tour.index(job)?;
+
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    insertion_ctx.solution.routes.get_mut(route_idx)?.route_mut().tour.remove(job);
+    insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+
+    let solution_ctx = &insertion_ctx.solution;
+    let route_ctx = solution_ctx.routes.get(route_idx)?;
+    let goal = &insertion_ctx.problem.goal;
+
+    if let Some(violation) = goal.evaluate(&MoveContext::route(solution_ctx, route_ctx, job)) {
+        return Some(violation.code);
+    }
+
+    // NOTE activity level check is done only for single jobs as multi job activities depend on each other
+    match job {
+        Job::Single(_) => {
+            let activity_ctx = ActivityContext {
+                index: index - 1,
+                prev: route_ctx.route().tour.get(index - 1)?,
+                target: original_route_ctx.route().tour.get(index)?,
+                next: route_ctx.route().tour.get(index),
+            };
+
+            goal.evaluate(&MoveContext::activity(route_ctx, &activity_ctx)).map(|violation| violation.code)
+        }
+        Job::Multi(_) => None,
+    }
+}
@@ -12,6 +12,7 @@ use std::ops::{Add, Mul, Sub};
 
 const CAPACITY_DIMENSION_KEY: &str = "cpc";
 const DEMAND_DIMENSION_KEY: &str = "dmd";
+const SAFETY_STOCK_DIMENSION_KEY: &str = "sfs";
 const LOAD_DIMENSION_SIZE: usize = 8;
 
 /// Represents a load type used to represent customer's demand or vehicle's load.
@@ -25,7 +26,8 @@ pub trait Load: Add + Sub + PartialOrd + Copy + Default + Debug + Send + Sync {
     /// Returns true if `other` can be loaded into existing capacity.
     fn can_fit(&self, other: &Self) -> bool;
 
-    /// Returns ratio.
+    /// Returns ratio. An empty load has zero ratio to zero capacity, e.g. the one of a vehicle
+    /// which serves only jobs without demand.
     fn ratio(&self, other: &Self) -> f64;
 }
 
@@ -60,11 +62,30 @@ pub trait DemandDimension<T: LoadOps> {
     fn get_demand(&self) -> Option<&Demand<T>>;
 }
 
+/// A trait to get or set safety stock: an extra amount added to each non-empty part of job demand,
+/// so vehicle capacity is checked against buffered demand.
+pub trait SafetyStockDimension<T: LoadOps> {
+    /// Sets safety stock.
+    fn set_safety_stock(&mut self, stock: T) -> &mut Self;
+    /// Gets safety stock.
+    fn get_safety_stock(&self) -> Option<&T>;
+}
+
 impl<T: LoadOps> Demand<T> {
     /// Returns capacity change as difference between pickup and delivery.
     pub fn change(&self) -> T {
         self.pickup.0 + self.pickup.1 - self.delivery.0 - self.delivery.1
     }
+
+    /// Returns demand with safety stock added to each of its non-empty parts.
+    pub fn with_safety_stock(&self, stock: T) -> Self {
+        let buffer = |value: T| if value.is_not_empty() { value + stock } else { value };
+
+        Self {
+            pickup: (buffer(self.pickup.0), buffer(self.pickup.1)),
+            delivery: (buffer(self.delivery.0), buffer(self.delivery.1)),
+        }
+    }
 }
 
 impl<T: LoadOps> Default for Demand<T> {
@@ -112,6 +133,56 @@ impl<T: LoadOps> DemandDimension<T> for Dimensions {
     }
 }
 
+impl<T: LoadOps> SafetyStockDimension<T> for Dimensions {
+    fn set_safety_stock(&mut self, stock: T) -> &mut Self {
+        self.set_value(SAFETY_STOCK_DIMENSION_KEY, stock);
+        self
+    }
+
+    fn get_safety_stock(&self) -> Option<&T> {
+        self.get_value(SAFETY_STOCK_DIMENSION_KEY)
+    }
+}
+
+/// A trait to get or set ride-pooling occupancy using single dimensional load: a vehicle capacity
+/// is its amount of seats and passengers occupy seats from their pickup till their drop-off. It
+/// relies on dynamic demand, so pickup and drop-off jobs should be parts of the same multi job.
+pub trait SeatDimension {
+    /// Sets amount of seats as vehicle capacity.
+    fn set_seats(&mut self, seats: i32) -> &mut Self;
+    /// Gets amount of seats.
+    fn get_seats(&self) -> Option<i32>;
+    /// Sets amount of passengers which take seats at the job.
+    fn set_pickup_passengers(&mut self, passengers: i32) -> &mut Self;
+    /// Sets amount of passengers which free seats at the job.
+    fn set_dropoff_passengers(&mut self, passengers: i32) -> &mut Self;
+}
+
+impl SeatDimension for Dimensions {
+    fn set_seats(&mut self, seats: i32) -> &mut Self {
+        self.set_capacity(SingleDimLoad::new(seats))
+    }
+
+    fn get_seats(&self) -> Option<i32> {
+        let capacity: Option<&SingleDimLoad> = self.get_capacity();
+        capacity.map(|capacity| capacity.value)
+    }
+
+    fn set_pickup_passengers(&mut self, passengers: i32) -> &mut Self {
+        self.set_demand(Demand {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::new(passengers)),
+            delivery: (SingleDimLoad::default(), SingleDimLoad::default()),
+        })
+    }
+
+    fn set_dropoff_passengers(&mut self, passengers: i32) -> &mut Self {
+        self.set_demand(Demand {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+            delivery: (SingleDimLoad::default(), SingleDimLoad::new(passengers)),
+        })
+    }
+}
+
 /// Specifies single dimensional load type.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct SingleDimLoad {
@@ -143,7 +214,11 @@ impl Load for SingleDimLoad {
     }
 
     fn ratio(&self, other: &Self) -> f64 {
-        self.value as f64 / other.value as f64
+        if self.value == 0 && other.value == 0 {
+            0.
+        } else {
+            self.value as f64 / other.value as f64
+        }
     }
 }
 
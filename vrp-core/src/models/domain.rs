@@ -58,6 +58,44 @@ pub struct Solution {
 
     /// List of unassigned jobs within reason code.
     pub unassigned: Vec<(Job, UnassignmentInfo)>,
+
+    /// A random seed used to discover the solution, if known.
+    pub seed: Option<u64>,
+}
+
+impl Solution {
+    /// Returns amount of unassigned jobs grouped by violation code sorted by code. When a job has
+    /// multiple codes, the most frequent one is used. Jobs without known code are reported with `None`.
+    pub fn get_violation_summary(&self) -> Vec<(Option<ViolationCode>, usize)> {
+        let mut summary = self
+            .unassigned
+            .iter()
+            .map(|(_, info)| match info {
+                UnassignmentInfo::Unknown => None,
+                UnassignmentInfo::Simple(code) => Some(*code),
+                UnassignmentInfo::Detailed(codes) => {
+                    let counts = codes.iter().fold(HashMap::<ViolationCode, usize>::new(), |mut acc, (_, code)| {
+                        *acc.entry(*code).or_insert(0) += 1;
+                        acc
+                    });
+
+                    counts
+                        .into_iter()
+                        .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+                        .map(|(code, _)| code)
+                }
+            })
+            .fold(HashMap::<Option<ViolationCode>, usize>::new(), |mut acc, code| {
+                *acc.entry(code).or_insert(0) += 1;
+                acc
+            })
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        summary.sort_by_key(|(code, _)| *code);
+
+        summary
+    }
 }
 
 /// An enumeration which specifies how jobs should be ordered in tour.
@@ -281,6 +281,12 @@ pub trait FeatureConstraint {
     /// Returns a new job, if it is possible to merge them together having theoretically assignable
     /// job. Otherwise returns violation error code.
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode>;
+
+    /// Returns true if route level evaluation depends on other routes of the solution. Otherwise,
+    /// route level violations are kept in the route's feasibility cache till the route is changed.
+    fn depends_on_other_routes(&self) -> bool {
+        false
+    }
 }
 
 /// Defines feature objective behavior.
@@ -345,7 +351,13 @@ impl Shuffled for GoalContext {
 impl GoalContext {
     /// Accepts job insertion.
     pub fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
-        accept_insertion_with_states(&self.states, solution_ctx, route_index, job)
+        accept_insertion_with_states(&self.states, solution_ctx, route_index, job);
+
+        // NOTE changed route invalidates its own cache, others are affected only by dependent constraints
+        if self.constraints.iter().any(|constraint| constraint.depends_on_other_routes()) {
+            solution_ctx.routes.iter_mut().for_each(|route_ctx| route_ctx.clear_feasibility_cache());
+            solution_ctx.registry.clear_feasibility_cache();
+        }
     }
 
     /// Accepts route state.
@@ -358,6 +370,57 @@ impl GoalContext {
         accept_solution_state_with_states(&self.states, solution_ctx);
     }
 
+    /// Calculates fitness values as `fitness` does, but only for routes with given indices, e.g. for
+    /// routes changed by incremental re-optimization. Solution level data, such as unassigned jobs,
+    /// is taken as is, while solution state is recalculated for given routes only. When all routes
+    /// are specified, the result is the same as full fitness. Invalid route indices are ignored.
+    pub fn partial_fitness(&self, insertion_ctx: &InsertionContext, route_indices: &[usize]) -> Vec<f64> {
+        self.get_routes_fitness(insertion_ctx, route_indices, true)
+    }
+
+    /// Calculates contribution of each route to each objective: the outer vector is for routes, the
+    /// inner one is for objectives in the same order as `fitness` returns them. Each route is evaluated
+    /// as the only route of the solution without unassigned jobs, so route contributions sum up to the
+    /// total fitness for additive objectives, such as transport costs or amount of tours, but not for
+    /// solution level ones, such as amount of unassigned jobs.
+    pub fn route_contributions(&self, insertion_ctx: &InsertionContext) -> Vec<Vec<f64>> {
+        (0..insertion_ctx.solution.routes.len())
+            .map(|route_idx| self.get_routes_fitness(insertion_ctx, &[route_idx], false))
+            .collect()
+    }
+
+    fn get_routes_fitness(
+        &self,
+        insertion_ctx: &InsertionContext,
+        route_indices: &[usize],
+        keep_jobs: bool,
+    ) -> Vec<f64> {
+        let solution = &insertion_ctx.solution;
+        let keep = |jobs: &Vec<Job>| if keep_jobs { jobs.clone() } else { Vec::default() };
+
+        let mut partial_ctx = InsertionContext {
+            problem: insertion_ctx.problem.clone(),
+            solution: SolutionContext {
+                required: keep(&solution.required),
+                ignored: keep(&solution.ignored),
+                unassigned: if keep_jobs { solution.unassigned.clone() } else { Default::default() },
+                locked: solution.locked.clone(),
+                routes: route_indices
+                    .iter()
+                    .filter_map(|&route_idx| solution.routes.get(route_idx))
+                    .map(|route_ctx| route_ctx.deep_copy())
+                    .collect(),
+                registry: solution.registry.deep_copy(),
+                state: Default::default(),
+            },
+            environment: insertion_ctx.environment.clone(),
+        };
+
+        self.accept_solution_state(&mut partial_ctx.solution);
+
+        self.flatten_objectives.iter().map(|objective| objective.fitness(&partial_ctx)).collect()
+    }
+
     /// Tries to merge two jobs taking into account common constraints.
     /// Returns a new job, if it is possible to merge them together having theoretically assignable
     /// job. Otherwise returns violation error code.
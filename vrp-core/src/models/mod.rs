@@ -8,6 +8,7 @@ pub use self::domain::*;
 mod goal;
 pub use self::goal::*;
 
+pub mod analysis;
 pub mod common;
 #[doc(hidden)]
 pub mod examples;
@@ -50,7 +50,191 @@ impl ActivityCost for SimpleActivityCost {
     }
 
     fn estimate_arrival(&self, _: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        activity.place.time.end.min(departure - activity.place.duration)
+        activity.latest_start().min(departure - activity.place.duration)
+    }
+}
+
+/// Provides way to enforce a minimum time gap between departure from a job activity and arrival at
+/// the next activity on top of travel time, e.g. for paperwork after service. The gap delays all
+/// downstream activities, so it is taken into account by time window checks.
+pub struct MinGapActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+    gap: Duration,
+}
+
+impl MinGapActivityCost {
+    /// Creates a new instance of `MinGapActivityCost` which adds given gap to activity costs.
+    pub fn new(inner: Arc<dyn ActivityCost + Send + Sync>, gap: Duration) -> Result<Self, String> {
+        if gap < 0. {
+            return Err("minimum gap should not be negative".to_string());
+        }
+
+        Ok(Self { inner, gap })
+    }
+
+    fn get_gap(&self, activity: &Activity) -> Duration {
+        if activity.job.is_some() {
+            self.gap
+        } else {
+            0.
+        }
+    }
+}
+
+impl ActivityCost for MinGapActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        self.inner.cost(route, activity, arrival)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        self.inner.estimate_departure(route, activity, arrival) + self.get_gap(activity)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        self.inner.estimate_arrival(route, activity, departure - self.get_gap(activity))
+    }
+}
+
+/// Provides way to vary service duration depending on time when service starts, e.g. loading is
+/// slower during rush hours. Each class is a time window with a duration multiplier: the first
+/// class which contains service start is used, otherwise the original duration is kept.
+pub struct TimeDependentServiceActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+    classes: Vec<(TimeWindow, f64)>,
+}
+
+impl TimeDependentServiceActivityCost {
+    /// Creates a new instance of `TimeDependentServiceActivityCost` with given time window classes.
+    pub fn new(inner: Arc<dyn ActivityCost + Send + Sync>, classes: Vec<(TimeWindow, f64)>) -> Result<Self, String> {
+        if classes.iter().any(|(_, multiplier)| *multiplier <= 0.) {
+            return Err("service duration multiplier should be positive".to_string());
+        }
+
+        Ok(Self { inner, classes })
+    }
+
+    fn get_extra_duration(&self, activity: &Activity, service_start: Timestamp) -> Duration {
+        self.classes
+            .iter()
+            .find(|(tw, _)| tw.contains(service_start))
+            .map_or(0., |(_, multiplier)| activity.place.duration * (multiplier - 1.))
+    }
+
+    fn get_latest_extra_duration(&self, activity: &Activity, departure: Timestamp) -> Duration {
+        // NOTE service start is unknown when moving backward, so take the worst case of all classes
+        //      which might contain it to keep latest arrival feasible
+        let max_multiplier = self.classes.iter().map(|(_, multiplier)| *multiplier).fold(1., f64::max);
+        let service = TimeWindow::new(departure - activity.place.duration * max_multiplier, departure);
+
+        self.classes
+            .iter()
+            .filter(|(tw, _)| tw.intersects(&service))
+            .map(|(_, multiplier)| activity.place.duration * (multiplier - 1.))
+            .fold(0., f64::max)
+    }
+}
+
+impl ActivityCost for TimeDependentServiceActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        let extra_duration = self.get_extra_duration(activity, arrival.max(activity.place.time.start));
+        let actor = route.actor.as_ref();
+
+        self.inner.cost(route, activity, arrival)
+            + extra_duration * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        let extra_duration = self.get_extra_duration(activity, arrival.max(activity.place.time.start));
+
+        self.inner.estimate_departure(route, activity, arrival) + extra_duration
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        self.inner.estimate_arrival(route, activity, departure - self.get_latest_extra_duration(activity, departure))
+    }
+}
+
+/// Provides way to reduce service duration of a dependent job when its prerequisite job is served
+/// before it on the same route, e.g. for bundled services where the first visit makes the second
+/// one faster. Each dependency is a tuple of prerequisite job id, dependent job id and a duration
+/// multiplier in (0, 1]: when several prerequisites are served, the biggest reduction is used.
+pub struct DependentServiceActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+    dependencies: HashMap<String, Vec<(String, f64)>>,
+}
+
+impl DependentServiceActivityCost {
+    /// Creates a new instance of `DependentServiceActivityCost` with given dependencies.
+    pub fn new(
+        inner: Arc<dyn ActivityCost + Send + Sync>,
+        dependencies: Vec<(String, String, f64)>,
+    ) -> Result<Self, String> {
+        if dependencies.iter().any(|(_, _, multiplier)| *multiplier <= 0. || *multiplier > 1.) {
+            return Err("dependent service duration multiplier should be in (0, 1] range".to_string());
+        }
+
+        if dependencies.iter().any(|(prerequisite, dependent, _)| prerequisite == dependent) {
+            return Err("job cannot be a prerequisite of its own service".to_string());
+        }
+
+        let dependencies = dependencies.into_iter().fold(
+            HashMap::<String, Vec<(String, f64)>>::new(),
+            |mut acc, (prerequisite, dependent, multiplier)| {
+                acc.entry(dependent).or_default().push((prerequisite, multiplier));
+                acc
+            },
+        );
+
+        Ok(Self { inner, dependencies })
+    }
+
+    fn get_saved_duration(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Duration {
+        let single = match activity.job.as_ref() {
+            Some(single) => single,
+            None => return 0.,
+        };
+
+        let dependencies = match single.dimens.get_id().and_then(|id| self.dependencies.get(id)) {
+            Some(dependencies) => dependencies,
+            None => return 0.,
+        };
+
+        // NOTE when activity is already in the tour, only activities before it are considered. Otherwise,
+        //      its insertion is evaluated and prerequisite's schedule tells whether it is served before
+        let is_served_before = |prerequisite: &String| {
+            route
+                .tour
+                .all_activities()
+                .take_while(|other| other.job.as_ref().map_or(true, |other| !Arc::ptr_eq(other, single)))
+                .filter(|other| compare_floats(other.schedule.departure, arrival) != Ordering::Greater)
+                .any(|other| other.job.as_ref().and_then(|other| other.dimens.get_id()) == Some(prerequisite))
+        };
+
+        dependencies
+            .iter()
+            .filter(|(prerequisite, _)| is_served_before(prerequisite))
+            .map(|(_, multiplier)| activity.place.duration * (1. - multiplier))
+            .fold(0., f64::max)
+    }
+}
+
+impl ActivityCost for DependentServiceActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        let saved_duration = self.get_saved_duration(route, activity, arrival);
+        let actor = route.actor.as_ref();
+
+        self.inner.cost(route, activity, arrival)
+            - saved_duration * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        self.inner.estimate_departure(route, activity, arrival) - self.get_saved_duration(route, activity, arrival)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        // NOTE keep the original duration as the worst case: the reduction cannot be guaranteed when
+        //      moving backward as prerequisite might be served after the activity
+        self.inner.estimate_arrival(route, activity, departure)
     }
 }
 
@@ -94,7 +278,7 @@ impl ActivityCost for DynamicActivityCost {
             };
 
             // NOTE: do not allow to start or restart work after break finished
-            if activity_start + extra_duration > activity.place.time.end {
+            if activity_start + extra_duration > activity.latest_start() {
                 // TODO this branch is the reason why departure rescheduling is disabled.
                 //      theoretically, rescheduling should be aware somehow about dynamic costs
                 f64::MAX
@@ -105,7 +289,7 @@ impl ActivityCost for DynamicActivityCost {
     }
 
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        let arrival = activity.place.time.end.min(departure - activity.place.duration);
+        let arrival = activity.latest_start().min(departure - activity.place.duration);
         let schedule = TimeWindow::new(arrival, departure);
 
         (self.reserved_times_fn)(route, &schedule).map_or(arrival, |reserved_time: TimeWindow| {
@@ -183,7 +367,9 @@ impl TransportCost for DynamicTransportCost {
     }
 }
 
-/// Contains matrix routing data for specific profile and, optionally, time.
+/// Contains matrix routing data for specific profile and, optionally, time. Durations and distances
+/// are independent: time constraints use durations only, while distances can hold any cost related
+/// metric (e.g. toll roads which are more expensive, but faster).
 pub struct MatrixData {
     /// A routing profile index.
     pub index: usize,
@@ -229,6 +415,218 @@ pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn Tr
     })
 }
 
+/// Specifies how zero or negative travel times between distinct locations are handled. Such
+/// values usually indicate a data error and can make the schedule stall or move backward in time.
+#[derive(Clone)]
+pub enum TravelTimePolicy {
+    /// Fails to create routing costs.
+    Reject,
+    /// Replaces invalid travel times with given minimum positive value.
+    Clamp(Duration),
+    /// Keeps invalid travel times, but reports them using given logger.
+    Warn(InfoLogger),
+}
+
+/// Creates routing costs as `create_matrix_transport_cost`, but checks travel times between
+/// distinct locations first and handles zero or negative ones according to given policy.
+pub fn create_matrix_transport_cost_with_policy(
+    mut costs: Vec<MatrixData>,
+    policy: TravelTimePolicy,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+    if let TravelTimePolicy::Clamp(min_duration) = &policy {
+        if *min_duration <= 0. {
+            return Err("minimum travel time should be positive".to_string());
+        }
+    }
+
+    for matrix in costs.iter_mut() {
+        let size = (matrix.durations.len() as f64).sqrt().round() as usize;
+        let invalid = matrix
+            .durations
+            .iter()
+            .enumerate()
+            .filter(|(idx, duration)| idx / size != idx % size && **duration <= 0.)
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        let first = match invalid.first() {
+            Some(first) => *first,
+            None => continue,
+        };
+
+        let message = format!(
+            "matrix with profile index {} has {} zero or negative travel time(s), first is from {} to {}: {}",
+            matrix.index,
+            invalid.len(),
+            first / size,
+            first % size,
+            matrix.durations[first]
+        );
+
+        match &policy {
+            TravelTimePolicy::Reject => return Err(message),
+            TravelTimePolicy::Clamp(min_duration) => {
+                invalid.iter().for_each(|&idx| matrix.durations[idx] = *min_duration)
+            }
+            TravelTimePolicy::Warn(logger) => (logger)(message.as_str()),
+        }
+    }
+
+    create_matrix_transport_cost(costs)
+}
+
+/// Specifies which checks are applied by `validate_matrix`.
+#[derive(Clone, Debug, Default)]
+pub struct MatrixValidationOptions {
+    /// Checks that a direct travel between two locations is never longer than a travel through
+    /// any other location. Has cubic complexity, so it is disabled by default.
+    pub check_triangle_inequality: bool,
+}
+
+/// Describes an issue found in a flattened square matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MatrixIssue {
+    /// Matrix length is not a square of some number.
+    InvalidSize(usize),
+    /// An entry has negative value.
+    NegativeValue {
+        /// A row index.
+        from: usize,
+        /// A column index.
+        to: usize,
+        /// A value of the entry.
+        value: f64,
+    },
+    /// A diagonal entry is not zero.
+    NonZeroDiagonal {
+        /// An index of the location.
+        index: usize,
+        /// A value of the entry.
+        value: f64,
+    },
+    /// A direct travel from one location to another is longer than a travel via third location.
+    TriangleInequality {
+        /// A row index.
+        from: usize,
+        /// An intermediate location index.
+        via: usize,
+        /// A column index.
+        to: usize,
+    },
+}
+
+/// Validates a flattened square matrix (e.g. durations or distances of `MatrixData`): entries
+/// should be non-negative, diagonal should be zero and, optionally, triangle inequality should hold.
+/// Returns all found issues.
+pub fn validate_matrix(matrix: &[f64], options: &MatrixValidationOptions) -> Result<(), Vec<MatrixIssue>> {
+    let size = (matrix.len() as f64).sqrt().round() as usize;
+    if size * size != matrix.len() {
+        return Err(vec![MatrixIssue::InvalidSize(matrix.len())]);
+    }
+
+    let value = |from: usize, to: usize| matrix[from * size + to];
+
+    let mut issues = (0..size)
+        .flat_map(|from| (0..size).map(move |to| (from, to)))
+        .filter_map(|(from, to)| {
+            let value = value(from, to);
+            if value < 0. {
+                Some(MatrixIssue::NegativeValue { from, to, value })
+            } else if from == to && value != 0. {
+                Some(MatrixIssue::NonZeroDiagonal { index: from, value })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if options.check_triangle_inequality {
+        for from in 0..size {
+            for to in (0..size).filter(|&to| to != from) {
+                let direct = value(from, to);
+                issues.extend(
+                    (0..size)
+                        .filter(|&via| via != from && via != to)
+                        .filter(|&via| compare_floats(direct, value(from, via) + value(via, to)) == Ordering::Greater)
+                        .map(|via| MatrixIssue::TriangleInequality { from, via, to }),
+                );
+            }
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+/// Normalizes a flattened square matrix by setting its diagonal entries to zero.
+pub fn normalize_matrix_diagonal(matrix: &mut [f64]) {
+    let len = matrix.len();
+    let size = (len as f64).sqrt().round() as usize;
+
+    (0..size).map(|index| index * size + index).filter(|&idx| idx < len).for_each(|idx| matrix[idx] = 0.);
+}
+
+/// Keeps precomputed lists of nearest neighbors for each location, so search operators can restrict
+/// their move candidates to them instead of evaluating all locations.
+pub struct NeighborLists {
+    neighbors: Vec<Vec<(Location, Distance)>>,
+}
+
+impl NeighborLists {
+    /// Creates neighbor lists from a flattened square matrix (e.g. distances of `MatrixData`) keeping
+    /// at most `k` nearest neighbors of each location sorted by distance. Location itself is excluded.
+    pub fn new(matrix: &[Distance], k: usize) -> Result<Self, String> {
+        let size = (matrix.len() as f64).sqrt().round() as usize;
+        if size * size != matrix.len() {
+            return Err(format!("matrix length is not a square: {}", matrix.len()));
+        }
+
+        Ok(Self::new_with_distance_fn(size, k, |from, to| matrix[from * size + to]))
+    }
+
+    /// Creates neighbor lists for locations in `0..size` range using approximated distances of given
+    /// profile, keeping at most `k` nearest neighbors of each location.
+    pub fn new_from_transport(
+        transport: &(dyn TransportCost + Send + Sync),
+        profile: &Profile,
+        size: usize,
+        k: usize,
+    ) -> Self {
+        Self::new_with_distance_fn(size, k, |from, to| transport.distance_approx(profile, from, to))
+    }
+
+    fn new_with_distance_fn(size: usize, k: usize, distance_fn: impl Fn(Location, Location) -> Distance) -> Self {
+        let neighbors = (0..size)
+            .map(|from| {
+                let mut neighbors =
+                    (0..size).filter(|&to| to != from).map(|to| (to, distance_fn(from, to))).collect::<Vec<_>>();
+
+                neighbors.sort_by(|(a_loc, a_dist), (b_loc, b_dist)| {
+                    compare_floats(*a_dist, *b_dist).then_with(|| a_loc.cmp(b_loc))
+                });
+                neighbors.truncate(k);
+
+                neighbors
+            })
+            .collect();
+
+        Self { neighbors }
+    }
+
+    /// Returns nearest neighbors of given location with their distances sorted by distance.
+    pub fn neighbors(&self, location: Location) -> &[(Location, Distance)] {
+        self.neighbors.get(location).map(|neighbors| neighbors.as_slice()).unwrap_or(&[])
+    }
+
+    /// Checks whether `other` location is among nearest neighbors of given location.
+    pub fn is_neighbor(&self, location: Location, other: Location) -> bool {
+        self.neighbors(location).iter().any(|(neighbor, _)| *neighbor == other)
+    }
+}
+
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost {
     durations: Vec<Vec<Duration>>,
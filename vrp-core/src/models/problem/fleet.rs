@@ -80,6 +80,50 @@ pub struct Vehicle {
     pub details: Vec<VehicleDetail>,
 }
 
+const END_DWELL_DIMENSION_KEY: &str = "end_dwell";
+
+/// A trait to get or set a vehicle's dwell time at its end place, e.g. to unload after return.
+/// The dwell time is a part of the route: it has to be finished before the end place's latest time.
+pub trait EndDwellDimension {
+    /// Sets end dwell time.
+    fn set_end_dwell(&mut self, dwell: Duration) -> &mut Self;
+    /// Gets end dwell time.
+    fn get_end_dwell(&self) -> Option<&Duration>;
+}
+
+impl EndDwellDimension for Dimensions {
+    fn set_end_dwell(&mut self, dwell: Duration) -> &mut Self {
+        self.set_value(END_DWELL_DIMENSION_KEY, dwell);
+        self
+    }
+
+    fn get_end_dwell(&self) -> Option<&Duration> {
+        self.get_value(END_DWELL_DIMENSION_KEY)
+    }
+}
+
+const FREE_RETURN_DIMENSION_KEY: &str = "free_return";
+
+/// A trait to get or set a vehicle's free return: the vehicle still returns to its end place, so
+/// the return leg is considered for time feasibility, but its distance is not charged (empty backhaul).
+pub trait FreeReturnDimension {
+    /// Sets free return flag.
+    fn set_free_return(&mut self, free_return: bool) -> &mut Self;
+    /// Gets free return flag.
+    fn get_free_return(&self) -> Option<&bool>;
+}
+
+impl FreeReturnDimension for Dimensions {
+    fn set_free_return(&mut self, free_return: bool) -> &mut Self {
+        self.set_value(FREE_RETURN_DIMENSION_KEY, free_return);
+        self
+    }
+
+    fn get_free_return(&self) -> Option<&bool> {
+        self.get_value(FREE_RETURN_DIMENSION_KEY)
+    }
+}
+
 /// Represents an actor detail.
 #[derive(Clone, Hash, Eq, PartialEq)]
 pub struct ActorDetail {
@@ -94,12 +94,44 @@ pub struct Place {
 
 /// Represents a job which should be performed once but actual place/time might vary.
 pub struct Single {
-    /// Specifies job details: where and when it can be performed.
+    /// Specifies job details: where and when it can be performed. Places are alternatives: only
+    /// one of them is visited and insertion evaluation picks the cheapest feasible one.
     pub places: Vec<Place>,
     /// Dimensions which contains extra work requirements.
     pub dimens: Dimensions,
 }
 
+const WINDOW_HARDNESS_DIMENSION_KEY: &str = "window_hardness";
+
+/// Specifies how strictly job's time windows have to be respected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WindowHardness {
+    /// A job cannot be started after its time window end. This is a default behavior.
+    Hard,
+    /// A job can be started after its time window end, but not before its start. Lateness is
+    /// expected to be penalized by an objective, e.g. weighted tardiness.
+    Soft,
+}
+
+/// A trait to get or set job's time window hardness.
+pub trait WindowHardnessDimension {
+    /// Sets time window hardness.
+    fn set_window_hardness(&mut self, hardness: WindowHardness) -> &mut Self;
+    /// Gets time window hardness.
+    fn get_window_hardness(&self) -> Option<&WindowHardness>;
+}
+
+impl WindowHardnessDimension for Dimensions {
+    fn set_window_hardness(&mut self, hardness: WindowHardness) -> &mut Self {
+        self.set_value(WINDOW_HARDNESS_DIMENSION_KEY, hardness);
+        self
+    }
+
+    fn get_window_hardness(&self) -> Option<&WindowHardness> {
+        self.get_value(WINDOW_HARDNESS_DIMENSION_KEY)
+    }
+}
+
 /// Represents a job which consists of multiple sub jobs.
 /// All of these jobs must be performed or none of them. Order can be controlled
 /// via specific dimension value.
@@ -274,6 +306,77 @@ impl Hash for Job {
     }
 }
 
+/// Specifies periods when a location is closed, e.g. a midday break of a shop, keyed by location.
+pub type ClosedPeriods = HashMap<Location, Vec<TimeWindow>>;
+
+/// Returns a copy of the single job with time windows of places at closed locations split around
+/// closed periods, so the time constraint never schedules the service within a closed period: it is
+/// either finished before the period starts or started once the period ends. A place without time
+/// windows is considered as available at any time, time offsets are kept as is. Places which cannot
+/// be served at all are removed and an error is returned when no place is left.
+pub fn exclude_closed_periods(single: &Single, closed_periods: &ClosedPeriods) -> Result<Single, String> {
+    let places = single
+        .places
+        .iter()
+        .filter_map(|place| {
+            let periods = match place.location.and_then(|location| closed_periods.get(&location)) {
+                Some(periods) if !periods.is_empty() => periods,
+                _ => return Some(place.clone()),
+            };
+
+            let windows =
+                if place.times.is_empty() { vec![TimeSpan::Window(TimeWindow::max())] } else { place.times.clone() };
+            let times = windows
+                .into_iter()
+                .flat_map(|time| match time {
+                    TimeSpan::Window(tw) => split_time_window(&tw, place.duration, periods.as_slice())
+                        .into_iter()
+                        .map(TimeSpan::Window)
+                        .collect(),
+                    TimeSpan::Offset(_) => vec![time],
+                })
+                .collect::<Vec<_>>();
+
+            if times.is_empty() {
+                None
+            } else {
+                Some(Place { location: place.location, duration: place.duration, times })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if places.is_empty() {
+        return Err("job cannot be served due to closed periods of its locations".to_string());
+    }
+
+    Ok(Single { places, dimens: single.dimens.clone() })
+}
+
+/// Splits time window of service start, so the service of given duration does not overlap closed periods.
+fn split_time_window(tw: &TimeWindow, duration: Duration, periods: &[TimeWindow]) -> Vec<TimeWindow> {
+    // NOTE a service start is not allowed within (period start - duration, period end) interval
+    let mut forbidden = periods.iter().map(|period| (period.start - duration, period.end)).collect::<Vec<_>>();
+    forbidden.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let (mut windows, start) = forbidden
+        .into_iter()
+        .filter(|&(_, end)| end > tw.start)
+        .take_while(|&(start, _)| start < tw.end)
+        .fold((Vec::default(), tw.start), |(mut windows, start), (forbidden_start, forbidden_end)| {
+            if forbidden_start > start {
+                windows.push(TimeWindow::new(start, forbidden_start));
+            }
+
+            (windows, start.max(forbidden_end))
+        });
+
+    if start <= tw.end {
+        windows.push(TimeWindow::new(start, tw.end));
+    }
+
+    windows
+}
+
 /// Returns job locations.
 pub fn get_job_locations<'a>(job: &'a Job) -> Box<dyn Iterator<Item = Option<Location>> + 'a> {
     match job {
@@ -0,0 +1,135 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/assignment_test.rs"]
+mod assignment_test;
+
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::models::common::*;
+use crate::models::problem::{Actor, Job, Single};
+use crate::models::solution::{Activity, Place, Registry, Route, Tour};
+use crate::models::{Problem, Solution};
+use hashbrown::HashMap;
+use rosomaxa::prelude::Environment;
+use std::io::BufRead;
+use std::sync::Arc;
+
+/// Reads a fixed assignment of jobs to vehicles from csv lines in `job_id,vehicle_id,sequence` format
+/// (a header line is optional) and constructs a solution, e.g. to warm start the solver from a plan
+/// of an external system. Jobs of each vehicle are ordered by sequence, sub jobs of a multi job are
+/// resolved by order of job id occurrences. Jobs which are not listed are reported as unassigned.
+/// NOTE: schedules are not calculated and solution feasibility is not checked.
+pub fn read_assignment_csv<R: BufRead>(problem: Arc<Problem>, reader: R) -> Result<Solution, String> {
+    let jobs = problem
+        .jobs
+        .all()
+        .filter_map(|job| job.dimens().get_id().cloned().map(|id| (id, job)))
+        .collect::<HashMap<_, _>>();
+
+    let mut assignments = Vec::<(String, String, usize)>::default();
+    for line in reader.lines() {
+        let line = line.map_err(|err| format!("cannot read assignment: {err}"))?;
+        let line = line.trim();
+        if line.is_empty() || line == "job_id,vehicle_id,sequence" {
+            continue;
+        }
+
+        let parse_error = || format!("invalid assignment line: '{line}'");
+        let (job_id, entry) = line.split_once(',').ok_or_else(parse_error)?;
+        let (vehicle_id, sequence) = entry.split_once(',').ok_or_else(parse_error)?;
+        let sequence = sequence.trim().parse::<usize>().map_err(|_| parse_error())?;
+
+        assignments.push((job_id.trim().to_string(), vehicle_id.trim().to_string(), sequence));
+    }
+
+    // NOTE keep vehicles in order of their first appearance
+    let mut routes = Vec::<(String, Vec<(usize, String)>)>::default();
+    assignments.into_iter().for_each(|(job_id, vehicle_id, sequence)| {
+        match routes.iter_mut().find(|(id, _)| *id == vehicle_id) {
+            Some((_, route)) => route.push((sequence, job_id)),
+            None => routes.push((vehicle_id, vec![(sequence, job_id)])),
+        }
+    });
+
+    let mut solution = Solution {
+        registry: Registry::new(&problem.fleet, Environment::default().random),
+        routes: vec![],
+        unassigned: vec![],
+        seed: None,
+    };
+    let mut occurrences = HashMap::<String, usize>::new();
+
+    for (vehicle_id, mut route) in routes {
+        route.sort_by_key(|(sequence, _)| *sequence);
+        if route.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(format!("duplicate sequence in assignment of vehicle '{vehicle_id}'"));
+        }
+
+        let actor = get_available_actor(&mut solution.registry, vehicle_id.as_str())?;
+        let mut tour = Tour::new(&actor);
+
+        route.into_iter().try_for_each(|(_, job_id)| {
+            let job = jobs.get(&job_id).ok_or_else(|| format!("unknown job id: '{job_id}'"))?;
+            let occurrence = occurrences.entry(job_id.clone()).or_insert(0);
+
+            let single = match job {
+                Job::Single(single) if *occurrence == 0 => Some(single.clone()),
+                Job::Single(_) => None,
+                Job::Multi(multi) => multi.jobs.get(*occurrence).cloned(),
+            }
+            .ok_or_else(|| format!("job '{job_id}' is assigned too many times"))?;
+            *occurrence += 1;
+
+            tour.insert_last(create_activity(single)?);
+
+            Ok::<_, String>(())
+        })?;
+
+        solution.routes.push(Route { actor, tour });
+    }
+
+    problem.jobs.all().try_for_each(|job| {
+        let id = job.dimens().get_id().cloned().unwrap_or_default();
+        let used = occurrences.get(&id).copied().unwrap_or(0);
+
+        let is_partial = match &job {
+            Job::Single(_) => false,
+            Job::Multi(multi) => used > 0 && used < multi.jobs.len(),
+        };
+
+        if is_partial {
+            return Err(format!("job '{id}' is not fully assigned"));
+        }
+
+        if used == 0 {
+            solution.unassigned.push((job, UnassignmentInfo::Unknown));
+        }
+
+        Ok(())
+    })?;
+
+    Ok(solution)
+}
+
+fn get_available_actor(registry: &mut Registry, vehicle_id: &str) -> Result<Arc<Actor>, String> {
+    let candidates = registry
+        .all()
+        .filter(|actor| actor.vehicle.dimens.get_id().map_or(false, |id| id == vehicle_id))
+        .collect::<Vec<_>>();
+
+    candidates
+        .into_iter()
+        .find(|actor| registry.use_actor(actor))
+        .ok_or_else(|| format!("unknown vehicle id: '{vehicle_id}'"))
+}
+
+fn create_activity(single: Arc<Single>) -> Result<Activity, String> {
+    let place = single.places.first().ok_or_else(|| "job without place".to_string())?;
+    let location = place.location.ok_or_else(|| "job without location".to_string())?;
+    let time = place.times.first().and_then(|span| span.as_time_window()).unwrap_or_else(TimeWindow::max);
+
+    Ok(Activity {
+        place: Place { location, duration: place.duration, time },
+        schedule: Schedule::new(0., 0.),
+        job: Some(single),
+        commute: None,
+    })
+}
@@ -1,5 +1,8 @@
 //! Solution domain models.
 
+mod assignment;
+pub use self::assignment::read_assignment_csv;
+
 mod route;
 pub use self::route::{Activity, Commute, CommuteInfo, Place, Route};
 
@@ -8,3 +11,9 @@ pub use self::registry::Registry;
 
 mod tour;
 pub use self::tour::{Leg, Tour};
+
+mod statistics;
+pub use self::statistics::{route_statistics, RouteStats};
+
+mod timeline;
+pub use self::timeline::{validate_timeline, TimelineError};
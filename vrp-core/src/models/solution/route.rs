@@ -1,5 +1,5 @@
-use crate::models::common::{Distance, Duration, Location, Schedule, TimeWindow};
-use crate::models::problem::{Actor, Job, Multi, Single};
+use crate::models::common::{Distance, Duration, Location, Schedule, TimeWindow, Timestamp};
+use crate::models::problem::{Actor, Job, Multi, Single, WindowHardness, WindowHardnessDimension};
 use crate::models::solution::Tour;
 use crate::utils::{compare_shared, short_type_name};
 use rosomaxa::prelude::compare_floats;
@@ -106,6 +106,15 @@ impl Activity {
         }
     }
 
+    /// Returns the latest time when activity can be started: time window end for jobs with hard
+    /// time windows and unlimited time for jobs with soft ones.
+    pub fn latest_start(&self) -> Timestamp {
+        match self.job.as_ref().and_then(|single| single.dimens.get_window_hardness()) {
+            Some(WindowHardness::Soft) => f64::MAX,
+            _ => self.place.time.end,
+        }
+    }
+
     /// Checks whether activity has given job.
     pub fn has_same_job(&self, job: &Job) -> bool {
         match self.retrieve_job() {
@@ -0,0 +1,69 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/statistics_test.rs"]
+mod statistics_test;
+
+use crate::models::common::*;
+use crate::models::problem::TravelTime;
+use crate::models::solution::{Activity, Route};
+use crate::models::Problem;
+
+/// Contains statistics of a single route.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RouteStats<T: LoadOps> {
+    /// A total traveled distance.
+    pub distance: Distance,
+    /// A total duration: a time between departure from the first activity and arrival to the last one.
+    pub duration: Duration,
+    /// A total waiting time before activities' time window start.
+    pub waiting: Duration,
+    /// A maximum load carried by the vehicle.
+    pub max_load: T,
+    /// An amount of stops: consecutive job activities at the same location are counted as one stop.
+    pub stops: usize,
+}
+
+/// Calculates route statistics in one pass over route's activities using their schedules.
+/// Max load considers only single trip: a vehicle is expected to load all static deliveries at start.
+pub fn route_statistics<T: LoadOps>(problem: &Problem, route: &Route) -> RouteStats<T> {
+    let get_demand = |activity: &Activity| {
+        activity.job.as_ref().and_then(|single| single.dimens.get_demand()).cloned().unwrap_or_default()
+    };
+
+    let mut stats = RouteStats::<T>::default();
+    // NOTE static deliveries are loaded at start, so max load is their total plus max load change
+    let (mut deliveries, mut change, mut max_change) = (T::default(), T::default(), T::default());
+
+    route.tour.all_activities().fold(None, |prev: Option<&Activity>, activity| {
+        if let Some(prev) = prev {
+            stats.distance += problem.transport.distance(
+                route,
+                prev.place.location,
+                activity.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+
+            if activity.job.is_some() && (prev.job.is_none() || prev.place.location != activity.place.location) {
+                stats.stops += 1;
+            }
+        } else if activity.job.is_some() {
+            stats.stops += 1;
+        }
+
+        stats.waiting += (activity.place.time.start - activity.schedule.arrival).max(0.);
+
+        let demand = get_demand(activity);
+        deliveries = deliveries + demand.delivery.0;
+        change = change + demand.change();
+        max_change = max_change.max_load(change);
+
+        Some(activity)
+    });
+
+    stats.max_load = deliveries + max_change;
+    stats.duration = match (route.tour.start(), route.tour.end()) {
+        (Some(start), Some(end)) => end.schedule.arrival - start.schedule.departure,
+        _ => Duration::default(),
+    };
+
+    stats
+}
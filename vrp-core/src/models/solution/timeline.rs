@@ -0,0 +1,108 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/timeline_test.rs"]
+mod timeline_test;
+
+use crate::models::common::Timestamp;
+use crate::models::problem::TravelTime;
+use crate::models::solution::{Activity, Route};
+use crate::models::{Problem, Solution};
+
+/// A tolerance used to compare reported and expected timestamps.
+const TIME_TOLERANCE: f64 = 1E-6;
+
+/// Describes an inconsistency between reported schedule of an activity and the problem's travel
+/// durations and service times.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimelineError {
+    /// Activity is reached earlier than it is possible to travel from the previous activity.
+    EarlyArrival {
+        /// An index of the route in the solution.
+        route_idx: usize,
+        /// An index of the activity in the route's tour.
+        activity_idx: usize,
+        /// An earliest possible arrival.
+        expected: Timestamp,
+        /// A reported arrival.
+        actual: Timestamp,
+    },
+    /// Activity is left before its service is finished.
+    EarlyDeparture {
+        /// An index of the route in the solution.
+        route_idx: usize,
+        /// An index of the activity in the route's tour.
+        activity_idx: usize,
+        /// An earliest possible departure.
+        expected: Timestamp,
+        /// A reported departure.
+        actual: Timestamp,
+    },
+}
+
+/// Validates that reported arrival and departure times of all activities in the solution are
+/// consistent with the problem's transport and activity costs, e.g. for imported solutions. Waiting
+/// time is allowed, but arriving earlier than travel duration permits or leaving earlier than service
+/// time permits is reported as an error.
+pub fn validate_timeline(problem: &Problem, solution: &Solution) -> Result<(), Vec<TimelineError>> {
+    let errors = solution
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(route_idx, route)| {
+            let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+            activities
+                .windows(2)
+                .enumerate()
+                .flat_map(|(idx, pair)| validate_activity(problem, route, route_idx, idx + 1, pair[0], pair[1]))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn validate_activity(
+    problem: &Problem,
+    route: &Route,
+    route_idx: usize,
+    activity_idx: usize,
+    prev: &Activity,
+    activity: &Activity,
+) -> Vec<TimelineError> {
+    let mut errors = vec![];
+
+    let departure = prev.schedule.departure;
+    let expected_arrival = departure
+        + problem.transport.duration(
+            route,
+            prev.place.location,
+            activity.place.location,
+            TravelTime::Departure(departure),
+        );
+
+    if activity.schedule.arrival < expected_arrival - TIME_TOLERANCE {
+        errors.push(TimelineError::EarlyArrival {
+            route_idx,
+            activity_idx,
+            expected: expected_arrival,
+            actual: activity.schedule.arrival,
+        });
+    }
+
+    let expected_departure = problem.activity.estimate_departure(route, activity, activity.schedule.arrival);
+
+    if activity.schedule.departure < expected_departure - TIME_TOLERANCE {
+        errors.push(TimelineError::EarlyDeparture {
+            route_idx,
+            activity_idx,
+            expected: expected_departure,
+            actual: activity.schedule.departure,
+        });
+    }
+
+    errors
+}
@@ -2,8 +2,8 @@
 #[path = "../../../tests/unit/models/solution/tour_test.rs"]
 mod tour_test;
 
-use crate::models::common::{IdDimension, Schedule};
-use crate::models::problem::{Actor, Job};
+use crate::models::common::{IdDimension, Schedule, TimeWindow};
+use crate::models::problem::{Actor, EndDwellDimension, Job};
 use crate::models::solution::{Activity, Place};
 use crate::models::OP_START_MSG;
 use crate::utils::{short_type_name, Either};
@@ -245,10 +245,14 @@ fn create_start_activity(actor: &Actor) -> Activity {
 /// Creates end activity if it is specified for the actor.
 fn create_end_activity(actor: &Actor) -> Option<Activity> {
     actor.detail.end.as_ref().map(|place| {
+        // NOTE end dwell has to be finished before the latest time, so it limits the arrival time
+        let dwell = actor.vehicle.dimens.get_end_dwell().cloned().unwrap_or(0.);
         let time = place.time.to_time_window();
+        let time = TimeWindow::new(time.start, time.end - dwell);
+
         Activity {
             schedule: Schedule { arrival: time.start, departure: time.start },
-            place: Place { location: place.location, duration: 0.0, time },
+            place: Place { location: place.location, duration: dwell, time },
             job: None,
             commute: None,
         }
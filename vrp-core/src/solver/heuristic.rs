@@ -272,11 +272,12 @@ mod builder {
     /// Create default processing.
     pub fn create_default_processing() -> ProcessingConfig<RefinementContext, GoalContext, InsertionContext> {
         ProcessingConfig {
-            context: vec![Box::<VicinityClustering>::default()],
+            context: vec![Box::<ImpossibleJobsPruning>::default(), Box::<VicinityClustering>::default()],
             solution: vec![
                 Box::<AdvanceDeparture>::default(),
                 Box::<UnassignmentReason>::default(),
                 Box::<VicinityClustering>::default(),
+                Box::<ImpossibleJobsPruning>::default(),
             ],
         }
     }
@@ -431,27 +432,29 @@ mod dynamic {
         environment: Arc<Environment>,
     ) -> Vec<(TargetSearchOperator, String, f64)> {
         let (normal_limits, small_limits) = get_limits(problem.as_ref());
-        let random = environment.random.clone();
+        // NOTE each operator has its own random sub-stream, so adding a new operator does not change
+        // sequences of existing ones for the same seed
+        let random = |name: &str| -> Arc<dyn Random + Send + Sync> { environment.random.substream(name).into() };
 
         // NOTE: consider checking usage of names within heuristic filter before changing them
 
         let recreates: Vec<(Arc<dyn Recreate + Send + Sync>, String)> = vec![
-            (Arc::new(RecreateWithSkipBest::new(1, 2, random.clone())), "skip_best".to_string()),
-            (Arc::new(RecreateWithRegret::new(1, 3, random.clone())), "regret".to_string()),
-            (Arc::new(RecreateWithCheapest::new(random.clone())), "cheapest".to_string()),
-            (Arc::new(RecreateWithPerturbation::new_with_defaults(random.clone())), "perturbation".to_string()),
-            (Arc::new(RecreateWithGaps::new(2, 20, random.clone())), "gaps".to_string()),
-            (create_recreate_with_blinks(problem.as_ref(), random.clone()), "blinks".to_string()),
-            (Arc::new(RecreateWithFarthest::new(random.clone())), "farthest".to_string()),
-            (Arc::new(RecreateWithNearestNeighbor::new(random.clone())), "nearest".to_string()),
+            (Arc::new(RecreateWithSkipBest::new(1, 2, random("skip_best"))), "skip_best".to_string()),
+            (Arc::new(RecreateWithRegret::new(1, 3, random("regret"))), "regret".to_string()),
+            (Arc::new(RecreateWithCheapest::new(random("cheapest"))), "cheapest".to_string()),
+            (Arc::new(RecreateWithPerturbation::new_with_defaults(random("perturbation"))), "perturbation".to_string()),
+            (Arc::new(RecreateWithGaps::new(2, 20, random("gaps"))), "gaps".to_string()),
+            (create_recreate_with_blinks(problem.as_ref(), random("blinks")), "blinks".to_string()),
+            (Arc::new(RecreateWithFarthest::new(random("farthest"))), "farthest".to_string()),
+            (Arc::new(RecreateWithNearestNeighbor::new(random("nearest"))), "nearest".to_string()),
             (
                 Arc::new(RecreateWithSkipRandom::default_explorative_phased(
-                    Arc::new(RecreateWithCheapest::new(random.clone())),
-                    random.clone(),
+                    Arc::new(RecreateWithCheapest::new(random("skip_random_cheapest"))),
+                    random("skip_random"),
                 )),
                 "skip_random".to_string(),
             ),
-            (Arc::new(RecreateWithSlice::new(random.clone())), "slice".to_string()),
+            (Arc::new(RecreateWithSlice::new(random("slice"))), "slice".to_string()),
         ];
 
         let ruins: Vec<(Arc<dyn Ruin + Send + Sync>, String, f64)> = vec![
@@ -497,14 +500,25 @@ mod dynamic {
                 "local_exch_intra_route_random".to_string(),
                 1.,
             ),
+            (Arc::new(LocalSearch::new(Arc::new(ReverseSegment::default()))), "local_reverse_segment".to_string(), 1.),
             (
                 Arc::new(LocalSearch::new(Arc::new(RescheduleDeparture::default()))),
                 "local_reschedule_departure".to_string(),
                 1.,
             ),
+            (
+                Arc::new(LocalSearch::new(Arc::new(PerturbDeparture::default()))),
+                "local_perturb_departure".to_string(),
+                1.,
+            ),
+            (
+                Arc::new(LocalSearch::new(Arc::new(ReassignVehicles::default()))),
+                "local_reassign_vehicles".to_string(),
+                1.,
+            ),
             (
                 Arc::new(LocalSearch::new(Arc::new(ExchangeSwapStar::new(
-                    random.clone(),
+                    random("local_swap_star"),
                     SINGLE_HEURISTIC_QUOTA_LIMIT,
                 )))),
                 "local_swap_star".to_string(),
@@ -515,7 +529,7 @@ mod dynamic {
                     Arc::new(WeightedHeuristicOperator::new(
                         vec![
                             create_default_inner_ruin_recreate(problem.clone(), environment.clone()),
-                            create_default_local_search(environment.random.clone()),
+                            create_default_local_search(random("decompose_search")),
                         ],
                         vec![10, 1],
                     )),
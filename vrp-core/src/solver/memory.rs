@@ -0,0 +1,100 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/memory_test.rs"]
+mod memory_test;
+
+use super::*;
+use crate::models::problem::Job;
+use rosomaxa::get_default_selection_size;
+use rosomaxa::population::Elitism;
+
+/// An approximate amount of bytes used by a single tour activity within a solution, including
+/// its share of route and solution states.
+const ACTIVITY_SIZE_ESTIMATE: usize = 512;
+
+/// A minimum amount of individuals kept in memory by population: one best known and one selected.
+const MIN_INDIVIDUALS: usize = 2;
+
+/// Specifies a single memory target used to derive limits of the solver's memory consumers: population,
+/// an archive of initial solutions and amount of solutions selected for parallel refinement.
+#[derive(Clone, Debug)]
+pub struct MemoryBudget {
+    target_bytes: usize,
+}
+
+/// Specifies limits derived from `MemoryBudget`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemoryLimits {
+    /// An estimated size of a single solution in bytes.
+    pub solution_size: usize,
+    /// Max amount of individuals kept by population.
+    pub population_size: usize,
+    /// Max amount of initial solutions built before the search starts.
+    pub initial_size: usize,
+    /// Amount of individuals selected and refined in parallel at each generation.
+    pub selection_size: usize,
+}
+
+impl MemoryBudget {
+    /// Creates a new instance of `MemoryBudget` with given memory target in bytes.
+    pub fn new(target_bytes: usize) -> Self {
+        Self { target_bytes }
+    }
+
+    /// Creates a new instance of `MemoryBudget` with given memory target in megabytes.
+    pub fn new_with_megabytes(target_mb: usize) -> Self {
+        Self::new(target_mb * 1024 * 1024)
+    }
+
+    /// Derives limits for the given problem. The amount of solutions which fit the target is split
+    /// between selected individuals, initial solutions and population. If the target is too small,
+    /// the minimum limits are returned, so the solver still can make progress.
+    pub fn derive_limits(&self, problem: &Problem, environment: &Environment) -> MemoryLimits {
+        let solution_size = estimate_solution_size(problem);
+        let total = (self.target_bytes / solution_size).max(MIN_INDIVIDUALS + 2);
+
+        let selection_size = get_default_selection_size(environment).min(total / 4).max(1);
+        let initial_size = (total / 4).clamp(1, 4);
+        let population_size = (total - selection_size - initial_size).max(MIN_INDIVIDUALS);
+
+        MemoryLimits { solution_size, population_size, initial_size, selection_size }
+    }
+}
+
+/// Creates config builder with default settings, but with population, initial solutions and
+/// selection limited by memory budget. As rosomaxa population grows its network without limit,
+/// elitism population is used instead.
+pub fn create_memory_bounded_config_builder(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    telemetry_mode: TelemetryMode,
+    budget: &MemoryBudget,
+) -> ProblemConfigBuilder {
+    let limits = budget.derive_limits(problem.as_ref(), environment.as_ref());
+    let population: TargetPopulation = Box::new(Elitism::new(
+        problem.goal.clone(),
+        environment.random.clone(),
+        limits.population_size,
+        limits.selection_size,
+    ));
+
+    ProblemConfigBuilder::default()
+        .with_heuristic(get_default_heuristic(problem.clone(), environment.clone()))
+        .with_context(RefinementContext::new(problem.clone(), population, telemetry_mode, environment.clone()))
+        .with_initial(limits.initial_size, 0.05, create_default_init_operators(problem, environment))
+        .with_processing(create_default_processing())
+}
+
+/// Estimates a size of a solution in bytes assuming that all jobs are assigned and all actors are used.
+fn estimate_solution_size(problem: &Problem) -> usize {
+    let job_activities = problem
+        .jobs
+        .all()
+        .map(|job| match job {
+            Job::Single(_) => 1,
+            Job::Multi(multi) => multi.jobs.len(),
+        })
+        .sum::<usize>();
+    let depot_activities = problem.fleet.actors.len() * 2;
+
+    (job_activities + depot_activities).max(1) * ACTIVITY_SIZE_ESTIMATE
+}
@@ -36,7 +36,10 @@
 //! This library utilizes `NSGA-II` algorithm to apply Pareto-based ranking over population in order
 //! to find Pareto set approximation. However, that Pareto optimality of the solutions cannot be
 //! guaranteed: it is only known that none of the generated solutions dominates the others.
-//! In the end, the top ranked individual is returned as best known solution.
+//! In the end, the top ranked individual is returned as best known solution. Alternatively, all
+//! mutually non-dominated solutions can be returned as Pareto front approximation: to keep some
+//! objectives (e.g. cost and amount of unassigned jobs) separate, they have to be defined on the
+//! same level of goal's hierarchy.
 //!
 //! # Evolutionary algorithm
 //!
@@ -85,27 +88,35 @@
 //! latency introduced. Reinforcement technics are used here (Markov Decision Process).
 //!
 
+#[cfg(test)]
+#[path = "../../tests/unit/solver/solver_test.rs"]
+mod solver_test;
+
 extern crate rand;
 
 use crate::construction::heuristics::InsertionContext;
 use crate::models::common::Cost;
-use crate::models::{GoalContext, Problem, Solution};
+use crate::models::{GoalContext, Problem, Solution, ViolationCode};
 use crate::solver::search::Recreate;
 use hashbrown::HashMap;
 use rosomaxa::evolution::*;
 use rosomaxa::prelude::*;
 use rosomaxa::{get_default_population, TelemetryHeuristicContext};
 use std::any::Any;
-use std::sync::Arc;
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{Arc, Condvar, Mutex};
 
 pub use self::heuristic::*;
+pub use self::memory::*;
 use rosomaxa::population::Rosomaxa;
-use rosomaxa::utils::Timer;
+use rosomaxa::utils::{parallel_into_collect, Timer};
 
 pub mod processing;
 pub mod search;
 
 mod heuristic;
+mod memory;
 
 /// A key to store a filter for heuristic methods applied by dynamic hyper-heuristic.
 pub const HEURISTIC_FILTER_KEY: &str = "heuristic_filter";
@@ -191,6 +202,16 @@ impl HeuristicContext for RefinementContext {
     }
 
     fn on_generation(&mut self, offspring: Vec<Self::Solution>, termination_estimate: f64, generation_time: Timer) {
+        // NOTE keep generation at which offspring is produced, so features can depend on search progress
+        let generation = self.statistics().generation + 1;
+        let offspring = offspring
+            .into_iter()
+            .map(|mut insertion_ctx| {
+                insertion_ctx.solution.set_generation(generation);
+                insertion_ctx
+            })
+            .collect();
+
         self.inner_context.on_generation(offspring, termination_estimate, generation_time)
     }
 
@@ -239,6 +260,154 @@ impl InitialOperator for RecreateInitialOperator {
     }
 }
 
+/// Creates an evolution config which uses given environment, it is used to run extra solver starts.
+pub type ConfigFactory = Arc<
+    dyn Fn(Arc<Environment>) -> Result<EvolutionConfig<RefinementContext, GoalContext, InsertionContext>, String>
+        + Send
+        + Sync,
+>;
+
+/// Provides a way to get an estimated completion of the running solver and to pause or resume it,
+/// e.g. from another thread.
+#[derive(Clone, Default)]
+pub struct SolverProgress {
+    estimate: Arc<AtomicU64>,
+    start_estimates: Arc<Mutex<Vec<f64>>>,
+    is_estimable: Arc<AtomicBool>,
+    is_finished: Arc<AtomicBool>,
+    pause: Arc<(Mutex<bool>, Condvar)>,
+    incumbent: Arc<Mutex<Option<Incumbent>>>,
+}
+
+type Incumbent = (Arc<Solution>, Cost);
+
+impl SolverProgress {
+    /// Returns estimated completion of the solver run in the `[0, 1]` range based on elapsed
+    /// generations or time versus termination criteria. Returns none while the run is in progress,
+    /// if termination criteria have no known end (e.g. they are based on convergence only).
+    pub fn progress(&self) -> Option<f64> {
+        if self.is_finished.load(AtomicOrdering::Relaxed) {
+            Some(1.)
+        } else if self.is_estimable.load(AtomicOrdering::Relaxed) {
+            Some(f64::from_bits(self.estimate.load(AtomicOrdering::Relaxed)))
+        } else {
+            None
+        }
+    }
+
+    /// Requests the solver to suspend its evolution loop at the next generation boundary at which
+    /// at least one solution is known. Population is kept as is, so the search continues from the
+    /// same state once resumed. Please note, that time based termination criteria keep counting
+    /// while the solver is paused.
+    pub fn pause(&self) {
+        let (is_paused, _) = self.pause.as_ref();
+        *is_paused.lock().unwrap() = true;
+    }
+
+    /// Resumes the solver suspended by `pause`.
+    pub fn resume(&self) {
+        let (is_paused, resumed) = self.pause.as_ref();
+        *is_paused.lock().unwrap() = false;
+        resumed.notify_all();
+    }
+
+    /// Returns the best known solution with its cost captured when the solver was suspended last
+    /// time. Returns none if the solver has not been suspended yet.
+    pub fn incumbent(&self) -> Option<(Arc<Solution>, Cost)> {
+        self.incumbent.lock().unwrap().clone()
+    }
+
+    fn wait_if_paused(&self, heuristic_ctx: &RefinementContext) {
+        let (is_paused, resumed) = self.pause.as_ref();
+        let mut is_paused = is_paused.lock().unwrap();
+
+        // NOTE do not suspend until initial solutions are built, so there is always an incumbent
+        let incumbent = match heuristic_ctx.ranked().next() {
+            Some((insertion_ctx, _)) if *is_paused => insertion_ctx.deep_copy(),
+            _ => return,
+        };
+
+        let cost = incumbent.solution.get_total_cost();
+        *self.incumbent.lock().unwrap() = Some((Arc::new(incumbent.solution.into()), cost));
+
+        while *is_paused {
+            is_paused = resumed.wait(is_paused).unwrap();
+        }
+    }
+
+    fn start(&self, starts: usize, is_estimable: bool) {
+        *self.start_estimates.lock().unwrap() = vec![0.; starts];
+        self.is_estimable.store(is_estimable, AtomicOrdering::Relaxed);
+    }
+
+    fn track(
+        &self,
+        mut config: EvolutionConfig<RefinementContext, GoalContext, InsertionContext>,
+        start_idx: usize,
+    ) -> EvolutionConfig<RefinementContext, GoalContext, InsertionContext> {
+        if !config.termination.is_estimable() {
+            self.is_estimable.store(false, AtomicOrdering::Relaxed);
+        }
+
+        let progress = self.clone();
+        config.termination = Box::new(ProgressTermination { inner: config.termination, progress, start_idx });
+
+        config
+    }
+
+    fn set_estimate(&self, start_idx: usize, estimate: f64) {
+        let mut start_estimates = self.start_estimates.lock().unwrap();
+
+        // NOTE keep estimate of each start monotonic, overall estimate is their average
+        if let Some(start_estimate) = start_estimates.get_mut(start_idx) {
+            *start_estimate = estimate.clamp(0., 1.).max(*start_estimate);
+        }
+
+        let estimate = start_estimates.iter().sum::<f64>() / start_estimates.len().max(1) as f64;
+        self.estimate.store(estimate.to_bits(), AtomicOrdering::Relaxed);
+    }
+
+    fn finish(&self) {
+        self.estimate.store(1_f64.to_bits(), AtomicOrdering::Relaxed);
+        self.is_finished.store(true, AtomicOrdering::Relaxed);
+    }
+}
+
+/// Wraps termination criteria of one solver start to report their estimation to solver progress.
+struct ProgressTermination {
+    inner: Box<dyn Termination<Context = RefinementContext, Objective = GoalContext>>,
+    progress: SolverProgress,
+    start_idx: usize,
+}
+
+impl Termination for ProgressTermination {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        self.progress.wait_if_paused(heuristic_ctx);
+
+        let is_termination = self.inner.is_termination(heuristic_ctx);
+
+        if is_termination {
+            self.progress.set_estimate(self.start_idx, 1.);
+        }
+
+        is_termination
+    }
+
+    fn estimate(&self, heuristic_ctx: &Self::Context) -> f64 {
+        let estimate = self.inner.estimate(heuristic_ctx);
+        self.progress.set_estimate(self.start_idx, estimate);
+
+        estimate
+    }
+
+    fn is_estimable(&self) -> bool {
+        self.inner.is_estimable()
+    }
+}
+
 /// Solves a Vehicle Routing Problem and returns a _(solution, its cost)_ pair in case of success
 /// or error description, if solution cannot be found.
 ///
@@ -278,6 +447,9 @@ impl InitialOperator for RecreateInitialOperator {
 pub struct Solver {
     problem: Arc<Problem>,
     config: EvolutionConfig<RefinementContext, GoalContext, InsertionContext>,
+    is_best_effort: bool,
+    multi_start: Option<(usize, ConfigFactory)>,
+    progress: SolverProgress,
 }
 
 impl Solver {
@@ -286,27 +458,165 @@ impl Solver {
         problem: Arc<Problem>,
         config: EvolutionConfig<RefinementContext, GoalContext, InsertionContext>,
     ) -> Self {
-        Self { problem, config }
+        let progress = SolverProgress::default();
+        progress.start(1, config.termination.is_estimable());
+
+        Self { problem, config, is_best_effort: true, multi_start: None, progress }
+    }
+
+    /// Returns a handle to track progress of the solver while it is running.
+    pub fn progress_handle(&self) -> SolverProgress {
+        self.progress.clone()
+    }
+
+    /// Runs the solver from `starts` independent random initializations in parallel and keeps the
+    /// overall best solutions. Configs of all starts are created by `config_fn`: the first one with
+    /// the environment of provided config, others with environments which random generators are derived
+    /// from the original seed, so the whole run is reproducible under that seed. Provided config is
+    /// used only as the source of environment then. Telemetry metrics are taken from the start which has found
+    /// the best solution and progress is estimated as average over all starts. Default is a single start.
+    pub fn with_multi_start(mut self, starts: usize, config_fn: ConfigFactory) -> Self {
+        self.multi_start = if starts > 1 { Some((starts, config_fn)) } else { None };
+        self
+    }
+
+    /// Specifies whether a solution with unassigned jobs is accepted as a result, default is true.
+    /// In best effort mode, the least violating solution is returned and a summary of violations is
+    /// logged. Otherwise, an error with the summary is returned.
+    pub fn with_best_effort(mut self, is_best_effort: bool) -> Self {
+        self.is_best_effort = is_best_effort;
+        self
     }
 
     /// Solves a Vehicle Routing Problem and returns a _(solution, its cost)_ pair in case of success
-    /// or error description, if solution cannot be found.
+    /// or error description, if solution cannot be found. The solution keeps a random seed used by
+    /// environment, if it is known.
     pub fn solve(self) -> Result<(Solution, Cost, Option<TelemetryMetrics>), String> {
-        (self.config.context.environment.logger)(&format!(
-            "total jobs: {}, actors: {}",
-            self.problem.jobs.size(),
-            self.problem.fleet.actors.len()
-        ));
-
-        let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+        let is_best_effort = self.is_best_effort;
+        let (environment, seed, mut solutions, metrics) = self.run()?;
 
         // NOTE select the first best individual from population
         let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
             .ok_or_else(|| "cannot find any solution".to_string())?;
 
         let cost = insertion_ctx.solution.get_total_cost();
-        let solution = insertion_ctx.solution.into();
+        let solution = Solution { seed, ..insertion_ctx.solution.into() };
+
+        let violations = solution.get_violation_summary();
+        if !violations.is_empty() {
+            let summary = format_violation_summary(violations.as_slice());
+
+            if !is_best_effort {
+                return Err(format!("cannot find feasible solution, {summary}"));
+            }
+
+            (environment.logger)(&format!("returning best effort solution, {summary}"));
+        }
 
         Ok((solution, cost, metrics))
     }
+
+    /// Solves a Vehicle Routing Problem and returns mutually non-dominated solutions found, each with
+    /// its cost. Amount of candidate solutions is controlled by evolution config (see
+    /// `with_desired_solutions` method of config builder). Best effort mode is not applied here as
+    /// the front may contain solutions with unassigned jobs by design.
+    #[allow(clippy::type_complexity)]
+    pub fn solve_pareto_front(self) -> Result<(Vec<(Solution, Cost)>, Option<TelemetryMetrics>), String> {
+        let goal = self.problem.goal.clone();
+        let (_, seed, solutions, metrics) = self.run()?;
+
+        if solutions.is_empty() {
+            return Err("cannot find any solution".to_string());
+        }
+
+        let is_dominated = |candidate: &InsertionContext| {
+            solutions.iter().any(|other| goal.total_order(other, candidate) == Ordering::Less)
+        };
+        let front_indices = (0..solutions.len()).filter(|&idx| !is_dominated(&solutions[idx])).collect::<Vec<_>>();
+
+        let front = solutions
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| front_indices.contains(idx))
+            .map(|(_, insertion_ctx)| {
+                let cost = insertion_ctx.solution.get_total_cost();
+                (Solution { seed, ..insertion_ctx.solution.into() }, cost)
+            })
+            .collect();
+
+        Ok((front, metrics))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn run(self) -> Result<(Arc<Environment>, Option<u64>, Vec<InsertionContext>, Option<TelemetryMetrics>), String> {
+        let environment = self.config.context.environment.clone();
+        let seed = environment.random.get_seed();
+
+        (environment.logger)(&format!(
+            "total jobs: {}, actors: {}{}",
+            self.problem.jobs.size(),
+            self.problem.fleet.actors.len(),
+            seed.map(|seed| format!(", random seed: {seed}")).unwrap_or_default()
+        ));
+
+        let (starts, mut results) = match self.multi_start {
+            Some((starts, config_fn)) => {
+                let random = seed.map_or_else(DefaultRandom::default, DefaultRandom::new_with_seed);
+                self.progress.start(starts, true);
+
+                // NOTE configs cannot be sent between threads, so each start creates its own one
+                let results = parallel_into_collect((0..starts).collect(), |start_idx| {
+                    let environment = match start_idx {
+                        0 => environment.clone(),
+                        _ => Arc::new(Environment {
+                            random: Arc::new(random.new_for_worker(start_idx)),
+                            ..environment.as_ref().clone()
+                        }),
+                    };
+
+                    (config_fn)(environment)
+                        .map(|config| self.progress.track(config, start_idx))
+                        .and_then(EvolutionSimulator::new)
+                        .and_then(|simulator| simulator.run())
+                });
+
+                (starts, results.into_iter().collect::<Result<Vec<_>, _>>()?)
+            }
+            None => (1, vec![EvolutionSimulator::new(self.progress.track(self.config, 0))?.run()?]),
+        };
+
+        // NOTE min_by keeps the first start among equally good ones
+        let goal = self.problem.goal.clone();
+        let best_idx = results
+            .iter()
+            .enumerate()
+            .filter_map(|(start_idx, (solutions, _))| solutions.first().map(|best| (start_idx, best)))
+            .min_by(|(_, a), (_, b)| goal.total_order(a, b))
+            .map_or(0, |(start_idx, _)| start_idx);
+        let metrics = results.get_mut(best_idx).and_then(|(_, metrics)| metrics.take());
+
+        let mut solutions = results.into_iter().flat_map(|(solutions, _)| solutions).collect::<Vec<_>>();
+        if starts > 1 {
+            // NOTE stable sort keeps solutions of earlier starts ahead of equal ones
+            solutions.sort_by(|a, b| goal.total_order(a, b));
+        }
+
+        self.progress.finish();
+
+        Ok((environment, seed, solutions, metrics))
+    }
+}
+
+fn format_violation_summary(violations: &[(Option<ViolationCode>, usize)]) -> String {
+    let total = violations.iter().map(|(_, count)| count).sum::<usize>();
+    let codes = violations
+        .iter()
+        .map(|(code, count)| match code {
+            Some(code) => format!("code {code}: {count}"),
+            None => format!("unknown: {count}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("unassigned jobs: {total} ({codes})")
 }
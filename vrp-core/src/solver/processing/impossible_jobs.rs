@@ -0,0 +1,151 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/impossible_jobs_test.rs"]
+mod impossible_jobs_test;
+
+use super::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::models::common::ValueDimension;
+use crate::models::problem::{Job, Jobs, Single};
+use crate::models::{Extras, GoalContext, Problem, ViolationCode};
+use crate::solver::RefinementContext;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+const IMPOSSIBLE_JOB_CODE_KEY: &str = "impossible_job_code";
+const ORIG_PROBLEM_KEY: &str = "impossible_jobs_orig_problem";
+
+/// A trait to get or set a violation code used to report jobs pruned as impossible.
+pub trait ImpossibleJobsDimension {
+    /// Sets violation code and enables pruning of impossible jobs.
+    fn set_impossible_job_code(&mut self, code: ViolationCode) -> &mut Self;
+    /// Gets violation code.
+    fn get_impossible_job_code(&self) -> Option<ViolationCode>;
+}
+
+impl ImpossibleJobsDimension for Extras {
+    fn set_impossible_job_code(&mut self, code: ViolationCode) -> &mut Self {
+        self.set_value(IMPOSSIBLE_JOB_CODE_KEY, code);
+        self
+    }
+
+    fn get_impossible_job_code(&self) -> Option<ViolationCode> {
+        self.get_value(IMPOSSIBLE_JOB_CODE_KEY).copied()
+    }
+}
+
+/// Provides way to remove jobs which time windows close before any vehicle can arrive, so the
+/// solver does not waste insertion attempts on them. Pruned jobs are reported as unassigned with
+/// the code specified in problem's extras. Pruning is disabled if no code is specified.
+#[derive(Default)]
+pub struct ImpossibleJobsPruning {}
+
+impl HeuristicContextProcessing for ImpossibleJobsPruning {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn pre_process(&self, context: Self::Context) -> Self::Context {
+        if context.problem.extras.get_impossible_job_code().is_none() {
+            return context;
+        }
+
+        let mut problem = if let Some((problem, _)) = prune_impossible_jobs(context.problem.as_ref()) {
+            problem
+        } else {
+            return context;
+        };
+
+        let mut extras: Extras =
+            problem.extras.iter().map(|(k, v)| (k.clone(), v.clone())).collect::<HashMap<_, _, _>>();
+        extras.insert(ORIG_PROBLEM_KEY.to_string(), context.problem.clone());
+        problem.extras = Arc::new(extras);
+
+        RefinementContext { problem: Arc::new(problem), ..context }
+    }
+}
+
+impl HeuristicSolutionProcessing for ImpossibleJobsPruning {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let mut insertion_ctx = solution;
+
+        let code = insertion_ctx.problem.extras.get_impossible_job_code();
+        let orig_problem =
+            insertion_ctx.problem.extras.get(ORIG_PROBLEM_KEY).cloned().and_then(|any| any.downcast::<Problem>().ok());
+
+        let (code, orig_problem) = if let Some((code, orig_problem)) = code.zip(orig_problem) {
+            (code, orig_problem)
+        } else {
+            return insertion_ctx;
+        };
+
+        let jobs = insertion_ctx.problem.jobs.all().collect::<HashSet<_>>();
+        insertion_ctx.solution.unassigned.extend(
+            orig_problem.jobs.all().filter(|job| !jobs.contains(job)).map(|job| (job, UnassignmentInfo::Simple(code))),
+        );
+
+        insertion_ctx.problem = orig_problem;
+
+        insertion_ctx
+    }
+}
+
+/// Removes jobs which cannot be served within their time windows by any actor from the problem:
+/// even when departing at earliest shift time directly to the job, an actor arrives after all job's
+/// time windows are closed. Locked jobs are kept. Returns a new problem and removed jobs or none,
+/// if all jobs can be served.
+pub fn prune_impossible_jobs(problem: &Problem) -> Option<(Problem, Vec<Job>)> {
+    let locked_jobs = problem
+        .locks
+        .iter()
+        .flat_map(|lock| lock.details.iter())
+        .flat_map(|detail| detail.jobs.iter().cloned())
+        .collect::<HashSet<_>>();
+
+    let (impossible_jobs, jobs): (Vec<_>, Vec<_>) = problem.jobs.all().partition(|job| {
+        !locked_jobs.contains(job)
+            && match job {
+                Job::Single(single) => !is_reachable(problem, single),
+                Job::Multi(multi) => multi.jobs.iter().any(|single| !is_reachable(problem, single)),
+            }
+    });
+
+    if impossible_jobs.is_empty() {
+        return None;
+    }
+
+    let pruned = Problem {
+        fleet: problem.fleet.clone(),
+        jobs: Arc::new(Jobs::new(problem.fleet.as_ref(), jobs, &problem.transport)),
+        locks: problem.locks.clone(),
+        goal: problem.goal.clone(),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        extras: problem.extras.clone(),
+    };
+
+    Some((pruned, impossible_jobs))
+}
+
+fn is_reachable(problem: &Problem, single: &Single) -> bool {
+    problem.fleet.actors.iter().any(|actor| {
+        let departure = actor.detail.time.start;
+        let start_location = actor.detail.start.as_ref().map(|place| place.location);
+
+        single.places.iter().any(|place| {
+            let arrival = departure
+                + match (start_location, place.location) {
+                    (Some(from), Some(to)) => problem.transport.duration_approx(&actor.vehicle.profile, from, to),
+                    _ => 0.,
+                };
+
+            place.times.is_empty()
+                || place
+                    .times
+                    .iter()
+                    .any(|time| compare_floats(arrival, time.to_time_window(departure).end) != Ordering::Greater)
+        })
+    })
+}
@@ -6,6 +6,12 @@ use rosomaxa::prelude::*;
 mod advance_departure;
 pub use self::advance_departure::AdvanceDeparture;
 
+mod impossible_jobs;
+pub use self::impossible_jobs::{prune_impossible_jobs, ImpossibleJobsDimension, ImpossibleJobsPruning};
+
+mod or_opt;
+pub use self::or_opt::{smooth_solution, OrOptSmoothing};
+
 mod unassignment_reason;
 pub use self::unassignment_reason::UnassignmentReason;
 
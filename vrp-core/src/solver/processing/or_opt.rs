@@ -0,0 +1,172 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/or_opt_test.rs"]
+mod or_opt_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use crate::models::{Problem, Solution};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Provides way to polish solution with Or-opt moves: chains of consecutive jobs are relocated to
+/// better positions within the same route while it reduces the cost of the solution.
+pub struct OrOptSmoothing {
+    max_chain: usize,
+}
+
+impl OrOptSmoothing {
+    /// Creates a new instance of `OrOptSmoothing` which relocates chains up to `max_chain` jobs.
+    pub fn new(max_chain: usize) -> Self {
+        assert!(max_chain > 0);
+
+        Self { max_chain }
+    }
+}
+
+impl Default for OrOptSmoothing {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl HeuristicSolutionProcessing for OrOptSmoothing {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let mut insertion_ctx = solution;
+
+        (0..insertion_ctx.solution.routes.len()).for_each(|route_idx| {
+            while let Some(improved_ctx) = try_improve_route(&insertion_ctx, route_idx, self.max_chain) {
+                insertion_ctx = improved_ctx;
+            }
+        });
+
+        insertion_ctx
+    }
+}
+
+/// Polishes the solution with Or-opt moves which relocate chains of 1..=`max_chain` consecutive jobs
+/// within their routes. Each move is checked against constraints and applied only if it improves
+/// the solution.
+pub fn smooth_solution(problem: Arc<Problem>, solution: &Solution, max_chain: usize) -> Solution {
+    let solution = Solution {
+        registry: solution.registry.deep_copy(),
+        routes: solution.routes.iter().map(|route| route.deep_copy()).collect(),
+        unassigned: solution.unassigned.clone(),
+        seed: solution.seed,
+    };
+    let insertion_ctx =
+        InsertionContext::new_from_solution(problem, (solution, None), Arc::new(Environment::default()));
+
+    OrOptSmoothing::new(max_chain).post_process(insertion_ctx).solution.into()
+}
+
+/// Specifies a chain relocation move: chain's first and last activity indices, index of activity
+/// after which the chain is inserted and the estimated cost delta.
+type ChainMove = (usize, usize, usize, Cost);
+
+fn try_improve_route(insertion_ctx: &InsertionContext, route_idx: usize, max_chain: usize) -> Option<InsertionContext> {
+    let route = insertion_ctx.solution.routes.get(route_idx)?.route();
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    get_improving_moves(insertion_ctx, route, transport, max_chain)
+        .into_iter()
+        .filter_map(|chain_move| relocate_chain(insertion_ctx, route_idx, chain_move))
+        .find(|new_ctx| insertion_ctx.problem.goal.total_order(new_ctx, insertion_ctx) == Ordering::Less)
+}
+
+/// Returns chain moves which reduce transport costs sorted by cost delta, the best one goes first.
+fn get_improving_moves(
+    insertion_ctx: &InsertionContext,
+    route: &Route,
+    transport: &(dyn TransportCost + Send + Sync),
+    max_chain: usize,
+) -> Vec<ChainMove> {
+    let tour = &route.tour;
+    let is_movable = |activity: Option<&Activity>| {
+        matches!(activity.and_then(|activity| activity.retrieve_job()),
+            Some(job) if job.as_single().is_some() && !insertion_ctx.solution.locked.contains(&job))
+    };
+    let is_target = |idx: usize| idx == 0 || tour.get(idx).map_or(false, |activity| activity.job.is_some());
+    let get_cost = |from: &Activity, to: &Activity| {
+        transport.cost(route, from.place.location, to.place.location, TravelTime::Departure(from.schedule.departure))
+    };
+    let get_leg_cost = |from: &Activity, to: Option<&Activity>| to.map_or(0., |to| get_cost(from, to));
+
+    let mut moves = (1..tour.total())
+        .filter(|&start| is_movable(tour.get(start)))
+        .flat_map(|start| {
+            (start..tour.total()).take(max_chain).take_while(|&end| is_movable(tour.get(end))).flat_map(move |end| {
+                let (prev, first, last) =
+                    (tour.get(start - 1).unwrap(), tour.get(start).unwrap(), tour.get(end).unwrap());
+                let next = tour.get(end + 1);
+                let removal_gain = get_cost(prev, first) + get_leg_cost(last, next) - get_leg_cost(prev, next);
+
+                (0..tour.total()).filter(move |&target| (target + 1 < start || target > end) && is_target(target)).map(
+                    move |target| {
+                        let (from, to) = (tour.get(target).unwrap(), tour.get(target + 1));
+                        let insertion_cost = get_cost(from, first) + get_leg_cost(last, to) - get_leg_cost(from, to);
+
+                        (start, end, target, insertion_cost - removal_gain)
+                    },
+                )
+            })
+        })
+        .filter(|(_, _, _, delta)| compare_floats(*delta, 0.) == Ordering::Less)
+        .collect::<Vec<_>>();
+
+    moves.sort_by(|(_, _, _, a), (_, _, _, b)| compare_floats(*a, *b));
+
+    moves
+}
+
+/// Relocates chain by reinserting its jobs at the new position, so all constraints are checked.
+/// Returns None if the chain cannot be inserted there.
+fn relocate_chain(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    (start, end, target, _): ChainMove,
+) -> Option<InsertionContext> {
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).unwrap();
+    let jobs = (start..=end).filter_map(|idx| route_ctx.route().tour.get(idx)?.retrieve_job()).collect::<Vec<_>>();
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    insertion_ctx.problem.goal.accept_route_state(route_ctx);
+
+    // NOTE target index is shifted when the chain is removed before it
+    let position = if target < start { target } else { target - jobs.len() };
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    for (offset, job) in jobs.iter().enumerate() {
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        let insertion = eval_job_insertion_in_route(
+            &insertion_ctx,
+            &eval_ctx,
+            insertion_ctx.solution.routes.get(route_idx).unwrap(),
+            InsertionPosition::Concrete(position + offset),
+            InsertionResult::make_failure(),
+        );
+
+        match insertion {
+            InsertionResult::Success(success) => apply_insertion_success(&mut insertion_ctx, success),
+            InsertionResult::Failure(_) => return None,
+        }
+    }
+
+    finalize_insertion_ctx(&mut insertion_ctx);
+
+    Some(insertion_ctx)
+}
@@ -168,4 +168,8 @@ impl FeatureConstraint for StochasticFeatureConstraint {
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
         self.inner.merge(source, candidate)
     }
+
+    fn depends_on_other_routes(&self) -> bool {
+        self.inner.depends_on_other_routes()
+    }
 }
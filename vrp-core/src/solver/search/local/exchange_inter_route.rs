@@ -3,7 +3,8 @@
 mod exchange_inter_route_test;
 
 use super::*;
-use crate::models::problem::Job;
+use crate::models::problem::{get_job_locations, Job, NeighborLists};
+use crate::models::Problem;
 use crate::solver::search::{select_seed_job_with_tabu_list, LocalOperator, TabuList};
 use crate::solver::RefinementContext;
 use crate::utils::Noise;
@@ -21,6 +22,14 @@ pub struct ExchangeInterRouteRandom {
     noise_range: (f64, f64),
 }
 
+/// A local search operator which tries to exchange jobs in best way between different routes, but
+/// only if they are located among each other's nearest neighbors.
+pub struct ExchangeInterRouteNeighbor {
+    neighbors: Arc<NeighborLists>,
+    noise_probability: f64,
+    noise_range: (f64, f64),
+}
+
 impl ExchangeInterRouteBest {
     /// Creates a new instance of `ExchangeInterRouteBest`.
     pub fn new(noise_probability: f64, min: f64, max: f64) -> Self {
@@ -44,7 +53,7 @@ impl LocalOperator for ExchangeInterRouteBest {
                 insertion_ctx.environment.random.clone(),
             ),
             Box::new(|_| true),
-            Box::new(|_| true),
+            Box::new(|_, _, _| true),
         )
     }
 }
@@ -74,19 +83,56 @@ impl LocalOperator for ExchangeInterRouteRandom {
             },
             {
                 let random = random.clone();
-                Box::new(move |_idx| random.is_head_not_tails())
+                Box::new(move |_idx, _, _| random.is_head_not_tails())
             },
         )
     }
 }
 
+impl ExchangeInterRouteNeighbor {
+    /// Creates a new instance of `ExchangeInterRouteNeighbor` which considers `k` nearest neighbors of
+    /// each job location. Neighbors are estimated using distances of the first vehicle profile.
+    pub fn new(problem: &Problem, k: usize, noise_probability: f64, min: f64, max: f64) -> Self {
+        let size = problem
+            .jobs
+            .all()
+            .flat_map(|job| get_job_locations(&job).flatten().collect::<Vec<_>>())
+            .max()
+            .map_or(0, |location| location + 1);
+        let profile = problem.fleet.profiles.first().cloned().unwrap_or_default();
+        let neighbors = NeighborLists::new_from_transport(problem.transport.as_ref(), &profile, size, k);
+
+        Self { neighbors: Arc::new(neighbors), noise_probability, noise_range: (min, max) }
+    }
+}
+
+impl LocalOperator for ExchangeInterRouteNeighbor {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let neighbors = self.neighbors.clone();
+        find_best_insertion_pair(
+            insertion_ctx,
+            Noise::new_with_addition(
+                self.noise_probability,
+                self.noise_range,
+                insertion_ctx.environment.random.clone(),
+            ),
+            Box::new(|_| true),
+            Box::new(move |_, seed_job, test_job| {
+                get_job_locations(seed_job).flatten().any(|seed| {
+                    get_job_locations(test_job).flatten().any(|test| seed == test || neighbors.is_neighbor(seed, test))
+                })
+            }),
+        )
+    }
+}
+
 type InsertionSuccessPair = ((InsertionSuccess, Option<RouteContext>), (InsertionSuccess, Option<RouteContext>));
 
 fn find_best_insertion_pair(
     insertion_ctx: &InsertionContext,
     noise: Noise,
     filter_route_indices: Box<dyn Fn(usize) -> bool + Send + Sync>,
-    filter_jobs_indices: Box<dyn Fn(usize) -> bool + Send + Sync>,
+    filter_jobs: Box<dyn Fn(usize, &Job, &Job) -> bool + Send + Sync>,
 ) -> Option<InsertionContext> {
     let mut tabu_list = TabuList::from(insertion_ctx);
 
@@ -116,7 +162,7 @@ fn find_best_insertion_pair(
                         .tour
                         .jobs()
                         .enumerate()
-                        .filter(|(idx, job)| !locked.contains(job) && filter_jobs_indices(*idx))
+                        .filter(|(idx, job)| !locked.contains(job) && filter_jobs(*idx, &seed_job, job))
                         .collect::<Vec<_>>()
                         .as_slice(),
                     |(_, test_job)| {
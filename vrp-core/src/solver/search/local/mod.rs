@@ -18,9 +18,18 @@ pub use self::exchange_sequence::*;
 mod exchange_swap_star;
 pub use self::exchange_swap_star::*;
 
+mod perturb_departure;
+pub use self::perturb_departure::*;
+
+mod reassign_vehicles;
+pub use self::reassign_vehicles::*;
+
 mod reschedule_departure;
 pub use self::reschedule_departure::*;
 
+mod reverse_segment;
+pub use self::reverse_segment::*;
+
 /// Specifies behavior of a local search operator.
 pub trait LocalOperator {
     /// Applies local search operator to passed solution in order to explore possible
@@ -0,0 +1,138 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/perturb_departure_test.rs"]
+mod perturb_departure_test;
+
+use super::*;
+use crate::construction::enablers::{update_route_departure, ScheduleStateKeys};
+use crate::construction::features::LIMIT_DURATION_KEY;
+use crate::models::common::Timestamp;
+
+/// A local search operator which moves departure time of a random route to a random time within
+/// allowed bounds and reschedules the route. The move is accepted only if it reduces waiting time
+/// of the route without delaying its end or allows more not yet assigned jobs to be inserted into it.
+#[derive(Default)]
+pub struct PerturbDeparture {
+    state_keys: ScheduleStateKeys,
+}
+
+impl LocalOperator for PerturbDeparture {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        let problem = refinement_ctx.problem.as_ref();
+        let random = insertion_ctx.environment.random.as_ref();
+
+        let routes = insertion_ctx.solution.routes.len();
+        if routes == 0 {
+            return None;
+        }
+
+        let route_idx = random.uniform_int(0, routes as i32 - 1) as usize;
+        let route_ctx = insertion_ctx.solution.routes.get(route_idx)?;
+        let (earliest, latest) = get_departure_bounds(route_ctx, &self.state_keys)?;
+        let departure = random.uniform_real(earliest, latest);
+
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+        let new_route_ctx = new_insertion_ctx.solution.routes.get_mut(route_idx)?;
+        update_route_departure(
+            new_route_ctx,
+            problem.activity.as_ref(),
+            problem.transport.as_ref(),
+            departure,
+            &self.state_keys,
+        );
+        problem.goal.accept_route_state(new_route_ctx);
+
+        let is_accepted = (compare_floats(get_waiting_time(new_route_ctx), get_waiting_time(route_ctx))
+            == Ordering::Less
+            && compare_floats(get_end_time(new_route_ctx), get_end_time(route_ctx)) != Ordering::Greater)
+            || count_insertable_jobs(&new_insertion_ctx, route_idx) > count_insertable_jobs(insertion_ctx, route_idx);
+
+        if is_accepted {
+            problem.goal.accept_solution_state(&mut new_insertion_ctx.solution);
+            Some(new_insertion_ctx)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns a range of departure times which keeps time windows of all activities feasible.
+fn get_departure_bounds(route_ctx: &RouteContext, state_keys: &ScheduleStateKeys) -> Option<(Timestamp, Timestamp)> {
+    let route = route_ctx.route();
+    if !route.tour.has_jobs() {
+        return None;
+    }
+
+    let start = route.tour.start()?;
+    let departure = start.schedule.departure;
+    let start_time = route.actor.detail.start.as_ref().map(|start| &start.time);
+
+    // NOTE receding departure can only increase route duration
+    let earliest = start_time.and_then(|time| time.earliest).unwrap_or(start.place.time.start);
+    let earliest = route_ctx
+        .state()
+        .get_route_state::<f64>(state_keys.total_duration)
+        .zip(route_ctx.state().get_route_state::<f64>(LIMIT_DURATION_KEY))
+        .map_or(earliest, |(&total, &limit)| earliest.max(departure - (limit - total).max(0.)));
+
+    // NOTE advancing departure is limited by waiting and remaining time at each activity
+    let max_shift = route.tour.all_activities().skip(1).rev().fold(f64::MAX, |max_shift, activity| {
+        let waiting_time = (activity.place.time.start - activity.schedule.arrival).max(0.);
+        let remaining_time = (activity.place.time.end - activity.schedule.arrival - waiting_time).max(0.);
+
+        waiting_time + remaining_time.min(max_shift)
+    });
+    let latest = start_time.and_then(|time| time.latest).unwrap_or(f64::MAX).min(departure + max_shift);
+
+    match compare_floats(earliest, latest) {
+        Ordering::Less => Some((earliest, latest)),
+        _ => None,
+    }
+}
+
+fn get_waiting_time(route_ctx: &RouteContext) -> f64 {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .map(|activity| (activity.place.time.start - activity.schedule.arrival).max(0.))
+        .sum()
+}
+
+fn get_end_time(route_ctx: &RouteContext) -> Timestamp {
+    route_ctx.route().tour.end().map_or(0., |end| end.schedule.arrival)
+}
+
+fn count_insertable_jobs(insertion_ctx: &InsertionContext, route_idx: usize) -> usize {
+    let route_ctx = insertion_ctx.solution.routes.get(route_idx).unwrap();
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    insertion_ctx
+        .solution
+        .required
+        .iter()
+        .chain(insertion_ctx.solution.unassigned.keys())
+        .filter(|job| {
+            let eval_ctx = EvaluationContext {
+                goal: &insertion_ctx.problem.goal,
+                job,
+                leg_selection: &leg_selection,
+                result_selector: &result_selector,
+            };
+
+            let result = eval_job_insertion_in_route(
+                insertion_ctx,
+                &eval_ctx,
+                route_ctx,
+                InsertionPosition::Any,
+                InsertionResult::make_failure(),
+            );
+
+            matches!(result, InsertionResult::Success(_))
+        })
+        .count()
+}
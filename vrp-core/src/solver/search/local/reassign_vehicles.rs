@@ -0,0 +1,247 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/reassign_vehicles_test.rs"]
+mod reassign_vehicles_test;
+
+use super::*;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job};
+use crate::models::solution::Route;
+
+/// A local search operator which keeps job sequences of routes fixed and reassigns vehicles to them.
+/// This solves an assignment problem where cost of serving a route's sequence is evaluated for each
+/// vehicle currently used in the solution and for each available vehicle type, so the move can fix
+/// routes which are served by an unsuitable vehicle.
+/// NOTE: routes with locked or multi jobs are not reassigned.
+#[derive(Default)]
+pub struct ReassignVehicles {}
+
+impl LocalOperator for ReassignVehicles {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let routes = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| route_ctx.route())
+            .filter(|route| {
+                route.tour.jobs().all(|job| job.as_single().is_some() && !insertion_ctx.solution.locked.contains(&job))
+            })
+            .map(|route| (route.actor.clone(), get_sequence(route)))
+            .filter(|(actor, jobs)| evaluate_route(insertion_ctx, actor, jobs).is_some())
+            .collect::<Vec<_>>();
+
+        if routes.is_empty() {
+            return None;
+        }
+
+        let actors = routes
+            .iter()
+            .map(|(actor, _)| actor.clone())
+            .chain(insertion_ctx.solution.registry.resources().next())
+            .collect::<Vec<_>>();
+
+        let costs = routes
+            .iter()
+            .map(|(_, jobs)| actors.iter().map(|actor| evaluate_route(insertion_ctx, actor, jobs)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let assignment = solve_assignment(costs.as_slice())?;
+
+        let get_total_cost = |assignment: &[usize]| -> Cost {
+            assignment.iter().enumerate().filter_map(|(route_idx, &actor_idx)| costs[route_idx][actor_idx]).sum()
+        };
+        let current = (0..routes.len()).collect::<Vec<_>>();
+
+        if compare_floats(get_total_cost(assignment.as_slice()), get_total_cost(current.as_slice())) != Ordering::Less {
+            return None;
+        }
+
+        let reassigned = assignment
+            .into_iter()
+            .enumerate()
+            .filter(|(route_idx, actor_idx)| route_idx != actor_idx)
+            .map(|(route_idx, actor_idx)| (routes[route_idx].0.clone(), actors[actor_idx].clone()))
+            .collect::<Vec<_>>();
+
+        reassign_routes(insertion_ctx, reassigned.as_slice())
+    }
+}
+
+/// Returns jobs of the route in order of their activities.
+fn get_sequence(route: &Route) -> Vec<Job> {
+    route.tour.all_activities().filter_map(|activity| activity.retrieve_job()).collect()
+}
+
+/// Returns cost of the route served by given actor when jobs are inserted in the given order or
+/// None if such route is not feasible.
+fn evaluate_route(insertion_ctx: &InsertionContext, actor: &Arc<Actor>, jobs: &[Job]) -> Option<Cost> {
+    let mut route_ctx = RouteContext::new(actor.clone());
+    insertion_ctx.problem.goal.accept_route_state(&mut route_ctx);
+
+    let mut probe_ctx = InsertionContext {
+        problem: insertion_ctx.problem.clone(),
+        solution: SolutionContext {
+            required: vec![],
+            ignored: vec![],
+            unassigned: Default::default(),
+            locked: Default::default(),
+            routes: vec![route_ctx],
+            registry: insertion_ctx.solution.registry.deep_slice(|_| false),
+            state: Default::default(),
+        },
+        environment: insertion_ctx.environment.clone(),
+    };
+
+    insert_jobs(&mut probe_ctx, 0, jobs)?;
+
+    Some(probe_ctx.solution.get_total_cost())
+}
+
+/// Moves sequences of jobs from routes of old actors to new routes of new actors.
+fn reassign_routes(
+    insertion_ctx: &InsertionContext,
+    reassigned: &[(Arc<Actor>, Arc<Actor>)],
+) -> Option<InsertionContext> {
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+
+    let sequences = reassigned
+        .iter()
+        .map(|(old_actor, new_actor)| {
+            let route_ctx =
+                insertion_ctx.solution.routes.iter().find(|route_ctx| route_ctx.route().actor == *old_actor).unwrap();
+            (new_actor.clone(), get_sequence(route_ctx.route()))
+        })
+        .collect::<Vec<_>>();
+
+    insertion_ctx
+        .solution
+        .keep_routes(&|route_ctx| reassigned.iter().all(|(old_actor, _)| route_ctx.route().actor != *old_actor));
+
+    for (actor, jobs) in sequences {
+        let route_ctx = insertion_ctx.solution.registry.get_route(actor.as_ref())?;
+        insertion_ctx.solution.routes.push(route_ctx);
+
+        let route_idx = insertion_ctx.solution.routes.len() - 1;
+        insert_jobs(&mut insertion_ctx, route_idx, jobs.as_slice())?;
+    }
+
+    finalize_insertion_ctx(&mut insertion_ctx);
+
+    Some(insertion_ctx)
+}
+
+/// Inserts jobs to the end of the route in the given order. Returns None if any insertion fails.
+fn insert_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: &[Job]) -> Option<()> {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    jobs.iter().try_for_each(|job| {
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        let insertion = eval_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            insertion_ctx.solution.routes.get(route_idx).unwrap(),
+            InsertionPosition::Last,
+            InsertionResult::make_failure(),
+        );
+
+        match insertion {
+            InsertionResult::Success(success) => {
+                apply_insertion_success(insertion_ctx, success);
+                Some(())
+            }
+            InsertionResult::Failure(_) => None,
+        }
+    })
+}
+
+/// Solves a rectangular assignment problem using the Hungarian algorithm: each row is assigned to
+/// a distinct column minimizing total cost, None cost means that assignment is not possible.
+/// Returns column index for each row or None if there is no feasible assignment.
+fn solve_assignment(costs: &[Vec<Option<Cost>>]) -> Option<Vec<usize>> {
+    let rows = costs.len();
+    let columns = costs.first().map_or(0, |row| row.len());
+    if rows == 0 || rows > columns {
+        return None;
+    }
+
+    // NOTE use a big but finite cost for infeasible assignments to keep potentials well defined
+    let infeasible = costs.iter().flatten().flatten().fold(1., |acc, cost| acc + cost.abs()) * (rows + 1) as f64;
+    let get_cost = |row: usize, column: usize| costs[row - 1][column - 1].unwrap_or(infeasible);
+
+    // NOTE indices are shifted by one: zero row and column are fictive
+    let mut row_potentials = vec![0.; rows + 1];
+    let mut column_potentials = vec![0.; columns + 1];
+    let mut matches = vec![0; columns + 1];
+    let mut parents = vec![0; columns + 1];
+
+    for row in 1..=rows {
+        matches[0] = row;
+        let mut column = 0;
+        let mut min_values = vec![f64::MAX; columns + 1];
+        let mut used = vec![false; columns + 1];
+
+        loop {
+            used[column] = true;
+            let matched_row = matches[column];
+            let mut delta = f64::MAX;
+            let mut next_column = 0;
+
+            for candidate in 1..=columns {
+                if used[candidate] {
+                    continue;
+                }
+
+                let value =
+                    get_cost(matched_row, candidate) - row_potentials[matched_row] - column_potentials[candidate];
+                if value < min_values[candidate] {
+                    min_values[candidate] = value;
+                    parents[candidate] = column;
+                }
+
+                if min_values[candidate] < delta {
+                    delta = min_values[candidate];
+                    next_column = candidate;
+                }
+            }
+
+            (0..=columns).for_each(|candidate| {
+                if used[candidate] {
+                    row_potentials[matches[candidate]] += delta;
+                    column_potentials[candidate] -= delta;
+                } else {
+                    min_values[candidate] -= delta;
+                }
+            });
+
+            column = next_column;
+            if matches[column] == 0 {
+                break;
+            }
+        }
+
+        while column != 0 {
+            let parent = parents[column];
+            matches[column] = matches[parent];
+            column = parent;
+        }
+    }
+
+    let mut assignment = vec![0; rows];
+    (1..=columns)
+        .filter(|&column| matches[column] != 0)
+        .for_each(|column| assignment[matches[column] - 1] = column - 1);
+
+    let is_feasible = assignment.iter().enumerate().all(|(row, &column)| costs[row][column].is_some());
+
+    if is_feasible {
+        Some(assignment)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/reverse_segment_test.rs"]
+mod reverse_segment_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use crate::solver::search::LocalOperator;
+use crate::solver::RefinementContext;
+use rand::prelude::SliceRandom;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+
+const MIN_JOBS: usize = 2;
+
+/// A local search operator which tries to reverse a sequence of jobs inside one route: a generalized
+/// 2-opt move. As transport costs can be asymmetric, the move is evaluated using costs of all legs
+/// inside the sequence, not only the ones at its boundaries.
+pub struct ReverseSegment {
+    max_segment_size: usize,
+}
+
+impl ReverseSegment {
+    /// Creates a new instance of `ReverseSegment`.
+    pub fn new(max_segment_size: usize) -> Self {
+        assert!(max_segment_size >= MIN_JOBS);
+
+        Self { max_segment_size }
+    }
+}
+
+impl Default for ReverseSegment {
+    fn default() -> Self {
+        Self::new(12)
+    }
+}
+
+impl LocalOperator for ReverseSegment {
+    fn explore(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> Option<InsertionContext> {
+        let transport = refinement_ctx.problem.transport.as_ref();
+
+        let mut route_indices = (0..insertion_ctx.solution.routes.len()).collect::<Vec<_>>();
+        route_indices.shuffle(&mut insertion_ctx.environment.random.get_rng());
+
+        route_indices.into_iter().find_map(|route_idx| {
+            let route_ctx = insertion_ctx.solution.routes.get(route_idx).unwrap();
+
+            get_improving_segments(insertion_ctx, route_ctx.route(), transport, self.max_segment_size)
+                .into_iter()
+                .find_map(|(start, end, _)| reverse_segment(insertion_ctx, route_idx, start, end))
+        })
+    }
+}
+
+/// Returns segments given as (start, end) activity indices which reversal reduces transport costs.
+/// Segments are sorted by cost delta, the best one goes first.
+fn get_improving_segments(
+    insertion_ctx: &InsertionContext,
+    route: &Route,
+    transport: &(dyn TransportCost + Send + Sync),
+    max_segment_size: usize,
+) -> Vec<(usize, usize, Cost)> {
+    let tour = &route.tour;
+    let is_movable = |activity: Option<&Activity>| {
+        matches!(activity.and_then(|activity| activity.retrieve_job()),
+            Some(job) if job.as_single().is_some() && !insertion_ctx.solution.locked.contains(&job))
+    };
+    let get_cost = |from: &Activity, to: &Activity| {
+        transport.cost(route, from.place.location, to.place.location, TravelTime::Departure(from.schedule.departure))
+    };
+
+    let mut segments = (1..tour.total())
+        .filter(|&start| is_movable(tour.get(start)))
+        .flat_map(|start| {
+            let prev = tour.get(start - 1).unwrap();
+            let first = tour.get(start).unwrap();
+
+            (start + 1..tour.total())
+                .take(max_segment_size - 1)
+                .take_while(|&end| is_movable(tour.get(end)))
+                .scan((Cost::default(), Cost::default()), move |(forward, backward), end| {
+                    let (before, last) = (tour.get(end - 1).unwrap(), tour.get(end).unwrap());
+                    *forward += get_cost(before, last);
+                    *backward += get_cost(last, before);
+
+                    let (old_cost, new_cost) = match tour.get(end + 1) {
+                        Some(next) => (
+                            get_cost(prev, first) + *forward + get_cost(last, next),
+                            get_cost(prev, last) + *backward + get_cost(first, next),
+                        ),
+                        None => (get_cost(prev, first) + *forward, get_cost(prev, last) + *backward),
+                    };
+
+                    Some((start, end, new_cost - old_cost))
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|(_, _, delta)| compare_floats(*delta, 0.) == Ordering::Less)
+        .collect::<Vec<_>>();
+
+    segments.sort_by(|(_, _, a), (_, _, b)| compare_floats(*a, *b));
+
+    segments
+}
+
+/// Reverses jobs between given activity indices by reinserting them in the opposite order, so all
+/// constraints are checked. Returns None if reversed sequence cannot be inserted.
+fn reverse_segment(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    start: usize,
+    end: usize,
+) -> Option<InsertionContext> {
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).unwrap();
+    let jobs =
+        (start..=end).rev().filter_map(|idx| route_ctx.route().tour.get(idx)?.retrieve_job()).collect::<Vec<_>>();
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    insertion_ctx.problem.goal.accept_route_state(route_ctx);
+
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    for (offset, job) in jobs.iter().enumerate() {
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        let insertion = eval_job_insertion_in_route(
+            &insertion_ctx,
+            &eval_ctx,
+            insertion_ctx.solution.routes.get(route_idx).unwrap(),
+            InsertionPosition::Concrete(start - 1 + offset),
+            InsertionResult::make_failure(),
+        );
+
+        match insertion {
+            InsertionResult::Success(success) => apply_insertion_success(&mut insertion_ctx, success),
+            InsertionResult::Failure(_) => return None,
+        }
+    }
+
+    finalize_insertion_ctx(&mut insertion_ctx);
+
+    Some(insertion_ctx)
+}
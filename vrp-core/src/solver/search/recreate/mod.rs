@@ -13,6 +13,9 @@ pub trait Recreate {
     fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext;
 }
 
+mod recreate_with_beam;
+pub use self::recreate_with_beam::BeamInsertion;
+
 mod recreate_with_blinks;
 pub use self::recreate_with_blinks::RecreateWithBlinks;
 
@@ -0,0 +1,147 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/recreate/recreate_with_beam_test.rs"]
+mod recreate_with_beam_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::solver::search::recreate::Recreate;
+use crate::solver::RefinementContext;
+use rosomaxa::prelude::*;
+use rosomaxa::utils::parallel_collect;
+use std::cmp::Ordering;
+
+/// A recreate method which runs a beam search over insertion steps: instead of following a single
+/// greedy path, the `width` cheapest partial solutions are kept and expanded on each step by the
+/// best insertion of every not yet assigned job. Partial solutions are ranked by amount of
+/// unassigned jobs and accumulated insertion cost, the best complete solution is returned.
+/// With width equal to one, it behaves as the cheapest insertion heuristic.
+pub struct BeamInsertion {
+    width: usize,
+}
+
+impl BeamInsertion {
+    /// Creates a new instance of `BeamInsertion`.
+    pub fn new(width: usize) -> Self {
+        assert!(width > 0);
+
+        Self { width }
+    }
+}
+
+impl Recreate for BeamInsertion {
+    fn run(&self, _: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+        prepare_insertion_ctx(&mut insertion_ctx);
+
+        let is_quota_reached = |insertion_ctx: &InsertionContext| {
+            insertion_ctx.environment.quota.as_ref().map_or(false, |q| q.is_reached())
+        };
+
+        let mut beams = vec![(insertion_ctx, InsertionCost::default())];
+
+        while beams.iter().any(|(insertion_ctx, _)| !insertion_ctx.solution.required.is_empty())
+            && !is_quota_reached(&beams[0].0)
+        {
+            let expansions =
+                beams.iter_mut().map(|(insertion_ctx, _)| evaluate_insertions(insertion_ctx)).collect::<Vec<_>>();
+
+            let mut candidates = expansions
+                .into_iter()
+                .enumerate()
+                .flat_map(|(beam_idx, successes)| {
+                    let (insertion_ctx, cost) = &beams[beam_idx];
+                    let unassigned = insertion_ctx.solution.unassigned.len();
+
+                    if successes.is_empty() {
+                        vec![(beam_idx, unassigned, cost.clone(), None)]
+                    } else {
+                        successes
+                            .into_iter()
+                            .map(|success| (beam_idx, unassigned, add_costs(cost, &success.cost), Some(success)))
+                            .collect()
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            candidates.sort_by(|(_, a_unassigned, a_cost, _), (_, b_unassigned, b_cost, _)| {
+                a_unassigned.cmp(b_unassigned).then_with(|| a_cost.cmp(b_cost))
+            });
+            candidates.truncate(self.width);
+
+            beams = candidates
+                .into_iter()
+                .map(|(beam_idx, _, cost, success)| {
+                    let mut insertion_ctx = beams[beam_idx].0.deep_copy();
+                    if let Some(success) = success {
+                        apply_insertion_success(&mut insertion_ctx, success);
+                    }
+
+                    (insertion_ctx, cost)
+                })
+                .collect();
+        }
+
+        beams
+            .into_iter()
+            .map(|(mut insertion_ctx, _)| {
+                finalize_insertion_ctx(&mut insertion_ctx);
+                insertion_ctx
+            })
+            .reduce(
+                |best, other| {
+                    if best.problem.goal.total_order(&other, &best) == Ordering::Less {
+                        other
+                    } else {
+                        best
+                    }
+                },
+            )
+            .expect("beam cannot be empty")
+    }
+}
+
+/// Returns the best insertion of each required job. Jobs which cannot be inserted are moved to
+/// the unassigned list.
+fn evaluate_insertions(insertion_ctx: &mut InsertionContext) -> Vec<InsertionSuccess> {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let evaluator = PositionInsertionEvaluator::default();
+
+    let results = {
+        let insertion_ctx: &InsertionContext = insertion_ctx;
+        let routes = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .chain(insertion_ctx.solution.registry.next_route())
+            .collect::<Vec<_>>();
+        let jobs = insertion_ctx.solution.required.iter().collect::<Vec<&Job>>();
+
+        parallel_collect(jobs.as_slice(), |job| {
+            let result =
+                evaluator.evaluate_job(insertion_ctx, job, routes.as_slice(), &leg_selection, &result_selector);
+            ((*job).clone(), result)
+        })
+    };
+
+    results
+        .into_iter()
+        .filter_map(|(job, result)| match result {
+            InsertionResult::Success(success) => Some(success),
+            InsertionResult::Failure(failure) => {
+                insertion_ctx.solution.required.retain(|required| *required != job);
+                insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Simple(failure.constraint));
+                None
+            }
+        })
+        .collect()
+}
+
+/// Sums hierarchical insertion costs level by level.
+fn add_costs(left: &InsertionCost, right: &InsertionCost) -> InsertionCost {
+    let (left, right) = (left.iter().collect::<Vec<_>>(), right.iter().collect::<Vec<_>>());
+
+    (0..left.len().max(right.len()))
+        .map(|idx| left.get(idx).copied().unwrap_or_default() + right.get(idx).copied().unwrap_or_default())
+        .collect()
+}
@@ -1,11 +1,13 @@
 use crate::construction::features::*;
 use crate::construction::heuristics::{InsertionContext, RegistryContext, SolutionContext, UnassignmentInfo};
-use crate::helpers::construction::features::create_goal_ctx_with_transport;
+use crate::helpers::construction::features::{create_goal_ctx_with_transport, create_simple_demand};
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::create_route_context_with_activities;
-use crate::models::common::IdDimension;
+use crate::models::common::{Duration, IdDimension, Location, SingleDimLoad, TimeWindow, Timestamp};
 use crate::models::examples::create_example_problem;
-use crate::models::problem::{Fleet, Job, Jobs};
+use crate::models::problem::{
+    ActivityCost, Costs, EndDwellDimension, Fleet, Job, Jobs, Vehicle, VehicleDetail, VehiclePlace,
+};
 use crate::models::solution::Registry;
 use crate::models::{GoalContext, Problem, Solution};
 use rosomaxa::utils::{DefaultRandom, Environment, Random};
@@ -43,8 +45,125 @@ pub fn create_problem_with_goal_ctx_jobs_and_fleet(
     })
 }
 
+/// Builds a small problem with capacity and time window constraints using fake routing. Its goal
+/// minimizes unassigned jobs, then tours, then transport costs.
+#[derive(Default)]
+pub struct ProblemBuilder {
+    jobs: Vec<Job>,
+    vehicles: Vec<Vehicle>,
+    activity: Option<Arc<dyn ActivityCost + Send + Sync>>,
+}
+
+impl ProblemBuilder {
+    /// Adds a job with given demand (see `create_simple_demand`) and time window.
+    pub fn add_job(&mut self, id: &str, location: Location, demand: i32, window: (f64, f64)) -> &mut Self {
+        self.jobs.push(
+            SingleBuilder::default()
+                .id(id)
+                .location(Some(location))
+                .demand(create_simple_demand(demand))
+                .times(vec![TimeWindow::new(window.0, window.1)])
+                .build_as_job_ref(),
+        );
+        self
+    }
+
+    /// Adds a job which can be served at any of given alternative locations.
+    pub fn add_job_with_locations(
+        &mut self,
+        id: &str,
+        locations: &[Location],
+        demand: i32,
+        window: (f64, f64),
+    ) -> &mut Self {
+        self.jobs.push(
+            SingleBuilder::default()
+                .id(id)
+                .places(locations.iter().map(|location| (Some(*location), 0., vec![window])).collect())
+                .demand(create_simple_demand(demand))
+                .build_as_job_ref(),
+        );
+        self
+    }
+
+    /// Adds a vehicle with given capacity which starts and ends at depot.
+    pub fn add_vehicle(&mut self, id: &str, capacity: i32, depot: Location) -> &mut Self {
+        self.add_vehicle_with_costs(id, capacity, depot, test_costs())
+    }
+
+    /// Adds a vehicle with given capacity and costs which starts and ends at depot.
+    pub fn add_vehicle_with_costs(&mut self, id: &str, capacity: i32, depot: Location, costs: Costs) -> &mut Self {
+        let detail = test_vehicle_detail();
+        let relocate = |place: Option<VehiclePlace>| place.map(|place| VehiclePlace { location: depot, ..place });
+
+        self.vehicles.push(
+            VehicleBuilder::default()
+                .id(id)
+                .capacity(capacity)
+                .costs(costs)
+                .details(vec![VehicleDetail { start: relocate(detail.start), end: relocate(detail.end) }])
+                .build(),
+        );
+        self
+    }
+
+    /// Sets latest return time and end dwell time of the last added vehicle.
+    pub fn vehicle_end(&mut self, latest: Timestamp, dwell: Duration) -> &mut Self {
+        let vehicle = self.vehicles.last_mut().expect("no vehicle added");
+        vehicle
+            .details
+            .iter_mut()
+            .filter_map(|detail| detail.end.as_mut())
+            .for_each(|end| end.time.latest = Some(latest));
+        vehicle.dimens.set_end_dwell(dwell);
+        self
+    }
+
+    /// Sets activity costs used instead of `TestActivityCost`.
+    pub fn activity_cost(&mut self, activity: Arc<dyn ActivityCost + Send + Sync>) -> &mut Self {
+        self.activity = Some(activity);
+        self
+    }
+
+    /// Builds a problem from added jobs and vehicles.
+    pub fn build(&mut self) -> Arc<Problem> {
+        let transport = TestTransportCost::new_shared();
+        let activity = self.activity.take().unwrap_or_else(TestActivityCost::new_shared);
+
+        let features = vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_minimize_tours_feature("min_tours").unwrap(),
+            create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1).unwrap(),
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+        ];
+        let feature_map =
+            vec![vec!["min_unassigned".to_string()], vec!["min_tours".to_string()], vec!["transport".to_string()]];
+        let goal = GoalContext::new(features.as_slice(), feature_map.as_slice(), feature_map.as_slice()).unwrap();
+
+        let fleet = Arc::new(
+            FleetBuilder::default().add_driver(test_driver()).add_vehicles(std::mem::take(&mut self.vehicles)).build(),
+        );
+        let jobs = Arc::new(Jobs::new(fleet.as_ref(), std::mem::take(&mut self.jobs), &transport));
+
+        Arc::new(Problem {
+            fleet,
+            jobs,
+            locks: vec![],
+            goal: Arc::new(goal),
+            activity,
+            transport,
+            extras: Arc::new(Default::default()),
+        })
+    }
+}
+
 pub fn create_empty_solution() -> Solution {
-    Solution { registry: Registry::new(&test_fleet(), test_random()), routes: vec![], unassigned: Default::default() }
+    Solution {
+        registry: Registry::new(&test_fleet(), test_random()),
+        routes: vec![],
+        unassigned: Default::default(),
+        seed: None,
+    }
 }
 
 pub fn create_registry_context(fleet: &Fleet) -> RegistryContext {
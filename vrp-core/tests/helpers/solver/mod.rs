@@ -155,7 +155,7 @@ pub fn generate_matrix_routes(
         extras: Arc::new(Default::default()),
     };
 
-    let solution = Solution { registry, routes, unassigned: Default::default() };
+    let solution = Solution { registry, routes, unassigned: Default::default(), seed: None };
 
     (problem, solution)
 }
@@ -0,0 +1,145 @@
+use super::*;
+use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::SingleDimLoad;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+fn create_affinity_job(id: &str, location: Location, affinity: &str) -> Job {
+    let mut single = SingleBuilder::default().id(id).location(Some(location)).demand(create_simple_demand(-1)).build();
+    single.dimens.set_affinity(affinity);
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_route_with_jobs(fleet: &Fleet, vehicle: &str, jobs: &[&Job]) -> RouteContext {
+    create_route_context_with_activities(
+        fleet,
+        vehicle,
+        jobs.iter().map(|job| test_activity_with_job(job.as_single().unwrap().clone())).collect(),
+    )
+}
+
+parameterized_test! {can_estimate_job_insertion, (route_idx, affinity, expected), {
+    can_estimate_job_insertion_impl(route_idx, affinity, expected);
+}}
+
+can_estimate_job_insertion! {
+    case_01_same_route: (0, "a", -1.),
+    case_02_other_route: (1, "a", 1.),
+    case_03_no_group_in_solution: (1, "c", 0.),
+}
+
+fn can_estimate_job_insertion_impl(route_idx: usize, affinity: &str, expected: Cost) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let job1 = create_affinity_job("job1", 1, "a");
+    let job2 = create_affinity_job("job2", 2, "b");
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes =
+        vec![create_route_with_jobs(&fleet, "v1", &[&job1]), create_route_with_jobs(&fleet, "v2", &[&job2])];
+    let feature = create_affinity_feature("affinity").unwrap();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let job = create_affinity_job("job3", 3, affinity);
+
+    let result = feature.objective.as_ref().unwrap().estimate(&MoveContext::route(
+        &solution_ctx,
+        &solution_ctx.routes[route_idx],
+        &job,
+    ));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_fitness_as_amount_of_split_groups() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let jobs = [
+        create_affinity_job("job1", 1, "a"),
+        create_affinity_job("job2", 2, "b"),
+        create_affinity_job("job3", 3, "a"),
+        create_affinity_job("job4", 4, "b"),
+    ];
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![
+        create_route_with_jobs(&fleet, "v1", &[&jobs[0], &jobs[1]]),
+        create_route_with_jobs(&fleet, "v2", &[&jobs[2]]),
+    ];
+    let feature = create_affinity_feature("affinity").unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), 1.);
+}
+
+#[test]
+fn can_recalculate_fitness_after_solution_is_changed() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let jobs = [create_affinity_job("job1", 1, "a"), create_affinity_job("job2", 2, "a")];
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes =
+        vec![create_route_with_jobs(&fleet, "v1", &[&jobs[0]]), create_route_with_jobs(&fleet, "v2", &[&jobs[1]])];
+    let feature = create_affinity_feature("affinity").unwrap();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), 1.);
+
+    insertion_ctx.solution.routes =
+        vec![create_route_with_jobs(&fleet, "v1", &[&jobs[0], &jobs[1]]), create_route_with_jobs(&fleet, "v2", &[])];
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), 0.);
+}
+
+#[test]
+fn can_keep_affinity_groups_on_one_route() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").capacity(2).build(),
+            VehicleBuilder::default().id("v2").capacity(2).build(),
+        ])
+        .build();
+    // NOTE without affinity, closest jobs from different groups would share a route
+    let jobs = vec![
+        create_affinity_job("a1", 10, "a"),
+        create_affinity_job("b1", 12, "b"),
+        create_affinity_job("a2", 30, "a"),
+        create_affinity_job("b2", 32, "b"),
+    ];
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+            create_affinity_feature("affinity").unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["affinity"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    // NOTE order of jobs within a route is not relevant here as both directions have the same cost
+    let mut routes = get_customer_ids_from_routes(&result);
+    routes.iter_mut().for_each(|route| route.sort());
+    routes.sort();
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(routes, vec![vec!["a1", "a2"], vec!["b1", "b2"]]);
+    assert_eq!(result.problem.goal.fitness(&result).next(), Some(0.));
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route_ctx(fleet: &Fleet, locations: &[Location]) -> RouteContext {
+    create_route_context_with_activities(
+        fleet,
+        "v1",
+        locations.iter().map(|location| test_activity_with_location(*location)).collect(),
+    )
+}
+
+parameterized_test! {can_calculate_backtracking_fitness, (locations, expected), {
+    can_calculate_backtracking_fitness_impl(locations, expected);
+}}
+
+can_calculate_backtracking_fitness! {
+    case_01_monotone: (&[2, 4, 10, 12], 0.),
+    case_02_back_and_forth: (&[10, 2, 12, 4], 10.),
+    case_03_zigzag: (&[10, 2, 12, 4, 14], 20.),
+    case_04_same_distance: (&[10, 10, 12], 0.),
+}
+
+fn can_calculate_backtracking_fitness_impl(locations: &[Location], expected: Cost) {
+    let fleet = test_fleet();
+    let feature = create_backtracking_feature("backtracking", TestTransportCost::new_shared(), 10.).unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(create_route_ctx(&fleet, locations));
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_penalize_back_and_forth_route_over_monotone_one() {
+    let fleet = test_fleet();
+    let feature = create_backtracking_feature("backtracking", TestTransportCost::new_shared(), 1.).unwrap();
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+    let (mut monotone, mut back_and_forth) = (create_empty_insertion_context(), create_empty_insertion_context());
+    monotone.solution.routes.push(create_route_ctx(&fleet, &[2, 4, 10, 12]));
+    back_and_forth.solution.routes.push(create_route_ctx(&fleet, &[10, 2, 12, 4]));
+    state.accept_solution_state(&mut monotone.solution);
+    state.accept_solution_state(&mut back_and_forth.solution);
+
+    let result = objective.total_order(&monotone, &back_and_forth);
+
+    assert_eq!(result, Ordering::Less);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (index, expected), {
+    can_estimate_activity_insertion_impl(index, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case_01_before_outward_job: (0, 1.),
+    case_02_between_jobs: (1, 0.),
+    case_03_on_the_way_back: (2, 0.),
+}
+
+fn can_estimate_activity_insertion_impl(index: usize, expected: Cost) {
+    let fleet = test_fleet();
+    let feature = create_backtracking_feature("backtracking", TestTransportCost::new_shared(), 1.).unwrap();
+    let route_ctx = create_route_ctx(&fleet, &[2, 10]);
+    let target = test_activity_with_location(4);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_return_error_for_negative_penalty() {
+    assert!(create_backtracking_feature("backtracking", TestTransportCost::new_shared(), -1.).is_err());
+}
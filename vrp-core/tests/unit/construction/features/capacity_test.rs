@@ -1,12 +1,15 @@
 use super::*;
 use crate::construction::heuristics::{ActivityContext, RouteState};
 use crate::helpers::construction::features::*;
-use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::domain::*;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
-use crate::models::common::{Demand, DemandDimension, SingleDimLoad};
-use crate::models::problem::{Job, Vehicle};
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::{Demand, DemandDimension, Location, SafetyStockDimension, SeatDimension, SingleDimLoad};
+use crate::models::problem::{Job, Vehicle, VehicleDetail};
 use crate::models::solution::Activity;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
 use std::sync::Arc;
 
 const STATE_KEY: StateKey = 2;
@@ -92,6 +95,31 @@ fn can_evaluate_demand_on_route_impl(size: i32, expected: Option<ConstraintViola
     assert_eq!(result, expected);
 }
 
+parameterized_test! {can_evaluate_demand_on_route_with_zero_capacity, (size, expected), {
+    can_evaluate_demand_on_route_with_zero_capacity_impl(size, expected);
+}}
+
+can_evaluate_demand_on_route_with_zero_capacity! {
+    case01_pickup: (1, Some(ConstraintViolation { code: VIOLATION_CODE, stopped: true })),
+    case02_delivery: (-1, Some(ConstraintViolation { code: VIOLATION_CODE, stopped: true })),
+    case03_no_demand: (0, None),
+}
+
+fn can_evaluate_demand_on_route_with_zero_capacity_impl(size: i32, expected: Option<ConstraintViolation>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(0)).build();
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let job = if size != 0 {
+        Job::Single(test_single_with_simple_demand(create_simple_demand(size)))
+    } else {
+        SingleBuilder::default().id("job1").build_as_job_ref()
+    };
+
+    let result = create_feature().constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, expected);
+}
+
 parameterized_test! {can_evaluate_demand_on_activity, (sizes, neighbours, size, expected), {
     can_evaluate_demand_on_activity_impl(sizes, neighbours, size, expected);
 }}
@@ -189,3 +217,288 @@ fn can_merge_jobs_with_demand_impl(
         (Err(result), Err(expected)) => assert_eq!(result, expected),
     }
 }
+
+parameterized_test! {can_estimate_soft_capacity_penalty, (sizes, size, expected), {
+    can_estimate_soft_capacity_penalty_impl(sizes, size, expected);
+}}
+
+can_estimate_soft_capacity_penalty! {
+    case01: (vec![-2], -3, 0.),
+    case02: (vec![-5], -3, 2.),
+    case03: (vec![-7], -2, 2.),
+    case04: (vec![], -8, 2.),
+}
+
+fn can_estimate_soft_capacity_penalty_impl(sizes: Vec<i32>, size: i32, expected: f64) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        sizes
+            .into_iter()
+            .map(|size| test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size))))
+            .collect(),
+    );
+    create_feature().state.unwrap().accept_route_state(&mut route_ctx);
+    let job = Job::Single(test_single_with_simple_demand(create_simple_demand(size)));
+    let solution_ctx = create_empty_solution_context();
+    let objective =
+        create_soft_capacity_feature::<SingleDimLoad>("soft_capacity", 0.6, 10.).unwrap().objective.unwrap();
+
+    let result = objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert!((result - expected).abs() < 1E-6);
+}
+
+parameterized_test! {can_validate_soft_capacity_parameters, (threshold, penalty_rate, is_ok), {
+    assert_eq!(create_soft_capacity_feature::<SingleDimLoad>("soft_capacity", threshold, penalty_rate).is_ok(), is_ok);
+}}
+
+can_validate_soft_capacity_parameters! {
+    case01: (0.5, 1., true),
+    case02: (1., 0., true),
+    case03: (0., 1., false),
+    case04: (1.1, 1., false),
+    case05: (0.5, -1., false),
+}
+
+parameterized_test! {can_avoid_loading_past_soft_capacity_unless_necessary, (jobs, expected), {
+    can_avoid_loading_past_soft_capacity_unless_necessary_impl(jobs, expected);
+}}
+
+can_avoid_loading_past_soft_capacity_unless_necessary! {
+    case01_below_threshold_when_possible: (4, vec![6, 6]),
+    case02_above_threshold_when_necessary: (6, vec![9, 9]),
+}
+
+fn can_avoid_loading_past_soft_capacity_unless_necessary_impl(jobs: usize, expected: Vec<i32>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").capacity(10).costs(fixed_costs()).build(),
+            VehicleBuilder::default().id("v2").capacity(10).costs(fixed_costs()).build(),
+        ])
+        .build();
+    let jobs = (0..jobs)
+        .map(|idx| {
+            SingleBuilder::default()
+                .id(format!("job{idx}").as_str())
+                .location(Some(idx + 1))
+                .demand(create_simple_demand(-3))
+                .build_as_job_ref()
+        })
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", VIOLATION_CODE).unwrap(),
+            create_soft_capacity_feature::<SingleDimLoad>("soft_capacity", 0.6, 100.).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                VIOLATION_CODE + 1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["soft_capacity"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    let mut loads = result
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.route().tour.job_count() as i32 * 3)
+        .collect::<Vec<_>>();
+    loads.sort();
+    assert_eq!(loads, expected);
+}
+
+#[test]
+fn can_serve_only_jobs_without_demand_with_zero_capacity_vehicle() {
+    // NOTE vehicles should be of different types to be offered by registry at the same time
+    let inspector_details = vec![VehicleDetail { end: None, ..test_vehicle_detail() }];
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default()
+                .id("inspector")
+                .capacity(0)
+                .costs(empty_costs())
+                .details(inspector_details)
+                .build(),
+            VehicleBuilder::default().id("truck").capacity(10).costs(fixed_costs()).build(),
+        ])
+        .build();
+    let jobs = vec![
+        SingleBuilder::default().id("service1").location(Some(1)).build_as_job_ref(),
+        SingleBuilder::default().id("service2").location(Some(2)).build_as_job_ref(),
+        SingleBuilder::default().id("delivery1").location(Some(3)).demand(create_simple_demand(-1)).build_as_job_ref(),
+        SingleBuilder::default().id("pickup1").location(Some(4)).demand(create_simple_demand(1)).build_as_job_ref(),
+    ];
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", VIOLATION_CODE).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                VIOLATION_CODE + 1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    let get_route = |vehicle_id: &str| {
+        result
+            .solution
+            .routes
+            .iter()
+            .find(|route_ctx| route_ctx.route().actor.vehicle.dimens.get_id().map(String::as_str) == Some(vehicle_id))
+            .expect("cannot find route")
+    };
+    let get_job_ids = |route_ctx: &RouteContext| {
+        let mut ids =
+            route_ctx.route().tour.jobs().map(|job| job.dimens().get_id().unwrap().clone()).collect::<Vec<_>>();
+        ids.sort();
+        ids
+    };
+    assert_eq!(get_job_ids(get_route("inspector")), vec!["service1", "service2"]);
+    assert_eq!(get_job_ids(get_route("truck")), vec!["delivery1", "pickup1"]);
+    assert_eq!(get_route("inspector").state().get_route_state::<f64>(MAX_LOAD_KEY), Some(&0.));
+}
+
+parameterized_test! {can_reuse_freed_seats_for_rides, (seats, rides, expected_unassigned), {
+    can_reuse_freed_seats_for_rides_impl(seats, rides, expected_unassigned);
+}}
+
+can_reuse_freed_seats_for_rides! {
+    case01_more_passengers_than_seats_in_total: (4, vec![(10, 20, 2), (30, 40, 3), (50, 60, 2)], 0),
+    case02_ride_does_not_fit_into_seats: (4, vec![(10, 20, 2), (30, 40, 5)], 1),
+}
+
+fn can_reuse_freed_seats_for_rides_impl(seats: i32, rides: Vec<(Location, Location, i32)>, expected_unassigned: usize) {
+    let mut vehicle = VehicleBuilder::default().id("v1").build();
+    vehicle.dimens.set_seats(seats);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let jobs = rides
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (pickup, dropoff, passengers))| {
+            let mut pickup = SingleBuilder::default().id("pickup").location(Some(pickup)).build();
+            pickup.dimens.set_pickup_passengers(passengers);
+            let mut dropoff = SingleBuilder::default().id("dropoff").location(Some(dropoff)).build();
+            dropoff.dimens.set_dropoff_passengers(passengers);
+
+            Job::Multi(test_multi_with_id(format!("ride{idx}").as_str(), vec![Arc::new(pickup), Arc::new(dropoff)]))
+        })
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", VIOLATION_CODE).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                VIOLATION_CODE + 1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert_eq!(result.solution.unassigned.len(), expected_unassigned);
+    assert_eq!(result.solution.routes.len(), 1);
+    assert_eq!(result.solution.routes[0].route().actor.vehicle.dimens.get_seats(), Some(seats));
+}
+
+parameterized_test! {can_fit_less_jobs_with_safety_stock, (safety_stock, expected_assigned), {
+    can_fit_less_jobs_with_safety_stock_impl(safety_stock, expected_assigned);
+}}
+
+can_fit_less_jobs_with_safety_stock! {
+    case01_base_demand: (None, 3),
+    case02_buffered_demand: (Some(2), 2),
+    case03_buffered_demand_over_capacity: (Some(8), 0),
+}
+
+fn can_fit_less_jobs_with_safety_stock_impl(safety_stock: Option<i32>, expected_assigned: usize) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let jobs = (0..4)
+        .map(|idx| {
+            let mut single = SingleBuilder::default()
+                .id(format!("job{idx}").as_str())
+                .location(Some(idx + 1))
+                .demand(create_simple_demand(-3))
+                .build();
+            if let Some(safety_stock) = safety_stock {
+                single.dimens.set_safety_stock(SingleDimLoad::new(safety_stock));
+            }
+
+            Job::Single(Arc::new(single))
+        })
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", VIOLATION_CODE).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                VIOLATION_CODE + 1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    let assigned = result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>();
+    assert_eq!(assigned, expected_assigned);
+    assert_eq!(result.solution.unassigned.len(), 4 - expected_assigned);
+}
+
+#[test]
+fn can_add_safety_stock_to_non_empty_demand_parts() {
+    let demand = Demand::<SingleDimLoad> {
+        pickup: (SingleDimLoad::new(2), SingleDimLoad::default()),
+        delivery: (SingleDimLoad::default(), SingleDimLoad::new(3)),
+    };
+
+    let buffered = demand.with_safety_stock(SingleDimLoad::new(1));
+
+    assert_eq!(buffered.pickup, (SingleDimLoad::new(3), SingleDimLoad::default()));
+    assert_eq!(buffered.delivery, (SingleDimLoad::default(), SingleDimLoad::new(4)));
+}
+
+#[test]
+fn can_detect_oversized_jobs() {
+    let problem = ProblemBuilder::default()
+        .add_job("job1", 1, -3, (0., 100.))
+        .add_job("job2", 2, 5, (0., 100.))
+        .add_job("job3", 3, -6, (0., 100.))
+        .add_vehicle("v1", 3, 0)
+        .add_vehicle("v2", 5, 0)
+        .build();
+
+    let oversized = detect_oversized_jobs::<SingleDimLoad>(&problem);
+
+    assert_eq!(oversized, vec!["job3".to_string()]);
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use std::cmp::Ordering;
+
+const RESOLUTION: f64 = 10.;
+
+fn create_coordinates() -> Vec<(f64, f64)> {
+    vec![(0., 0.), (1., 1.), (2., 2.), (15., 1.), (25., 1.), (1., 15.)]
+}
+
+fn create_route_ctx(locations: &[Location]) -> RouteContext {
+    create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        locations.iter().map(|location| test_activity_with_location(*location)).collect(),
+    )
+}
+
+fn create_insertion_ctx(feature: &Feature, routes: &[&[Location]]) -> InsertionContext {
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = routes.iter().map(|locations| create_route_ctx(locations)).collect();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+parameterized_test! {can_calculate_coverage_fitness, (routes, expected), {
+    can_calculate_coverage_fitness_impl(routes, expected);
+}}
+
+can_calculate_coverage_fitness! {
+    case_01_same_cell: (vec![vec![1, 2]], -1.),
+    case_02_different_cells: (vec![vec![1, 3, 4]], -3.),
+    case_03_shared_cell_across_routes: (vec![vec![1, 3], vec![2, 5]], -3.),
+    case_04_empty: (vec![], 0.),
+}
+
+fn can_calculate_coverage_fitness_impl(routes: Vec<Vec<Location>>, expected: Cost) {
+    let routes = routes.iter().map(|locations| locations.as_slice()).collect::<Vec<_>>();
+    let feature = create_coverage_feature("coverage", create_coordinates(), RESOLUTION).unwrap();
+    let insertion_ctx = create_insertion_ctx(&feature, routes.as_slice());
+
+    let result = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_prefer_route_covering_more_cells_over_shorter_one() {
+    let feature = create_coverage_feature("coverage", create_coordinates(), RESOLUTION).unwrap();
+    // NOTE the short route visits two nearby locations within the same cell
+    let short = create_insertion_ctx(&feature, &[&[1, 2]]);
+    let wide = create_insertion_ctx(&feature, &[&[1, 3]]);
+
+    let result = feature.objective.as_ref().unwrap().total_order(&wide, &short);
+
+    assert_eq!(result, Ordering::Less);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (location, expected), {
+    can_estimate_activity_insertion_impl(location, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case_01_covered_cell: (2, 0.),
+    case_02_new_cell: (3, -1.),
+    case_03_no_coordinates: (10, 0.),
+}
+
+fn can_estimate_activity_insertion_impl(location: Location, expected: Cost) {
+    let feature = create_coverage_feature("coverage", create_coordinates(), RESOLUTION).unwrap();
+    let mut route_ctx = create_route_ctx(&[1]);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location(location);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let result = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_return_error_for_non_positive_resolution() {
+    assert!(create_coverage_feature("coverage", create_coordinates(), 0.).is_err());
+}
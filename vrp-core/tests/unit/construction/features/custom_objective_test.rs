@@ -0,0 +1,95 @@
+use super::*;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::ThreadPool;
+
+const PER_VEHICLE: Cost = 100.;
+
+fn get_distance_and_vehicles(solution: &SolutionContext, problem: &Problem) -> f64 {
+    let distance = solution
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.route())
+        .flat_map(|route| {
+            route.tour.all_activities().zip(route.tour.all_activities().skip(1)).map(move |(from, to)| {
+                let departure = TravelTime::Departure(from.schedule.departure);
+                problem.transport.distance(route, from.place.location, to.place.location, departure)
+            })
+        })
+        .sum::<f64>();
+
+    distance + PER_VEHICLE * solution.routes.len() as f64
+}
+
+fn create_problem() -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+            .build(),
+    );
+    let jobs = (1..=4)
+        .map(|idx| SingleBuilder::default().id(format!("c{idx}").as_str()).location(Some(idx)).build_as_job_ref())
+        .collect();
+
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_custom_objective_feature("custom", Arc::new(get_distance_and_vehicles)).unwrap(),
+            create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1).unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["custom"], vec!["transport"]],
+    );
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    })
+}
+
+#[test]
+fn can_use_closure_as_objective_fitness() {
+    let insertion_ctx = create_empty_insertion_context();
+    let objective =
+        ClosureObjective::new(Arc::new(|solution: &SolutionContext, _: &Problem| solution.required.len() as f64 + 0.5));
+
+    assert_eq!(objective.fitness(&insertion_ctx), 0.5);
+}
+
+#[test]
+fn can_optimize_custom_objective_combining_distance_and_vehicle_count() {
+    let problem = create_problem();
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(50))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+    let distance = solution
+        .routes
+        .iter()
+        .flat_map(|route| route.tour.all_activities().zip(route.tour.all_activities().skip(1)))
+        .map(|(from, to)| (from.place.location as f64 - to.place.location as f64).abs())
+        .sum::<f64>();
+    assert_eq!(distance, 8.);
+}
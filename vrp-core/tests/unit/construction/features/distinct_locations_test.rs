@@ -0,0 +1,114 @@
+use super::*;
+use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::SingleDimLoad;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+fn create_job(id: &str, locations: &[Location]) -> Job {
+    SingleBuilder::default()
+        .id(id)
+        .places(locations.iter().map(|location| (Some(*location), 0., vec![(0., 1000.)])).collect())
+        .demand(create_simple_demand(-1))
+        .build_as_job_ref()
+}
+
+#[test]
+fn can_calculate_fitness_as_amount_of_distinct_locations() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![
+        create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![test_activity_with_location(1), test_activity_with_location(2), test_activity_with_location(2)],
+        ),
+        create_route_context_with_activities(
+            &fleet,
+            "v2",
+            vec![test_activity_with_location(2), test_activity_with_location(3)],
+        ),
+    ];
+    let feature = create_minimize_distinct_locations_feature("distinct_locations").unwrap();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), 3.);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (location, expected), {
+    can_estimate_activity_insertion_impl(location, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case_01_visited_location: (10, 0.),
+    case_02_new_location: (5, 1.),
+}
+
+fn can_estimate_activity_insertion_impl(location: Location, expected: Cost) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_location(10)]);
+    let feature = create_minimize_distinct_locations_feature("distinct_locations").unwrap();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location(location);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_prefer_already_visited_location_at_equal_distance() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(2).build())
+        .build();
+    // NOTE c2 at location 5 is on the way to c1, so both alternatives have the same distance
+    let jobs = vec![create_job("c1", &[10]), create_job("c2", &[5, 10])];
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+            create_minimize_distinct_locations_feature("distinct_locations").unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["distinct_locations"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let recreate = RecreateWithCheapest::new(test_random());
+    let mut insertion_ctx = InsertionContext::new(problem, Arc::new(Environment::default()));
+    // NOTE insert c1 first, so c2 has a choice between visited and a new location
+    let c2 = insertion_ctx.solution.unassigned.keys().find(|job| get_customer_id(job) == "c2").cloned().unwrap();
+    insertion_ctx.solution.unassigned.remove(&c2);
+    insertion_ctx.solution.ignored.push(c2);
+    let mut insertion_ctx = recreate.run(&refinement_ctx, insertion_ctx);
+    let c2 = insertion_ctx.solution.ignored.pop().unwrap();
+    insertion_ctx.solution.required.push(c2);
+
+    let result = recreate.run(&refinement_ctx, insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(
+        result.solution.routes[0].route().tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>(),
+        vec![0, 10, 10, 0]
+    );
+    assert_eq!(result.problem.goal.fitness(&result).next(), Some(1.));
+}
@@ -1,6 +1,11 @@
 use super::*;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
 use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
 use std::cmp::Ordering;
 
 fn create_test_insertion_ctx(routes: &[f64]) -> InsertionContext {
@@ -53,3 +58,194 @@ fn can_properly_estimate_solutions_impl(left: &[f64], right: &[f64], expected: O
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_estimate_target_tours_fitness, (routes, target, expected), {
+    can_estimate_target_tours_fitness_impl(routes, target, expected);
+}}
+
+can_estimate_target_tours_fitness! {
+    case_01_below_target: (1, 3, 2.),
+    case_02_at_target: (3, 3, 0.),
+    case_03_above_target: (4, 3, 1.),
+}
+
+fn can_estimate_target_tours_fitness_impl(routes: usize, target: usize, expected: f64) {
+    let insertion_ctx = create_test_insertion_ctx(vec![10.; routes].as_slice());
+
+    let result =
+        create_target_tours_feature("target_tours", target).unwrap().objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_use_target_amount_of_tours, target, {
+    can_use_target_amount_of_tours_impl(target);
+}}
+
+can_use_target_amount_of_tours! {
+    case_01_one: 1,
+    case_02_three: 3,
+    case_03_all: 4,
+}
+
+fn can_use_target_amount_of_tours_impl(target: usize) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles((1..=4).map(|idx| test_vehicle_with_id(format!("v{idx}").as_str())).collect())
+        .build();
+    let jobs = (1..=4)
+        .map(|idx| SingleBuilder::default().id(format!("job{idx}").as_str()).location(Some(idx)).build_as_job_ref())
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_target_tours_feature("target_tours", target).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["target_tours"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.len(), target);
+}
+
+parameterized_test! {can_estimate_soft_fleet_size_fitness, (routes, target, expected), {
+    can_estimate_soft_fleet_size_fitness_impl(routes, target, expected);
+}}
+
+can_estimate_soft_fleet_size_fitness! {
+    case_01_below_target: (1, 2, 0.),
+    case_02_at_target: (2, 2, 0.),
+    case_03_one_extra: (3, 2, 10.),
+    case_04_two_extra: (4, 2, 25.),
+    case_05_three_extra: (5, 2, 45.),
+}
+
+fn can_estimate_soft_fleet_size_fitness_impl(routes: usize, target: usize, expected: f64) {
+    let insertion_ctx = create_test_insertion_ctx(vec![10.; routes].as_slice());
+
+    let result = create_soft_fleet_size_feature("fleet_size", target, 10., 5.)
+        .unwrap()
+        .objective
+        .unwrap()
+        .fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_return_error_for_negative_fleet_size_penalty() {
+    assert!(create_soft_fleet_size_feature("fleet_size", 1, -1., 0.).is_err());
+    assert!(create_soft_fleet_size_feature("fleet_size", 1, 1., -1.).is_err());
+}
+
+parameterized_test! {can_use_extra_vehicles_only_when_savings_exceed_penalty, (penalty, increment, expected), {
+    can_use_extra_vehicles_only_when_savings_exceed_penalty_impl(penalty, increment, expected);
+}}
+
+can_use_extra_vehicles_only_when_savings_exceed_penalty! {
+    case_01_penalty_exceeds_savings: (1000., 0., 1),
+    case_02_savings_exceed_penalty: (100., 0., 3),
+    case_03_escalated_penalty_exceeds_savings: (100., 800., 2),
+}
+
+fn can_use_extra_vehicles_only_when_savings_exceed_penalty_impl(penalty: Cost, increment: Cost, expected: usize) {
+    // NOTE each vehicle has its own depot next to one job, serving a job from a neighbor depot costs
+    // 792 more than using a dedicated vehicle
+    let create_vehicle = |id: &str, location: Location| {
+        let detail = test_vehicle_detail();
+        VehicleBuilder::default()
+            .id(id)
+            .details(vec![VehicleDetail {
+                start: detail.start.map(|start| VehiclePlace { location, ..start }),
+                end: detail.end.map(|end| VehiclePlace { location, ..end }),
+            }])
+            .build()
+    };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![create_vehicle("v1", 0), create_vehicle("v2", 100), create_vehicle("v3", 200)])
+        .build();
+    let jobs = [1, 101, 201]
+        .iter()
+        .map(|&location| {
+            SingleBuilder::default().id(format!("job{location}").as_str()).location(Some(location)).build_as_job_ref()
+        })
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_soft_fleet_size_feature("fleet_size", 1, penalty, increment).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["fleet_size", "transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.len(), expected);
+}
+
+#[test]
+fn can_use_only_fixed_routes() {
+    // NOTE vehicles are not interchangeable here, so each actor gets its own group
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        (1..=4).map(|idx| Arc::new(test_vehicle_with_id(format!("v{idx}").as_str()))).collect(),
+        Box::new(|actors| {
+            let actors = actors.to_vec();
+            Box::new(move |actor| actors.iter().position(|other| other == actor).unwrap())
+        }),
+    );
+    let jobs = (1..=4)
+        .map(|idx| SingleBuilder::default().id(format!("job{idx}").as_str()).location(Some(idx)).build_as_job_ref())
+        .collect();
+    let vehicle_ids = ["v2", "v3"].iter().map(|id| id.to_string()).collect::<HashSet<_>>();
+    // NOTE maximize tours objective pushes solver to open as many routes as possible
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_fixed_routes_feature("fixed_routes", vehicle_ids.clone(), 1).unwrap(),
+            create_maximize_tours_feature("max_tours").unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                2,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["max_tours"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.len(), 2);
+    assert!(result.solution.routes.iter().all(|route_ctx| {
+        route_ctx.route().actor.vehicle.dimens.get_id().map_or(false, |id| vehicle_ids.contains(id))
+    }));
+}
+
+#[test]
+fn can_return_error_for_empty_fixed_routes() {
+    assert!(create_fixed_routes_feature("fixed_routes", HashSet::new(), 1).is_err());
+}
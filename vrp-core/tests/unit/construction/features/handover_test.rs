@@ -0,0 +1,154 @@
+use super::*;
+use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use hashbrown::HashSet;
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::ThreadPool;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_consignment_job(id: &str, consignment: &str) -> Arc<Single> {
+    let mut single = SingleBuilder::default().id(id).location(Some(1)).demand(create_simple_demand(-1)).build();
+    single.dimens.set_consignment(consignment);
+
+    Arc::new(single)
+}
+
+fn create_route_with_visits(fleet: &Fleet, vehicle: &str, visits: Vec<(&Arc<Single>, Timestamp)>) -> RouteContext {
+    let activities = visits
+        .into_iter()
+        .map(|(job, arrival)| {
+            ActivityBuilder::default().job(Some(job.clone())).schedule(Schedule::new(arrival, arrival)).build()
+        })
+        .collect();
+    let mut route_ctx = create_route_context_with_activities(fleet, vehicle, activities);
+
+    HandoverState { state_keys: vec![] }.accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_test_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build()
+}
+
+parameterized_test! {can_count_handovers_in_fitness, (routes, expected), {
+    can_count_handovers_in_fitness_impl(routes, expected);
+}}
+
+can_count_handovers_in_fitness! {
+    case_01_single_vehicle: (vec![("v1", vec![(0, 10.), (1, 20.), (2, 30.), (3, 40.)])], 0.),
+    case_02_one_transfer: (vec![("v1", vec![(0, 10.), (1, 20.)]), ("v2", vec![(2, 30.), (3, 40.)])], 1.),
+    case_03_transfer_back: (vec![("v1", vec![(0, 10.), (3, 40.)]), ("v2", vec![(1, 20.), (2, 30.)])], 2.),
+    case_04_unordered_tour: (vec![("v1", vec![(1, 20.), (0, 10.)]), ("v2", vec![(2, 30.), (3, 40.)])], 1.),
+}
+
+fn can_count_handovers_in_fitness_impl(routes: Vec<(&str, Vec<(usize, Timestamp)>)>, expected: f64) {
+    let fleet = create_test_fleet();
+    let jobs = (0..4).map(|idx| create_consignment_job(format!("job{idx}").as_str(), "c1")).collect::<Vec<_>>();
+    let objective = create_handover_feature("handover").unwrap().objective.unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = routes
+        .into_iter()
+        .map(|(vehicle, visits)| {
+            let visits = visits.into_iter().map(|(job_idx, arrival)| (&jobs[job_idx], arrival)).collect();
+            create_route_with_visits(&fleet, vehicle, visits)
+        })
+        .collect();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_estimate_route_which_adds_handover() {
+    let fleet = create_test_fleet();
+    let (job1, job2) = (create_consignment_job("job1", "c1"), create_consignment_job("job2", "c1"));
+    let other = Job::Single(create_consignment_job("job3", "c2"));
+    let objective = create_handover_feature("handover").unwrap().objective.unwrap();
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_with_visits(&fleet, "v1", vec![(&job1, 10.)]));
+    let other_route = create_route_with_visits(&fleet, "v2", vec![]);
+    let job2 = Job::Single(job2);
+
+    let same_cost = objective.estimate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job2));
+    let other_cost = objective.estimate(&MoveContext::route(&solution_ctx, &other_route, &job2));
+    let unrelated_cost = objective.estimate(&MoveContext::route(&solution_ctx, &other_route, &other));
+
+    assert_eq!(same_cost, 0.);
+    assert_eq!(other_cost, 1.);
+    assert_eq!(unrelated_cost, 0.);
+}
+
+#[test]
+fn can_prefer_solution_with_fewer_handovers_at_equal_transport_cost() {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![
+                VehicleBuilder::default().id("v1").capacity(2).build(),
+                VehicleBuilder::default().id("v2").capacity(2).build(),
+            ])
+            .build(),
+    );
+    // NOTE all jobs share the same location, so any assignment of two jobs per vehicle has the same cost
+    let jobs =
+        ["a1", "b1", "a2", "b2"].iter().map(|id| Job::Single(create_consignment_job(id, &id[..1]))).collect::<Vec<_>>();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", VIOLATION_CODE).unwrap(),
+            create_handover_feature("handover").unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                transport.clone(),
+                activity.clone(),
+                VIOLATION_CODE + 1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["handover"], vec!["transport"]],
+    );
+    let problem = Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    });
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(50))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 2);
+    solution.routes.iter().for_each(|route| {
+        let consignments = route
+            .tour
+            .jobs()
+            .filter_map(|job| job.as_single().and_then(|single| single.dimens.get_consignment()).cloned())
+            .collect::<HashSet<_>>();
+        assert_eq!(consignments.len(), 1);
+    });
+}
@@ -0,0 +1,354 @@
+use super::*;
+use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::ThreadPool;
+use std::cmp::Ordering;
+
+const PER_IDLE_TIME: Cost = 2.;
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_feature() -> Feature {
+    create_minimize_idle_time_feature(
+        "idle",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        PER_IDLE_TIME,
+    )
+    .unwrap()
+}
+
+fn create_insertion_ctx_with_route(stops: &[(Location, Timestamp)]) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![create_route_ctx_with_stops(&fleet, stops)];
+
+    insertion_ctx
+}
+
+fn create_route_ctx_with_stops(fleet: &Fleet, stops: &[(Location, Timestamp)]) -> RouteContext {
+    let mut route_ctx = create_route_context_with_activities(
+        fleet,
+        "v1",
+        stops
+            .iter()
+            .map(|(location, start)| test_activity_with_location_and_tw(*location, TimeWindow::new(*start, 1000.)))
+            .collect(),
+    );
+    update_route_schedule(
+        &mut route_ctx,
+        &TestActivityCost::default(),
+        &TestTransportCost::default(),
+        &ScheduleStateKeys::default(),
+    );
+
+    route_ctx
+}
+
+parameterized_test! {can_estimate_idle_time_of_activity, (tw_start, expected), {
+    can_estimate_idle_time_of_activity_impl(tw_start, expected);
+}}
+
+can_estimate_idle_time_of_activity! {
+    case_01_no_waiting: (0., 0.),
+    case_02_arrival_before_tw: (30., 20. * PER_IDLE_TIME),
+}
+
+fn can_estimate_idle_time_of_activity_impl(tw_start: Timestamp, expected: Cost) {
+    let insertion_ctx = create_insertion_ctx_with_route(&[]);
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let target = test_activity_with_location_and_tw(10, TimeWindow::new(tw_start, 1000.));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = create_feature().objective.unwrap().estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_prefer_solution_with_less_idle_time_when_distance_is_equal() {
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_distance_feature(
+                "distance",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+            create_feature(),
+        ],
+        vec![vec!["distance"], vec!["idle"]],
+    );
+    // NOTE both tours have the same distance, but the first one waits at the first stop
+    let waiting = create_insertion_ctx_with_route(&[(10, 30.), (20, 0.)]);
+    let no_waiting = create_insertion_ctx_with_route(&[(20, 0.), (10, 30.)]);
+
+    assert_eq!(goal.fitness(&waiting).collect::<Vec<_>>(), vec![40., 20. * PER_IDLE_TIME]);
+    assert_eq!(goal.fitness(&no_waiting).collect::<Vec<_>>(), vec![40., 0.]);
+    assert_eq!(goal.total_order(&no_waiting, &waiting), Ordering::Less);
+}
+
+parameterized_test! {can_evaluate_fleet_idle_time_limit, (max_idle_time, expected), {
+    can_evaluate_fleet_idle_time_limit_impl(max_idle_time, expected);
+}}
+
+can_evaluate_fleet_idle_time_limit! {
+    case_01_exceeds_limit: (25., Some(VIOLATION_CODE)),
+    case_02_equals_limit: (30., None),
+    case_03_within_limit: (40., None),
+}
+
+fn can_evaluate_fleet_idle_time_limit_impl(max_idle_time: Duration, expected: Option<ViolationCode>) {
+    let feature = create_idle_time_limit_feature(
+        "idle_limit",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        max_idle_time,
+    )
+    .unwrap();
+    // NOTE vehicle waits 20 at the first stop
+    let mut insertion_ctx = create_insertion_ctx_with_route(&[(10, 30.)]);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    // NOTE vehicle waits 10 more at the target
+    let target = test_activity_with_location_and_tw(20, TimeWindow::new(50., 1000.));
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_evaluate_fleet_idle_time_limit_for_new_route, (max_idle_time, expected), {
+    can_evaluate_fleet_idle_time_limit_for_new_route_impl(max_idle_time, expected);
+}}
+
+can_evaluate_fleet_idle_time_limit_for_new_route! {
+    case_01_exceeds_limit: (45., Some(VIOLATION_CODE)),
+    case_02_equals_limit: (50., None),
+}
+
+fn can_evaluate_fleet_idle_time_limit_for_new_route_impl(max_idle_time: Duration, expected: Option<ViolationCode>) {
+    let feature = create_idle_time_limit_feature(
+        "idle_limit",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        max_idle_time,
+    )
+    .unwrap();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    // NOTE vehicle of the used route waits 20 at the first stop
+    let mut solution_ctx = SolutionContext {
+        routes: vec![create_route_ctx_with_stops(&fleet, &[(10, 30.)])],
+        registry: create_registry_context(&fleet),
+        ..create_empty_solution_context()
+    };
+    let v1 = solution_ctx.routes[0].route().actor.clone();
+    solution_ctx.registry.get_route(&v1).unwrap();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.registry.next_route().next().unwrap();
+    assert_eq!(route_ctx.route().actor.vehicle.dimens.get_id().unwrap(), "v2");
+    // NOTE vehicle of the new route waits 30 at the target
+    let target = test_activity_with_location_and_tw(20, TimeWindow::new(50., 1000.));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+fn create_problem_with_waiting(max_idle_time: Option<Duration>) -> Arc<Problem> {
+    // NOTE the shortest tour visits c1 first and waits for c2, the longest one visits c2 first without waiting
+    let matrix = vec![
+        0., 1., 20., //
+        10., 0., 1., //
+        1., 10., 0., //
+    ];
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, matrix.clone(), matrix)]).unwrap();
+    let activity = TestActivityCost::new_shared();
+
+    let detail = test_vehicle_detail();
+    let detail = VehicleDetail {
+        start: detail
+            .start
+            .map(|place| VehiclePlace { time: TimeInterval { earliest: Some(0.), latest: Some(0.) }, ..place }),
+        ..detail
+    };
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicle(VehicleBuilder::default().id("v1").capacity(2).details(vec![detail]).build())
+            .build(),
+    );
+    let jobs = vec![
+        SingleBuilder::default().id("c1").location(Some(1)).build_as_job_ref(),
+        SingleBuilder::default().id("c2").location(Some(2)).times(vec![TimeWindow::new(15., 1000.)]).build_as_job_ref(),
+    ];
+
+    let mut features = vec![
+        create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+        create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 2).unwrap(),
+    ];
+    if let Some(max_idle_time) = max_idle_time {
+        features.push(
+            create_idle_time_limit_feature(
+                "idle_limit",
+                VIOLATION_CODE,
+                transport.clone(),
+                activity.clone(),
+                max_idle_time,
+            )
+            .unwrap(),
+        );
+    }
+    let goal = create_goal_ctx_with_features(features, vec![vec!["min_unassigned"], vec!["transport"]]);
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    })
+}
+
+fn solve_and_get_tour(problem: Arc<Problem>) -> Vec<String> {
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(10))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+
+    solution.routes[0]
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .map(|job| get_customer_id(&job))
+        .collect()
+}
+
+#[test]
+fn can_reorder_jobs_to_keep_fleet_idle_time_under_limit() {
+    let unconstrained = solve_and_get_tour(create_problem_with_waiting(None));
+    let constrained = solve_and_get_tour(create_problem_with_waiting(Some(5.)));
+
+    assert_eq!(unconstrained, vec!["c1", "c2"]);
+    assert_eq!(constrained, vec!["c2", "c1"]);
+}
+
+parameterized_test! {can_calculate_max_idle_gap_fitness, (stops, expected), {
+    can_calculate_max_idle_gap_fitness_impl(stops, expected);
+}}
+
+can_calculate_max_idle_gap_fitness! {
+    case_01_no_waiting: (&[(10, 0.), (20, 0.)], 0.),
+    case_02_two_short_gaps: (&[(10, 30.), (20, 60.)], 20. * PER_IDLE_TIME),
+    case_03_one_long_gap: (&[(10, 50.), (20, 0.)], 40. * PER_IDLE_TIME),
+}
+
+fn can_calculate_max_idle_gap_fitness_impl(stops: &[(Location, Timestamp)], expected: Cost) {
+    let insertion_ctx = create_insertion_ctx_with_route(stops);
+    let feature = create_minimize_max_idle_gap_feature(
+        "idle_gap",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        PER_IDLE_TIME,
+    )
+    .unwrap();
+
+    let result = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reorder_jobs_to_avoid_long_idle_gap_when_distance_is_equal() {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let detail = test_vehicle_detail();
+    let detail = VehicleDetail {
+        start: detail
+            .start
+            .map(|place| VehiclePlace { time: TimeInterval { earliest: Some(0.), latest: Some(0.) }, ..place }),
+        ..detail
+    };
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicle(VehicleBuilder::default().id("v1").details(vec![detail]).build())
+            .build(),
+    );
+    // NOTE all jobs share the location, so any order has the same distance, but serving c2 before c1
+    // makes the vehicle wait for c2 time window start
+    let jobs = vec![
+        SingleBuilder::default().id("c0").location(Some(10)).build_as_job_ref(),
+        SingleBuilder::default().id("c1").location(Some(10)).duration(40.).build_as_job_ref(),
+        SingleBuilder::default()
+            .id("c2")
+            .location(Some(10))
+            .times(vec![TimeWindow::new(50., 1000.)])
+            .build_as_job_ref(),
+    ];
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_minimize_distance_feature("distance", transport.clone(), activity.clone(), 1).unwrap(),
+            create_minimize_max_idle_gap_feature("idle_gap", transport.clone(), activity.clone(), PER_IDLE_TIME)
+                .unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["distance"], vec!["idle_gap"]],
+    );
+    let problem = Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    });
+
+    let tour = solve_and_get_tour(problem);
+
+    let position = |id: &str| tour.iter().position(|job_id| job_id == id).unwrap();
+    assert!(position("c1") < position("c2"));
+}
@@ -0,0 +1,174 @@
+use super::*;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::get_customer_id;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::ThreadPool;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_single(id: &str, location: Location, position: Option<JobPosition>) -> Arc<Single> {
+    let mut dimens = Dimensions::default();
+    dimens.set_id(id);
+    if let Some(position) = position {
+        dimens.set_job_position(position);
+    }
+
+    SingleBuilder::default().dimens(dimens).location(Some(location)).build_shared()
+}
+
+fn create_activity(id: &str, location: Location, position: Option<JobPosition>) -> Activity {
+    Activity { job: Some(create_single(id, location, position)), ..test_activity_with_location(location) }
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (tour, index, target, expected), {
+    can_evaluate_activity_insertion_impl(tour, index, target, expected);
+}}
+
+can_evaluate_activity_insertion! {
+    case_01_first_at_start: (vec![None, None], 0, Some(JobPosition::MustBeFirst), None),
+    case_02_first_in_middle: (vec![None, None], 1, Some(JobPosition::MustBeFirst), Some(VIOLATION_CODE)),
+    case_03_last_at_end: (vec![None, None], 2, Some(JobPosition::MustBeLast), None),
+    case_04_last_in_middle: (vec![None, None], 1, Some(JobPosition::MustBeLast), Some(VIOLATION_CODE)),
+    case_05_before_first: (vec![Some(JobPosition::MustBeFirst), None], 0, None, Some(VIOLATION_CODE)),
+    case_06_after_first: (vec![Some(JobPosition::MustBeFirst), None], 1, None, None),
+    case_07_before_last: (vec![None, Some(JobPosition::MustBeLast)], 1, None, None),
+    case_08_after_last: (vec![None, Some(JobPosition::MustBeLast)], 2, None, Some(VIOLATION_CODE)),
+    case_09_no_positions: (vec![None, None], 1, None, None),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    tour: Vec<Option<JobPosition>>,
+    index: usize,
+    target: Option<JobPosition>,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = tour
+        .into_iter()
+        .enumerate()
+        .map(|(idx, position)| create_activity(format!("job{idx}").as_str(), idx + 1, position))
+        .collect();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let target = create_activity("target", 10, target);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = create_job_position_feature("job_position", VIOLATION_CODE)
+        .unwrap()
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::activity(&route_ctx, &activity_ctx))
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case_01_no_positions: (None, None, Ok("source")),
+    case_02_source_position: (Some(JobPosition::MustBeFirst), None, Err(VIOLATION_CODE)),
+    case_03_candidate_position: (None, Some(JobPosition::MustBeLast), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(
+    source: Option<JobPosition>,
+    candidate: Option<JobPosition>,
+    expected: Result<&str, ViolationCode>,
+) {
+    let source = Job::Single(create_single("source", 1, source));
+    let candidate = Job::Single(create_single("candidate", 2, candidate));
+    let constraint = create_job_position_feature("job_position", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(source, candidate).map(|job| get_customer_id(&job));
+
+    assert_eq!(result, expected.map(|id| id.to_string()));
+}
+
+fn create_problem(last: Option<&str>) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let fleet =
+        Arc::new(FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build());
+    // NOTE key job is placed among other jobs, so it is not visited first in unconstrained tour
+    let jobs = (1..=8)
+        .map(|idx| {
+            let id = format!("c{idx}");
+            let position = if last == Some(id.as_str()) { Some(JobPosition::MustBeLast) } else { None };
+            Job::Single(create_single(id.as_str(), idx, position))
+        })
+        .chain(std::iter::once(Job::Single(create_single("key", 5, Some(JobPosition::MustBeFirst)))))
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 2).unwrap(),
+            create_job_position_feature("job_position", VIOLATION_CODE).unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["transport"]],
+    );
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    })
+}
+
+fn solve_and_get_tour(problem: Arc<Problem>) -> Vec<String> {
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(50))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+
+    solution.routes[0]
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .map(|job| get_customer_id(&job))
+        .collect()
+}
+
+parameterized_test! {can_keep_job_position_across_generations, last, {
+    can_keep_job_position_across_generations_impl(last);
+}}
+
+can_keep_job_position_across_generations! {
+    case_01_first_only: None,
+    case_02_first_and_last: Some("c8"),
+}
+
+fn can_keep_job_position_across_generations_impl(last: Option<&str>) {
+    let tour = solve_and_get_tour(create_problem(last));
+
+    assert_eq!(tour.len(), 9);
+    assert_eq!(tour.first().map(|id| id.as_str()), Some("key"));
+    if let Some(last) = last {
+        assert_eq!(tour.last().map(|id| id.as_str()), Some(last));
+    }
+}
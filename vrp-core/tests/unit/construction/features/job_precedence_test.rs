@@ -0,0 +1,241 @@
+use super::*;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::{create_empty_solution_context, create_registry_context, get_customer_id};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::ThreadPool;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_precedences(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(from, to)| (from.to_string(), to.to_string())).collect()
+}
+
+fn create_feature(pairs: &[(&str, &str)]) -> Result<Feature, String> {
+    create_job_precedence_feature(
+        "precedence",
+        VIOLATION_CODE,
+        create_precedences(pairs).as_slice(),
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+}
+
+fn create_activity(id: &str, location: Location) -> Activity {
+    Activity {
+        job: Some(test_single_with_id_and_location(id, Some(location))),
+        ..test_activity_with_location(location)
+    }
+}
+
+parameterized_test! {can_validate_precedences, (pairs, expected), {
+    can_validate_precedences_impl(pairs, expected);
+}}
+
+can_validate_precedences! {
+    case_01: (&[("c1", "c2"), ("c2", "c3")], None),
+    case_02: (&[("c1", "c1")], Some("job 'c1' cannot precede itself")),
+    case_03: (&[("c1", "c2"), ("c2", "c1")], Some("precedences should not contain cycles")),
+    case_04: (&[("c1", "c2"), ("c2", "c3"), ("c3", "c1")], Some("precedences should not contain cycles")),
+}
+
+fn can_validate_precedences_impl(pairs: &[(&str, &str)], expected: Option<&str>) {
+    let result = create_feature(pairs).err();
+
+    match expected {
+        Some(expected) => assert!(result.unwrap().starts_with(expected)),
+        None => assert!(result.is_none()),
+    }
+}
+
+parameterized_test! {can_evaluate_successor_insertion, (vehicle_id, index, location, expected), {
+    can_evaluate_successor_insertion_impl(vehicle_id, index, location, expected);
+}}
+
+can_evaluate_successor_insertion! {
+    case_01_same_route_before: ("v1", 0, 5, Some(VIOLATION_CODE)),
+    case_02_same_route_after: ("v1", 1, 5, None),
+    case_03_other_route_earlier: ("v2", 0, 5, Some(VIOLATION_CODE)),
+    case_04_other_route_later: ("v2", 0, 50, None),
+}
+
+fn can_evaluate_successor_insertion_impl(
+    vehicle_id: &str,
+    index: usize,
+    location: Location,
+    expected: Option<ViolationCode>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let feature = create_feature(&[("c1", "c2")]).unwrap();
+    let mut solution_ctx = SolutionContext {
+        routes: vec![
+            create_route_context_with_activities(&fleet, "v1", vec![create_activity("c1", 10)]),
+            create_route_context_with_activities(&fleet, "v2", vec![]),
+        ],
+        ..create_empty_solution_context()
+    };
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route().actor.vehicle.dimens.get_id().unwrap() == vehicle_id)
+        .unwrap();
+    let target = create_activity("c2", location);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_evaluate_successor_insertion_into_new_route, (location, expected), {
+    can_evaluate_successor_insertion_into_new_route_impl(location, expected);
+}}
+
+can_evaluate_successor_insertion_into_new_route! {
+    case_01_earlier: (5, Some(VIOLATION_CODE)),
+    case_02_later: (50, None),
+}
+
+fn can_evaluate_successor_insertion_into_new_route_impl(location: Location, expected: Option<ViolationCode>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let feature = create_feature(&[("c1", "c2")]).unwrap();
+    let mut solution_ctx = SolutionContext {
+        routes: vec![create_route_context_with_activities(&fleet, "v1", vec![create_activity("c1", 10)])],
+        registry: create_registry_context(&fleet),
+        ..create_empty_solution_context()
+    };
+    let v1 = solution_ctx.routes[0].route().actor.clone();
+    solution_ctx.registry.get_route(&v1).unwrap();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.registry.next_route().next().unwrap();
+    assert_eq!(route_ctx.route().actor.vehicle.dimens.get_id().unwrap(), "v2");
+    let target = create_activity("c2", location);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_ignore_successor_when_predecessor_is_not_assigned() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let feature = create_feature(&[("c1", "c2")]).unwrap();
+    let mut solution_ctx = SolutionContext {
+        routes: vec![create_route_context_with_activities(&fleet, "v1", vec![])],
+        ..create_empty_solution_context()
+    };
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = solution_ctx.routes.first().unwrap();
+    let target = create_activity("c2", 5);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert!(result.is_none());
+}
+
+fn create_asymmetric_problem(pairs: &[(&str, &str)]) -> Arc<Problem> {
+    // NOTE moving to a higher location index is cheap, moving to a lower one is expensive
+    let matrix = vec![
+        0., 1., 10., //
+        10., 0., 1., //
+        1., 10., 0., //
+    ];
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, matrix.clone(), matrix)]).unwrap();
+    let activity = TestActivityCost::new_shared();
+
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicle(VehicleBuilder::default().id("v1").capacity(2).build())
+            .build(),
+    );
+    let jobs = (1..=2)
+        .map(|idx| SingleBuilder::default().id(format!("c{idx}").as_str()).location(Some(idx)).build_as_job_ref())
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 2).unwrap(),
+            create_job_precedence_feature(
+                "precedence",
+                VIOLATION_CODE,
+                create_precedences(pairs).as_slice(),
+                transport.clone(),
+                activity.clone(),
+            )
+            .unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["transport"]],
+    );
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    })
+}
+
+fn solve_and_get_tour(problem: Arc<Problem>) -> Vec<String> {
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(10))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+
+    solution.routes[0]
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .map(|job| get_customer_id(&job))
+        .collect()
+}
+
+#[test]
+fn can_reorder_jobs_against_unconstrained_optimum() {
+    let unconstrained = solve_and_get_tour(create_asymmetric_problem(&[]));
+    let constrained = solve_and_get_tour(create_asymmetric_problem(&[("c2", "c1")]));
+
+    assert_eq!(unconstrained, vec!["c1", "c2"]);
+    assert_eq!(constrained, vec!["c2", "c1"]);
+}
@@ -0,0 +1,221 @@
+use super::*;
+use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+use std::cmp::Ordering;
+
+fn create_feature() -> Feature {
+    create_minimize_late_jobs_feature("late_jobs", TestTransportCost::new_shared(), TestActivityCost::new_shared())
+        .unwrap()
+}
+
+fn create_activity_with_due_time(location: Location, due_time: Option<Timestamp>) -> Activity {
+    let mut single = SingleBuilder::default().location(Some(location)).build();
+    if let Some(due_time) = due_time {
+        single.dimens.set_due_time(due_time);
+    }
+
+    Activity { job: Some(Arc::new(single)), ..test_activity_with_location(location) }
+}
+
+fn create_insertion_ctx_with_route(stops: &[(Location, Timestamp)]) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        stops.iter().map(|(location, due_time)| create_activity_with_due_time(*location, Some(*due_time))).collect(),
+    );
+    update_route_schedule(
+        &mut route_ctx,
+        &TestActivityCost::default(),
+        &TestTransportCost::default(),
+        &ScheduleStateKeys::default(),
+    );
+
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![route_ctx];
+
+    insertion_ctx
+}
+
+parameterized_test! {can_estimate_late_job_activity, (due_time, next_due_time, expected), {
+    can_estimate_late_job_activity_impl(due_time, next_due_time, expected);
+}}
+
+can_estimate_late_job_activity! {
+    case_01_no_due_time: (None, 100., 0.),
+    case_02_in_time: (Some(30.), 100., 0.),
+    case_03_late: (Some(29.), 100., 1.),
+    case_04_makes_next_late: (None, 25., 1.),
+    case_05_late_and_makes_next_late: (Some(5.), 25., 2.),
+}
+
+fn can_estimate_late_job_activity_impl(due_time: Option<Timestamp>, next_due_time: Timestamp, expected: Cost) {
+    let insertion_ctx = create_insertion_ctx_with_route(&[(20, next_due_time)]);
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let target = create_activity_with_due_time(30, due_time);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = create_feature().objective.unwrap().estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_prefer_one_very_late_job_over_few_slightly_late_jobs() {
+    let goal = create_goal_ctx_with_features(vec![create_feature()], vec![vec!["late_jobs"]]);
+    let one_very_late = create_insertion_ctx_with_route(&[(10, 100.), (20, 100.), (30, 0.)]);
+    let three_slightly_late = create_insertion_ctx_with_route(&[(10, 9.), (20, 19.), (30, 29.)]);
+
+    assert_eq!(goal.fitness(&one_very_late).collect::<Vec<_>>(), vec![1.]);
+    assert_eq!(goal.fitness(&three_slightly_late).collect::<Vec<_>>(), vec![3.]);
+    assert_eq!(goal.total_order(&one_very_late, &three_slightly_late), Ordering::Less);
+}
+
+fn create_tardiness_feature() -> Feature {
+    create_minimize_weighted_tardiness_feature(
+        "tardiness",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_activity_with_priority(
+    location: Location,
+    duration: Duration,
+    due_time: Timestamp,
+    priority: f64,
+) -> Activity {
+    let mut single = SingleBuilder::default().location(Some(location)).build();
+    single.dimens.set_due_time(due_time).set_job_priority(priority);
+
+    Activity { job: Some(Arc::new(single)), ..test_activity_with_location_and_duration(location, duration) }
+}
+
+fn create_insertion_ctx_with_prioritized_route(stops: Vec<Activity>) -> InsertionContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", stops);
+    update_route_schedule(
+        &mut route_ctx,
+        &TestActivityCost::default(),
+        &TestTransportCost::default(),
+        &ScheduleStateKeys::default(),
+    );
+
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![route_ctx];
+
+    insertion_ctx
+}
+
+parameterized_test! {can_estimate_weighted_tardiness_of_activity, (due_time, priority, next_due_time, expected), {
+    can_estimate_weighted_tardiness_of_activity_impl(due_time, priority, next_due_time, expected);
+}}
+
+can_estimate_weighted_tardiness_of_activity! {
+    case_01_in_time: (30., 2., 100., 0.),
+    case_02_late: (25., 2., 100., 10.),
+    case_03_makes_next_late: (30., 2., 25., 45.),
+    case_04_late_and_makes_next_late: (25., 2., 25., 55.),
+}
+
+fn can_estimate_weighted_tardiness_of_activity_impl(
+    due_time: Timestamp,
+    priority: f64,
+    next_due_time: Timestamp,
+    expected: Cost,
+) {
+    let insertion_ctx =
+        create_insertion_ctx_with_prioritized_route(vec![create_activity_with_priority(20, 0., next_due_time, 3.)]);
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let target = create_activity_with_priority(30, 0., due_time, priority);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result =
+        create_tardiness_feature().objective.unwrap().estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_prefer_ordering_by_weighted_tardiness, (high_priority, expected), {
+    can_prefer_ordering_by_weighted_tardiness_impl(high_priority, expected);
+}}
+
+can_prefer_ordering_by_weighted_tardiness! {
+    case_01_same_priority: (1., (1., 9., Ordering::Less)),
+    case_02_high_priority: (10., (10., 9., Ordering::Greater)),
+}
+
+fn can_prefer_ordering_by_weighted_tardiness_impl(high_priority: f64, expected: (f64, f64, Ordering)) {
+    let goal = create_goal_ctx_with_features(vec![create_tardiness_feature()], vec![vec!["tardiness"]]);
+    let create_high = || create_activity_with_priority(10, 10., 11., high_priority);
+    let create_low = || create_activity_with_priority(11, 0., 12., 1.);
+    // NOTE high priority job is slightly late when served second, low priority one is very late otherwise
+    let high_is_late = create_insertion_ctx_with_prioritized_route(vec![create_low(), create_high()]);
+    let low_is_late = create_insertion_ctx_with_prioritized_route(vec![create_high(), create_low()]);
+
+    let high_is_late_fitness = goal.fitness(&high_is_late).next().unwrap();
+    let low_is_late_fitness = goal.fitness(&low_is_late).next().unwrap();
+    let order = goal.total_order(&high_is_late, &low_is_late);
+
+    assert_eq!((high_is_late_fitness, low_is_late_fitness, order), expected);
+}
+
+fn create_job_with_window_hardness(id: &str, hardness: WindowHardness) -> Job {
+    let mut dimens = Dimensions::default();
+    dimens.set_id(id).set_window_hardness(hardness);
+
+    SingleBuilder::default().dimens(dimens).places(vec![(Some(10), 0., vec![(0., 5.)])]).build_as_job_ref()
+}
+
+#[test]
+fn can_serve_soft_window_job_late_with_penalty_and_skip_hard_window_job() {
+    let jobs = vec![
+        create_job_with_window_hardness("soft", WindowHardness::Soft),
+        create_job_with_window_hardness("hard", WindowHardness::Hard),
+    ];
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_minimize_weighted_tardiness_feature(
+                "tardiness",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+            )
+            .unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["tardiness"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, test_fleet());
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert_eq!(get_customer_ids_from_routes(&result), vec![vec!["soft".to_string()]]);
+    assert_eq!(get_customer_ids_from_unassigned(&result), vec!["hard".to_string()]);
+    let fitness = result.problem.goal.fitness(&result).collect::<Vec<_>>();
+    assert_eq!(fitness[..2], [1., 5.]);
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_customer_job(id: &str, customer: Option<&str>) -> Arc<Single> {
+    let mut single = SingleBuilder::default().id(id).build();
+    if let Some(customer) = customer {
+        single.dimens.set_customer(customer);
+    }
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_limit_distinct_customers, (customers, customer, limit, expected), {
+    can_limit_distinct_customers_impl(customers, customer, limit, expected);
+}}
+
+can_limit_distinct_customers! {
+    case01_same_customer_counts_once: (vec!["a", "a", "b"], Some("a"), Some(2), None),
+    case02_new_customer: (vec!["a", "a", "b"], Some("c"), Some(2), ConstraintViolation::fail(VIOLATION_CODE)),
+    case03_below_limit: (vec!["a", "a", "a"], Some("b"), Some(2), None),
+    case04_no_limit: (vec!["a", "b"], Some("c"), None, None),
+    case05_no_customer: (vec!["a", "b"], None, Some(2), None),
+    case06_empty_route: (vec![], Some("a"), Some(1), None),
+}
+
+fn can_limit_distinct_customers_impl(
+    customers: Vec<&str>,
+    customer: Option<&str>,
+    limit: Option<usize>,
+    expected: Option<ConstraintViolation>,
+) {
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        customers
+            .iter()
+            .enumerate()
+            .map(|(idx, customer)| test_activity_with_job(create_customer_job(&format!("job{idx}"), Some(*customer))))
+            .collect(),
+    );
+    let job = Job::Single(create_customer_job("new", customer));
+    let constraint = create_max_customers_per_route_feature("max_customers", VIOLATION_CODE, Arc::new(move |_| limit))
+        .unwrap()
+        .constraint
+        .unwrap();
+
+    let result = constraint.evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_merge_jobs_of_the_same_customer_only() {
+    let constraint = create_max_customers_per_route_feature("max_customers", VIOLATION_CODE, Arc::new(|_| Some(1)))
+        .unwrap()
+        .constraint
+        .unwrap();
+    let source = Job::Single(create_customer_job("job1", Some("a")));
+
+    assert!(constraint.merge(source.clone(), Job::Single(create_customer_job("job2", Some("a")))).is_ok());
+    assert_eq!(
+        constraint.merge(source, Job::Single(create_customer_job("job3", Some("b")))).err(),
+        Some(VIOLATION_CODE)
+    );
+}
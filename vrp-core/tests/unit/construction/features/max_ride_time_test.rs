@@ -0,0 +1,106 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_feature() -> Feature {
+    create_max_ride_time_feature(
+        "max_ride_time",
+        VIOLATION_CODE,
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    )
+    .unwrap()
+}
+
+fn create_ride_job(max_ride_time: Duration) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_id("pd").set_max_ride_time(max_ride_time);
+
+    Multi::new_shared(
+        vec![test_single_with_id_and_location("p", Some(10)), test_single_with_id_and_location("d", Some(20))],
+        dimens,
+    )
+}
+
+fn create_ride_activity(multi: &Arc<Multi>, idx: usize, location: Location) -> Activity {
+    Activity { job: Some(multi.jobs[idx].clone()), ..test_activity_with_location(location) }
+}
+
+fn evaluate_activity(route_ctx: &RouteContext, index: usize, target: &Activity) -> Option<ViolationCode> {
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    create_feature()
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::activity(route_ctx, &activity_ctx))
+        .map(|violation| violation.code)
+}
+
+parameterized_test! {can_evaluate_delivery_insertion, (max_ride_time, expected), {
+    can_evaluate_delivery_insertion_impl(max_ride_time, expected);
+}}
+
+can_evaluate_delivery_insertion! {
+    case_01_exceeds_limit: (5., Some(VIOLATION_CODE)),
+    case_02_equals_limit: (10., None),
+    case_03_within_limit: (15., None),
+}
+
+fn can_evaluate_delivery_insertion_impl(max_ride_time: Duration, expected: Option<ViolationCode>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let multi = create_ride_job(max_ride_time);
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![create_ride_activity(&multi, 0, 10)]);
+    let target = create_ride_activity(&multi, 1, 20);
+
+    let result = evaluate_activity(&route_ctx, 1, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_insertion_inside_ride, (index, expected), {
+    can_evaluate_insertion_inside_ride_impl(index, expected);
+}}
+
+can_evaluate_insertion_inside_ride! {
+    case_01_before_pickup: (0, None),
+    case_02_between_pickup_and_delivery: (1, Some(VIOLATION_CODE)),
+    case_03_after_delivery: (2, None),
+}
+
+fn can_evaluate_insertion_inside_ride_impl(index: usize, expected: Option<ViolationCode>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let multi = create_ride_job(15.);
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![create_ride_activity(&multi, 0, 10), create_ride_activity(&multi, 1, 20)],
+    );
+    create_feature().state.unwrap().accept_route_state(&mut route_ctx);
+    let target = test_activity_with_location(30);
+
+    let result = evaluate_activity(&route_ctx, index, &target);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_ignore_jobs_without_max_ride_time() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let multi = test_multi_with_id(
+        "pd",
+        vec![test_single_with_id_and_location("p", Some(10)), test_single_with_id_and_location("d", Some(20))],
+    );
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![create_ride_activity(&multi, 0, 10)]);
+    let target = create_ride_activity(&multi, 1, 100);
+
+    let result = evaluate_activity(&route_ctx, 1, &target);
+
+    assert!(result.is_none());
+}
@@ -0,0 +1,71 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::solution::{Activity, Place};
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_activity(single: &Arc<Single>, location: Location) -> Activity {
+    ActivityBuilder::default()
+        .job(Some(single.clone()))
+        .place(Place { location, duration: DEFAULT_JOB_DURATION, time: DEFAULT_ACTIVITY_TIME_WINDOW })
+        .build()
+}
+
+parameterized_test! {can_limit_insertion_distance_from_nearest_neighbor, (insertion_idx, ratio, expected), {
+    can_limit_insertion_distance_from_nearest_neighbor_impl(insertion_idx, ratio, expected);
+}}
+
+can_limit_insertion_distance_from_nearest_neighbor! {
+    case_01_after_start: (0, 3., None),
+    case_02_between_jobs: (1, 3., None),
+    case_03_before_end_far_flung: (2, 3., ConstraintViolation::skip(VIOLATION_CODE)),
+    case_04_before_end_large_ratio: (2, 50., None),
+}
+
+fn can_limit_insertion_distance_from_nearest_neighbor_impl(
+    insertion_idx: usize,
+    ratio: f64,
+    expected: Option<ConstraintViolation>,
+) {
+    let transport = TestTransportCost::new_shared();
+    let fleet = test_fleet();
+    let (near, target, far) = (
+        test_single_with_id_and_location("near", Some(10)),
+        test_single_with_id_and_location("target", Some(12)),
+        test_single_with_id_and_location("far", Some(100)),
+    );
+    let jobs = Arc::new(Jobs::new(
+        &fleet,
+        vec![Job::Single(near.clone()), Job::Single(target.clone()), Job::Single(far.clone())],
+        &transport,
+    ));
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![create_activity(&near, 10), create_activity(&far, 100)],
+    );
+    let target = create_activity(&target, 12);
+    let activity_ctx = ActivityContext {
+        index: insertion_idx,
+        prev: route_ctx.route().tour.get(insertion_idx).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(insertion_idx + 1),
+    };
+    let feature =
+        create_neighbor_distance_limit_feature("neighbor_distance", jobs, transport, ratio, VIOLATION_CODE).unwrap();
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_reject_ratio_less_than_one() {
+    let transport = TestTransportCost::new_shared();
+    let jobs = Arc::new(Jobs::new(&test_fleet(), vec![], &transport));
+
+    let result = create_neighbor_distance_limit_feature("neighbor_distance", jobs, transport, 0.5, VIOLATION_CODE);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,72 @@
+use super::*;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_visit_job(id: &str) -> Arc<Single> {
+    SingleBuilder::default().id(id).location(Some(1)).times(vec![TimeWindow::new(0., 300.)]).build_shared()
+}
+
+fn create_day_detail(start: Timestamp, end: Timestamp) -> VehicleDetail {
+    VehicleDetail {
+        start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(start), latest: None } }),
+        end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(end) } }),
+    }
+}
+
+#[test]
+fn can_create_periodic_visit_jobs() {
+    let visits = create_periodic_visit_jobs(&create_visit_job("job1"), 3).unwrap();
+
+    assert_eq!(get_customer_ids_from_jobs(visits.as_slice()), vec!["job1_0", "job1_1", "job1_2"]);
+    assert!(visits.iter().all(|visit| get_visit_group(visit) == Some(&"job1".to_string())));
+}
+
+#[test]
+fn can_return_error_for_invalid_parameters() {
+    assert!(create_periodic_visit_jobs(&create_visit_job("job1"), 0).is_err());
+    assert!(create_periodic_visits_feature("periodic", VIOLATION_CODE, 0.).is_err());
+}
+
+#[test]
+fn can_schedule_visits_on_separated_days() {
+    let min_spacing = 150.;
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(
+            VehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_day_detail(0., 40.), create_day_detail(50., 90.), create_day_detail(200., 240.)])
+                .build(),
+        )
+        .build();
+    let jobs = create_periodic_visit_jobs(&create_visit_job("job1"), 2).unwrap();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_periodic_visits_feature("periodic", VIOLATION_CODE, min_spacing).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                VIOLATION_CODE + 1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.len(), 2);
+    let days =
+        result.solution.routes.iter().map(|route_ctx| route_ctx.route().actor.detail.time.start).collect::<Vec<_>>();
+    assert!((days[0] - days[1]).abs() >= min_spacing);
+}
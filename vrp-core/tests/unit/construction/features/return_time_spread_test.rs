@@ -0,0 +1,126 @@
+use super::*;
+use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+use crate::helpers::models::domain::{create_empty_insertion_context, create_problem_with_goal_ctx_jobs_and_fleet};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::create_route_context_with_activities;
+use crate::models::common::SingleDimLoad;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use rosomaxa::utils::ThreadPool;
+
+parameterized_test! {can_calculate_return_time_spread_fitness, (return_times, expected), {
+    can_calculate_return_time_spread_fitness_impl(return_times, expected);
+}}
+
+can_calculate_return_time_spread_fitness! {
+    case01_no_routes: (vec![], 0.),
+    case02_single_route: (vec![50.], 0.),
+    case03_same_time: (vec![50., 50.], 30.),
+    case04_close: (vec![60., 50., 90.], 20.),
+    case05_far_enough: (vec![10., 100., 40.], 0.),
+}
+
+fn can_calculate_return_time_spread_fitness_impl(return_times: Vec<Timestamp>, expected: f64) {
+    // NOTE fleet cannot be empty, so there is at least one vehicle
+    let vehicle_ids = (0..return_times.len().max(1)).map(|idx| format!("v{idx}")).collect::<Vec<_>>();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vehicle_ids.iter().map(|id| test_vehicle_with_id(id)).collect())
+        .build();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vehicle_ids
+        .iter()
+        .zip(return_times)
+        .map(|(id, return_time)| {
+            let mut route_ctx = create_route_context_with_activities(&fleet, id, vec![]);
+            let end_idx = route_ctx.route().tour.total() - 1;
+            route_ctx.route_mut().tour.get_mut(end_idx).unwrap().schedule.arrival = return_time;
+            route_ctx
+        })
+        .collect();
+
+    let fitness = ReturnTimeSpreadObjective::new(30.).unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+#[test]
+fn can_validate_return_time_interval() {
+    assert!(ReturnTimeSpreadObjective::new(10.).is_ok());
+    assert!(ReturnTimeSpreadObjective::new(0.).is_err());
+    assert!(ReturnTimeSpreadObjective::new(-10.).is_err());
+}
+
+#[test]
+fn can_stagger_routes_to_spread_return_times() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").capacity(2).build(),
+            VehicleBuilder::default().id("v2").capacity(2).build(),
+        ])
+        .build();
+    let jobs = [("short1", 0.), ("short2", 0.), ("long1", 50.), ("long2", 50.)]
+        .iter()
+        .map(|(id, duration)| {
+            SingleBuilder::default()
+                .id(id)
+                .location(Some(10))
+                .duration(*duration)
+                .demand(create_simple_demand(-1))
+                .build_as_job_ref()
+        })
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_return_time_spread_feature("return_spread", 100.).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["return_spread"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(50))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    let mut routes = solution
+        .routes
+        .iter()
+        .map(|route| {
+            let mut jobs = route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.job.as_ref())
+                .map(|single| single.dimens.get_id().cloned().unwrap())
+                .collect::<Vec<_>>();
+            jobs.sort();
+            (jobs, route.tour.end().unwrap().schedule.arrival)
+        })
+        .collect::<Vec<_>>();
+    routes.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+    assert_eq!(
+        routes,
+        vec![
+            (vec!["short1".to_string(), "short2".to_string()], 20.),
+            (vec!["long1".to_string(), "long2".to_string()], 120.),
+        ]
+    );
+}
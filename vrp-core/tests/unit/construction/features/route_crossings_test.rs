@@ -0,0 +1,65 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+const PENALTY: Cost = 10.;
+
+fn create_square_coordinates() -> Vec<Point> {
+    vec![(0., 0.), (0., 10.), (10., 10.), (10., 0.)]
+}
+
+fn create_route_ctx(locations: &[Location]) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+
+    create_route_context_with_activities(
+        &fleet,
+        "v1",
+        locations.iter().map(|location| test_activity_with_location(*location)).collect(),
+    )
+}
+
+parameterized_test! {can_penalize_route_with_crossing, (locations, expected), {
+    can_penalize_route_with_crossing_impl(locations, expected);
+}}
+
+can_penalize_route_with_crossing! {
+    case_01_no_crossing: (&[1, 2, 3], 0.),
+    case_02_crossing: (&[1, 3, 2], PENALTY),
+    case_03_same_stops_reversed: (&[3, 2, 1], 0.),
+}
+
+fn can_penalize_route_with_crossing_impl(locations: &[Location], expected: Cost) {
+    let feature = create_route_crossings_feature("crossings", create_square_coordinates(), PENALTY).unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![create_route_ctx(locations)];
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(feature.objective.as_ref().unwrap().fitness(&insertion_ctx), expected);
+}
+
+parameterized_test! {can_estimate_crossings_of_insertion, (index, expected), {
+    can_estimate_crossings_of_insertion_impl(index, expected);
+}}
+
+can_estimate_crossings_of_insertion! {
+    case_01_creates_crossing: (1, PENALTY),
+    case_02_no_crossing: (2, 0.),
+}
+
+fn can_estimate_crossings_of_insertion_impl(index: usize, expected: Cost) {
+    let feature = create_route_crossings_feature("crossings", create_square_coordinates(), PENALTY).unwrap();
+    let route_ctx = create_route_ctx(&[1, 2]);
+    let target = test_activity_with_location(3);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let result = feature.objective.as_ref().unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,121 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::test_fleet;
+use crate::helpers::models::solution::*;
+
+struct ConstantObjective {
+    value: f64,
+}
+
+impl Objective for ConstantObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, _: &Self::Solution) -> f64 {
+        self.value
+    }
+}
+
+impl FeatureObjective for ConstantObjective {
+    fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+        self.value
+    }
+}
+
+fn create_constant_feature(name: &str, value: f64) -> Feature {
+    FeatureBuilder::default().with_name(name).with_objective(ConstantObjective { value }).build().unwrap()
+}
+
+fn create_schedule() -> Arc<WeightSchedule> {
+    Arc::new(WeightSchedule::new(vec![1., 0.], vec![0., 1.], 10).unwrap())
+}
+
+fn create_feature(schedule: Arc<WeightSchedule>) -> Feature {
+    let features = vec![create_constant_feature("diversity", 2.), create_constant_feature("cost", 10.)];
+
+    create_scheduled_weights_feature("weighted", features.as_slice(), schedule).unwrap()
+}
+
+parameterized_test! {can_get_weights_at_generation, (generation, expected), {
+    can_get_weights_at_generation_impl(generation, expected);
+}}
+
+can_get_weights_at_generation! {
+    case_01: (0, vec![1., 0.]),
+    case_02: (5, vec![0.5, 0.5]),
+    case_03: (10, vec![0., 1.]),
+    case_04: (20, vec![0., 1.]),
+}
+
+fn can_get_weights_at_generation_impl(generation: usize, expected: Vec<f64>) {
+    let schedule = create_schedule();
+
+    assert_eq!(schedule.get_weights_at(generation), expected);
+}
+
+parameterized_test! {can_validate_schedule, (initial, target, last_generation, is_ok), {
+    can_validate_schedule_impl(initial, target, last_generation, is_ok);
+}}
+
+can_validate_schedule! {
+    case_01: (vec![1., 0.], vec![0., 1.], 10, true),
+    case_02: (vec![], vec![], 10, false),
+    case_03: (vec![1., 0.], vec![1.], 10, false),
+    case_04: (vec![-1., 0.], vec![0., 1.], 10, false),
+    case_05: (vec![1., 0.], vec![0., 1.], 0, false),
+}
+
+fn can_validate_schedule_impl(initial: Vec<f64>, target: Vec<f64>, last_generation: usize, is_ok: bool) {
+    let result = WeightSchedule::new(initial, target, last_generation);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+#[test]
+fn can_return_error_when_weights_do_not_match_objectives() {
+    let features = vec![create_constant_feature("cost", 10.)];
+
+    let result = create_scheduled_weights_feature("weighted", features.as_slice(), create_schedule());
+
+    assert!(result.is_err());
+}
+
+parameterized_test! {can_use_scheduled_weights_in_fitness, (generation, expected), {
+    can_use_scheduled_weights_in_fitness_impl(generation, expected);
+}}
+
+can_use_scheduled_weights_in_fitness! {
+    case_01_no_generation: (None, 2.),
+    case_02_middle: (Some(5), 6.),
+    case_03_last: (Some(10), 10.),
+}
+
+fn can_use_scheduled_weights_in_fitness_impl(generation: Option<usize>, expected: f64) {
+    let objective = create_feature(create_schedule()).objective.unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+    if let Some(generation) = generation {
+        insertion_ctx.solution.set_generation(generation);
+    }
+
+    assert_eq!(objective.fitness(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_use_solution_generation_in_activity_estimate() {
+    let feature = create_feature(create_schedule());
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes = vec![create_route_context_with_activities(&test_fleet(), "v1", vec![])];
+    insertion_ctx.solution.set_generation(5);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let target = test_activity();
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.objective.unwrap().estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, 6.);
+}
@@ -0,0 +1,63 @@
+use super::*;
+use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::SingleDimLoad;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+const PREMIUM: usize = 0;
+const STANDARD: usize = 1;
+
+fn create_tier_job(id: &str, location: Location, tier: usize) -> Job {
+    let mut single = SingleBuilder::default().id(id).location(Some(location)).demand(create_simple_demand(-1)).build();
+    single.dimens.set_service_tier(tier);
+
+    Job::Single(Arc::new(single))
+}
+
+#[test]
+fn can_return_error_for_negative_weights() {
+    assert!(create_maximize_served_tiers_feature("tiers", vec![10., -1.]).is_err());
+}
+
+#[test]
+fn can_serve_premium_customers_first_under_tight_capacity() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(2).build())
+        .build();
+    // NOTE standard customers are closer to the depot, so they would be served without tiers
+    let jobs = vec![
+        create_tier_job("s1", 1, STANDARD),
+        create_tier_job("s2", 2, STANDARD),
+        create_tier_job("p1", 10, PREMIUM),
+        create_tier_job("p2", 11, PREMIUM),
+    ];
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+            create_maximize_served_tiers_feature("tiers", vec![10., 1.]).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["tiers"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    // NOTE order of jobs within the route is not relevant here as both directions have the same cost
+    let mut routes = get_customer_ids_from_routes(&result);
+    routes.iter_mut().for_each(|route| route.sort());
+    assert_eq!(routes, vec![vec!["p1", "p2"]]);
+    assert_eq!(result.solution.unassigned.len(), 2);
+    assert_eq!(result.problem.goal.fitness(&result).next(), Some(-20.));
+}
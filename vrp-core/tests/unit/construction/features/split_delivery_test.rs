@@ -0,0 +1,175 @@
+use super::*;
+use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_split_job(id: &str, demand: i32) -> Arc<Single> {
+    SingleBuilder::default().id(id).location(Some(1)).demand(create_simple_demand(-demand)).build_shared()
+}
+
+fn get_delivery_size(job: &Job) -> i32 {
+    let demand: &Demand<SingleDimLoad> = job.as_single().unwrap().dimens.get_demand().unwrap();
+    demand.delivery.0.value
+}
+
+fn create_route_with_jobs(fleet: &Fleet, vehicle: &str, jobs: Vec<Job>) -> RouteContext {
+    let activities =
+        jobs.iter().map(|job| ActivityBuilder::default().job(Some(job.as_single().unwrap().clone())).build()).collect();
+    let mut route_ctx = create_route_context_with_activities(fleet, vehicle, activities);
+
+    SplitDeliveryState { state_keys: vec![] }.accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_split_delivery_job, (demand, max_part_size, expected), {
+    can_split_delivery_job_impl(demand, max_part_size, expected);
+}}
+
+can_split_delivery_job! {
+    case_01: (15, 10, vec![10, 5]),
+    case_02: (20, 10, vec![10, 10]),
+    case_03: (10, 10, vec![10]),
+    case_04: (5, 10, vec![5]),
+    case_05: (7, 2, vec![2, 2, 2, 1]),
+}
+
+fn can_split_delivery_job_impl(demand: i32, max_part_size: i32, expected: Vec<i32>) {
+    let job = create_split_job("job1", demand);
+
+    let parts = split_delivery_job(&job, max_part_size).unwrap();
+
+    assert_eq!(parts.iter().map(get_delivery_size).collect::<Vec<_>>(), expected);
+    if expected.len() > 1 {
+        assert!(parts.iter().all(|part| get_split_group(part) == Some(&"job1".to_string())));
+        assert_eq!(
+            get_customer_ids_from_jobs(parts.as_slice()),
+            (0..expected.len()).map(|idx| format!("job1_{idx}")).collect::<Vec<_>>()
+        );
+    } else {
+        assert!(get_split_group(&parts[0]).is_none());
+    }
+}
+
+#[test]
+fn can_return_error_for_invalid_part_size() {
+    let job = create_split_job("job1", 10);
+
+    assert!(split_delivery_job(&job, 0).is_err());
+}
+
+#[test]
+fn can_count_extra_routes_in_fitness() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let parts = split_delivery_job(&create_split_job("job1", 30), 10).unwrap();
+    let feature = create_split_delivery_feature("split").unwrap();
+    let objective = feature.objective.unwrap();
+    let mut insertion_ctx = create_empty_insertion_context();
+
+    insertion_ctx.solution.routes.push(create_route_with_jobs(&fleet, "v1", vec![parts[0].clone(), parts[1].clone()]));
+    assert_eq!(objective.fitness(&insertion_ctx), 0.);
+
+    insertion_ctx.solution.routes.push(create_route_with_jobs(&fleet, "v2", vec![parts[2].clone()]));
+    assert_eq!(objective.fitness(&insertion_ctx), 1.);
+}
+
+#[test]
+fn can_estimate_route_which_adds_split() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let parts = split_delivery_job(&create_split_job("job1", 30), 10).unwrap();
+    let objective = create_split_delivery_feature("split").unwrap().objective.unwrap();
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_with_jobs(&fleet, "v1", vec![parts[0].clone()]));
+    let other_route = create_route_with_jobs(&fleet, "v2", vec![]);
+
+    let same_cost = objective.estimate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &parts[1]));
+    let other_cost = objective.estimate(&MoveContext::route(&solution_ctx, &other_route, &parts[1]));
+
+    assert_eq!(same_cost, 0.);
+    assert_eq!(other_cost, 1.);
+}
+
+#[test]
+fn can_split_demand_larger_than_vehicle_capacity_across_two_vehicles() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").capacity(10).build(),
+            VehicleBuilder::default().id("v2").capacity(10).build(),
+        ])
+        .build();
+    let jobs = split_delivery_job(&create_split_job("job1", 15), 10).unwrap();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", VIOLATION_CODE).unwrap(),
+            create_split_delivery_feature("split").unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                VIOLATION_CODE + 1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["split"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.len(), 2);
+    let mut delivered = result
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs().map(|job| get_delivery_size(&job)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    delivered.sort();
+    assert_eq!(delivered, vec![5, 10]);
+    assert_eq!(result.problem.goal.fitness(&result).next(), Some(1.));
+}
+
+parameterized_test! {can_sum_demand_of_parts_on_same_route, (capacity, expected_unassigned), {
+    can_sum_demand_of_parts_on_same_route_impl(capacity, expected_unassigned);
+}}
+
+can_sum_demand_of_parts_on_same_route! {
+    case_01_fits_all_parts: (20, 0),
+    case_02_fits_one_part: (15, 1),
+}
+
+fn can_sum_demand_of_parts_on_same_route_impl(capacity: i32, expected_unassigned: usize) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(capacity).build())
+        .build();
+    let jobs = split_delivery_job(&create_split_job("job1", 20), 10).unwrap();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", VIOLATION_CODE).unwrap(),
+            create_split_delivery_feature("split").unwrap(),
+        ],
+        vec![vec!["split"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+    let result = RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert_eq!(result.solution.unassigned.len(), expected_unassigned);
+    assert_eq!(result.solution.routes.len(), 1);
+}
@@ -0,0 +1,121 @@
+use super::*;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::ThreadPool;
+
+const PER_EXCESS_STOP: Cost = 10.;
+
+parameterized_test! {can_estimate_excess_stops, (max_stops, period, route_stops, expected), {
+    can_estimate_excess_stops_impl(max_stops, period, route_stops, expected);
+}}
+
+can_estimate_excess_stops! {
+    case_01_below_threshold: (1., 250., 3, 0.),
+    case_02_reaches_threshold: (1., 250., 4, PER_EXCESS_STOP),
+    case_03_over_threshold: (1., 250., 5, PER_EXCESS_STOP),
+    case_04_fractional_threshold: (1., 400., 2, 0.5 * PER_EXCESS_STOP),
+}
+
+fn can_estimate_excess_stops_impl(max_stops: f64, period: Duration, route_stops: usize, expected: Cost) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        (1..=route_stops).map(test_activity_with_location).collect(),
+    );
+    let solution_ctx = create_empty_solution_context();
+    let job = Job::Single(test_single_with_id("job"));
+    let objective =
+        create_stop_density_feature("density", max_stops, period, PER_EXCESS_STOP).unwrap().objective.unwrap();
+
+    let result = objective.estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_validate_parameters, (max_stops, period, per_excess_stop, is_ok), {
+    can_validate_parameters_impl(max_stops, period, per_excess_stop, is_ok);
+}}
+
+can_validate_parameters! {
+    case_01_valid: (2., 3600., 1., true),
+    case_02_zero_stops: (0., 3600., 1., false),
+    case_03_zero_period: (2., 0., 1., false),
+    case_04_negative_cost: (2., 3600., -1., false),
+}
+
+fn can_validate_parameters_impl(max_stops: f64, period: Duration, per_excess_stop: Cost, is_ok: bool) {
+    let result = create_stop_density_feature("density", max_stops, period, per_excess_stop);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+fn create_problem(max_stops: Option<f64>) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+            .build(),
+    );
+    let jobs = (1..=4)
+        .map(|idx| SingleBuilder::default().id(format!("c{idx}").as_str()).location(Some(idx)).build_as_job_ref())
+        .collect();
+
+    let mut features = vec![
+        create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+        create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1).unwrap(),
+    ];
+    let mut layers = vec![vec!["min_unassigned"], vec!["transport"]];
+    if let Some(max_stops) = max_stops {
+        features.push(create_stop_density_feature("density", max_stops, 1000., PER_EXCESS_STOP).unwrap());
+        layers.insert(1, vec!["density"]);
+    }
+    let goal = create_goal_ctx_with_features(features, layers);
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    })
+}
+
+parameterized_test! {can_spread_stops_to_avoid_density_penalty, (max_stops, expected), {
+    can_spread_stops_to_avoid_density_penalty_impl(max_stops, expected);
+}}
+
+can_spread_stops_to_avoid_density_penalty! {
+    case_01_no_density_limit: (None, vec![4]),
+    case_02_density_limit: (Some(2.), vec![2, 2]),
+}
+
+fn can_spread_stops_to_avoid_density_penalty_impl(max_stops: Option<f64>, expected: Vec<usize>) {
+    let problem = create_problem(max_stops);
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(50))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    let mut stops = solution.routes.iter().map(|route| route.tour.job_activity_count()).collect::<Vec<_>>();
+    stops.sort();
+    assert_eq!(stops, expected);
+}
@@ -160,3 +160,252 @@ mod traveling {
         assert_eq!(result, ConstraintViolation::skip(DURATION_CODE));
     }
 }
+
+mod overtime {
+    use super::*;
+    use crate::construction::heuristics::{ActivityContext, InsertionContext, MoveContext, RouteContext};
+    use crate::helpers::models::domain::create_empty_insertion_context;
+    use crate::models::problem::Fleet;
+
+    fn create_route_with_duration(fleet: &Fleet, vehicle: &str, duration: f64, distance: f64) -> RouteContext {
+        let mut route_ctx = create_route_context_with_activities(fleet, vehicle, vec![]);
+        route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, duration);
+        route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, distance);
+
+        route_ctx
+    }
+
+    fn get_total_distance(insertion_ctx: &InsertionContext) -> f64 {
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| *route_ctx.state().get_route_state::<f64>(TOTAL_DISTANCE_KEY).unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn can_prefer_redistributed_jobs_with_less_overtime() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+            .build();
+        let objective =
+            create_minimize_overtime_feature("overtime", TestTransportCost::new_shared(), Arc::new(|_| Some(100.)))
+                .unwrap()
+                .objective
+                .unwrap();
+        let mut concentrated = create_empty_insertion_context();
+        concentrated.solution.routes.push(create_route_with_duration(&fleet, "v1", 130., 130.));
+        let mut redistributed = create_empty_insertion_context();
+        redistributed.solution.routes.push(create_route_with_duration(&fleet, "v1", 80., 80.));
+        redistributed.solution.routes.push(create_route_with_duration(&fleet, "v2", 70., 70.));
+
+        assert_eq!(objective.fitness(&concentrated), 30.);
+        assert_eq!(objective.fitness(&redistributed), 0.);
+        assert!(get_total_distance(&redistributed) > get_total_distance(&concentrated));
+    }
+
+    #[test]
+    fn can_estimate_overtime_change_on_insertion() {
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+        let objective =
+            create_minimize_overtime_feature("overtime", TestTransportCost::new_shared(), Arc::new(|_| Some(100.)))
+                .unwrap()
+                .objective
+                .unwrap();
+        let route_ctx = create_route_with_duration(&fleet, "v1", 90., 90.);
+        let prev = test_activity_with_location(10);
+        let target = test_activity_with_location(20);
+        let next = test_activity_with_location(10);
+        let activity_ctx = ActivityContext { index: 0, prev: &prev, target: &target, next: Some(&next) };
+
+        let result = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+        assert_eq!(result, 10.);
+    }
+}
+
+mod elapsed {
+    use super::*;
+    use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
+    use crate::construction::heuristics::{ActivityContext, MoveContext};
+    use crate::models::common::{Duration, TimeWindow};
+
+    const VIOLATION_CODE: ViolationCode = 1;
+
+    parameterized_test! {can_limit_elapsed_time_regardless_time_windows, (limit, expected), {
+        can_limit_elapsed_time_regardless_time_windows_impl(limit, expected);
+    }}
+
+    can_limit_elapsed_time_regardless_time_windows! {
+        case01_exceeds_limit: (Some(60.), ConstraintViolation::skip(VIOLATION_CODE)),
+        case02_meets_limit: (Some(70.), None),
+        case03_no_limit: (None, None),
+    }
+
+    fn can_limit_elapsed_time_regardless_time_windows_impl(
+        limit: Option<Duration>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+        let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_location(10)]);
+        update_route_schedule(
+            &mut route_ctx,
+            &TestActivityCost::default(),
+            &TestTransportCost::default(),
+            &ScheduleStateKeys::default(),
+        );
+        // NOTE target's time window is met, but waiting for it makes the tour last 70 time units
+        let target = test_activity_with_location_and_tw(20, TimeWindow::new(50., 1000.));
+        let activity_ctx = ActivityContext {
+            index: 1,
+            prev: route_ctx.route().tour.get(1).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(2),
+        };
+        let feature = create_max_elapsed_feature(
+            "max_elapsed",
+            VIOLATION_CODE,
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            Arc::new(move |_| limit),
+        )
+        .unwrap();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod depot {
+    use super::*;
+    use crate::construction::heuristics::{InsertionContext, MoveContext};
+    use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+    use crate::helpers::models::domain::{create_problem_with_goal_ctx_jobs_and_fleet, test_random};
+    use crate::helpers::solver::create_default_refinement_ctx;
+    use crate::models::common::SingleDimLoad;
+    use crate::models::problem::{Fleet, Vehicle, VehicleDetail, VehiclePlace};
+    use crate::solver::search::{Recreate, RecreateWithCheapest};
+    use rosomaxa::prelude::Environment;
+
+    const VIOLATION_CODE: ViolationCode = 1;
+
+    fn create_vehicle_at_depot(id: &str, depot: Location) -> Vehicle {
+        let detail = test_vehicle_detail();
+        let relocate = |place: Option<VehiclePlace>| place.map(|place| VehiclePlace { location: depot, ..place });
+
+        VehicleBuilder::default()
+            .id(id)
+            .capacity(1)
+            .details(vec![VehicleDetail { start: relocate(detail.start), end: relocate(detail.end) }])
+            .build()
+    }
+
+    fn create_fleet() -> Fleet {
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![
+                create_vehicle_at_depot("a1", 0),
+                create_vehicle_at_depot("a2", 0),
+                create_vehicle_at_depot("a3", 0),
+                create_vehicle_at_depot("b1", 100),
+            ])
+            .build()
+    }
+
+    fn create_feature(limit: Option<usize>) -> Feature {
+        create_depot_route_limit_feature(
+            "depot_limit",
+            VIOLATION_CODE,
+            Arc::new(move |depot| if depot == 0 { limit } else { None }),
+        )
+        .unwrap()
+    }
+
+    parameterized_test! {can_limit_routes_per_depot, (used_routes, vehicle, limit, expected), {
+        can_limit_routes_per_depot_impl(used_routes, vehicle, limit, expected);
+    }}
+
+    can_limit_routes_per_depot! {
+        case01_below_limit: (1, "a3", Some(2), None),
+        case02_at_limit: (2, "a3", Some(2), ConstraintViolation::fail(VIOLATION_CODE)),
+        case03_no_limit: (2, "a3", None, None),
+        case04_other_depot: (2, "b1", Some(2), None),
+    }
+
+    fn can_limit_routes_per_depot_impl(
+        used_routes: usize,
+        vehicle: &str,
+        limit: Option<usize>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let fleet = create_fleet();
+        let mut solution_ctx = create_empty_solution_context();
+        solution_ctx.routes = ["a1", "a2"]
+            .iter()
+            .take(used_routes)
+            .map(|id| create_route_context_with_activities(&fleet, id, vec![test_activity_with_location(1)]))
+            .collect();
+        let route_ctx = create_route_context_with_activities(&fleet, vehicle, vec![]);
+        let job = Job::Single(test_single_with_id("job1"));
+
+        let result =
+            create_feature(limit).constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_use_other_depot_when_limit_is_reached, (limit, expected), {
+        can_use_other_depot_when_limit_is_reached_impl(limit, expected);
+    }}
+
+    can_use_other_depot_when_limit_is_reached! {
+        case01_no_limit: (None, vec!["a", "a", "a"]),
+        case02_limit_of_two: (Some(2), vec!["a", "a", "b"]),
+    }
+
+    fn can_use_other_depot_when_limit_is_reached_impl(limit: Option<usize>, expected: Vec<&str>) {
+        let jobs = (1..=3)
+            .map(|idx| {
+                SingleBuilder::default()
+                    .id(format!("c{idx}").as_str())
+                    .location(Some(idx))
+                    .demand(create_simple_demand(-1))
+                    .build_as_job_ref()
+            })
+            .collect();
+        let goal = create_goal_ctx_with_features(
+            vec![
+                create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+                create_feature(limit),
+                create_minimize_transport_costs_feature(
+                    "transport",
+                    TestTransportCost::new_shared(),
+                    TestActivityCost::new_shared(),
+                    3,
+                )
+                .unwrap(),
+            ],
+            vec![vec!["transport"]],
+        );
+        let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, create_fleet());
+        let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+
+        let result =
+            RecreateWithCheapest::new(test_random()).run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+        // NOTE vehicles at the same depot are interchangeable, so compare only depot prefixes of their ids
+        let mut depots = result
+            .solution
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route().tour.has_jobs())
+            .map(|route_ctx| get_vehicle_id(&route_ctx.route().actor.vehicle)[..1].to_string())
+            .collect::<Vec<_>>();
+        depots.sort();
+        assert!(result.solution.unassigned.is_empty());
+        assert_eq!(depots, expected);
+    }
+}
@@ -432,3 +432,246 @@ mod time_dependent {
         }
     }
 }
+
+mod early_completion {
+    use super::*;
+    use crate::helpers::construction::features::create_goal_ctx_with_features;
+    use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+    use rosomaxa::evolution::TelemetryMode;
+    use rosomaxa::utils::ThreadPool;
+
+    fn create_problem(bonus_per_time: Cost, max_bonus: Cost) -> Arc<Problem> {
+        let transport = TestTransportCost::new_shared();
+        let activity = TestActivityCost::new_shared();
+        let costs = Costs { per_distance: 1., ..empty_costs() };
+        let fleet = Arc::new(
+            FleetBuilder::default()
+                .add_driver(test_driver_with_costs(empty_costs()))
+                .add_vehicle(VehicleBuilder::default().id("v1").costs(costs).build())
+                .build(),
+        );
+        // NOTE the closest place requires waiting, so the tour is completed later
+        let jobs = vec![SingleBuilder::default()
+            .id("job1")
+            .places(vec![(Some(5), 0., vec![(0., 1000.)]), (Some(1), 0., vec![(50., 1000.)])])
+            .build_as_job_ref()];
+        let goal = create_goal_ctx_with_features(
+            vec![
+                create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+                create_minimize_transport_costs_with_early_completion_feature(
+                    "transport",
+                    transport.clone(),
+                    activity.clone(),
+                    VIOLATION_CODE,
+                    bonus_per_time,
+                    max_bonus,
+                )
+                .unwrap(),
+            ],
+            vec![vec!["min_unassigned"], vec!["transport"]],
+        );
+
+        Arc::new(Problem {
+            jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+            fleet,
+            locks: vec![],
+            goal: Arc::new(goal),
+            activity,
+            transport,
+            extras: Arc::new(Default::default()),
+        })
+    }
+
+    parameterized_test! {can_prefer_earlier_completion_with_bonus, (bonus_per_time, max_bonus, expected_location), {
+        can_prefer_earlier_completion_with_bonus_impl(bonus_per_time, max_bonus, expected_location);
+    }}
+
+    can_prefer_earlier_completion_with_bonus! {
+        case01_no_bonus: (0., 0., 1),
+        case02_bonus: (1., 1000., 5),
+        case03_capped_bonus: (1., 10., 1),
+    }
+
+    fn can_prefer_earlier_completion_with_bonus_impl(
+        bonus_per_time: Cost,
+        max_bonus: Cost,
+        expected_location: Location,
+    ) {
+        let problem = create_problem(bonus_per_time, max_bonus);
+        let environment =
+            Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+        let solution = ThreadPool::new(1).execute(|| {
+            create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+                .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+                .with_max_generations(Some(10))
+                .build()
+                .map(|config| Solver::new(problem, config))
+                .and_then(|solver| solver.solve())
+                .map(|(solution, _, _)| solution)
+                .unwrap()
+        });
+
+        assert!(solution.unassigned.is_empty());
+        assert_eq!(solution.routes.len(), 1);
+        assert_eq!(solution.routes[0].tour.get(1).map(|activity| activity.place.location), Some(expected_location));
+    }
+
+    #[test]
+    fn can_reject_negative_bonus() {
+        let result = create_minimize_transport_costs_with_early_completion_feature(
+            "transport",
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            VIOLATION_CODE,
+            -1.,
+            10.,
+        );
+
+        assert!(result.is_err());
+    }
+}
+
+mod free_return {
+    use super::*;
+    use crate::helpers::models::domain::create_empty_insertion_context;
+    use crate::models::problem::FreeReturnDimension;
+
+    fn create_route_ctx(free_return: bool, locations: Vec<Location>) -> RouteContext {
+        let mut vehicle = VehicleBuilder::default().id("v1").costs(Costs { per_distance: 1., ..empty_costs() }).build();
+        vehicle.dimens.set_free_return(free_return);
+        let fleet =
+            FleetBuilder::default().add_driver(test_driver_with_costs(empty_costs())).add_vehicle(vehicle).build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            locations.into_iter().map(test_activity_with_location).collect(),
+        );
+
+        TransportState::new(TestTransportCost::new_shared(), TestActivityCost::new_shared())
+            .accept_route_state(&mut route_ctx);
+
+        route_ctx
+    }
+
+    parameterized_test! {can_calculate_total_cost_with_free_return, (free_return, expected_distance, expected_cost), {
+        can_calculate_total_cost_with_free_return_impl(free_return, expected_distance, expected_cost);
+    }}
+
+    can_calculate_total_cost_with_free_return! {
+        case01_paid_return: (false, 40., 40.),
+        case02_free_return: (true, 40., 20.),
+    }
+
+    fn can_calculate_total_cost_with_free_return_impl(free_return: bool, expected_distance: f64, expected_cost: Cost) {
+        let route_ctx = create_route_ctx(free_return, vec![10, 20]);
+        let distance = route_ctx.state().get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned();
+        let end_arrival = route_ctx.route().tour.end().map(|end| end.schedule.arrival);
+        let mut insertion_ctx = create_empty_insertion_context();
+        insertion_ctx.solution.routes.push(route_ctx);
+
+        let cost = insertion_ctx.solution.get_total_cost();
+
+        assert_eq!(distance, Some(expected_distance));
+        assert_eq!(end_arrival, Some(40.));
+        assert_eq!(cost, expected_cost);
+    }
+
+    parameterized_test! {can_estimate_insertion_before_free_return, (free_return, expected), {
+        can_estimate_insertion_before_free_return_impl(free_return, expected);
+    }}
+
+    can_estimate_insertion_before_free_return! {
+        case01_paid_return: (false, 20.),
+        case02_free_return: (true, 10.),
+    }
+
+    fn can_estimate_insertion_before_free_return_impl(free_return: bool, expected: Cost) {
+        let route_ctx = create_route_ctx(free_return, vec![10, 20]);
+        let target = test_activity_with_location(30);
+        let activity_ctx = ActivityContext {
+            index: 2,
+            prev: route_ctx.route().tour.get(2).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(3),
+        };
+        let objective = create_minimize_transport_costs_feature(
+            "transport",
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+            VIOLATION_CODE,
+        )
+        .unwrap()
+        .objective
+        .unwrap();
+
+        let result = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+    }
+}
+
+mod vehicle_preference {
+    use super::*;
+    use crate::construction::features::create_minimize_unassigned_jobs_feature;
+    use crate::helpers::construction::features::create_goal_ctx_with_features;
+    use crate::helpers::models::domain::create_problem_with_goal_ctx_jobs_and_fleet;
+    use crate::helpers::solver::create_default_refinement_ctx;
+    use crate::models::problem::{Job, Vehicle};
+    use crate::solver::search::{Recreate, RecreateWithCheapest};
+    use rosomaxa::prelude::Environment;
+
+    fn create_vehicle(id: &str, depot: Location) -> Vehicle {
+        VehicleBuilder::default().id(id).details(vec![create_detail((Some(depot), Some(depot)), None)]).build()
+    }
+
+    fn create_preferred_job(vehicle_id: &str, penalty: Cost) -> Job {
+        let mut single = SingleBuilder::default().id("job1").location(Some(10)).build();
+        single.dimens.set_vehicle_preference(VehiclePreference { vehicle_id: vehicle_id.to_string(), penalty });
+
+        Job::Single(Arc::new(single))
+    }
+
+    parameterized_test! {can_serve_job_by_preferred_vehicle_unless_detour_is_expensive, (penalty, expected), {
+        can_serve_job_by_preferred_vehicle_unless_detour_is_expensive_impl(penalty, expected);
+    }}
+
+    can_serve_job_by_preferred_vehicle_unless_detour_is_expensive! {
+        case01_cheap_preference: (10., "v1"),
+        case02_expensive_preference: (100., "v2"),
+    }
+
+    fn can_serve_job_by_preferred_vehicle_unless_detour_is_expensive_impl(penalty: Cost, expected: &str) {
+        // NOTE a round trip to the job costs 40 for v1 and 80 for v2 which is preferred
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![create_vehicle("v1", 0), create_vehicle("v2", 30)])
+            .build();
+        let goal = create_goal_ctx_with_features(
+            vec![
+                create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+                create_minimize_transport_costs_with_vehicle_preference_feature(
+                    "transport",
+                    TestTransportCost::new_shared(),
+                    TestActivityCost::new_shared(),
+                    VIOLATION_CODE,
+                )
+                .unwrap(),
+            ],
+            vec![vec!["min_unassigned"], vec!["transport"]],
+        );
+        let problem =
+            create_problem_with_goal_ctx_jobs_and_fleet(goal, vec![create_preferred_job("v2", penalty)], fleet);
+        let environment = Arc::new(Environment::default());
+
+        let insertion_ctx = RecreateWithCheapest::new(environment.random.clone())
+            .run(&create_default_refinement_ctx(problem.clone()), InsertionContext::new(problem.clone(), environment));
+
+        assert!(insertion_ctx.solution.unassigned.is_empty());
+        assert_eq!(insertion_ctx.solution.routes.len(), 1);
+        let route = insertion_ctx.solution.routes[0].route();
+        assert_eq!(route.actor.vehicle.dimens.get_id().map(|id| id.as_str()), Some(expected));
+        let expected_cost = if expected == "v1" { 40. + penalty } else { 80. };
+        assert_eq!(problem.goal.fitness(&insertion_ctx).last(), Some(expected_cost));
+    }
+}
@@ -0,0 +1,117 @@
+use super::*;
+use crate::helpers::construction::features::{create_goal_ctx_with_feature, create_simple_demand};
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::domain::test_random;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::solution::{Activity, Registry};
+
+fn create_vehicle_at_depot(id: &str, depot: Location) -> Vehicle {
+    let detail = test_vehicle_detail();
+    let relocate = |place: Option<VehiclePlace>| place.map(|place| VehiclePlace { location: depot, ..place });
+
+    VehicleBuilder::default()
+        .id(id)
+        .details(vec![VehicleDetail { start: relocate(detail.start), end: relocate(detail.end) }])
+        .build()
+}
+
+fn create_insertion_ctx_with_routes(feature: Feature, routes: Vec<(&str, Vec<Location>)>) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            create_vehicle_at_depot("a1", 0),
+            create_vehicle_at_depot("a2", 0),
+            create_vehicle_at_depot("b1", 100),
+        ])
+        .build();
+    let routes = routes
+        .into_iter()
+        .map(|(vehicle, locations)| {
+            let activities = locations.into_iter().map(test_activity_with_location).collect();
+            create_route_context_with_activities(&fleet, vehicle, activities)
+        })
+        .collect();
+
+    create_insertion_context(Registry::new(&fleet, test_random()), create_goal_ctx_with_feature(feature), routes)
+}
+
+#[test]
+fn can_prefer_depot_balanced_solution_with_the_same_workload() {
+    let feature = create_depot_balanced_feature("depot_balance", None, DepotWorkload::Jobs).unwrap();
+    let objective = feature.objective.clone().unwrap();
+    let unbalanced = create_insertion_ctx_with_routes(feature.clone(), vec![("a1", vec![1, 2]), ("a2", vec![3, 4])]);
+    let balanced = create_insertion_ctx_with_routes(feature, vec![("a1", vec![1, 2]), ("b1", vec![3, 4])]);
+
+    assert_eq!(objective.fitness(&balanced), 0.);
+    assert!(objective.fitness(&unbalanced) > 0.);
+    assert_eq!(objective.total_order(&balanced, &unbalanced), Ordering::Less);
+}
+
+parameterized_test! {can_estimate_depot_workload_of_route, (workload, distance, expected), {
+    can_estimate_depot_workload_of_route_impl(workload, distance, expected);
+}}
+
+can_estimate_depot_workload_of_route! {
+    case01_jobs: (DepotWorkload::Jobs, 10., 2.),
+    case02_distance: (DepotWorkload::Distance, 10., 10.),
+}
+
+fn can_estimate_depot_workload_of_route_impl(workload: DepotWorkload, distance: f64, expected: f64) {
+    let feature = create_depot_balanced_feature("depot_balance", None, workload).unwrap();
+    let mut insertion_ctx = create_insertion_ctx_with_routes(feature.clone(), vec![("a1", vec![1, 2])]);
+    let route_ctx = insertion_ctx.solution.routes.first_mut().unwrap();
+    route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, distance);
+    feature.state.as_ref().unwrap().accept_route_state(route_ctx);
+    let job = Job::Single(test_single_with_id("job1"));
+
+    let result = feature.objective.unwrap().estimate(&MoveContext::route(
+        &insertion_ctx.solution,
+        insertion_ctx.solution.routes.first().unwrap(),
+        &job,
+    ));
+
+    assert_eq!(result, expected);
+}
+
+fn create_insertion_ctx_with_schedules(feature: Feature, departures: &[Timestamp]) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![
+            VehicleBuilder::default().id("v1").capacity(10).build(),
+            VehicleBuilder::default().id("v2").capacity(10).build(),
+        ])
+        .build();
+    let capacity = create_capacity_limit_feature::<SingleDimLoad>("capacity", 1).unwrap();
+    let routes = departures
+        .iter()
+        .zip(["v1", "v2"])
+        .map(|(&departure, vehicle)| {
+            let pickup = Activity {
+                job: Some(test_single_with_simple_demand(create_simple_demand(1))),
+                ..test_activity_with_location(5)
+            };
+            let mut route_ctx = create_route_context_with_activities(&fleet, vehicle, vec![pickup]);
+            route_ctx.route_mut().tour.all_activities_mut().zip([0., 5., 10.]).for_each(|(activity, time)| {
+                activity.schedule = Schedule::new(departure + time, departure + time);
+            });
+            capacity.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+            route_ctx
+        })
+        .collect();
+
+    create_insertion_context(Registry::new(&fleet, test_random()), create_goal_ctx_with_feature(feature), routes)
+}
+
+#[test]
+fn can_reduce_peak_concurrent_load_by_staggering_pickups() {
+    let feature = create_peak_concurrent_load_feature::<SingleDimLoad>("peak_load", None).unwrap();
+    let objective = feature.objective.clone().unwrap();
+    let concurrent = create_insertion_ctx_with_schedules(feature.clone(), &[0., 0.]);
+    let staggered = create_insertion_ctx_with_schedules(feature, &[0., 20.]);
+
+    assert_eq!(objective.fitness(&concurrent), 0.1);
+    assert_eq!(objective.fitness(&staggered), 0.05);
+    assert_eq!(objective.total_order(&staggered, &concurrent), Ordering::Less);
+}
@@ -0,0 +1,141 @@
+use super::*;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::ThreadPool;
+
+fn create_zone_job(id: &str, location: Location, zone: &str) -> Job {
+    let mut single = SingleBuilder::default().id(id).location(Some(location)).build();
+    single.dimens.set_zone(zone);
+
+    Job::Single(Arc::new(single))
+}
+
+fn create_targets(targets: &[(&str, usize)]) -> Vec<(String, usize)> {
+    targets.iter().map(|(zone, target)| (zone.to_string(), *target)).collect()
+}
+
+parameterized_test! {can_validate_targets, (targets, expected), {
+    can_validate_targets_impl(targets, expected);
+}}
+
+can_validate_targets! {
+    case_01_valid: (vec![("a", 1), ("b", 2)], None),
+    case_02_empty: (vec![], Some("zone route targets should not be empty")),
+    case_03_duplicates: (vec![("a", 1), ("a", 2)], Some("zone route targets should have unique zones")),
+}
+
+fn can_validate_targets_impl(targets: Vec<(&str, usize)>, expected: Option<&str>) {
+    let result = create_zone_route_target_feature("zone_routes", create_targets(targets.as_slice()).as_slice());
+
+    assert_eq!(result.err(), expected.map(|err| err.to_string()));
+}
+
+parameterized_test! {can_estimate_job_insertion, (route_idx, zone, target, expected), {
+    can_estimate_job_insertion_impl(route_idx, zone, target, expected);
+}}
+
+can_estimate_job_insertion! {
+    case_01_same_route: (0, "a", 2, 0.),
+    case_02_other_route_below_target: (1, "a", 2, -1.),
+    case_03_other_route_at_target: (1, "a", 1, 1.),
+    case_04_no_target: (1, "c", 1, 0.),
+}
+
+fn can_estimate_job_insertion_impl(route_idx: usize, zone: &str, target: usize, expected: Cost) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let job1 = create_zone_job("job1", 1, "a");
+    let job2 = create_zone_job("job2", 2, "b");
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes = vec![
+        create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_job(job1.to_single().clone())]),
+        create_route_context_with_activities(&fleet, "v2", vec![test_activity_with_job(job2.to_single().clone())]),
+    ];
+    let feature =
+        create_zone_route_target_feature("zone_routes", create_targets(&[("a", target), ("b", 1)]).as_slice()).unwrap();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let job = create_zone_job("job3", 3, zone);
+
+    let result = feature.objective.as_ref().unwrap().estimate(&MoveContext::route(
+        &solution_ctx,
+        &solution_ctx.routes[route_idx],
+        &job,
+    ));
+
+    assert_eq!(result, expected);
+}
+
+fn create_problem(targets: &[(&str, usize)]) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let activity = TestActivityCost::new_shared();
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2"), test_vehicle_with_id("v3")])
+            .build(),
+    );
+    let jobs = (1..=4)
+        .map(|idx| create_zone_job(format!("a{idx}").as_str(), idx, "a"))
+        .chain((1..=2).map(|idx| create_zone_job(format!("b{idx}").as_str(), 10 + idx, "b")))
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_zone_route_target_feature("zone_routes", create_targets(targets).as_slice()).unwrap(),
+            create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1).unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["zone_routes"], vec!["transport"]],
+    );
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    })
+}
+
+parameterized_test! {can_distribute_routes_to_match_zone_targets, (targets, expected_counts), {
+    can_distribute_routes_to_match_zone_targets_impl(targets, expected_counts);
+}}
+
+can_distribute_routes_to_match_zone_targets! {
+    case_01_one_route_per_zone: (&[("a", 1), ("b", 1)], (1, 1)),
+    case_02_two_routes_for_first_zone: (&[("a", 2), ("b", 1)], (2, 1)),
+}
+
+fn can_distribute_routes_to_match_zone_targets_impl(targets: &[(&str, usize)], expected_counts: (usize, usize)) {
+    let problem = create_problem(targets);
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(10))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    let get_zone_count = |zone: &str| {
+        solution
+            .routes
+            .iter()
+            .filter(|route| route.tour.jobs().any(|job| get_zone(&job).map(String::as_str) == Some(zone)))
+            .count()
+    };
+    assert_eq!((get_zone_count("a"), get_zone_count("b")), expected_counts);
+}
@@ -1,9 +1,11 @@
-use crate::construction::heuristics::{RouteState, UnassignmentInfo};
+use crate::construction::features::create_minimize_transport_costs_feature;
+use crate::construction::heuristics::{CostComponents, RouteState, UnassignmentInfo};
 use crate::helpers::construction::features::create_goal_ctx_with_transport;
 use crate::helpers::construction::heuristics::create_insertion_context;
-use crate::helpers::models::domain::test_random;
-use crate::helpers::models::problem::{test_fleet, SingleBuilder};
+use crate::helpers::models::domain::{create_empty_insertion_context, test_random};
+use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
+use crate::models::problem::Costs;
 use crate::models::solution::Registry;
 
 #[test]
@@ -119,3 +121,33 @@ fn can_use_debug_fmt_for_insertion_ctx() {
     assert!(result.contains("unassigned"));
     assert!(result.contains("id: \"single\""));
 }
+
+#[test]
+fn can_report_monetary_and_time_cost_components() {
+    let costs = Costs { fixed: 10., per_distance: 2., per_driving_time: 3., ..empty_costs() };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver_with_costs(empty_costs()))
+        .add_vehicle(VehicleBuilder::default().id("v1").costs(costs).build())
+        .build();
+    let feature = create_minimize_transport_costs_feature(
+        "transport",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        1,
+    )
+    .unwrap();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_location(10), test_activity_with_location(20)],
+    );
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes.push(route_ctx);
+
+    let components = insertion_ctx.solution.get_cost_components();
+
+    // NOTE route distance and duration are 40
+    assert_eq!(components, CostComponents { monetary: 90., time: 120. });
+    assert_eq!(components.monetary + components.time, feature.objective.unwrap().fitness(&insertion_ctx));
+}
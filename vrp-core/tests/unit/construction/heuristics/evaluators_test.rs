@@ -341,3 +341,438 @@ mod multi {
         assert_activities(success, vec![(0, 5), (1, 10), (2, 15)]);
     }
 }
+
+mod feasibility_cache {
+    use super::*;
+    use crate::construction::features::create_minimize_transport_costs_feature;
+    use crate::helpers::construction::features::create_goal_ctx_with_features;
+    use crate::helpers::models::domain::create_problem_with_goal_ctx_jobs_and_fleet;
+    use crate::helpers::models::solution::create_route_context_with_activities;
+    use crate::models::common::IdDimension;
+    use crate::models::problem::{Vehicle, VehiclePlace};
+    use crate::models::{ConstraintViolation, FeatureBuilder, FeatureConstraint, Problem, ViolationCode};
+    use rosomaxa::prelude::Environment;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const VIOLATION_CODE: ViolationCode = 1;
+
+    /// Rejects any job on route level for given vehicle and counts such checks.
+    struct CountingConstraint {
+        checks: Arc<AtomicUsize>,
+        vehicle_id: &'static str,
+        depends_on_other_routes: bool,
+    }
+
+    impl FeatureConstraint for CountingConstraint {
+        fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+            match move_ctx {
+                MoveContext::Route { route_ctx, .. }
+                    if route_ctx.route().actor.vehicle.dimens.get_id().map(String::as_str) == Some(self.vehicle_id) =>
+                {
+                    self.checks.fetch_add(1, Ordering::Relaxed);
+                    ConstraintViolation::fail(VIOLATION_CODE)
+                }
+                _ => None,
+            }
+        }
+
+        fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+            Ok(source)
+        }
+
+        fn depends_on_other_routes(&self) -> bool {
+            self.depends_on_other_routes
+        }
+    }
+
+    fn create_test_problem(
+        jobs: Vec<Job>,
+        vehicle_ids: &[&str],
+        infeasible_vehicle_id: &'static str,
+        depends_on_other_routes: bool,
+    ) -> (Arc<Problem>, Arc<AtomicUsize>) {
+        let checks = Arc::new(AtomicUsize::new(0));
+        let counting =
+            CountingConstraint { checks: checks.clone(), vehicle_id: infeasible_vehicle_id, depends_on_other_routes };
+        let features = vec![
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                2,
+            )
+            .unwrap(),
+            FeatureBuilder::default().with_name("counting").with_constraint(counting).build().unwrap(),
+        ];
+        // NOTE vehicles have different depots to be of different types, so registry offers each of them
+        let vehicles = vehicle_ids
+            .iter()
+            .enumerate()
+            .map(|(location, id)| {
+                let place = |time| Some(VehiclePlace { location, time });
+                let detail = test_vehicle_detail();
+                let details = vec![VehicleDetail {
+                    start: place(detail.start.unwrap().time),
+                    end: place(detail.end.unwrap().time),
+                }];
+
+                Vehicle { details, ..test_vehicle_with_id(id) }
+            })
+            .collect();
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicles(vehicles).build();
+        let goal = create_goal_ctx_with_features(features, vec![vec!["transport"]]);
+
+        (create_problem_with_goal_ctx_jobs_and_fleet(goal, jobs, fleet), checks)
+    }
+
+    fn create_test_data(depends_on_other_routes: bool) -> (InsertionContext, Job, Arc<AtomicUsize>) {
+        let job = SingleBuilder::default().id("job1").build_as_job_ref();
+        let (problem, checks) = create_test_problem(vec![job.clone()], &["v1", "v2"], "v1", depends_on_other_routes);
+        let mut insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+        insertion_ctx.solution.routes.extend(
+            ["v1", "v2"].iter().map(|id| create_route_context_with_activities(problem.fleet.as_ref(), id, vec![])),
+        );
+
+        (insertion_ctx, job, checks)
+    }
+
+    fn evaluate_in_first_route(insertion_ctx: &InsertionContext, job: &Job) -> InsertionResult {
+        let leg_selection = LegSelection::Exhaustive;
+        let result_selector = BestResultSelector::default();
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        eval_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            &insertion_ctx.solution.routes[0],
+            InsertionPosition::Any,
+            InsertionResult::make_failure(),
+        )
+    }
+
+    fn assert_failure_code(result: InsertionResult) {
+        match result {
+            InsertionResult::Failure(failure) => assert_eq!(failure.constraint, VIOLATION_CODE),
+            InsertionResult::Success(_) => unreachable!("unexpected success"),
+        }
+    }
+
+    #[test]
+    fn can_skip_redundant_route_constraint_checks() {
+        let (insertion_ctx, job, checks) = create_test_data(false);
+
+        assert_failure_code(evaluate_in_first_route(&insertion_ctx, &job));
+        assert_failure_code(evaluate_in_first_route(&insertion_ctx, &job));
+        assert_failure_code(evaluate_in_first_route(&insertion_ctx, &job));
+
+        assert_eq!(checks.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn can_invalidate_cache_on_route_mutation() {
+        let (mut insertion_ctx, job, checks) = create_test_data(false);
+        assert_failure_code(evaluate_in_first_route(&insertion_ctx, &job));
+
+        insertion_ctx.solution.routes[0].route_mut();
+        assert_failure_code(evaluate_in_first_route(&insertion_ctx, &job));
+        assert_failure_code(evaluate_in_first_route(&insertion_ctx, &job));
+
+        assert_eq!(checks.load(Ordering::Relaxed), 2);
+    }
+
+    parameterized_test! {can_invalidate_cache_on_accepted_insertion, (depends_on_other_routes, expected_checks), {
+        can_invalidate_cache_on_accepted_insertion_impl(depends_on_other_routes, expected_checks);
+    }}
+
+    can_invalidate_cache_on_accepted_insertion! {
+        case_01_route_local: (false, 1),
+        case_02_route_dependent: (true, 2),
+    }
+
+    fn can_invalidate_cache_on_accepted_insertion_impl(depends_on_other_routes: bool, expected_checks: usize) {
+        let (mut insertion_ctx, job, checks) = create_test_data(depends_on_other_routes);
+        assert_failure_code(evaluate_in_first_route(&insertion_ctx, &job));
+
+        insertion_ctx.problem.goal.accept_insertion(&mut insertion_ctx.solution, 1, &job);
+        assert_failure_code(evaluate_in_first_route(&insertion_ctx, &job));
+
+        assert_eq!(checks.load(Ordering::Relaxed), expected_checks);
+    }
+
+    parameterized_test! {can_reuse_route_checks_during_insertion_heuristic_run, (depends_on_other_routes, expected_checks), {
+        can_reuse_route_checks_during_insertion_heuristic_run_impl(depends_on_other_routes, expected_checks);
+    }}
+
+    can_reuse_route_checks_during_insertion_heuristic_run! {
+        case_01_route_local: (false, 4),
+        case_02_route_dependent: (true, 10),
+    }
+
+    fn can_reuse_route_checks_during_insertion_heuristic_run_impl(
+        depends_on_other_routes: bool,
+        expected_checks: usize,
+    ) {
+        let jobs = (1..=4)
+            .map(|idx| SingleBuilder::default().id(format!("job{idx}").as_str()).location(Some(idx)).build_as_job_ref())
+            .collect::<Vec<_>>();
+        let (problem, checks) = create_test_problem(jobs, &["v1", "v2"], "v2", depends_on_other_routes);
+        let insertion_ctx = InsertionContext::new(problem, Arc::new(Environment::default()));
+
+        let insertion_ctx = InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        );
+
+        assert!(insertion_ctx.solution.unassigned.is_empty());
+        assert_eq!(insertion_ctx.solution.routes.len(), 1);
+        assert_eq!(checks.load(Ordering::Relaxed), expected_checks);
+    }
+}
+
+mod marginal_cost {
+    use super::*;
+    use crate::helpers::construction::features::create_simple_demand;
+    use crate::helpers::models::domain::{test_random, ProblemBuilder};
+    use crate::helpers::solver::create_default_refinement_ctx;
+    use crate::models::{Solution, ViolationCode};
+    use crate::solver::search::{Recreate, RecreateWithCheapest};
+    use rosomaxa::utils::Environment;
+
+    // NOTE codes are defined by features of `ProblemBuilder`
+    const TIME_CONSTRAINT_CODE: ViolationCode = 1;
+    const CAPACITY_CONSTRAINT_CODE: ViolationCode = 2;
+
+    parameterized_test! {can_calculate_marginal_cost, (location, window, expected), {
+        can_calculate_marginal_cost_impl(location, window, expected);
+    }}
+
+    // NOTE both vehicle and driver costs are charged per distance and duration
+    can_calculate_marginal_cost! {
+        case_01_near_job: (11, (0., 100.), Some(8.)),
+        case_02_far_job: (50, (0., 100.), Some(320.)),
+        case_03_infeasible_job: (100, (0., 50.), None),
+    }
+
+    fn can_calculate_marginal_cost_impl(location: Location, window: (f64, f64), expected: Option<Cost>) {
+        let problem = ProblemBuilder::default().add_job("c1", 10, -1, (0., 100.)).add_vehicle("v1", 2, 0).build();
+        let insertion_ctx = RecreateWithCheapest::new(test_random()).run(
+            &create_default_refinement_ctx(problem.clone()),
+            InsertionContext::new(problem.clone(), Arc::new(Environment::default())),
+        );
+        let solution: Solution = insertion_ctx.solution.into();
+        let job = SingleBuilder::default()
+            .id("new")
+            .location(Some(location))
+            .demand(create_simple_demand(-1))
+            .times(vec![TimeWindow::new(window.0, window.1)])
+            .build_as_job_ref();
+
+        let result = marginal_cost(problem, &solution, &job);
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_explain_infeasible_marginal_cost, (location, demand, window, expected), {
+        can_explain_infeasible_marginal_cost_impl(location, demand, window, expected);
+    }}
+
+    can_explain_infeasible_marginal_cost! {
+        case_01_feasible: (11, -1, (0., 100.), Ok(8.)),
+        case_02_time_window: (100, -1, (0., 50.), Err(TIME_CONSTRAINT_CODE)),
+        case_03_capacity: (11, -3, (0., 100.), Err(CAPACITY_CONSTRAINT_CODE)),
+    }
+
+    fn can_explain_infeasible_marginal_cost_impl(
+        location: Location,
+        demand: i32,
+        window: (f64, f64),
+        expected: Result<Cost, ViolationCode>,
+    ) {
+        let problem = ProblemBuilder::default().add_job("c1", 10, -1, (0., 100.)).add_vehicle("v1", 2, 0).build();
+        let insertion_ctx = RecreateWithCheapest::new(test_random()).run(
+            &create_default_refinement_ctx(problem.clone()),
+            InsertionContext::new(problem.clone(), Arc::new(Environment::default())),
+        );
+        let solution: Solution = insertion_ctx.solution.into();
+        let job = SingleBuilder::default()
+            .id("new")
+            .location(Some(location))
+            .demand(create_simple_demand(demand))
+            .times(vec![TimeWindow::new(window.0, window.1)])
+            .build_as_job_ref();
+
+        let result = try_marginal_cost(problem, &solution, &job);
+
+        assert_eq!(result.map_err(|violation| violation.code), expected);
+    }
+}
+
+mod insert_job {
+    use super::*;
+    use crate::helpers::construction::features::create_simple_demand;
+    use crate::helpers::models::domain::{get_customer_id, test_random, ProblemBuilder};
+    use crate::helpers::solver::create_default_refinement_ctx;
+    use crate::models::common::Distance;
+    use crate::models::{Problem, Solution};
+    use crate::solver::search::{Recreate, RecreateWithCheapest};
+    use rosomaxa::utils::Environment;
+
+    fn create_problem_and_solution() -> (Arc<Problem>, Solution) {
+        // NOTE time windows force near jobs to be served by v1 and far jobs by v2, c1 is served first
+        let problem = ProblemBuilder::default()
+            .add_job("c1", 10, -1, (0., 15.))
+            .add_job("c2", 20, -1, (0., 50.))
+            .add_job("c3", 80, -1, (0., 50.))
+            .add_job("c4", 90, -1, (0., 50.))
+            .add_vehicle("v1", 10, 0)
+            .add_vehicle("v2", 10, 100)
+            .build();
+        let insertion_ctx = RecreateWithCheapest::new(test_random()).run(
+            &create_default_refinement_ctx(problem.clone()),
+            InsertionContext::new(problem.clone(), Arc::new(Environment::default())),
+        );
+
+        (problem, insertion_ctx.solution.into())
+    }
+
+    fn get_tour_ids(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+        solution
+            .routes
+            .iter()
+            .find(|route| get_vehicle_id(&route.actor.vehicle) == vehicle_id)
+            .map(|route| {
+                route
+                    .tour
+                    .all_activities()
+                    .filter_map(|activity| activity.retrieve_job())
+                    .map(|job| get_customer_id(&job))
+            })
+            .expect("cannot find route")
+            .collect()
+    }
+
+    fn create_new_job(location: Location, window: (f64, f64)) -> Job {
+        SingleBuilder::default()
+            .id("new")
+            .location(Some(location))
+            .demand(create_simple_demand(-1))
+            .times(vec![TimeWindow::new(window.0, window.1)])
+            .build_as_job_ref()
+    }
+
+    parameterized_test! {can_insert_job_changing_only_nearest_route, reopt_radius, {
+        can_insert_job_changing_only_nearest_route_impl(reopt_radius);
+    }}
+
+    can_insert_job_changing_only_nearest_route! {
+        case_01_no_reoptimization: None,
+        case_02_with_reoptimization: Some(10.),
+    }
+
+    fn can_insert_job_changing_only_nearest_route_impl(reopt_radius: Option<Distance>) {
+        let (problem, solution) = create_problem_and_solution();
+        let original_far_tour = get_tour_ids(&solution, "v2");
+        // NOTE time window allows only one position: between c1 and c2
+        let job = create_new_job(15, (0., 18.));
+
+        let result = insert_job(problem, &solution, &job, reopt_radius).expect("cannot insert job");
+
+        assert_eq!(result.routes.len(), 2);
+        assert!(result.unassigned.is_empty());
+        assert_eq!(get_tour_ids(&result, "v1"), vec!["c1", "new", "c2"]);
+        assert_eq!(get_tour_ids(&result, "v2"), original_far_tour);
+        assert_eq!(get_tour_ids(&solution, "v1"), vec!["c1", "c2"]);
+    }
+
+    #[test]
+    fn can_return_violation_when_job_cannot_be_inserted() {
+        let (problem, solution) = create_problem_and_solution();
+        let job = create_new_job(50, (0., 10.));
+
+        let result = insert_job(problem, &solution, &job, Some(10.));
+
+        assert_eq!(result.map(|_| ()).map_err(|violation| violation.code), Err(1));
+    }
+}
+
+mod remove_job {
+    use super::*;
+    use crate::helpers::models::domain::{get_customer_id, test_random, ProblemBuilder};
+    use crate::helpers::solver::create_default_refinement_ctx;
+    use crate::models::{Problem, Solution};
+    use crate::solver::search::{Recreate, RecreateWithCheapest};
+    use rosomaxa::utils::Environment;
+
+    fn create_problem_and_solution() -> (Arc<Problem>, Solution) {
+        // NOTE time windows force c1, c2, c3 order with waiting at c2
+        let problem = ProblemBuilder::default()
+            .add_job("c1", 10, -1, (0., 15.))
+            .add_job("c2", 20, -1, (40., 45.))
+            .add_job("c3", 30, -1, (45., 100.))
+            .add_vehicle("v1", 10, 0)
+            .build();
+        let insertion_ctx = RecreateWithCheapest::new(test_random()).run(
+            &create_default_refinement_ctx(problem.clone()),
+            InsertionContext::new(problem.clone(), Arc::new(Environment::default())),
+        );
+
+        (problem, insertion_ctx.solution.into())
+    }
+
+    fn get_tour_schedules(solution: &Solution) -> Vec<(String, Timestamp)> {
+        solution.routes[0]
+            .tour
+            .all_activities()
+            .map(|activity| {
+                let id = activity.retrieve_job().map_or("depot".to_string(), |job| get_customer_id(&job));
+                (id, activity.schedule.arrival)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn can_remove_job_in_the_middle_of_route() {
+        let (problem, solution) = create_problem_and_solution();
+        assert_eq!(
+            get_tour_schedules(&solution),
+            vec![
+                ("depot".to_string(), 0.),
+                ("c1".to_string(), 10.),
+                ("c2".to_string(), 20.),
+                ("c3".to_string(), 50.),
+                ("depot".to_string(), 80.)
+            ]
+        );
+
+        let result = remove_job(problem, &solution, "c2").expect("cannot remove job");
+
+        assert_eq!(result.routes.len(), 1);
+        assert!(result.unassigned.is_empty());
+        assert_eq!(
+            get_tour_schedules(&result),
+            vec![
+                ("depot".to_string(), 0.),
+                ("c1".to_string(), 10.),
+                ("c3".to_string(), 30.),
+                ("depot".to_string(), 75.)
+            ]
+        );
+    }
+
+    #[test]
+    fn can_return_error_when_job_is_not_in_solution() {
+        let (problem, solution) = create_problem_and_solution();
+
+        let result = remove_job(problem, &solution, "unknown");
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::models::domain::ProblemBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+fn create_problem_sharing_costs(base: &Problem, jobs: &[(&str, Location)], vehicles: &[&str]) -> Problem {
+    let mut builder = ProblemBuilder::default();
+    jobs.iter().for_each(|(id, location)| {
+        builder.add_job(id, *location, 1, (0., 1000.));
+    });
+    vehicles.iter().for_each(|id| {
+        builder.add_vehicle(id, 10, 0);
+    });
+    let problem = builder.build();
+
+    Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: vec![],
+        goal: base.goal.clone(),
+        activity: base.activity.clone(),
+        transport: base.transport.clone(),
+        extras: base.extras.clone(),
+    }
+}
+
+#[test]
+fn can_merge_disjoint_problems() {
+    let a = ProblemBuilder::default().add_job("c1", 1, 1, (0., 1000.)).add_vehicle("v1", 10, 0).build();
+    let b = create_problem_sharing_costs(&a, &[("c2", 2), ("c3", 3)], &["v2", "v3"]);
+
+    let merged = merge_problems(&a, &b).expect("cannot merge problems");
+
+    assert_eq!(merged.jobs.size(), 3);
+    assert_eq!(merged.fleet.vehicles.len(), 3);
+    assert_eq!(merged.fleet.actors.len(), 3);
+    assert_eq!(merged.fleet.drivers.len(), 1);
+}
+
+#[test]
+fn can_detect_merge_conflict_of_drivers() {
+    let a = ProblemBuilder::default().add_job("c1", 1, 1, (0., 1000.)).add_vehicle("v1", 10, 0).build();
+    let b = create_problem_sharing_costs(&a, &[("c2", 2)], &["v2"]);
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver_with_costs(Costs { fixed: 10., ..test_costs() }))],
+        b.fleet.vehicles.clone(),
+        Box::new(|actors| create_details_actor_groups(actors)),
+    );
+    let b = Problem { fleet: Arc::new(fleet), ..b };
+
+    let result = merge_problems(&a, &b);
+
+    assert_eq!(result.err(), Some("cannot merge problems with different drivers".to_string()));
+}
+
+parameterized_test! {can_detect_merge_conflicts, (jobs, vehicles, is_shared, expected), {
+    can_detect_merge_conflicts_impl(jobs, vehicles, is_shared, expected);
+}}
+
+can_detect_merge_conflicts! {
+    case_01_job_id: (&[("c1", 2)], &["v2"], true, "cannot merge problems: duplicate job id 'c1'"),
+    case_02_vehicle_id: (&[("c2", 2)], &["v1"], true, "cannot merge problems: duplicate vehicle id 'v1'"),
+    case_03_costs: (&[("c2", 2)], &["v2"], false, "cannot merge problems with incompatible costs"),
+}
+
+fn can_detect_merge_conflicts_impl(jobs: &[(&str, Location)], vehicles: &[&str], is_shared: bool, expected: &str) {
+    let a = ProblemBuilder::default().add_job("c1", 1, 1, (0., 1000.)).add_vehicle("v1", 10, 0).build();
+    let b = create_problem_sharing_costs(&a, jobs, vehicles);
+    let b = if is_shared { b } else { Problem { transport: TestTransportCost::new_shared(), ..b } };
+
+    let result = merge_problems(&a, &b);
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
+
+#[test]
+fn can_export_matrices_for_problem_locations() {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 1, 1, (0., 1000.))
+        .add_job("c2", 3, 1, (0., 1000.))
+        .add_vehicle("v1", 10, 0)
+        .build();
+    let mut buffer = Vec::new();
+
+    problem.export_matrices(&mut buffer).expect("cannot export matrices");
+
+    let content = String::from_utf8(buffer).unwrap();
+    let lines = content.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "profile,from,to,distance,duration");
+    assert_eq!(lines.len() - 1, problem.fleet.profiles.len() * 3 * 3);
+    assert!(lines.contains(&"0,3,0,3,3"));
+}
+
+#[test]
+fn can_compute_savings_sorted_descending() {
+    // NOTE c2 and c3 are close to each other and far from the depot, so merging them saves the most
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 2, 1, (0., 1000.))
+        .add_job("c2", 20, 1, (0., 1000.))
+        .add_job("c3", 22, 1, (0., 1000.))
+        .add_vehicle("v1", 10, 0)
+        .build();
+
+    let savings = compute_savings(problem.as_ref());
+
+    assert_eq!(savings.len(), 3);
+    assert_eq!(savings[0], ("c2".to_string(), "c3".to_string(), 40.));
+    assert!(savings.windows(2).all(|pair| pair[0].2 >= pair[1].2));
+}
+
+parameterized_test! {can_compute_min_vehicles_lower_bound, (demands, expected), {
+    can_compute_min_vehicles_lower_bound_impl(demands, expected);
+}}
+
+can_compute_min_vehicles_lower_bound! {
+    case01_total_deliveries: (&[-4, -4, -4, -4, -4], 2),
+    case02_large_deliveries: (&[-6, -6, -6, -1], 3),
+    case03_total_pickups: (&[3, 3, 3, 3, -1], 2),
+    case04_large_delivery_and_pickup: (&[-6, 6], 1),
+    case05_no_demand: (&[0, 0], 0),
+}
+
+fn can_compute_min_vehicles_lower_bound_impl(demands: &[i32], expected: usize) {
+    let mut builder = ProblemBuilder::default();
+    demands.iter().enumerate().for_each(|(idx, demand)| {
+        builder.add_job(format!("c{idx}").as_str(), idx + 1, *demand, (0., 1000.));
+    });
+    let problem = builder.add_vehicle("v1", 10, 0).add_vehicle("v2", 10, 0).add_vehicle("v3", 10, 0).build();
+
+    let result = min_vehicles_lower_bound(problem.as_ref());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_use_not_less_vehicles_than_lower_bound() {
+    let mut builder = ProblemBuilder::default();
+    [-6, -6, -6, -1, -3, -2].iter().enumerate().for_each(|(idx, demand)| {
+        builder.add_job(format!("c{idx}").as_str(), idx + 1, *demand, (0., 1000.));
+    });
+    let problem =
+        (0..5).fold(&mut builder, |builder, idx| builder.add_vehicle(format!("v{idx}").as_str(), 10, 0)).build();
+    let environment = Arc::new(Environment::default());
+
+    let insertion_ctx = RecreateWithCheapest::new(environment.random.clone())
+        .run(&create_default_refinement_ctx(problem.clone()), InsertionContext::new(problem.clone(), environment));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(min_vehicles_lower_bound(problem.as_ref()), 3);
+    assert!(insertion_ctx.solution.routes.len() >= 3);
+}
+
+#[test]
+fn can_score_tight_and_spread_instance_as_more_difficult() {
+    let create_problem = |locations: &[Location], window_size: f64| {
+        let mut builder = ProblemBuilder::default();
+        locations.iter().enumerate().for_each(|(idx, &location)| {
+            let start = location as f64;
+            builder.add_job(format!("c{idx}").as_str(), location, -1, (start, start + window_size));
+        });
+        builder.add_vehicle("v1", 10, 0).build()
+    };
+    let loose = create_problem(&[1, 2, 3, 4, 5], 1000.);
+    let tight = create_problem(&[100, 200, 300, 400, 500], 50.);
+
+    let loose = instance_difficulty(loose.as_ref());
+    let tight = instance_difficulty(tight.as_ref());
+
+    assert_eq!(loose.job_count, tight.job_count);
+    assert_eq!(loose.capacity_tightness, 0.5);
+    assert_eq!(tight.capacity_tightness, 0.5);
+    assert!(tight.time_window_tightness > loose.time_window_tightness);
+    assert!(tight.geographic_spread > loose.geographic_spread);
+    assert!(tight.total > loose.total);
+}
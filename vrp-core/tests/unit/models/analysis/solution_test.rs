@@ -0,0 +1,131 @@
+use super::*;
+use crate::helpers::models::domain::{test_random, ProblemBuilder};
+use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::Location;
+use crate::models::solution::Registry;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+
+#[test]
+fn can_report_route_feasibility_with_binding_constraint() {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 10, -1, (0., 1000.))
+        .add_job("c2", 20, -1, (0., 1000.))
+        .add_job("c3", 30, -1, (0., 1000.))
+        .add_vehicle("v1", 1, 0)
+        .add_vehicle("v2", 1, 0)
+        .build();
+    let get_activities = |ids: &[&str]| {
+        ids.iter()
+            .map(|id| {
+                let job =
+                    problem.jobs.all().find(|job| job.dimens().get_id().map(|job_id| job_id == id).unwrap_or(false));
+                test_activity_with_job(job.unwrap().to_single().clone())
+            })
+            .collect::<Vec<_>>()
+    };
+    // NOTE first route exceeds vehicle capacity
+    let solution = Solution {
+        registry: Registry::new(&problem.fleet, test_random()),
+        routes: vec![
+            create_route_with_activities(&problem.fleet, "v1", get_activities(&["c1", "c2"])),
+            create_route_with_activities(&problem.fleet, "v2", get_activities(&["c3"])),
+        ],
+        unassigned: vec![],
+        seed: None,
+    };
+
+    let result = solution.route_feasibility(problem.clone());
+
+    assert_eq!(
+        result,
+        vec![
+            RouteFeasibility { vehicle_id: Some("v1".to_string()), is_feasible: false, violation: Some(2) },
+            RouteFeasibility { vehicle_id: Some("v2".to_string()), is_feasible: true, violation: None },
+        ]
+    );
+}
+
+#[test]
+fn can_compute_route_utilization() {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 10, -4, (0., 1000.))
+        .add_job("c2", 20, -2, (0., 1000.))
+        .add_vehicle("v1", 10, 0)
+        // NOTE use other depot to keep vehicles in different actor groups, so v1 is always available
+        .add_vehicle("v2", 0, 1)
+        .build();
+    let environment = Arc::new(Environment::default());
+    let insertion_ctx = RecreateWithCheapest::new(environment.random.clone())
+        .run(&create_default_refinement_ctx(problem.clone()), InsertionContext::new(problem.clone(), environment));
+    let mut solution = Solution::from(insertion_ctx.solution);
+    // NOTE empty route of vehicle without capacity checks zero division
+    solution.routes.push(create_route_with_activities(&problem.fleet, "v2", vec![]));
+
+    let result = solution.utilization(problem.as_ref());
+
+    assert_eq!(
+        result,
+        vec![
+            RouteUtilization { vehicle_id: Some("v1".to_string()), capacity: 0.6, time: 0.04, distance: 40. },
+            RouteUtilization { vehicle_id: Some("v2".to_string()), capacity: 0., time: 0., distance: 0. },
+        ]
+    );
+    assert!(result
+        .iter()
+        .all(|utilization| (0. ..=1.).contains(&utilization.capacity) && (0. ..=1.).contains(&utilization.time)));
+}
+
+#[test]
+fn can_get_vehicle_manifest_in_route_order() {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 10, -1, (0., 1000.))
+        .add_job("c2", 20, -1, (0., 1000.))
+        .add_job("c3", 30, -1, (0., 1000.))
+        .add_vehicle("v1", 10, 0)
+        .add_vehicle("v2", 10, 0)
+        .build();
+    let get_activities = |ids: &[&str]| {
+        ids.iter()
+            .map(|id| {
+                let job = problem.jobs.all().find(|job| job.dimens().get_id().map_or(false, |job_id| job_id == id));
+                test_activity_with_job(job.unwrap().to_single().clone())
+            })
+            .collect::<Vec<_>>()
+    };
+    let solution = Solution {
+        registry: Registry::new(&problem.fleet, test_random()),
+        routes: vec![create_route_with_activities(&problem.fleet, "v1", get_activities(&["c3", "c1", "c2"]))],
+        unassigned: vec![],
+        seed: None,
+    };
+
+    assert_eq!(solution.vehicle_manifest("v1"), Some(vec!["c3".to_string(), "c1".to_string(), "c2".to_string()]));
+    assert_eq!(solution.vehicle_manifest("v2"), None);
+    assert_eq!(solution.vehicle_manifest("v3"), None);
+}
+
+parameterized_test! {can_compute_robustness_margin, (demands, expected), {
+    can_compute_robustness_margin_impl(demands, expected);
+}}
+
+can_compute_robustness_margin! {
+    case01_tightly_loaded: (&[-5, -3], 25.),
+    case02_loosely_loaded: (&[-1, -1], 400.),
+    case03_no_demand: (&[], f64::INFINITY),
+}
+
+fn can_compute_robustness_margin_impl(demands: &[i32], expected: f64) {
+    let mut builder = ProblemBuilder::default();
+    demands.iter().enumerate().for_each(|(idx, &demand)| {
+        builder.add_job(format!("c{idx}").as_str(), (idx + 1) as Location, demand, (0., 1000.));
+    });
+    let problem = builder.add_vehicle("v1", 10, 0).build();
+    let environment = Arc::new(Environment::default());
+    let insertion_ctx = RecreateWithCheapest::new(environment.random.clone())
+        .run(&create_default_refinement_ctx(problem.clone()), InsertionContext::new(problem.clone(), environment));
+    let solution = Solution::from(insertion_ctx.solution);
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.robustness_margin(), expected);
+}
@@ -45,6 +45,11 @@ mod single {
 
         assert!(from_value(10).can_fit(&from_value(5)));
         assert!(!from_value(5).can_fit(&from_value(10)));
+
+        assert_eq!(from_value(5).ratio(&from_value(10)), 0.5);
+        assert_eq!(from_value(0).ratio(&from_value(0)), 0.);
+        assert!(from_value(0).can_fit(&from_value(0)));
+        assert!(!from_value(0).can_fit(&from_value(1)));
     }
 }
 
@@ -101,5 +106,9 @@ mod multi {
 
         assert!(!from_vec(vec![1, 0]).can_fit(&from_vec(vec![0, 1])));
         assert!(!from_vec(vec![3, 0, 2]).can_fit(&from_vec(vec![1, 1, 4])));
+
+        assert_eq!(from_vec(vec![1, 2]).ratio(&from_vec(vec![4, 4])), 0.5);
+        assert_eq!(from_vec(vec![0, 0]).ratio(&from_vec(vec![0, 0])), 0.);
+        assert!(!from_vec(vec![0, 0]).can_fit(&from_vec(vec![0, 1])));
     }
 }
@@ -1,9 +1,11 @@
 use super::*;
 use crate::construction::features::*;
 use crate::helpers::construction::features::create_goal_ctx_with_features;
-use crate::helpers::models::domain::create_empty_insertion_context;
+use crate::helpers::models::domain::{create_empty_insertion_context, test_random, ProblemBuilder};
 use crate::helpers::models::solution::{test_activity_without_job, test_actor};
+use crate::helpers::solver::create_default_refinement_ctx;
 use crate::models::common::SingleDimLoad;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
 
 fn create_constraint_feature(name: &str, violation: Option<ConstraintViolation>) -> Feature {
     struct TestFeatureConstraint {
@@ -235,3 +237,71 @@ fn can_use_objective_total_order_impl(
 
     assert_eq!(goal_ctx.total_order(&left, &right), expected);
 }
+
+parameterized_test! {can_calculate_partial_fitness, (route_indices, expected_tours), {
+    can_calculate_partial_fitness_impl(route_indices, expected_tours);
+}}
+
+can_calculate_partial_fitness! {
+    case_01_all_routes: (vec![0, 1], 2.),
+    case_02_one_route: (vec![1], 1.),
+    case_03_no_routes: (vec![], 0.),
+}
+
+fn can_calculate_partial_fitness_impl(route_indices: Vec<usize>, expected_tours: f64) {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 10, -1, (0., 1000.))
+        .add_job("c2", 20, -1, (0., 1000.))
+        .add_job("c3", 30, -1, (0., 1000.))
+        .add_vehicle("v1", 2, 0)
+        .add_vehicle("v2", 2, 0)
+        .build();
+    let insertion_ctx = RecreateWithCheapest::new(test_random()).run(
+        &create_default_refinement_ctx(problem.clone()),
+        InsertionContext::new(problem.clone(), Arc::new(Environment::default())),
+    );
+    assert_eq!(insertion_ctx.solution.routes.len(), 2);
+    let full_fitness = problem.goal.fitness(&insertion_ctx).collect::<Vec<_>>();
+
+    let partial_fitness = problem.goal.partial_fitness(&insertion_ctx, route_indices.as_slice());
+
+    // NOTE objectives are defined by `ProblemBuilder`: min unassigned, min tours, transport costs
+    assert_eq!(partial_fitness.len(), 3);
+    assert_eq!(partial_fitness[0], full_fitness[0]);
+    assert_eq!(partial_fitness[1], expected_tours);
+    if route_indices.len() == insertion_ctx.solution.routes.len() {
+        assert_eq!(partial_fitness, full_fitness);
+    } else {
+        assert!(partial_fitness[2] < full_fitness[2]);
+    }
+}
+
+#[test]
+fn can_calculate_route_contributions() {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 10, -1, (0., 1000.))
+        .add_job("c2", 20, -1, (0., 1000.))
+        .add_job("c3", 30, -1, (0., 1000.))
+        .add_job("c4", 40, -5, (0., 1000.))
+        .add_vehicle("v1", 2, 0)
+        .add_vehicle("v2", 2, 0)
+        .build();
+    let insertion_ctx = RecreateWithCheapest::new(test_random()).run(
+        &create_default_refinement_ctx(problem.clone()),
+        InsertionContext::new(problem.clone(), Arc::new(Environment::default())),
+    );
+    assert_eq!(insertion_ctx.solution.routes.len(), 2);
+    assert_eq!(insertion_ctx.solution.unassigned.len(), 1);
+    let full_fitness = problem.goal.fitness(&insertion_ctx).collect::<Vec<_>>();
+
+    let contributions = problem.goal.route_contributions(&insertion_ctx);
+
+    // NOTE objectives are defined by `ProblemBuilder`: min unassigned, min tours, transport costs
+    assert_eq!(contributions.len(), 2);
+    assert!(contributions.iter().all(|route| route.len() == 3 && route[0] == 0. && route[1] == 1.));
+    assert!(contributions.iter().all(|route| route[2] > 0.));
+    let sum_of = |idx: usize| contributions.iter().map(|route| route[idx]).sum::<f64>();
+    assert_eq!(sum_of(1), full_fitness[1]);
+    assert!((sum_of(2) - full_fitness[2]).abs() < 1E-9);
+    assert_eq!(full_fitness[0], 1.);
+}
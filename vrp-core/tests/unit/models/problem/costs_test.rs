@@ -1,5 +1,20 @@
 use super::*;
-use crate::helpers::models::solution::{create_empty_route_ctx, test_actor_with_profile};
+use crate::construction::enablers::{update_route_schedule, ScheduleStateKeys};
+use crate::construction::features::{create_minimize_transport_costs_feature, create_minimize_unassigned_jobs_feature};
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::problem::{
+    test_driver, test_fleet, test_vehicle_with_id, FleetBuilder, SingleBuilder, TestTransportCost,
+};
+use crate::helpers::models::solution::{
+    create_empty_route_ctx, create_route_context_with_activities, test_activity_with_location_and_duration,
+    test_actor_with_profile, ActivityBuilder,
+};
+use crate::models::problem::Jobs;
+use crate::models::solution::Place;
+use crate::models::Problem;
+use crate::solver::{create_default_config_builder, get_static_heuristic, Solver};
+use rosomaxa::evolution::TelemetryMode;
+use rosomaxa::utils::ThreadPool;
 
 fn create_matrix_data(
     profile: Profile,
@@ -264,3 +279,381 @@ mod objective {
         assert_eq!(result, expected);
     }
 }
+
+parameterized_test! {can_add_min_gap_to_job_activity, (has_job, expected_departure, expected_arrival), {
+    can_add_min_gap_to_job_activity_impl(has_job, expected_departure, expected_arrival);
+}}
+
+can_add_min_gap_to_job_activity! {
+    case_01_job: (true, 25., 75.),
+    case_02_depot: (false, 15., 85.),
+}
+
+fn can_add_min_gap_to_job_activity_impl(has_job: bool, expected_departure: Timestamp, expected_arrival: Timestamp) {
+    let route_ctx = create_empty_route_ctx();
+    let activity_cost = MinGapActivityCost::new(Arc::new(SimpleActivityCost::default()), 10.).unwrap();
+    let mut activity = test_activity_with_location_and_duration(0, 5.);
+    if !has_job {
+        activity.job = None;
+    }
+
+    assert_eq!(activity_cost.estimate_departure(route_ctx.route(), &activity, 10.), expected_departure);
+    assert_eq!(activity_cost.estimate_arrival(route_ctx.route(), &activity, 90.), expected_arrival);
+}
+
+parameterized_test! {can_vary_service_duration_by_time_class, (arrival, exp_departure, departure, exp_arrival), {
+    can_vary_service_duration_by_time_class_impl(arrival, exp_departure, departure, exp_arrival);
+}}
+
+can_vary_service_duration_by_time_class! {
+    case_01_normal_class: (10., 15., 90., 85.),
+    case_02_slow_class: (25., 40., 30., 15.),
+    case_03_fast_class: (50., 52.5, 58., 53.),
+}
+
+fn can_vary_service_duration_by_time_class_impl(
+    arrival: Timestamp,
+    expected_departure: Timestamp,
+    departure: Timestamp,
+    expected_arrival: Timestamp,
+) {
+    let route_ctx = create_empty_route_ctx();
+    let classes = vec![(TimeWindow::new(20., 40.), 3.), (TimeWindow::new(50., 60.), 0.5)];
+    let activity_cost =
+        TimeDependentServiceActivityCost::new(Arc::new(SimpleActivityCost::default()), classes).unwrap();
+    let activity = test_activity_with_location_and_duration(0, 5.);
+
+    assert_eq!(activity_cost.estimate_departure(route_ctx.route(), &activity, arrival), expected_departure);
+    assert_eq!(activity_cost.estimate_arrival(route_ctx.route(), &activity, departure), expected_arrival);
+}
+
+parameterized_test! {can_shift_downstream_arrivals_with_slow_service, (slow_class, expected), {
+    can_shift_downstream_arrivals_with_slow_service_impl(TimeWindow::new(slow_class.0, slow_class.1), expected);
+}}
+
+can_shift_downstream_arrivals_with_slow_service! {
+    case_01_arrival_out_of_slow_class: ((50., 60.), vec![(10., 15.), (25., 30.)]),
+    case_02_arrival_in_slow_class: ((8., 12.), vec![(10., 25.), (35., 40.)]),
+}
+
+fn can_shift_downstream_arrivals_with_slow_service_impl(slow_class: TimeWindow, expected_schedules: Vec<(f64, f64)>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_location_and_duration(10, 5.), test_activity_with_location_and_duration(20, 5.)],
+    );
+    let activity_cost =
+        TimeDependentServiceActivityCost::new(Arc::new(SimpleActivityCost::default()), vec![(slow_class, 3.)]).unwrap();
+
+    update_route_schedule(&mut route_ctx, &activity_cost, &TestTransportCost::default(), &ScheduleStateKeys::default());
+
+    let schedules = route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .skip(1)
+        .take(2)
+        .map(|activity| (activity.schedule.arrival, activity.schedule.departure))
+        .collect::<Vec<_>>();
+    assert_eq!(schedules, expected_schedules);
+}
+
+parameterized_test! {can_validate_matrix, (matrix, check_triangle_inequality, expected), {
+    can_validate_matrix_impl(matrix, check_triangle_inequality, expected);
+}}
+
+can_validate_matrix! {
+    case_01_valid: (vec![0., 1., 2., 1., 0., 1., 2., 1., 0.], true, Ok(())),
+    case_02_negative_entry: (vec![0., -1., 1., 0.], false, Err(vec![
+        MatrixIssue::NegativeValue { from: 0, to: 1, value: -1. },
+    ])),
+    case_03_nonzero_diagonal: (vec![0., 1., 1., 2.], false, Err(vec![
+        MatrixIssue::NonZeroDiagonal { index: 1, value: 2. },
+    ])),
+    case_04_triangle_violation: (vec![0., 1., 5., 1., 0., 1., 5., 1., 0.], true, Err(vec![
+        MatrixIssue::TriangleInequality { from: 0, via: 1, to: 2 },
+        MatrixIssue::TriangleInequality { from: 2, via: 1, to: 0 },
+    ])),
+    case_05_triangle_violation_unchecked: (vec![0., 1., 5., 1., 0., 1., 5., 1., 0.], false, Ok(())),
+    case_06_invalid_size: (vec![0., 1., 1.], false, Err(vec![MatrixIssue::InvalidSize(3)])),
+}
+
+fn can_validate_matrix_impl(matrix: Vec<f64>, check_triangle_inequality: bool, expected: Result<(), Vec<MatrixIssue>>) {
+    let options = MatrixValidationOptions { check_triangle_inequality };
+
+    let result = validate_matrix(matrix.as_slice(), &options);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_normalize_matrix_diagonal() {
+    let mut matrix = vec![1., 2., 3., 4.];
+
+    normalize_matrix_diagonal(matrix.as_mut_slice());
+
+    assert_eq!(matrix, vec![0., 2., 3., 0.]);
+    assert_eq!(validate_matrix(matrix.as_slice(), &MatrixValidationOptions::default()), Ok(()));
+}
+
+parameterized_test! {can_create_neighbor_lists, (k, expected), {
+    can_create_neighbor_lists_impl(k, expected);
+}}
+
+can_create_neighbor_lists! {
+    case_01_capped: (2, vec![
+        vec![(2, 1.), (3, 1.)],
+        vec![(3, 2.), (0, 4.)],
+        vec![(0, 1.), (1, 3.)],
+        vec![(1, 1.), (0, 5.)],
+    ]),
+    case_02_all: (5, vec![
+        vec![(2, 1.), (3, 1.), (1, 2.)],
+        vec![(3, 2.), (0, 4.), (2, 6.)],
+        vec![(0, 1.), (1, 3.), (3, 3.)],
+        vec![(1, 1.), (0, 5.), (2, 7.)],
+    ]),
+    case_03_empty: (0, vec![vec![], vec![], vec![], vec![]]),
+}
+
+fn can_create_neighbor_lists_impl(k: usize, expected: Vec<Vec<(Location, Distance)>>) {
+    #[rustfmt::skip]
+    let matrix = vec![
+        0., 2., 1., 1.,
+        4., 0., 6., 2.,
+        1., 3., 0., 3.,
+        5., 1., 7., 0.,
+    ];
+
+    let neighbor_lists = NeighborLists::new(matrix.as_slice(), k).unwrap();
+
+    let result = (0..4).map(|location| neighbor_lists.neighbors(location).to_vec()).collect::<Vec<_>>();
+    assert_eq!(result, expected);
+    assert!(result.iter().all(|neighbors| neighbors.windows(2).all(|pair| pair[0].1 <= pair[1].1)));
+}
+
+#[test]
+fn can_check_neighbor_and_reject_non_square_matrix() {
+    let neighbor_lists = NeighborLists::new(&[0., 1., 2., 1., 0., 3., 2., 3., 0.], 1).unwrap();
+
+    assert!(neighbor_lists.is_neighbor(0, 1));
+    assert!(!neighbor_lists.is_neighbor(0, 2));
+    assert!(neighbor_lists.neighbors(3).is_empty());
+    assert!(NeighborLists::new(&[0., 1., 2.], 1).is_err());
+}
+
+#[test]
+fn can_create_neighbor_lists_from_transport() {
+    #[rustfmt::skip]
+    let distances = vec![
+        0., 2., 1.,
+        4., 0., 6.,
+        1., 3., 0.,
+    ];
+    let transport =
+        create_matrix_transport_cost(vec![MatrixData::new(0, None, distances.clone(), distances.clone())]).unwrap();
+
+    let neighbor_lists = NeighborLists::new_from_transport(transport.as_ref(), &Profile::default(), 3, 1);
+
+    let result = (0..3).map(|location| neighbor_lists.neighbors(location).to_vec()).collect::<Vec<_>>();
+    assert_eq!(result, vec![vec![(2, 1.)], vec![(0, 4.)], vec![(0, 1.)]]);
+}
+
+fn create_matrix_data_with_negative_travel_time() -> MatrixData {
+    // NOTE travel time from location 1 to 2 is negative
+    let durations = vec![0., 10., 20., 10., 0., -5., 20., 5., 0.];
+
+    MatrixData::new(0, None, durations.clone(), durations.iter().map(|duration| duration.abs()).collect())
+}
+
+#[test]
+fn can_clamp_negative_travel_time_to_keep_schedule_monotonic() {
+    let transport = create_matrix_transport_cost_with_policy(
+        vec![create_matrix_data_with_negative_travel_time()],
+        TravelTimePolicy::Clamp(1.),
+    )
+    .unwrap();
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_location_and_duration(1, 0.), test_activity_with_location_and_duration(2, 0.)],
+    );
+
+    update_route_schedule(
+        &mut route_ctx,
+        &SimpleActivityCost::default(),
+        transport.as_ref(),
+        &ScheduleStateKeys::default(),
+    );
+
+    let schedules =
+        route_ctx.route().tour.all_activities().map(|activity| activity.schedule.clone()).collect::<Vec<_>>();
+    assert_eq!(
+        schedules.iter().skip(1).take(2).map(|schedule| (schedule.arrival, schedule.departure)).collect::<Vec<_>>(),
+        vec![(10., 10.), (11., 11.)]
+    );
+    assert!(schedules.windows(2).all(|pair| pair[1].arrival >= pair[0].departure));
+}
+
+#[test]
+fn can_reject_or_warn_about_negative_travel_time() {
+    let messages = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+    let logger: InfoLogger = {
+        let messages = messages.clone();
+        Arc::new(move |message: &str| messages.lock().unwrap().push(message.to_string()))
+    };
+
+    let rejected = create_matrix_transport_cost_with_policy(
+        vec![create_matrix_data_with_negative_travel_time()],
+        TravelTimePolicy::Reject,
+    );
+    let warned = create_matrix_transport_cost_with_policy(
+        vec![create_matrix_data_with_negative_travel_time()],
+        TravelTimePolicy::Warn(logger),
+    );
+    let invalid_clamp = create_matrix_transport_cost_with_policy(
+        vec![create_matrix_data_with_negative_travel_time()],
+        TravelTimePolicy::Clamp(0.),
+    );
+
+    let expected = "matrix with profile index 0 has 1 zero or negative travel time(s), first is from 1 to 2: -5";
+    assert_eq!(rejected.err(), Some(expected.to_string()));
+    assert_eq!(warned.unwrap().duration_approx(&Profile::default(), 1, 2), -5.);
+    assert_eq!(*messages.lock().unwrap(), vec![expected.to_string()]);
+    assert_eq!(invalid_clamp.err(), Some("minimum travel time should be positive".to_string()));
+}
+
+fn create_activity_with_job(id: &str, location: Location, duration: Duration) -> Activity {
+    let single = SingleBuilder::default().id(id).location(Some(location)).duration(duration).build_shared();
+    ActivityBuilder::default().place(Place { location, duration, time: TimeWindow::max() }).job(Some(single)).build()
+}
+
+fn create_dependent_service_activity_cost() -> DependentServiceActivityCost {
+    let dependencies = vec![("a".to_string(), "b".to_string(), 0.2), ("c".to_string(), "b".to_string(), 0.5)];
+
+    DependentServiceActivityCost::new(Arc::new(SimpleActivityCost::default()), dependencies).unwrap()
+}
+
+parameterized_test! {can_reduce_service_duration_of_dependent_job, (jobs, expected), {
+    can_reduce_service_duration_of_dependent_job_impl(jobs, expected);
+}}
+
+can_reduce_service_duration_of_dependent_job! {
+    case_01_prerequisite_before: (vec![("a", 10), ("b", 20)], (20., 22.)),
+    case_02_prerequisite_after: (vec![("b", 20), ("a", 10)], (20., 30.)),
+    case_03_two_prerequisites: (vec![("c", 5), ("a", 10), ("b", 20)], (20., 22.)),
+    case_04_no_prerequisite: (vec![("c", 10), ("b", 20)], (20., 25.)),
+    case_05_unrelated_job: (vec![("d", 10), ("b", 20)], (20., 30.)),
+}
+
+fn can_reduce_service_duration_of_dependent_job_impl(jobs: Vec<(&str, Location)>, expected: (Timestamp, Timestamp)) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = jobs
+        .into_iter()
+        .map(|(id, location)| create_activity_with_job(id, location, if id == "b" { 10. } else { 0. }))
+        .collect();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+    let activity_cost = create_dependent_service_activity_cost();
+
+    update_route_schedule(&mut route_ctx, &activity_cost, &TestTransportCost::default(), &ScheduleStateKeys::default());
+
+    let schedule = route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .find(|activity| activity.job.as_ref().and_then(|single| single.dimens.get_id()).map_or(false, |id| id == "b"))
+        .map(|activity| (activity.schedule.arrival, activity.schedule.departure))
+        .unwrap();
+    assert_eq!(schedule, expected);
+}
+
+parameterized_test! {can_estimate_dependent_service_duration_on_insertion, (arrival, expected_departure), {
+    can_estimate_dependent_service_duration_on_insertion_impl(arrival, expected_departure);
+}}
+
+can_estimate_dependent_service_duration_on_insertion! {
+    case_01_after_prerequisite: (50., 52.),
+    case_02_before_prerequisite: (5., 15.),
+}
+
+fn can_estimate_dependent_service_duration_on_insertion_impl(arrival: Timestamp, expected_departure: Timestamp) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![create_activity_with_job("a", 10, 0.)]);
+    let activity_cost = create_dependent_service_activity_cost();
+    update_route_schedule(&mut route_ctx, &activity_cost, &TestTransportCost::default(), &ScheduleStateKeys::default());
+    let activity = create_activity_with_job("b", 20, 10.);
+
+    let departure = activity_cost.estimate_departure(route_ctx.route(), &activity, arrival);
+
+    assert_eq!(departure, expected_departure);
+    assert_eq!(activity_cost.estimate_arrival(route_ctx.route(), &activity, 100.), 90.);
+}
+
+#[test]
+fn can_validate_dependent_service_multiplier() {
+    let create = |multiplier: f64| {
+        DependentServiceActivityCost::new(
+            Arc::new(SimpleActivityCost::default()),
+            vec![("a".to_string(), "b".to_string(), multiplier)],
+        )
+    };
+
+    assert!(create(0.5).is_ok());
+    assert!(create(1.).is_ok());
+    assert!(create(0.).is_err());
+    assert!(create(1.5).is_err());
+}
+
+#[test]
+fn can_serve_prerequisite_job_first_to_reduce_dependent_service_duration() {
+    let transport = TestTransportCost::new_shared();
+    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(create_dependent_service_activity_cost());
+    let fleet = Arc::new(test_fleet());
+    let jobs = vec![
+        SingleBuilder::default().id("a").location(Some(10)).build_as_job_ref(),
+        SingleBuilder::default().id("b").location(Some(5)).duration(100.).build_as_job_ref(),
+    ];
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1).unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["transport"]],
+    );
+    let problem = Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    });
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(50))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+    let schedules = solution.routes[0]
+        .tour
+        .all_activities()
+        .filter_map(|activity| {
+            let id = activity.job.as_ref().and_then(|single| single.dimens.get_id().cloned())?;
+            Some((id, activity.schedule.departure - activity.schedule.arrival))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(schedules, vec![("a".to_string(), 0.), ("b".to_string(), 20.)]);
+}
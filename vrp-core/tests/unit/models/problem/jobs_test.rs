@@ -1,7 +1,14 @@
 use super::*;
+use crate::construction::features::{create_minimize_transport_costs_feature, create_minimize_unassigned_jobs_feature};
+use crate::construction::heuristics::InsertionContext;
+use crate::helpers::construction::features::create_goal_ctx_with_features;
+use crate::helpers::models::domain::{create_problem_with_goal_ctx_jobs_and_fleet, test_random};
 use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
 use crate::models::problem::{TravelTime, VehicleDetail, VehiclePlace};
 use crate::models::solution::Route;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
 
 #[derive(Default)]
 struct OnlyDistanceCost {}
@@ -251,3 +258,86 @@ fn can_handle_negative_distances_durations_impl(transport_costs: Arc<dyn Transpo
             .all(|(_, cost)| { (cost as LowPrecisionCost - UNREACHABLE_COST).abs() < f32::EPSILON }));
     }
 }
+
+fn create_closed_periods(periods: Vec<(Timestamp, Timestamp)>) -> ClosedPeriods {
+    vec![(10, periods.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect())].into_iter().collect()
+}
+
+fn create_single_with_window(window: (Timestamp, Timestamp)) -> Single {
+    SingleBuilder::default()
+        .id("job1")
+        .location(Some(10))
+        .duration(10.)
+        .times(vec![TimeWindow::new(window.0, window.1)])
+        .build()
+}
+
+parameterized_test! {can_exclude_closed_periods_from_time_windows, (periods, expected), {
+    can_exclude_closed_periods_from_time_windows_impl(periods, expected);
+}}
+
+can_exclude_closed_periods_from_time_windows! {
+    case01_no_overlap: (vec![(200., 300.)], Ok(vec![(0., 100.)])),
+    case02_in_the_middle: (vec![(50., 60.)], Ok(vec![(0., 40.), (60., 100.)])),
+    case03_at_the_start: (vec![(0., 20.)], Ok(vec![(20., 100.)])),
+    case04_at_the_end: (vec![(95., 120.)], Ok(vec![(0., 85.)])),
+    case05_multiple: (vec![(70., 80.), (20., 30.)], Ok(vec![(0., 10.), (30., 60.), (80., 100.)])),
+    case06_whole_window: (vec![(0., 200.)], Err(())),
+}
+
+fn can_exclude_closed_periods_from_time_windows_impl(
+    periods: Vec<(Timestamp, Timestamp)>,
+    expected: Result<Vec<(Timestamp, Timestamp)>, ()>,
+) {
+    let single = create_single_with_window((0., 100.));
+
+    let result = exclude_closed_periods(&single, &create_closed_periods(periods)).map(|single| {
+        single.places[0]
+            .times
+            .iter()
+            .map(|time| time.as_time_window().map(|tw| (tw.start, tw.end)).unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    assert_eq!(result.map_err(|_| ()), expected);
+}
+
+parameterized_test! {can_schedule_job_outside_of_closed_period, (period, expected_departure), {
+    can_schedule_job_outside_of_closed_period_impl(period, expected_departure);
+}}
+
+can_schedule_job_outside_of_closed_period! {
+    case01_served_before: ((50., 100.), 20.),
+    case02_arrival_during: ((5., 30.), 40.),
+    case03_service_during: ((15., 30.), 40.),
+}
+
+fn can_schedule_job_outside_of_closed_period_impl(period: (Timestamp, Timestamp), expected_departure: Timestamp) {
+    let closed_periods = create_closed_periods(vec![period]);
+    let job = exclude_closed_periods(&create_single_with_window((0., 1000.)), &closed_periods).unwrap();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_minimize_transport_costs_feature(
+                "transport",
+                TestTransportCost::new_shared(),
+                TestActivityCost::new_shared(),
+                1,
+            )
+            .unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["transport"]],
+    );
+    let problem = create_problem_with_goal_ctx_jobs_and_fleet(goal, vec![Job::Single(Arc::new(job))], test_fleet());
+
+    let insertion_ctx = RecreateWithCheapest::new(test_random()).run(
+        &create_default_refinement_ctx(problem.clone()),
+        InsertionContext::new(problem, Arc::new(Environment::default())),
+    );
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    let activity = insertion_ctx.solution.routes[0].route().tour.get(1).unwrap();
+    let service = TimeWindow::new(activity.schedule.departure - 10., activity.schedule.departure);
+    assert_eq!(activity.schedule.departure, expected_departure);
+    assert!(service.overlapping(&TimeWindow::new(period.0, period.1)).map_or(true, |overlap| overlap.duration() == 0.));
+}
@@ -0,0 +1,64 @@
+use super::*;
+use crate::helpers::models::domain::ProblemBuilder;
+use std::io::BufReader;
+
+fn create_test_problem() -> Arc<Problem> {
+    ProblemBuilder::default()
+        .add_job("c1", 1, -1, (0., 1000.))
+        .add_job("c2", 2, -1, (0., 1000.))
+        .add_job("c3", 3, -1, (0., 1000.))
+        .add_job("c4", 4, -1, (0., 1000.))
+        .add_vehicle("v1", 10, 0)
+        .add_vehicle("v2", 10, 0)
+        .build()
+}
+
+fn get_ids(dimens: &Dimensions) -> String {
+    dimens.get_id().cloned().unwrap()
+}
+
+#[test]
+fn can_read_assignment_csv() {
+    let csv = "job_id,vehicle_id,sequence\nc3,v2,2\nc1,v2,1\nc2,v1,1\n";
+
+    let solution = read_assignment_csv(create_test_problem(), BufReader::new(csv.as_bytes())).unwrap();
+
+    let routes = solution
+        .routes
+        .iter()
+        .map(|route| {
+            let jobs = route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.job.as_ref())
+                .map(|single| get_ids(&single.dimens))
+                .collect::<Vec<_>>();
+            (get_ids(&route.actor.vehicle.dimens), jobs)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        routes,
+        vec![("v2".to_string(), vec!["c1".to_string(), "c3".to_string()]), ("v1".to_string(), vec!["c2".to_string()]),]
+    );
+    assert_eq!(solution.unassigned.iter().map(|(job, _)| get_ids(job.dimens())).collect::<Vec<_>>(), vec!["c4"]);
+    assert_eq!(solution.registry.next().count(), 0);
+}
+
+parameterized_test! {can_validate_assignment_csv, (csv, expected), {
+    can_validate_assignment_csv_impl(csv, expected);
+}}
+
+can_validate_assignment_csv! {
+    case01_unknown_job: ("c5,v1,1", "unknown job id: 'c5'"),
+    case02_unknown_vehicle: ("c1,v3,1", "unknown vehicle id: 'v3'"),
+    case03_duplicate_job: ("c1,v1,1\nc1,v2,1", "job 'c1' is assigned too many times"),
+    case04_duplicate_sequence: ("c1,v1,1\nc2,v1,1", "duplicate sequence in assignment of vehicle 'v1'"),
+    case05_invalid_sequence: ("c1,v1,first", "invalid assignment line: 'c1,v1,first'"),
+    case06_missing_column: ("c1,v1", "invalid assignment line: 'c1,v1'"),
+}
+
+fn can_validate_assignment_csv_impl(csv: &str, expected: &str) {
+    let result = read_assignment_csv(create_test_problem(), BufReader::new(csv.as_bytes()));
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
@@ -0,0 +1,55 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::models::domain::ProblemBuilder;
+use crate::helpers::models::problem::SingleBuilder;
+use crate::helpers::models::solution::{create_route_with_activities, ActivityBuilder};
+use crate::models::solution::Place;
+
+fn create_activity(location: Location, demand: i32, tw_start: Timestamp, schedule: (Timestamp, Timestamp)) -> Activity {
+    ActivityBuilder::default()
+        .place(Place { location, duration: 0., time: TimeWindow::new(tw_start, 1000.) })
+        .schedule(Schedule::new(schedule.0, schedule.1))
+        .job(Some(
+            SingleBuilder::default().location(Some(location)).demand(create_simple_demand(demand)).build_shared(),
+        ))
+        .build()
+}
+
+#[test]
+fn can_calculate_route_statistics() {
+    let problem = ProblemBuilder::default().add_vehicle("v1", 10, 0).build();
+    let mut route = create_route_with_activities(
+        &problem.fleet,
+        "v1",
+        vec![
+            create_activity(10, -3, 0., (10., 10.)),
+            create_activity(10, 2, 0., (10., 10.)),
+            create_activity(20, -1, 40., (20., 40.)),
+        ],
+    );
+    let end_idx = route.tour.total() - 1;
+    route.tour.get_mut(end_idx).unwrap().schedule = Schedule::new(60., 60.);
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let expected_distance = activities
+        .windows(2)
+        .map(|pair| {
+            problem.transport.distance_approx(
+                &route.actor.vehicle.profile,
+                pair[0].place.location,
+                pair[1].place.location,
+            )
+        })
+        .sum::<Distance>();
+    let expected_waiting =
+        activities.iter().map(|activity| (activity.place.time.start - activity.schedule.arrival).max(0.)).sum::<f64>();
+
+    let stats = route_statistics::<SingleDimLoad>(&problem, &route);
+
+    assert_eq!(stats.distance, expected_distance);
+    assert_eq!(stats.distance, 40.);
+    assert_eq!(stats.duration, 60.);
+    assert_eq!(stats.waiting, expected_waiting);
+    assert_eq!(stats.waiting, 20.);
+    assert_eq!(stats.max_load, SingleDimLoad::new(4));
+    assert_eq!(stats.stops, 2);
+}
@@ -0,0 +1,41 @@
+use super::*;
+use crate::helpers::models::domain::{test_random, ProblemBuilder};
+use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_location};
+use crate::models::common::Schedule;
+use crate::models::solution::Registry;
+
+fn create_solution(problem: &Problem, second_arrival: Timestamp) -> Solution {
+    let mut second = test_activity_with_location(20);
+    second.schedule = Schedule::new(second_arrival, second_arrival);
+    let mut route = create_route_with_activities(&problem.fleet, "v1", vec![test_activity_with_location(10), second]);
+    let end_idx = route.tour.total() - 1;
+    route.tour.get_mut(end_idx).unwrap().schedule = Schedule::new(40., 40.);
+
+    Solution {
+        registry: Registry::new(&problem.fleet, test_random()),
+        routes: vec![route],
+        unassigned: vec![],
+        seed: None,
+    }
+}
+
+#[test]
+fn can_accept_consistent_timeline() {
+    let problem = ProblemBuilder::default().add_vehicle("v1", 10, 0).build();
+    let solution = create_solution(&problem, 20.);
+
+    assert_eq!(validate_timeline(&problem, &solution), Ok(()));
+}
+
+#[test]
+fn can_detect_arrival_earlier_than_travel_allows() {
+    let problem = ProblemBuilder::default().add_vehicle("v1", 10, 0).build();
+    let solution = create_solution(&problem, 15.);
+
+    let result = validate_timeline(&problem, &solution);
+
+    assert_eq!(
+        result,
+        Err(vec![TimelineError::EarlyArrival { route_idx: 0, activity_idx: 2, expected: 20., actual: 15. }])
+    );
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::helpers::models::domain::ProblemBuilder;
+use rosomaxa::utils::ThreadPool;
+
+const SOLUTION_SIZE: usize = 12 * ACTIVITY_SIZE_ESTIMATE;
+
+fn create_test_problem() -> Arc<Problem> {
+    let mut builder = ProblemBuilder::default();
+    (1..=10).for_each(|idx| {
+        builder.add_job(format!("c{idx}").as_str(), idx, -1, (0., 1000.));
+    });
+
+    builder.add_vehicle("v1", 10, 0).build()
+}
+
+parameterized_test! {can_derive_limits_within_memory_target, (target, expected_total), {
+    can_derive_limits_within_memory_target_impl(target, expected_total);
+}}
+
+can_derive_limits_within_memory_target! {
+    case_01_small_target: (10 * SOLUTION_SIZE, 10),
+    case_02_large_target: (100 * SOLUTION_SIZE + 1, 100),
+}
+
+fn can_derive_limits_within_memory_target_impl(target: usize, expected_total: usize) {
+    let problem = create_test_problem();
+
+    let limits = MemoryBudget::new(target).derive_limits(problem.as_ref(), &Environment::default());
+
+    assert_eq!(limits.solution_size, SOLUTION_SIZE);
+    assert_eq!(limits.population_size + limits.initial_size + limits.selection_size, expected_total);
+    assert!(limits.selection_size >= 1);
+    assert!((1..=4).contains(&limits.initial_size));
+}
+
+#[test]
+fn can_use_min_limits_when_target_is_too_small() {
+    let problem = create_test_problem();
+
+    let limits = MemoryBudget::new(1).derive_limits(problem.as_ref(), &Environment::default());
+
+    assert_eq!(
+        limits,
+        MemoryLimits { solution_size: SOLUTION_SIZE, population_size: 2, initial_size: 1, selection_size: 1 }
+    );
+}
+
+#[test]
+fn can_solve_problem_with_small_memory_target() {
+    let problem = create_test_problem();
+    let environment = Arc::new(Environment::default());
+    let budget = MemoryBudget::new(8 * SOLUTION_SIZE);
+
+    let solution = ThreadPool::new(1).execute(|| {
+        create_memory_bounded_config_builder(problem.clone(), environment, TelemetryMode::None, &budget)
+            .with_max_generations(Some(20))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+            .unwrap()
+    });
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+}
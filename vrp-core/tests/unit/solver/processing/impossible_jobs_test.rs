@@ -0,0 +1,107 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::Location;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use std::ops::Deref;
+
+const VIOLATION_CODE: ViolationCode = 42;
+
+fn create_problem_with_code(problem: Arc<Problem>, code: Option<ViolationCode>) -> Arc<Problem> {
+    let problem = Arc::try_unwrap(problem).unwrap_or_else(|_| unreachable!());
+
+    Arc::new(Problem {
+        extras: Arc::new({
+            let mut extras = problem.extras.deref().clone();
+            if let Some(code) = code {
+                extras.set_impossible_job_code(code);
+            }
+            extras
+        }),
+        ..problem
+    })
+}
+
+parameterized_test! {can_detect_impossible_job, (location, window, is_impossible), {
+    can_detect_impossible_job_impl(location, window, is_impossible);
+}}
+
+can_detect_impossible_job! {
+    case_01_closes_before_arrival: (10, (0., 5.), true),
+    case_02_closes_at_arrival: (10, (0., 10.), false),
+    case_03_opens_after_arrival: (10, (20., 30.), false),
+    case_04_at_depot: (0, (0., 5.), false),
+}
+
+fn can_detect_impossible_job_impl(location: Location, window: (f64, f64), is_impossible: bool) {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 5, -1, (0., 100.))
+        .add_job("c2", location, -1, window)
+        .add_vehicle("v1", 2, 0)
+        .build();
+
+    let result = prune_impossible_jobs(problem.as_ref());
+
+    match result {
+        Some((pruned, impossible_jobs)) => {
+            assert!(is_impossible);
+            assert_eq!(get_customer_ids_from_jobs(impossible_jobs.as_slice()), vec!["c2"]);
+            assert_eq!(get_customer_ids_from_jobs(pruned.jobs.all().collect::<Vec<_>>().as_slice()), vec!["c1"]);
+        }
+        None => assert!(!is_impossible),
+    }
+}
+
+#[test]
+fn can_keep_job_reachable_by_any_vehicle() {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 100, -1, (0., 5.))
+        .add_vehicle("v1", 2, 0)
+        .add_vehicle("v2", 2, 98)
+        .build();
+
+    assert!(prune_impossible_jobs(problem.as_ref()).is_none());
+}
+
+parameterized_test! {can_prune_impossible_job_and_report_it_as_unassigned, (code, expected_jobs), {
+    can_prune_impossible_job_and_report_it_as_unassigned_impl(code, expected_jobs);
+}}
+
+can_prune_impossible_job_and_report_it_as_unassigned! {
+    case_01_enabled: (Some(VIOLATION_CODE), 1),
+    case_02_disabled: (None, 2),
+}
+
+fn can_prune_impossible_job_and_report_it_as_unassigned_impl(code: Option<ViolationCode>, expected_jobs: usize) {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 5, -1, (0., 100.))
+        .add_job("c2", 10, -1, (0., 5.))
+        .add_vehicle("v1", 2, 0)
+        .build();
+    let problem = create_problem_with_code(problem, code);
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+
+    let refinement_ctx = ImpossibleJobsPruning::default().pre_process(refinement_ctx);
+    assert_eq!(refinement_ctx.problem.jobs.size(), expected_jobs);
+
+    let insertion_ctx = RecreateWithCheapest::new(test_random()).run(
+        &refinement_ctx,
+        InsertionContext::new(refinement_ctx.problem.clone(), refinement_ctx.environment.clone()),
+    );
+    let insertion_ctx = ImpossibleJobsPruning::default().post_process(insertion_ctx);
+
+    assert_eq!(insertion_ctx.problem.jobs.size(), 2);
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+    if code.is_some() {
+        assert_eq!(insertion_ctx.solution.unassigned.len(), 1);
+        let (job, info) = insertion_ctx.solution.unassigned.iter().next().unwrap();
+        assert_eq!(get_customer_id(job), "c2");
+        assert!(matches!(info, UnassignmentInfo::Simple(VIOLATION_CODE)));
+    } else {
+        // NOTE without pruning, the impossible job is still not assigned, but with a reason found by the solver
+        assert_eq!(insertion_ctx.solution.unassigned.len(), 1);
+        let (job, info) = insertion_ctx.solution.unassigned.iter().next().unwrap();
+        assert_eq!(get_customer_id(job), "c2");
+        assert!(!matches!(info, UnassignmentInfo::Simple(VIOLATION_CODE)));
+    }
+}
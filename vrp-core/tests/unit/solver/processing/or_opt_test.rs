@@ -0,0 +1,96 @@
+use super::*;
+use crate::construction::features::create_minimize_transport_costs_feature;
+use crate::helpers::construction::features::create_goal_ctx_with_feature;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
+use crate::models::problem::{create_matrix_transport_cost, Job, Jobs, MatrixData};
+use crate::models::solution::Registry;
+
+// NOTE no single job relocation improves the initial tour, but relocation of c1 and c2 chain does
+const POINTS: [(f64, f64); 6] = [(0., 0.), (4., 1.), (5., 1.), (0., 7.), (2., 10.), (3., 7.)];
+
+fn create_problem_and_solution() -> (Arc<Problem>, Solution) {
+    let fleet =
+        Arc::new(FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build());
+    let singles = (1..POINTS.len())
+        .map(|idx| test_single_with_id_and_location(format!("c{idx}").as_str(), Some(idx)))
+        .collect::<Vec<_>>();
+    let jobs = singles.iter().cloned().map(Job::Single).collect::<Vec<_>>();
+
+    let mut route = create_route_with_activities(&fleet, "v1", vec![]);
+    singles.into_iter().enumerate().for_each(|(idx, single)| {
+        let mut activity = test_activity_with_job(single);
+        activity.place.location = idx + 1;
+        route.tour.insert_last(activity);
+    });
+
+    let matrix = POINTS
+        .iter()
+        .flat_map(|&(x1, y1)| POINTS.iter().map(move |&(x2, y2)| ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt()))
+        .collect::<Vec<_>>();
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, matrix.clone(), matrix)]).unwrap();
+    let activity = Arc::new(TestActivityCost::default());
+    let goal = create_goal_ctx_with_feature(
+        create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1).unwrap(),
+    );
+    let problem = Problem {
+        fleet: fleet.clone(),
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    };
+    let solution = Solution {
+        registry: Registry::new(&fleet, test_random()),
+        routes: vec![route],
+        unassigned: Default::default(),
+        seed: None,
+    };
+
+    (Arc::new(problem), solution)
+}
+
+fn get_tour_ids(solution: &Solution) -> Vec<String> {
+    solution.routes[0]
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .map(|job| get_customer_id(&job))
+        .collect()
+}
+
+parameterized_test! {can_relocate_chains_to_reduce_distance, (max_chain, expected), {
+    can_relocate_chains_to_reduce_distance_impl(max_chain, expected);
+}}
+
+can_relocate_chains_to_reduce_distance! {
+    case_01_single_jobs: (1, vec!["c1", "c2", "c3", "c4", "c5"]),
+    case_02_chains_of_two: (2, vec!["c3", "c4", "c5", "c2", "c1"]),
+    case_03_chains_of_three: (3, vec!["c3", "c4", "c5", "c2", "c1"]),
+}
+
+fn can_relocate_chains_to_reduce_distance_impl(max_chain: usize, expected: Vec<&str>) {
+    let (problem, solution) = create_problem_and_solution();
+    let get_distance = |solution: &Solution| {
+        let tour_locations = solution.routes[0].tour.all_activities().map(|activity| activity.place.location);
+        tour_locations
+            .clone()
+            .zip(tour_locations.skip(1))
+            .map(|(from, to)| {
+                let ((x1, y1), (x2, y2)) = (POINTS[from], POINTS[to]);
+                ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt()
+            })
+            .sum::<f64>()
+    };
+
+    let result = smooth_solution(problem, &solution, max_chain);
+
+    assert_eq!(get_tour_ids(&result), expected);
+    assert!(get_distance(&result) <= get_distance(&solution));
+    if max_chain > 1 {
+        assert!(get_distance(&result) < get_distance(&solution) - 1.);
+    }
+}
@@ -46,3 +46,33 @@ fn can_use_exchange_inter_route_best_operator_impl(
 
     compare_with_ignore(get_customer_ids_from_routes(&new_insertion_ctx).as_slice(), expected_ids, "cX");
 }
+
+parameterized_test! {can_use_exchange_inter_route_neighbor_operator, (k, expected_ids), {
+    can_use_exchange_inter_route_neighbor_operator_impl(k, expected_ids);
+}}
+
+// NOTE seed job is c2: its nearest neighbor is c1 from the same route, the next one is c5
+can_use_exchange_inter_route_neighbor_operator! {
+    case_01_neighbor_in_same_route: (1, None),
+    case_02_neighbor_in_other_route: (2, Some(vec![vec!["c0", "c1", "c5"], vec!["c2", "c3", "c4"], vec!["c6", "c7", "c8"]])),
+}
+
+fn can_use_exchange_inter_route_neighbor_operator_impl(k: usize, expected_ids: Option<Vec<Vec<&str>>>) {
+    let ints = [0, 3].into_iter().chain([16; 1024].into_iter()).collect();
+    let reals = vec![1.; 1024];
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 3, true);
+    let insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(ints, reals))),
+    );
+    let operator = ExchangeInterRouteNeighbor::new(insertion_ctx.problem.as_ref(), k, 0., 0., 0.);
+
+    let new_insertion_ctx =
+        operator.explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx);
+
+    let result = new_insertion_ctx.map(|insertion_ctx| get_customer_ids_from_routes(&insertion_ctx));
+    let expected_ids = expected_ids
+        .map(|routes| routes.iter().map(|ids| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>()).collect());
+    assert_eq!(result, expected_ids);
+}
@@ -0,0 +1,58 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use std::sync::Arc;
+
+fn create_insertion_ctx(window: (f64, f64), reals: Vec<f64>) -> (RefinementContext, InsertionContext) {
+    let problem = ProblemBuilder::default().add_job("c1", 10, -1, window).add_vehicle("v1", 1, 0).build();
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let insertion_ctx = RecreateWithCheapest::new(test_random())
+        .run(&refinement_ctx, InsertionContext::new(problem, refinement_ctx.environment.clone()));
+
+    let insertion_ctx = InsertionContext {
+        environment: create_test_environment_with_random(Arc::new(FakeRandom::new(vec![0], reals))),
+        ..insertion_ctx
+    };
+
+    (refinement_ctx, insertion_ctx)
+}
+
+fn get_departure(insertion_ctx: &InsertionContext) -> Timestamp {
+    insertion_ctx.solution.routes[0].route().tour.start().unwrap().schedule.departure
+}
+
+#[test]
+fn can_eliminate_waiting_time_by_shifting_departure() {
+    let (refinement_ctx, insertion_ctx) = create_insertion_ctx((100., 200.), vec![90.]);
+    assert_eq!(get_departure(&insertion_ctx), 0.);
+    assert_eq!(get_waiting_time(&insertion_ctx.solution.routes[0]), 90.);
+
+    let result = PerturbDeparture::default().explore(&refinement_ctx, &insertion_ctx).expect("no new solution");
+
+    assert_eq!(get_departure(&result), 90.);
+    assert_eq!(get_waiting_time(&result.solution.routes[0]), 0.);
+    assert_eq!(refinement_ctx.problem.goal.total_order(&result, &insertion_ctx), Ordering::Less);
+}
+
+#[test]
+fn can_reject_shift_without_waiting_time_reduction() {
+    let (refinement_ctx, insertion_ctx) = create_insertion_ctx((0., 200.), vec![50.]);
+    assert_eq!(get_waiting_time(&insertion_ctx.solution.routes[0]), 0.);
+
+    let result = PerturbDeparture::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn can_reject_shift_which_delays_route_end() {
+    let (refinement_ctx, insertion_ctx) = create_insertion_ctx((100., 200.), vec![120.]);
+    assert_eq!(get_waiting_time(&insertion_ctx.solution.routes[0]), 90.);
+
+    let result = PerturbDeparture::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::helpers::models::domain::ProblemBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::problem::Costs;
+use crate::models::solution::read_assignment_csv;
+use std::io::BufReader;
+
+fn create_insertion_ctx(assignment: &str) -> (RefinementContext, InsertionContext) {
+    let expensive = Costs { per_distance: 10., ..test_costs() };
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 20, -1, (0., 1000.))
+        .add_job("c2", 2, -1, (0., 1000.))
+        .add_vehicle("cheap", 1, 0)
+        .add_vehicle_with_costs("expensive", 1, 0, expensive)
+        .build();
+    let refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let solution = read_assignment_csv(problem.clone(), BufReader::new(assignment.as_bytes())).unwrap();
+    let insertion_ctx =
+        InsertionContext::new_from_solution(problem, (solution, None), refinement_ctx.environment.clone());
+
+    (refinement_ctx, insertion_ctx)
+}
+
+fn get_assignment(insertion_ctx: &InsertionContext) -> Vec<(String, String)> {
+    let mut assignment = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let vehicle_id = get_vehicle_id(&route_ctx.route().actor.vehicle).clone();
+            route_ctx.route().tour.jobs().map(move |job| (get_job_id(&job).clone(), vehicle_id.clone()))
+        })
+        .collect::<Vec<_>>();
+    assignment.sort();
+
+    assignment
+}
+
+#[test]
+fn can_swap_vehicles_of_fixed_routes_to_reduce_cost() {
+    let (refinement_ctx, insertion_ctx) = create_insertion_ctx("c1,expensive,1\nc2,cheap,1");
+
+    let result = ReassignVehicles::default().explore(&refinement_ctx, &insertion_ctx).expect("no new solution");
+
+    assert_eq!(
+        get_assignment(&result),
+        vec![("c1".to_string(), "cheap".to_string()), ("c2".to_string(), "expensive".to_string())]
+    );
+    assert!(result.solution.required.is_empty());
+    assert!(result.solution.unassigned.is_empty());
+    assert!(result.solution.get_total_cost() < insertion_ctx.solution.get_total_cost());
+    assert_eq!(refinement_ctx.problem.goal.total_order(&result, &insertion_ctx), Ordering::Less);
+}
+
+#[test]
+fn can_reject_reassignment_without_cost_reduction() {
+    let (refinement_ctx, insertion_ctx) = create_insertion_ctx("c1,cheap,1\nc2,expensive,1");
+
+    let result = ReassignVehicles::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
+
+parameterized_test! {can_solve_assignment, (costs, expected), {
+    can_solve_assignment_impl(costs, expected);
+}}
+
+can_solve_assignment! {
+    case01_identity: (vec![vec![Some(1.), Some(5.)], vec![Some(5.), Some(1.)]], Some(vec![0, 1])),
+    case02_swap: (vec![vec![Some(5.), Some(1.)], vec![Some(1.), Some(5.)]], Some(vec![1, 0])),
+    case03_rect: (vec![vec![Some(3.), Some(4.), Some(1.)], vec![Some(2.), Some(9.), Some(2.)]], Some(vec![2, 0])),
+    case04_infeasible_pair: (vec![vec![None, Some(7.)], vec![Some(1.), Some(1.)]], Some(vec![1, 0])),
+    case05_infeasible: (vec![vec![None, Some(1.)], vec![None, Some(1.)]], None),
+    case06_too_many_rows: (vec![vec![Some(1.)], vec![Some(1.)]], None),
+}
+
+fn can_solve_assignment_impl(costs: Vec<Vec<Option<Cost>>>, expected: Option<Vec<usize>>) {
+    assert_eq!(solve_assignment(costs.as_slice()), expected);
+}
@@ -0,0 +1,100 @@
+use super::*;
+use crate::construction::features::create_minimize_transport_costs_feature;
+use crate::helpers::construction::features::create_goal_ctx_with_feature;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
+use crate::helpers::solver::*;
+use crate::models::problem::{create_matrix_transport_cost, Job, Jobs, MatrixData};
+use crate::models::solution::Registry;
+use crate::models::{Problem, Solution};
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+fn create_insertion_ctx(matrix: Vec<f64>, locked_ids: &[&str]) -> InsertionContext {
+    let fleet =
+        Arc::new(FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build());
+    let singles =
+        (1..=3).map(|idx| test_single_with_id_and_location(format!("c{idx}").as_str(), Some(idx))).collect::<Vec<_>>();
+    let jobs = singles.iter().cloned().map(Job::Single).collect::<Vec<_>>();
+
+    let mut route = create_route_with_activities(&fleet, "v1", vec![]);
+    singles.into_iter().enumerate().for_each(|(idx, single)| {
+        let mut activity = test_activity_with_job(single);
+        activity.place.location = idx + 1;
+        route.tour.insert_last(activity);
+    });
+
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, matrix.clone(), matrix)]).unwrap();
+    let activity = Arc::new(TestActivityCost::default());
+    let goal = create_goal_ctx_with_feature(
+        create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1).unwrap(),
+    );
+    let problem = Problem {
+        fleet: fleet.clone(),
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    };
+    let solution = Solution {
+        registry: Registry::new(&fleet, test_random()),
+        routes: vec![route],
+        unassigned: Default::default(),
+        seed: None,
+    };
+
+    promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        locked_ids,
+    )
+}
+
+fn create_asymmetric_matrix() -> Vec<f64> {
+    // NOTE moving from lower to higher location index is expensive, except depot legs
+    vec![
+        0., 1., 1., 1., //
+        1., 0., 10., 10., //
+        1., 1., 0., 10., //
+        1., 1., 1., 0., //
+    ]
+}
+
+fn get_total_cost(insertion_ctx: &InsertionContext) -> f64 {
+    insertion_ctx.problem.goal.fitness(insertion_ctx).next().unwrap()
+}
+
+#[test]
+fn can_reverse_segment_with_asymmetric_costs() {
+    let insertion_ctx = create_insertion_ctx(create_asymmetric_matrix(), &[]);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let original_cost = get_total_cost(&insertion_ctx);
+
+    let result = ReverseSegment::default().explore(&refinement_ctx, &insertion_ctx).expect("should reverse segment");
+
+    assert_eq!(get_customer_ids_from_routes(&result), vec![vec!["c3", "c2", "c1"]]);
+    assert!(get_total_cost(&result) < original_cost);
+}
+
+#[test]
+fn can_ignore_reversal_without_improvement() {
+    let matrix = (0..16).map(|idx| if idx % 5 == 0 { 0. } else { 1. }).collect();
+    let insertion_ctx = create_insertion_ctx(matrix, &[]);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = ReverseSegment::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn can_keep_locked_jobs_in_place() {
+    let insertion_ctx = create_insertion_ctx(create_asymmetric_matrix(), &["c2"]);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = ReverseSegment::default().explore(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.is_none());
+}
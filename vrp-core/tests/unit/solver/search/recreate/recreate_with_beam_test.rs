@@ -0,0 +1,134 @@
+use super::*;
+use crate::construction::features::*;
+use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::{IdDimension, Location, SingleDimLoad};
+use crate::models::problem::{create_matrix_transport_cost, Jobs, MatrixData, Vehicle, VehicleDetail, VehiclePlace};
+use crate::models::Problem;
+use crate::solver::search::RecreateWithCheapest;
+use rosomaxa::prelude::{DefaultRandom, Environment};
+use std::sync::Arc;
+
+fn create_vehicle(id: &str, depot: Location) -> Vehicle {
+    let detail = test_vehicle_detail();
+    let relocate = |place: Option<VehiclePlace>| place.map(|place| VehiclePlace { location: depot, ..place });
+
+    VehicleBuilder::default()
+        .id(id)
+        .capacity(1)
+        .details(vec![VehicleDetail { start: relocate(detail.start), end: relocate(detail.end) }])
+        .build()
+}
+
+/// Creates a problem where the cheapest first insertion leads to an expensive completion: job `a`
+/// is the cheapest to serve by `v1`, but then job `b` can be served only by the distant `v2`.
+fn create_test_problem(vehicles: Vec<Vehicle>) -> Arc<Problem> {
+    #[rustfmt::skip]
+    let distances = vec![
+        0., 5., 1., 2.,
+        5., 0., 2., 10.,
+        1., 2., 0., 5.,
+        2., 10., 5., 0.,
+    ];
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, distances.clone(), distances)]).unwrap();
+    let activity = TestActivityCost::new_shared();
+    let fleet = Arc::new(FleetBuilder::default().add_driver(test_driver()).add_vehicles(vehicles).build());
+    let jobs = [("a", 2), ("b", 3)]
+        .iter()
+        .map(|(id, location)| {
+            SingleBuilder::default()
+                .id(id)
+                .location(Some(*location))
+                .demand(create_simple_demand(-1))
+                .build_as_job_ref()
+        })
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_minimize_tours_feature("min_tours").unwrap(),
+            create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1).unwrap(),
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["min_tours"], vec!["transport"]],
+    );
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    })
+}
+
+fn run_recreate(recreate: &(dyn Recreate + Send + Sync)) -> InsertionContext {
+    let problem = create_test_problem(vec![create_vehicle("v1", 0), create_vehicle("v2", 1)]);
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+
+    recreate.run(&create_default_refinement_ctx(problem.clone()), InsertionContext::new(problem, environment))
+}
+
+fn get_assignment(insertion_ctx: &InsertionContext) -> Vec<(String, String)> {
+    let mut assignment = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let vehicle_id = get_vehicle_id(&route_ctx.route().actor.vehicle).clone();
+            route_ctx.route().tour.jobs().map(move |job| (job.dimens().get_id().cloned().unwrap(), vehicle_id.clone()))
+        })
+        .collect::<Vec<_>>();
+    assignment.sort();
+
+    assignment
+}
+
+fn to_assignment(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+    expected.iter().map(|(job, vehicle)| (job.to_string(), vehicle.to_string())).collect()
+}
+
+parameterized_test! {can_find_cheaper_completion_with_wider_beam, (width, expected), {
+    can_find_cheaper_completion_with_wider_beam_impl(width, to_assignment(expected));
+}}
+
+can_find_cheaper_completion_with_wider_beam! {
+    case01_greedy: (1, &[("a", "v1"), ("b", "v2")]),
+    case02_beam: (3, &[("a", "v2"), ("b", "v1")]),
+}
+
+fn can_find_cheaper_completion_with_wider_beam_impl(width: usize, expected: Vec<(String, String)>) {
+    let insertion_ctx = run_recreate(&BeamInsertion::new(width));
+
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(get_assignment(&insertion_ctx), expected);
+}
+
+#[test]
+fn can_find_cheaper_solution_than_greedy_insertion() {
+    let greedy = run_recreate(&RecreateWithCheapest::new(Arc::new(DefaultRandom::new_with_seed(0))));
+    let narrow = run_recreate(&BeamInsertion::new(1));
+    let wide = run_recreate(&BeamInsertion::new(3));
+
+    assert_eq!(get_assignment(&narrow), get_assignment(&greedy));
+    assert_eq!(narrow.solution.get_total_cost(), greedy.solution.get_total_cost());
+    assert!(wide.solution.get_total_cost() < narrow.solution.get_total_cost());
+    assert_eq!(wide.problem.goal.total_order(&wide, &narrow), Ordering::Less);
+}
+
+#[test]
+fn can_report_unassigned_jobs() {
+    let problem = create_test_problem(vec![create_vehicle("v1", 0)]);
+    let environment = Arc::new(Environment::default());
+
+    let insertion_ctx = BeamInsertion::new(3)
+        .run(&create_default_refinement_ctx(problem.clone()), InsertionContext::new(problem, environment));
+
+    assert_eq!(insertion_ctx.solution.routes.len(), 1);
+    assert_eq!(insertion_ctx.solution.unassigned.len(), 1);
+}
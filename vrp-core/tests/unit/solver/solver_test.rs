@@ -0,0 +1,677 @@
+use super::*;
+use crate::construction::features::*;
+use crate::construction::heuristics::UnassignmentInfo;
+use crate::helpers::construction::features::{create_goal_ctx_with_features, create_simple_demand};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::{IdDimension, Location, SingleDimLoad};
+use crate::models::problem::{create_matrix_transport_cost, Costs, Job, Jobs, MatrixData, MinGapActivityCost};
+use crate::models::solution::Registry;
+use rosomaxa::utils::{ThreadPool, Timer};
+use std::sync::RwLock;
+
+fn create_test_problem() -> Arc<Problem> {
+    let size = 21;
+    let points = (0..size).map(|idx| (((idx * 37) % 101) as f64, ((idx * 53) % 97) as f64)).collect::<Vec<_>>();
+    let distances = points
+        .iter()
+        .flat_map(|&(x1, y1)| points.iter().map(move |&(x2, y2)| ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt()))
+        .collect::<Vec<_>>();
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, distances.clone(), distances)]).unwrap();
+    let activity = TestActivityCost::new_shared();
+
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(
+                (0..4)
+                    .map(|idx| VehicleBuilder::default().id(format!("v{idx}").as_str()).capacity(6).build())
+                    .collect(),
+            )
+            .build(),
+    );
+    let jobs = (1..size)
+        .map(|idx| {
+            SingleBuilder::default()
+                .id(format!("c{idx}").as_str())
+                .location(Some(idx))
+                .demand(create_simple_demand(-1))
+                .build_as_job_ref()
+        })
+        .collect();
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 1).unwrap(),
+            create_minimize_tours_feature("min_tours").unwrap(),
+            create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 2).unwrap(),
+        ],
+        vec![vec!["min_tours"], vec!["transport"]],
+    );
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    })
+}
+
+fn solve_with_random(problem: Arc<Problem>, random: DefaultRandom) -> Solution {
+    try_solve(problem, random, true).unwrap()
+}
+
+fn try_solve(problem: Arc<Problem>, random: DefaultRandom, is_best_effort: bool) -> Result<Solution, String> {
+    let environment = Arc::new(Environment { random: Arc::new(random), ..Environment::default() });
+
+    // NOTE use single thread and heuristic which does not depend on timing to consume random values
+    // in the same order
+    ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(20))
+            .build()
+            .map(|config| Solver::new(problem, config).with_best_effort(is_best_effort))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, _, _)| solution)
+    })
+}
+
+fn create_oversubscribed_problem() -> Arc<Problem> {
+    let mut builder = ProblemBuilder::default();
+    (1..=4).for_each(|idx| {
+        builder.add_job(format!("c{idx}").as_str(), idx, -1, (0., 100.));
+    });
+
+    builder.add_vehicle("v1", 2, 0).build()
+}
+
+fn get_route_ids(solution: &Solution) -> Vec<Vec<String>> {
+    let mut routes = solution
+        .routes
+        .iter()
+        .map(|route| route.tour.jobs().map(|job| get_customer_id(&job)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    routes.sort();
+
+    routes
+}
+
+#[test]
+fn can_report_provided_seed() {
+    let solution = solve_with_random(create_test_problem(), DefaultRandom::new_with_seed(42));
+
+    assert_eq!(solution.seed, Some(42));
+}
+
+#[test]
+fn can_reproduce_solution_with_reported_seed() {
+    let problem = create_test_problem();
+
+    let original = solve_with_random(problem.clone(), DefaultRandom::default());
+    let seed = original.seed.expect("auto generated seed should be reported");
+    let repeated = solve_with_random(problem, DefaultRandom::new_with_seed(seed));
+
+    assert_eq!(repeated.seed, Some(seed));
+    assert_eq!(get_route_ids(&original), get_route_ids(&repeated));
+}
+
+fn solve_with_multi_start(problem: Arc<Problem>, seed: u64, starts: usize) -> (Solution, Cost) {
+    let config_fn: ConfigFactory = {
+        let problem = problem.clone();
+        Arc::new(move |environment: Arc<Environment>| {
+            create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+                .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+                .with_max_generations(Some(20))
+                .build()
+        })
+    };
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(seed)), ..Environment::default() });
+
+    ThreadPool::new(1).execute(|| {
+        (config_fn)(environment)
+            .map(|config| Solver::new(problem, config).with_multi_start(starts, config_fn.clone()))
+            .and_then(|solver| solver.solve())
+            .map(|(solution, cost, _)| (solution, cost))
+            .unwrap()
+    })
+}
+
+#[test]
+fn can_return_best_solution_of_multiple_starts() {
+    let problem = create_test_problem();
+    let get_quality = |(solution, cost): &(Solution, Cost)| (solution.routes.len(), *cost);
+    let seeds = (0..3)
+        .map(|start_idx| match start_idx {
+            0 => 42,
+            _ => DefaultRandom::new_with_seed(42).new_for_worker(start_idx).get_seed().unwrap(),
+        })
+        .collect::<Vec<_>>();
+
+    let result = solve_with_multi_start(problem.clone(), 42, 3);
+    let repeated = solve_with_multi_start(problem.clone(), 42, 3);
+
+    let best_single = seeds
+        .into_iter()
+        .map(|seed| get_quality(&solve_with_multi_start(problem.clone(), seed, 1)))
+        .min_by(|a, b| a.partial_cmp(b).unwrap())
+        .unwrap();
+    assert_eq!(result.0.seed, Some(42));
+    assert_eq!(get_quality(&result), best_single);
+    assert_eq!(get_route_ids(&result.0), get_route_ids(&repeated.0));
+}
+
+#[test]
+fn can_track_progress_and_metrics_of_all_starts() {
+    let problem = create_test_problem();
+    let starts = 3;
+    let seeds = (0..starts)
+        .map(|start_idx| match start_idx {
+            0 => 42,
+            _ => DefaultRandom::new_with_seed(42).new_for_worker(start_idx).get_seed().unwrap(),
+        })
+        .collect::<Vec<_>>();
+    let get_generations = |environment: &Environment| if environment.random.get_seed() == Some(42) { 5 } else { 20 };
+    let solve = |seed: u64, starts: usize, recorder: Option<Arc<ProgressRecorder>>| {
+        let config_fn: ConfigFactory = {
+            let problem = problem.clone();
+            let telemetry_mode = TelemetryMode::OnlyMetrics { track_population: 1000 };
+            Arc::new(move |environment: Arc<Environment>| {
+                create_default_config_builder(problem.clone(), environment.clone(), telemetry_mode.clone())
+                    .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment.clone())))
+                    .with_max_generations(Some(get_generations(environment.as_ref())))
+                    .build()
+            })
+        };
+        let environment = Arc::new(Environment {
+            random: Arc::new(DefaultRandom::new_with_seed(seed)),
+            quota: recorder.clone().map(|recorder| recorder as Arc<dyn Quota + Send + Sync>),
+            ..Environment::default()
+        });
+
+        ThreadPool::new(1).execute(|| {
+            let solver = (config_fn)(environment)
+                .map(|config| Solver::new(problem.clone(), config).with_multi_start(starts, config_fn.clone()))
+                .unwrap();
+            if let Some(recorder) = recorder {
+                *recorder.progress.write().unwrap() = Some(solver.progress_handle());
+            }
+
+            let (solution, cost, metrics) = solver.solve().unwrap();
+            ((solution.routes.len(), cost), metrics.expect("no metrics").trajectory.points.len())
+        })
+    };
+    let expected_points = seeds
+        .iter()
+        .map(|&seed| solve(seed, 1, None))
+        .fold(None, |best: Option<((usize, Cost), usize)>, (quality, points)| match best {
+            Some((best_quality, _)) if best_quality <= quality => best,
+            _ => Some((quality, points)),
+        })
+        .map(|(_, points)| points)
+        .unwrap();
+    let recorder = Arc::new(ProgressRecorder::default());
+
+    let (_, points) = solve(42, starts, Some(recorder.clone()));
+
+    assert_eq!(points, expected_points);
+    let samples = recorder.samples.read().unwrap().iter().map(|sample| sample.unwrap()).collect::<Vec<_>>();
+    assert!(samples.windows(2).all(|pair| pair[0] <= pair[1]));
+    // NOTE starts are run one by one within a single thread, so the first one is done long before the end
+    let finished_idx = samples.iter().position(|&sample| sample == 1.).unwrap();
+    assert!(finished_idx > samples.len() / 2);
+}
+
+/// Records solver progress each time quota is checked.
+#[derive(Default)]
+struct ProgressRecorder {
+    progress: RwLock<Option<SolverProgress>>,
+    samples: RwLock<Vec<Option<f64>>>,
+}
+
+impl Quota for ProgressRecorder {
+    fn is_reached(&self) -> bool {
+        // NOTE quota can be checked from different threads, so progress is read under the lock
+        let mut samples = self.samples.write().unwrap();
+        if let Some(progress) = self.progress.read().unwrap().as_ref() {
+            samples.push(progress.progress());
+        }
+
+        false
+    }
+}
+
+#[test]
+fn can_return_search_trajectory_with_metrics() {
+    let problem = create_test_problem();
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+    let telemetry_mode = TelemetryMode::OnlyMetrics { track_population: 1000 };
+    let generations = 10;
+
+    let metrics = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), telemetry_mode)
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_max_generations(Some(generations))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve())
+            .map(|(_, _, metrics)| metrics)
+            .unwrap()
+    });
+
+    let points = metrics.expect("no metrics").trajectory.points;
+    // NOTE generations are counted from zero, so the limit is reached after one more generation
+    assert_eq!(points.len(), generations + 1);
+    assert_eq!(points.iter().map(|point| point.generation).collect::<Vec<_>>(), (0..=generations).collect::<Vec<_>>());
+    assert!(points.iter().all(|point| point.population_size > 0));
+    assert!(points
+        .windows(2)
+        .all(|pair| compare_floats(pair[1].best_fitness[0], pair[0].best_fitness[0]) != Ordering::Greater));
+}
+
+#[test]
+fn can_estimate_progress_of_generation_capped_run() {
+    let problem = create_test_problem();
+    let recorder = Arc::new(ProgressRecorder::default());
+    let environment = Arc::new(Environment {
+        random: Arc::new(DefaultRandom::new_with_seed(0)),
+        quota: Some(recorder.clone()),
+        ..Environment::default()
+    });
+    let solver = create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+        .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+        .with_max_generations(Some(20))
+        .build()
+        .map(|config| Solver::new(problem, config))
+        .unwrap();
+    let progress = solver.progress_handle();
+    *recorder.progress.write().unwrap() = Some(progress.clone());
+    assert_eq!(progress.progress(), Some(0.));
+
+    solver.solve().unwrap();
+
+    let samples = recorder.samples.read().unwrap().iter().map(|sample| sample.unwrap()).collect::<Vec<_>>();
+    assert!(samples.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(samples.iter().any(|&sample| sample > 0. && sample < 1.));
+    assert_eq!(samples.last(), Some(&1.));
+    assert_eq!(progress.progress(), Some(1.));
+}
+
+#[test]
+fn can_solve_problem_created_by_builder() {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 1, -1, (0., 100.))
+        .add_job("c2", 2, -1, (0., 100.))
+        .add_job("c3", 3, -1, (0., 100.))
+        .add_vehicle("v1", 2, 0)
+        .add_vehicle("v2", 2, 0)
+        .build();
+
+    let solution = solve_with_random(problem, DefaultRandom::new_with_seed(0));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 2);
+    assert_eq!(get_route_ids(&solution).into_iter().flatten().count(), 3);
+}
+
+#[test]
+fn can_return_best_effort_solution_with_violation_summary() {
+    let solution = try_solve(create_oversubscribed_problem(), DefaultRandom::new_with_seed(0), true)
+        .expect("best effort solution should be returned");
+
+    assert_eq!(solution.unassigned.len(), 2);
+    assert_eq!(solution.get_violation_summary(), vec![(Some(2), 2)]);
+}
+
+#[test]
+fn can_return_error_for_infeasible_solution_without_best_effort() {
+    let result = try_solve(create_oversubscribed_problem(), DefaultRandom::new_with_seed(0), false);
+
+    assert_eq!(result.err(), Some("cannot find feasible solution, unassigned jobs: 2 (code 2: 2)".to_string()));
+}
+
+fn create_problem_with_cost_and_unassigned_objectives(is_same_layer: bool) -> Arc<Problem> {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 1, -1, (0., 1000.))
+        .add_job("c2", 50, -1, (0., 1000.))
+        .add_vehicle("v1", 10, 0)
+        .build();
+
+    let features = vec![
+        create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+        create_minimize_transport_costs_feature("transport", problem.transport.clone(), problem.activity.clone(), 1)
+            .unwrap(),
+        create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+    ];
+    let layers = if is_same_layer {
+        vec![vec!["min_unassigned", "transport"]]
+    } else {
+        vec![vec!["min_unassigned"], vec!["transport"]]
+    };
+    let goal = create_goal_ctx_with_features(features, layers);
+
+    Arc::new(Problem { goal: Arc::new(goal), ..Arc::try_unwrap(problem).ok().unwrap() })
+}
+
+fn create_solution_with_assigned(problem: &Arc<Problem>, assigned: &[&str]) -> InsertionContext {
+    let (assigned_jobs, unassigned_jobs): (Vec<Job>, Vec<Job>) =
+        problem.jobs.all().partition(|job| assigned.contains(&get_customer_id(job).as_str()));
+
+    let activities = assigned_jobs
+        .into_iter()
+        .map(|job| {
+            let single = job.to_single().clone();
+            let mut activity = test_activity_with_job(single.clone());
+            activity.place.location = single.places.first().and_then(|place| place.location).unwrap();
+            activity
+        })
+        .collect();
+    let solution = Solution {
+        registry: Registry::new(problem.fleet.as_ref(), test_random()),
+        routes: vec![create_route_with_activities(problem.fleet.as_ref(), "v1", activities)],
+        unassigned: unassigned_jobs.into_iter().map(|job| (job, UnassignmentInfo::Unknown)).collect(),
+        seed: None,
+    };
+
+    InsertionContext::new_from_solution(problem.clone(), (solution, None), Arc::new(Environment::default()))
+}
+
+fn solve_pareto_front(problem: Arc<Problem>) -> Vec<(usize, Cost)> {
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+    let solutions = vec![
+        create_solution_with_assigned(&problem, &[]),
+        create_solution_with_assigned(&problem, &["c1"]),
+        create_solution_with_assigned(&problem, &["c1", "c2"]),
+    ];
+    let population = Box::new(create_elitism_population(problem.goal.clone(), environment.clone()));
+
+    let mut front = ThreadPool::new(1).execute(|| {
+        create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+            .with_context(RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment.clone()))
+            .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+            .with_init_solutions(solutions, Some(3))
+            .with_desired_solutions(4)
+            .with_max_generations(Some(1))
+            .build()
+            .map(|config| Solver::new(problem, config))
+            .and_then(|solver| solver.solve_pareto_front())
+            .map(|(front, _)| {
+                front.into_iter().map(|(solution, cost)| (solution.unassigned.len(), cost)).collect::<Vec<_>>()
+            })
+            .unwrap()
+    });
+    front.sort_by_key(|(unassigned, _)| *unassigned);
+
+    front
+}
+
+#[test]
+fn can_return_pareto_front_with_cost_and_unassigned_trade_off() {
+    let front = solve_pareto_front(create_problem_with_cost_and_unassigned_objectives(true));
+
+    let (min_unassigned, max_cost) = front.first().cloned().unwrap();
+    let (max_unassigned, min_cost) = front.last().cloned().unwrap();
+    assert_eq!(min_unassigned, 0);
+    assert!(max_unassigned > 0);
+    assert!(min_cost < max_cost);
+}
+
+#[test]
+fn can_return_single_point_front_with_hierarchical_objectives() {
+    let front = solve_pareto_front(create_problem_with_cost_and_unassigned_objectives(false));
+
+    assert!(front.iter().all(|(unassigned, _)| *unassigned == 0));
+}
+
+#[test]
+fn can_assign_longer_route_to_vehicle_with_cheaper_distance_rate() {
+    let problem = ProblemBuilder::default()
+        .add_job("near", 5, -1, (0., 1000.))
+        .add_job("far", 50, -1, (0., 1000.))
+        .add_vehicle_with_costs("cheap", 1, 0, Costs { per_distance: 1., ..test_costs() })
+        .add_vehicle_with_costs("expensive", 1, 0, Costs { per_distance: 10., ..test_costs() })
+        .build();
+
+    let solution = solve_with_random(problem, DefaultRandom::new_with_seed(0));
+
+    let mut assignments = solution
+        .routes
+        .iter()
+        .map(|route| {
+            let vehicle_id = route.actor.vehicle.dimens.get_id().cloned().unwrap();
+            let job_ids = route.tour.jobs().map(|job| get_customer_id(&job)).collect::<Vec<_>>();
+            (vehicle_id, job_ids)
+        })
+        .collect::<Vec<_>>();
+    assignments.sort();
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(
+        assignments,
+        vec![("cheap".to_string(), vec!["far".to_string()]), ("expensive".to_string(), vec!["near".to_string()])]
+    );
+}
+
+#[test]
+fn can_keep_vehicles_at_their_depots() {
+    let depots = [("a", 0), ("b", 100)];
+    let mut builder = ProblemBuilder::default();
+    depots.iter().for_each(|&(depot, location)| {
+        (1..=3).for_each(|idx| {
+            builder.add_job(format!("{depot}{idx}").as_str(), location + idx, -1, (0., 1000.));
+        });
+        // NOTE capacity allows to serve all depot's jobs by one vehicle, so minimizing tours does not
+        // force a vehicle to serve jobs of another depot
+        (1..=2).for_each(|idx| {
+            builder.add_vehicle(format!("{depot}_v{idx}").as_str(), 3, location);
+        });
+    });
+    let problem = builder.build();
+
+    let solution = solve_with_random(problem, DefaultRandom::new_with_seed(0));
+
+    assert!(solution.unassigned.is_empty());
+    solution.routes.iter().for_each(|route| {
+        let vehicle_id = route.actor.vehicle.dimens.get_id().unwrap();
+        let (depot, depot_location) = depots.iter().find(|(depot, _)| vehicle_id.starts_with(depot)).unwrap();
+
+        assert_eq!(route.tour.start().unwrap().place.location, *depot_location);
+        assert_eq!(route.tour.end().unwrap().place.location, *depot_location);
+        assert!(route.tour.jobs().all(|job| get_customer_id(&job).starts_with(depot)));
+    });
+}
+
+parameterized_test! {can_use_min_gap_between_stops, (gap, expected_unassigned), {
+    can_use_min_gap_between_stops_impl(gap, expected_unassigned);
+}}
+
+can_use_min_gap_between_stops! {
+    case_01_no_gap: (0., 0),
+    case_02_gap_breaks_time_windows: (10., 1),
+}
+
+fn can_use_min_gap_between_stops_impl(gap: f64, expected_unassigned: usize) {
+    let activity = Arc::new(MinGapActivityCost::new(TestActivityCost::new_shared(), gap).unwrap());
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 10, -1, (0., 15.))
+        .add_job("c2", 20, -1, (0., 25.))
+        .add_vehicle("v1", 2, 0)
+        .activity_cost(activity)
+        .build();
+
+    let solution = solve_with_random(problem, DefaultRandom::new_with_seed(0));
+
+    assert_eq!(solution.unassigned.len(), expected_unassigned);
+}
+
+parameterized_test! {can_serve_job_at_cheapest_alternative_location, (locations, expected), {
+    can_serve_job_at_cheapest_alternative_location_impl(locations, expected);
+}}
+
+can_serve_job_at_cheapest_alternative_location! {
+    case_01_home_only: (&[50], 50),
+    case_02_home_or_locker: (&[50, 5], 5),
+    case_03_locker_or_home: (&[5, 50], 5),
+}
+
+fn can_serve_job_at_cheapest_alternative_location_impl(locations: &[Location], expected: Location) {
+    let problem = ProblemBuilder::default()
+        .add_job_with_locations("c1", locations, -1, (0., 1000.))
+        .add_vehicle("v1", 1, 0)
+        .build();
+
+    let solution = solve_with_random(problem, DefaultRandom::new_with_seed(0));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.routes[0].tour.get(1).unwrap().place.location, expected);
+}
+
+parameterized_test! {can_use_separate_cost_and_time_matrices, (minimize_time, expected), {
+    can_use_separate_cost_and_time_matrices_impl(minimize_time, expected);
+}}
+
+can_use_separate_cost_and_time_matrices! {
+    case_01_minimize_cost: (false, vec!["c1", "c2"]),
+    case_02_minimize_time: (true, vec!["c2", "c1"]),
+}
+
+fn can_use_separate_cost_and_time_matrices_impl(minimize_time: bool, expected: Vec<&str>) {
+    // NOTE toll roads: 0 -> 1 -> 2 -> 0 is cheap but slow, 0 -> 2 -> 1 -> 0 is expensive but fast
+    let distances = vec![0., 1., 5., 5., 0., 1., 1., 5., 0.];
+    let durations = vec![0., 10., 1., 1., 0., 10., 10., 1., 0.];
+    let transport = create_matrix_transport_cost(vec![MatrixData::new(0, None, durations, distances)]).unwrap();
+    let activity = TestActivityCost::new_shared();
+    let costs = if minimize_time {
+        Costs { per_distance: 0., ..test_costs() }
+    } else {
+        Costs { per_driving_time: 0., ..test_costs() }
+    };
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver_with_costs(costs.clone()))
+            .add_vehicle(VehicleBuilder::default().id("v1").capacity(2).costs(costs).build())
+            .build(),
+    );
+    let jobs = (1..=2)
+        .map(|idx| {
+            SingleBuilder::default()
+                .id(format!("c{idx}").as_str())
+                .location(Some(idx))
+                .demand(create_simple_demand(-1))
+                .build_as_job_ref()
+        })
+        .collect();
+    let transport_feature = if minimize_time {
+        create_minimize_duration_feature("transport", transport.clone(), activity.clone(), 1)
+    } else {
+        create_minimize_transport_costs_feature("transport", transport.clone(), activity.clone(), 1)
+    };
+    let goal = create_goal_ctx_with_features(
+        vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap(),
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 2).unwrap(),
+            transport_feature.unwrap(),
+        ],
+        vec![vec!["min_unassigned"], vec!["transport"]],
+    );
+    let problem = Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        goal: Arc::new(goal),
+        activity,
+        transport,
+        extras: Arc::new(Default::default()),
+    });
+
+    let solution = solve_with_random(problem, DefaultRandom::new_with_seed(0));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+    let job_ids = solution.routes[0]
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.job.as_ref())
+        .map(|single| single.dimens.get_id().unwrap().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids, expected);
+}
+
+parameterized_test! {can_use_end_dwell_with_return_deadline, (dwell, expected_unassigned), {
+    can_use_end_dwell_with_return_deadline_impl(dwell, expected_unassigned);
+}}
+
+can_use_end_dwell_with_return_deadline! {
+    case_01_no_dwell: (0., 0),
+    case_02_dwell_shortens_route: (15., 1),
+}
+
+fn can_use_end_dwell_with_return_deadline_impl(dwell: f64, expected_unassigned: usize) {
+    let problem = ProblemBuilder::default()
+        .add_job("c1", 10, -1, (0., 100.))
+        .add_job("c2", 20, -1, (0., 100.))
+        .add_vehicle("v1", 2, 0)
+        .vehicle_end(50., dwell)
+        .build();
+
+    let solution = solve_with_random(problem, DefaultRandom::new_with_seed(0));
+
+    assert_eq!(solution.unassigned.len(), expected_unassigned);
+    assert!(solution.routes.iter().all(|route| route.tour.end().unwrap().schedule.departure <= 50.));
+}
+
+#[test]
+fn can_pause_and_resume_solver() {
+    let problem = create_test_problem();
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(0)), ..Environment::default() });
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    let handle = std::thread::spawn(move || {
+        ThreadPool::new(1).execute(|| {
+            let solver = create_default_config_builder(problem.clone(), environment.clone(), TelemetryMode::None)
+                .with_heuristic(Box::new(get_static_heuristic(problem.clone(), environment)))
+                .with_max_generations(Some(100))
+                .build()
+                .map(|config| Solver::new(problem, config))
+                .unwrap();
+            let progress = solver.progress_handle();
+            progress.pause();
+            sender.send(progress).unwrap();
+
+            solver.solve().unwrap()
+        })
+    });
+    let progress = receiver.recv().unwrap();
+    let (_, incumbent_cost) = loop {
+        if let Some(incumbent) = progress.incumbent() {
+            break incumbent;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    };
+    assert_eq!(progress.progress(), Some(0.));
+
+    progress.resume();
+    let (_, cost, _) = handle.join().unwrap();
+
+    assert!(cost < incumbent_cost);
+    assert_eq!(progress.progress(), Some(1.));
+}
+
+#[test]
+fn can_keep_generation_of_offspring_in_solution() {
+    let mut refinement_ctx = create_default_refinement_ctx(create_empty_problem());
+
+    refinement_ctx.on_generation(vec![create_empty_insertion_context()], 0., Timer::start());
+
+    let (insertion_ctx, _) = refinement_ctx.ranked().next().unwrap();
+    assert_eq!(insertion_ctx.solution.get_generation(), Some(1));
+}
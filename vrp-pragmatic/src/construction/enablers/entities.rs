@@ -30,6 +30,11 @@ pub trait VehicleTie {
     fn get_tour_size(&self) -> Option<usize>;
     /// Sets vehicle's tour size.
     fn set_tour_size(&mut self, tour_size: usize) -> &mut Self;
+
+    /// Gets vehicle's max amount of stops before a break.
+    fn get_break_stops_limit(&self) -> Option<usize>;
+    /// Sets vehicle's max amount of stops before a break.
+    fn set_break_stops_limit(&mut self, limit: usize) -> &mut Self;
 }
 
 impl VehicleTie for Dimensions {
@@ -79,6 +84,15 @@ impl VehicleTie for Dimensions {
         self.set_value("tour_size", tour_size);
         self
     }
+
+    fn get_break_stops_limit(&self) -> Option<usize> {
+        self.get_value("break_stops_limit").cloned()
+    }
+
+    fn set_break_stops_limit(&mut self, limit: usize) -> &mut Self {
+        self.set_value("break_stops_limit", limit);
+        self
+    }
 }
 
 /// Specifies job entity.
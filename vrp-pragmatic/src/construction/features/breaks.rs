@@ -45,6 +45,19 @@ pub fn create_optional_break_feature(name: &str, code: ViolationCode) -> Result<
         .build()
 }
 
+/// Specifies a function which returns max amount of consecutive job activities before a break.
+pub type BreakStopsLimitFn = Arc<dyn Fn(&Actor) -> Option<usize> + Send + Sync>;
+
+/// Creates a feature which limits amount of consecutive job activities served without a break in
+/// between, so a break has to be scheduled to serve more jobs. This is a hard constraint.
+pub fn create_break_stops_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    limit_fn: BreakStopsLimitFn,
+) -> Result<Feature, String> {
+    FeatureBuilder::default().with_name(name).with_constraint(BreakStopsLimitConstraint { code, limit_fn }).build()
+}
+
 struct OptionalBreakConstraint {
     code: ViolationCode,
 }
@@ -83,6 +96,42 @@ impl FeatureConstraint for OptionalBreakConstraint {
     }
 }
 
+struct BreakStopsLimitConstraint {
+    code: ViolationCode,
+    limit_fn: BreakStopsLimitFn,
+}
+
+impl FeatureConstraint for BreakStopsLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let limit = (self.limit_fn)(route_ctx.route().actor.as_ref())?;
+
+                if as_break_job(activity_ctx.target).is_some() {
+                    return None;
+                }
+
+                let tour = &route_ctx.route().tour;
+                let is_stop = |activity: &&Activity| activity.job.is_some() && as_break_job(activity).is_none();
+
+                let before = tour.all_activities().take(activity_ctx.index + 1).rev().take_while(is_stop).count();
+                let after = tour.all_activities().skip(activity_ctx.index + 1).take_while(is_stop).count();
+
+                if before + after + 1 > limit {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
 struct OptionalBreakObjective {}
 
 impl Objective for OptionalBreakObjective {
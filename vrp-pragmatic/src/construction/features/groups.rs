@@ -61,6 +61,10 @@ impl FeatureConstraint for GroupConstraint {
             _ => Err(self.code),
         }
     }
+
+    fn depends_on_other_routes(&self) -> bool {
+        true
+    }
 }
 
 struct GroupState {
@@ -101,6 +101,7 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let profile = Profile::new(index, vehicle.profile.scale);
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let break_stops_limit = vehicle.limits.as_ref().and_then(|l| l.max_stops_before_break);
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
@@ -139,6 +140,10 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(break_stops_limit) = break_stops_limit {
+                    dimens.set_break_stops_limit(break_stops_limit);
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
@@ -149,6 +154,10 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
 
+                if let Some(dwell) = shift.end.as_ref().and_then(|end| end.dwell) {
+                    dimens.set_end_dwell(dwell);
+                }
+
                 vehicles.push(Arc::new(Vehicle {
                     profile: profile.clone(),
                     costs: costs.clone(),
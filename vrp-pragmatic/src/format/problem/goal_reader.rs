@@ -74,6 +74,14 @@ pub(crate) fn create_goal_context(
         )?);
     }
 
+    if props.has_break_stops_limits {
+        features.push(create_break_stops_limit_feature(
+            "break_stops_limit",
+            BREAK_CONSTRAINT_CODE,
+            Arc::new(|actor| actor.vehicle.dimens.get_break_stops_limit()),
+        )?);
+    }
+
     GoalContext::new(features.as_slice(), global_objective_map.as_slice(), local_objective_map.as_slice())
 }
 
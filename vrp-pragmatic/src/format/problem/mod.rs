@@ -27,6 +27,9 @@ mod job_reader;
 mod problem_reader;
 use self::problem_reader::{map_to_problem_with_approx, map_to_problem_with_matrices};
 
+mod relaxation;
+pub use self::relaxation::*;
+
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
     /// Reads problem defined in pragmatic format.
@@ -114,6 +117,7 @@ pub(crate) struct ProblemProperties {
     has_compatibility: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_break_stops_limits: bool,
 }
 
 fn parse_time_window(tw: &[String]) -> TimeWindow {
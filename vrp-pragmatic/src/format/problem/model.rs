@@ -274,6 +274,11 @@ pub struct ShiftEnd {
 
     /// Shift end location.
     pub location: Location,
+
+    /// A dwell time at shift end location, e.g. to unload after return. It has to be finished
+    /// before shift end latest time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dwell: Option<f64>,
 }
 
 /// Specifies vehicle shift.
@@ -369,6 +374,11 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Max amount of consecutive job activities served without a break in between.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_stops_before_break: Option<usize>,
 }
 
 /// Vehicle optional break time variant.
@@ -680,6 +690,69 @@ pub struct Matrix {
     pub error_codes: Option<Vec<i64>>,
 }
 
+impl Matrix {
+    /// Creates a routing matrix for given locations using a custom distance function, e.g. to
+    /// support geometries other than euclidean or haversine. Locations are expected in the order
+    /// of their routing matrix indices (see `get_unique_locations`). Values are rounded to the
+    /// nearest integer and used for both distances and travel times.
+    pub fn from_fn<F>(locations: &[Location], distance_fn: F) -> Self
+    where
+        F: Fn(&Location, &Location) -> f64,
+    {
+        let distances = locations
+            .iter()
+            .flat_map(|from| locations.iter().map(|to| distance_fn(from, to).round() as i64))
+            .collect::<Vec<_>>();
+
+        Self { profile: None, timestamp: None, travel_times: distances.clone(), distances, error_codes: None }
+    }
+}
+
+// endregion
+
+// region Binary
+
+/// A header which starts problem in binary format.
+const BINARY_PROBLEM_MAGIC: &[u8; 4] = b"VRPB";
+
+/// A version of problem binary format. Should be incremented on incompatible model changes.
+const BINARY_PROBLEM_VERSION: u16 = 1;
+
+impl Problem {
+    /// Writes problem in compact binary format which can be used to cache parsed problems.
+    /// The format has a header with version followed by problem data encoded in CBOR.
+    pub fn write_binary<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_all(BINARY_PROBLEM_MAGIC)?;
+        writer.write_all(&BINARY_PROBLEM_VERSION.to_le_bytes())?;
+
+        ciborium::into_writer(self, writer).map_err(|err| Error::other(err.to_string()))
+    }
+
+    /// Reads problem written in compact binary format by `write_binary`. Returns an error if
+    /// header is invalid or format version is not compatible.
+    pub fn read_binary<R: Read>(reader: &mut R) -> Result<Problem, MultiFormatError> {
+        let create_error = |action: String| -> MultiFormatError {
+            vec![FormatError::new("E0000".to_string(), "cannot deserialize problem".to_string(), action)].into()
+        };
+
+        let mut header = [0_u8; 6];
+        reader.read_exact(&mut header).map_err(|err| create_error(format!("check binary header: '{err}'")))?;
+
+        if &header[..4] != BINARY_PROBLEM_MAGIC {
+            return Err(create_error("check that input is a problem in binary format".to_string()));
+        }
+
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != BINARY_PROBLEM_VERSION {
+            return Err(create_error(format!(
+                "binary format version {version} is not supported, expected {BINARY_PROBLEM_VERSION}"
+            )));
+        }
+
+        ciborium::from_reader(reader).map_err(|err| create_error(format!("check binary input: '{err}'")))
+    }
+}
+
 // endregion
 
 /// Deserializes problem in json format from `BufReader`.
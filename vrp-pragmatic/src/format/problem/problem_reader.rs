@@ -241,6 +241,12 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .iter()
         .any(|v| v.limits.as_ref().map_or(false, |l| l.max_duration.or(l.max_distance).is_some()));
 
+    let has_break_stops_limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|v| v.limits.as_ref().map_or(false, |l| l.max_stops_before_break.is_some()));
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -254,5 +260,6 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_compatibility,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_break_stops_limits,
     }
 }
@@ -0,0 +1,65 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/relaxation_test.rs"]
+mod relaxation_test;
+
+use super::*;
+use crate::format_time;
+use serde::{Deserialize, Serialize};
+
+/// Specifies a step which relaxes problem constraints. Steps are meant to be applied one by one
+/// when no feasible solution can be found for the original problem.
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum RelaxationStep {
+    /// Widens each job time window by given amount of seconds on both sides.
+    #[serde(rename_all = "camelCase")]
+    WidenTimeWindows {
+        /// Amount of seconds added to each side of time window.
+        seconds: f64,
+    },
+
+    /// Scales capacity of each vehicle type by given factor, rounding the result up.
+    #[serde(rename_all = "camelCase")]
+    ScaleCapacity {
+        /// A capacity multiplier, expected to be greater than one.
+        factor: f64,
+    },
+}
+
+/// Returns a copy of the problem with given relaxation step applied.
+pub fn relax_problem(problem: &Problem, step: &RelaxationStep) -> Problem {
+    let mut problem = problem.clone();
+
+    match step {
+        RelaxationStep::WidenTimeWindows { seconds } => {
+            problem
+                .plan
+                .jobs
+                .iter_mut()
+                .flat_map(|job| {
+                    job.pickups
+                        .iter_mut()
+                        .chain(job.deliveries.iter_mut())
+                        .chain(job.services.iter_mut())
+                        .chain(job.replacements.iter_mut())
+                        .flatten()
+                })
+                .flat_map(|task| task.places.iter_mut())
+                .flat_map(|place| place.times.iter_mut().flatten())
+                .filter(|tw| tw.len() == 2)
+                .for_each(|tw| {
+                    let start = parse_time(tw[0].as_str()) - seconds;
+                    let end = parse_time(tw[1].as_str()) + seconds;
+
+                    *tw = vec![format_time(start.max(0.)), format_time(end)];
+                });
+        }
+        RelaxationStep::ScaleCapacity { factor } => {
+            problem.fleet.vehicles.iter_mut().flat_map(|vehicle| vehicle.capacity.iter_mut()).for_each(|capacity| {
+                *capacity = (*capacity as f64 * factor).ceil() as i32;
+            });
+        }
+    }
+
+    problem
+}
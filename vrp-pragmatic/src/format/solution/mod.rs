@@ -8,6 +8,12 @@ pub(crate) mod activity_matcher;
 mod geo_serializer;
 pub use self::geo_serializer::*;
 
+mod ndjson_serializer;
+pub use self::ndjson_serializer::*;
+
+mod timeline_serializer;
+pub use self::timeline_serializer::*;
+
 mod initial_reader;
 pub use self::initial_reader::read_init_solution;
 
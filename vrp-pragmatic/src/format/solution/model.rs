@@ -196,10 +196,24 @@ pub struct Tour {
     pub shift_index: usize,
     /// List of stops.
     pub stops: Vec<Stop>,
+    /// Trips of the tour. Specified only when vehicle is reloaded, so the tour has more than one trip.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trips: Option<Vec<Trip>>,
     /// Tour statistic.
     pub statistic: Statistic,
 }
 
+/// A trip is a part of the tour between two consecutive depot visits: departure, reload or arrival.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Trip {
+    /// Index of the stop where trip starts.
+    pub start_stop_index: usize,
+    /// Index of the stop where trip ends.
+    pub end_stop_index: usize,
+}
+
 /// Unassigned job reason.
 #[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
 pub struct UnassignedJobReason {
@@ -0,0 +1,16 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/ndjson_serializer_test.rs"]
+mod ndjson_serializer_test;
+
+use super::Solution;
+use std::io::{BufWriter, Error, Write};
+
+/// Serializes solution tours into newline delimited json (NDJSON) format: each tour is written
+/// as a separate json object on its own line, so it can be consumed as soon as the line is flushed.
+pub fn write_solution_ndjson<W: Write>(solution: &Solution, writer: &mut BufWriter<W>) -> Result<(), Error> {
+    solution.tours.iter().try_for_each(|tour| {
+        serde_json::to_writer(&mut *writer, tour).map_err(Error::from)?;
+        writer.write_all(b"\n")?;
+        writer.flush()
+    })
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/timeline_serializer_test.rs"]
+mod timeline_serializer_test;
+
+use crate::construction::enablers::{JobTie, VehicleTie};
+use crate::format_time;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::io::{BufWriter, Error, Write};
+use vrp_core::models::common::Timestamp;
+use vrp_core::models::problem::Multi;
+use vrp_core::models::solution::{Activity, Route};
+use vrp_core::models::Solution;
+use vrp_core::prelude::compare_floats;
+
+/// Specifies a single bar on the timeline.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineInterval {
+    /// Interval type: travel, service, waiting or break.
+    #[serde(rename(deserialize = "type", serialize = "type"))]
+    pub interval_type: String,
+    /// Id of the job served within the interval, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// Start time in RFC3339 format.
+    pub start: String,
+    /// End time in RFC3339 format.
+    pub end: String,
+}
+
+/// Specifies a timeline of one vehicle shift.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTimeline {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Contiguous intervals which cover the whole tour from its start till its end.
+    pub intervals: Vec<TimelineInterval>,
+}
+
+/// Specifies a Gantt-style timeline of the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Debug)]
+pub struct Timeline {
+    /// Timelines of all used vehicle shifts.
+    pub vehicles: Vec<VehicleTimeline>,
+}
+
+/// Serializes solution as a Gantt-style timeline json: each tour is split into contiguous travel,
+/// waiting, service and break intervals, so it can be visualized without further processing.
+pub fn serialize_solution_as_timeline<W: Write>(writer: &mut BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    let timeline = Timeline { vehicles: solution.routes.iter().map(create_vehicle_timeline).collect() };
+
+    serde_json::to_writer_pretty(writer, &timeline).map_err(Error::from)
+}
+
+fn create_vehicle_timeline(route: &Route) -> VehicleTimeline {
+    let vehicle = route.actor.vehicle.as_ref();
+    let mut intervals = Vec::default();
+    let mut add_interval = |interval_type: &str, job_id: Option<String>, start: Timestamp, end: Timestamp| {
+        if compare_floats(start, end) == Ordering::Less {
+            intervals.push(TimelineInterval {
+                interval_type: interval_type.to_string(),
+                job_id,
+                start: format_time(start),
+                end: format_time(end),
+            });
+        }
+    };
+
+    if let Some(start) = route.tour.start() {
+        // NOTE departure can be shifted from the shift start
+        add_interval("waiting", None, start.schedule.arrival, start.schedule.departure);
+    }
+
+    route.tour.all_activities().zip(route.tour.all_activities().skip(1)).for_each(|(prev, activity)| {
+        let service_start = activity.schedule.arrival.max(activity.place.time.start).min(activity.schedule.departure);
+        let service_type = match activity.job.as_ref().and_then(|single| single.dimens.get_job_type()) {
+            Some(job_type) if job_type == "break" => "break",
+            _ => "service",
+        };
+
+        add_interval("travel", None, prev.schedule.departure, activity.schedule.arrival);
+        add_interval("waiting", None, activity.schedule.arrival, service_start);
+        add_interval(service_type, get_job_id(activity), service_start, activity.schedule.departure);
+    });
+
+    VehicleTimeline {
+        vehicle_id: vehicle.dimens.get_vehicle_id().cloned().unwrap_or_default(),
+        shift_index: vehicle.dimens.get_shift_index().unwrap_or_default(),
+        intervals,
+    }
+}
+
+fn get_job_id(activity: &Activity) -> Option<String> {
+    activity.job.as_ref().and_then(|single| {
+        single
+            .dimens
+            .get_job_id()
+            .cloned()
+            .or_else(|| Multi::roots(single).and_then(|multi| multi.dimens.get_job_id().cloned()))
+    })
+}
@@ -11,6 +11,7 @@ use crate::format::*;
 use crate::{format_time, parse_time};
 use std::cmp::Ordering;
 use std::io::{BufWriter, Write};
+use std::iter::once;
 use vrp_core::construction::enablers::route_intervals;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
@@ -38,37 +39,81 @@ pub trait PragmaticSolution<W: Write> {
     /// Serializes solution in pragmatic json format.
     fn write_pragmatic_json(&self, problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), String>;
 
+    /// Serializes solution in pragmatic json format with all times rounded to the nearest multiple
+    /// of given granularity in seconds, e.g. 60 to report times in whole minutes.
+    fn write_pragmatic_json_rounded(
+        &self,
+        problem: &Problem,
+        granularity: usize,
+        writer: &mut BufWriter<W>,
+    ) -> Result<(), String>;
+
     /// Serializes solution in pragmatic geo json format.
     fn write_geo_json(&self, problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), String>;
+
+    /// Serializes solution as a Gantt-style timeline json with per vehicle activity intervals.
+    fn write_timeline_json(&self, problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), String>;
 }
 
 impl<W: Write> PragmaticSolution<W> for (&Solution, f64) {
     fn write_pragmatic_json(&self, problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), String> {
-        write_pragmatic_json(problem, self.0, None, writer)
+        write_pragmatic_json(problem, self.0, None, None, writer)
+    }
+
+    fn write_pragmatic_json_rounded(
+        &self,
+        problem: &Problem,
+        granularity: usize,
+        writer: &mut BufWriter<W>,
+    ) -> Result<(), String> {
+        write_pragmatic_json(problem, self.0, None, Some(granularity), writer)
     }
 
     fn write_geo_json(&self, problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), String> {
         write_geo_json(problem, self.0, writer)
     }
+
+    fn write_timeline_json(&self, _: &Problem, writer: &mut BufWriter<W>) -> Result<(), String> {
+        serialize_solution_as_timeline(writer, self.0).map_err(|err| err.to_string())
+    }
 }
 
 impl<W: Write> PragmaticSolution<W> for (&Solution, f64, &TelemetryMetrics) {
     fn write_pragmatic_json(&self, problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), String> {
-        write_pragmatic_json(problem, self.0, Some(self.2), writer)
+        write_pragmatic_json(problem, self.0, Some(self.2), None, writer)
+    }
+
+    fn write_pragmatic_json_rounded(
+        &self,
+        problem: &Problem,
+        granularity: usize,
+        writer: &mut BufWriter<W>,
+    ) -> Result<(), String> {
+        write_pragmatic_json(problem, self.0, Some(self.2), Some(granularity), writer)
     }
 
     fn write_geo_json(&self, problem: &Problem, writer: &mut BufWriter<W>) -> Result<(), String> {
         write_geo_json(problem, self.0, writer)
     }
+
+    fn write_timeline_json(&self, _: &Problem, writer: &mut BufWriter<W>) -> Result<(), String> {
+        serialize_solution_as_timeline(writer, self.0).map_err(|err| err.to_string())
+    }
 }
 
 fn write_pragmatic_json<W: Write>(
     problem: &Problem,
     solution: &Solution,
     metrics: Option<&TelemetryMetrics>,
+    granularity: Option<usize>,
     writer: &mut BufWriter<W>,
 ) -> Result<(), String> {
-    let solution = create_solution(problem, solution, metrics);
+    let mut solution = create_solution(problem, solution, metrics);
+
+    if let Some(granularity) = granularity {
+        round_times(&mut solution, granularity)?;
+    }
+
     serialize_solution(&solution, writer).map_err(|err| err.to_string())?;
     Ok(())
 }
@@ -136,6 +181,7 @@ fn create_tour(
         type_id: vehicle.dimens.get_vehicle_type().unwrap().clone(),
         shift_index: vehicle.dimens.get_shift_index().unwrap(),
         stops: vec![],
+        trips: None,
         statistic: Statistic::default(),
     };
 
@@ -351,6 +397,8 @@ fn create_tour(
 
     insert_reserved_times(route, &mut tour, reserved_times_index);
 
+    tour.trips = create_trips(tour.stops.as_slice());
+
     // NOTE remove redundant info
     tour.stops
         .iter_mut()
@@ -370,6 +418,31 @@ fn create_tour(
     tour
 }
 
+fn create_trips(stops: &[Stop]) -> Option<Vec<Trip>> {
+    let reload_indices = stops
+        .iter()
+        .enumerate()
+        .filter(|(_, stop)| stop.activities().iter().any(|activity| activity.activity_type == "reload"))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if reload_indices.is_empty() {
+        return None;
+    }
+
+    let last_idx = stops.len() - 1;
+
+    Some(
+        once(0)
+            .chain(reload_indices.iter().cloned())
+            .zip(reload_indices.iter().cloned().chain(once(last_idx)))
+            // NOTE reload can share the stop with departure or arrival
+            .filter(|(start_stop_index, end_stop_index)| start_stop_index < end_stop_index)
+            .map(|(start_stop_index, end_stop_index)| Trip { start_stop_index, end_stop_index })
+            .collect(),
+    )
+}
+
 fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &ReservedTimesIndex) {
     let shift_time = route
         .tour
@@ -497,6 +570,36 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
         });
 }
 
+/// Rounds all times of the solution to the nearest multiple of given granularity in seconds.
+fn round_times(solution: &mut ApiSolution, granularity: usize) -> Result<(), String> {
+    if granularity == 0 {
+        return Err("time rounding granularity should be positive".to_string());
+    }
+
+    let granularity = granularity as f64;
+    let round = |time: &mut String| *time = format_time((parse_time(time) / granularity).round() * granularity);
+    let round_interval = |interval: &mut Interval| {
+        round(&mut interval.start);
+        round(&mut interval.end);
+    };
+
+    solution.tours.iter_mut().flat_map(|tour| tour.stops.iter_mut()).for_each(|stop| {
+        let (schedule, activities) = match stop {
+            Stop::Point(point) => {
+                point.parking.iter_mut().for_each(round_interval);
+                (&mut point.time, &mut point.activities)
+            }
+            Stop::Transit(transit) => (&mut transit.time, &mut transit.activities),
+        };
+
+        round(&mut schedule.arrival);
+        round(&mut schedule.departure);
+        activities.iter_mut().filter_map(|activity| activity.time.as_mut()).for_each(round_interval);
+    });
+
+    Ok(())
+}
+
 fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
@@ -286,6 +286,31 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that vehicle end dwell time is correct.
+fn check_e1309_vehicle_end_dwell_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, shift_time| {
+            shift.end.as_ref().and_then(|end| end.dwell).map_or(true, |dwell| {
+                dwell >= 0. && shift_time.map_or(true, |shift_time| shift_time.duration() >= dwell)
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid end dwell in vehicle shift".to_string(),
+            format!(
+                "ensure that end dwell is not negative and fits shift time, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -334,6 +359,7 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_required_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_end_dwell_is_correct(ctx),
     ])
     .map_err(|errors| errors.into())
 }
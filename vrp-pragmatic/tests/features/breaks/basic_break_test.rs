@@ -91,6 +91,7 @@ fn can_assign_break_between_jobs() {
                         20
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 54.,
                     distance: 20,
@@ -0,0 +1,53 @@
+use crate::format::problem::Objective::{MinimizeCost, MinimizeUnassignedJobs};
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_insert_break_when_too_many_consecutive_stops() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
+                        places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_stops_before_break: Some(2),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![vec![MinimizeUnassignedJobs { breaks: Some(10.) }], vec![MinimizeCost]]),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activity_types = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.activity_type.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(activity_types, vec!["departure", "delivery", "delivery", "break", "delivery", "delivery", "arrival"]);
+}
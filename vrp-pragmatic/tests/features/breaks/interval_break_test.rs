@@ -108,6 +108,7 @@ fn can_assign_interval_break_between_jobs() {
                         30
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 74.,
                     distance: 30,
@@ -140,7 +141,12 @@ fn can_assign_interval_break_with_reload() {
                         latest: Some(format_time(0.)),
                         location: (0., 0.).to_loc(),
                     },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (30., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (30., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeOffset(vec![8., 12.]),
@@ -263,6 +269,10 @@ fn can_assign_interval_break_with_reload() {
                         60
                     )
                 ],
+                trips: Some(vec![
+                    Trip { start_stop_index: 0, end_stop_index: 3 },
+                    Trip { start_stop_index: 3, end_stop_index: 6 }
+                ]),
                 statistic: Statistic {
                     cost: 139.,
                     distance: 60,
@@ -1,4 +1,5 @@
 mod basic_break_test;
+mod break_stops_limit_test;
 mod break_with_multiple_locations;
 mod interval_break_test;
 mod multi_break_test;
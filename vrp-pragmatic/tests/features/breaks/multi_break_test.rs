@@ -127,6 +127,7 @@ fn can_use_two_breaks() {
                         198,
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 412.,
                     distance: 198,
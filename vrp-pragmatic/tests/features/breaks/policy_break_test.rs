@@ -28,6 +28,7 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                             earliest: None,
                             latest: format_time(1000.),
                             location: (100., 0.).to_loc(),
+                            dwell: None,
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -100,6 +101,7 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                         20,
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 52.,
                     distance: 20,
@@ -188,6 +190,7 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                         2,
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 24.,
                     distance: 2,
@@ -302,6 +305,7 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                         20,
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 54.,
                     distance: 20,
@@ -126,6 +126,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                         6
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 26.,
                     distance: 6,
@@ -205,6 +206,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                         6
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 26.,
                     distance: 6,
@@ -97,6 +97,7 @@ fn can_assign_break_during_travel() {
                         20
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 54.,
                     distance: 20,
@@ -199,6 +200,7 @@ fn can_assign_break_during_activity() {
                         10
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 35.,
                     distance: 10,
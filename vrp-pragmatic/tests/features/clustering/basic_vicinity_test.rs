@@ -104,6 +104,7 @@ fn can_cluster_simple_jobs_impl(
                         10,
                     ),
                 ],
+                trips: None,
                 statistic,
             }],
             ..create_empty_solution()
@@ -236,6 +237,7 @@ fn can_handle_two_clusters_impl(
                 ))
                 .chain(stops.into_iter().map(StopData::into))
                 .collect(),
+                trips: None,
                 statistic,
             }],
             ..create_empty_solution()
@@ -67,6 +67,7 @@ fn can_mix_pickup_delivery_jobs() {
                         10,
                     ),
                 ],
+                trips: None,
                 statistic,
             }],
             ..create_empty_solution()
@@ -154,6 +155,7 @@ fn can_vary_cluster_size_based_on_capacity_impl(
                 .chain(stops.into_iter().map(StopData::into))
                 .collect(),
 
+                trips: None,
                 statistic,
             }],
             unassigned: unassigned.map(|job_ids| job_ids
@@ -50,6 +50,7 @@ fn can_use_scale_on_profile() {
                     ),
                     stop2.into(),
                 ],
+                trips: None,
                 statistic,
             }],
             ..create_empty_solution()
@@ -35,6 +35,7 @@ fn can_handle_parking_with_no_clusters_and_job_time_windows() {
                         earliest: None,
                         latest: "1970-01-01T18:00:00Z".to_string(),
                         location: Location::Coordinate { lat: 52.497, lng: 13.547 },
+                        dwell: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -110,6 +111,7 @@ fn can_handle_waiting_time_with_parking_impl(
                         earliest: None,
                         latest: "1970-01-01T18:00:00Z".to_string(),
                         location: vehicle_location,
+                        dwell: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -86,6 +86,7 @@ fn can_assign_single_dispatch() {
                         14
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 42.,
                     distance: 14,
@@ -105,6 +105,7 @@ fn can_use_init_solution_with_dispatch() {
                         2,
                     ),
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 17.,
                     distance: 2,
@@ -168,6 +169,7 @@ fn can_use_init_solution_with_dispatch() {
                         2,
                     ),
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 17.,
                     distance: 2,
@@ -19,12 +19,22 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 shifts: vec![
                     VehicleShift {
                         start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                        end: Some(ShiftEnd { earliest: None, latest: format_time(99.), location: (0., 0.).to_loc() }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(99.),
+                            location: (0., 0.).to_loc(),
+                            dwell: None,
+                        }),
                         ..create_default_vehicle_shift()
                     },
                     VehicleShift {
                         start: ShiftStart { earliest: format_time(100.), latest: None, location: (0., 0.).to_loc() },
-                        end: Some(ShiftEnd { earliest: None, latest: format_time(200.), location: (0., 0.).to_loc() }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(200.),
+                            location: (0., 0.).to_loc(),
+                            dwell: None,
+                        }),
                         ..create_default_vehicle_shift()
                     },
                 ],
@@ -79,6 +89,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             20,
                         ),
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 51.,
                         distance: 20,
@@ -116,6 +127,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             20,
                         ),
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 51.,
                         distance: 20,
@@ -50,6 +50,7 @@ fn can_use_vehicle_with_open_end() {
                         1
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 13.,
                     distance: 1,
@@ -1,5 +1,6 @@
 mod basic_multi_shift;
 mod basic_open_end;
 mod multi_dimens;
+mod partial_day_availability;
 mod profile_variation;
 mod unreachable_jobs;
@@ -65,6 +65,7 @@ fn can_use_two_dimensions() {
                         2
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 16.,
                     distance: 2,
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_available_from(id: &str, earliest: f64) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            start: ShiftStart { earliest: format_time(earliest), latest: None, location: (0., 0.).to_loc() },
+            end: Some(ShiftEnd {
+                earliest: None,
+                latest: format_time(1000.),
+                location: (0., 0.).to_loc(),
+                dwell: None,
+            }),
+            ..create_default_vehicle_shift()
+        }],
+        ..create_vehicle_with_capacity(id, vec![2])
+    }
+}
+
+fn get_vehicle_jobs(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+    let mut jobs = solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect::<Vec<_>>();
+    jobs.sort();
+
+    jobs
+}
+
+#[test]
+fn can_assign_only_afternoon_jobs_to_vehicle_available_from_noon() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("morning1", (10., 0.), vec![(0, 400)], 1.),
+                create_delivery_job_with_times("morning2", (20., 0.), vec![(0, 400)], 1.),
+                create_delivery_job_with_times("afternoon1", (10., 0.), vec![(500, 1000)], 1.),
+                create_delivery_job_with_times("afternoon2", (20., 0.), vec![(500, 1000)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_available_from("full_day", 0.), create_vehicle_available_from("noon", 500.)],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_vehicle_jobs(&solution, "noon_1"), vec!["afternoon1", "afternoon2"]);
+    assert_eq!(get_vehicle_jobs(&solution, "full_day_1"), vec!["morning1", "morning2"]);
+}
@@ -96,6 +96,7 @@ fn can_use_location_index() {
                         .to_point()
                     })
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 18.,
                     distance: 3,
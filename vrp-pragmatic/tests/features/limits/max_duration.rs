@@ -4,7 +4,12 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_max_duration_limit(max_duration: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, max_duration: Some(max_duration), tour_size: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            max_duration: Some(max_duration),
+            tour_size: None,
+            max_stops_before_break: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -119,6 +124,7 @@ fn can_skip_job_from_multiple_because_of_max_duration() {
                         6
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 52.,
                     distance: 6,
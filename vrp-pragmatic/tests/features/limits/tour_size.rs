@@ -16,7 +16,12 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                limits: Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: Some(2),
+                    max_stops_before_break: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -66,6 +71,7 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                         2
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 16.,
                     distance: 2,
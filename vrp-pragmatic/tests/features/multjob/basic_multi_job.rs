@@ -89,6 +89,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                         16
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 46.,
                     distance: 16,
@@ -177,6 +178,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                         12
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 37.,
                     distance: 12,
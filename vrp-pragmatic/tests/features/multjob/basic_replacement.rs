@@ -17,7 +17,12 @@ fn can_assign_replacement_job() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (4., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (4., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -85,6 +90,7 @@ fn can_assign_replacement_job() {
                         4
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 21.,
                     distance: 4,
@@ -64,6 +64,7 @@ fn can_use_only_deliveries_as_static_demand() {
                         "d1"
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 29.,
                     distance: 8,
@@ -86,7 +87,12 @@ fn can_use_only_pickups_as_static_demand() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (10., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -148,6 +154,7 @@ fn can_use_only_pickups_as_static_demand() {
                         10
                     ),
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 33.,
                     distance: 10,
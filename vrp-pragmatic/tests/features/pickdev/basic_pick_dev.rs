@@ -62,6 +62,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                         4
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 20.,
                     distance: 4,
@@ -85,6 +85,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                         8
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 30.,
                     distance: 8,
@@ -98,6 +98,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                         50
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 114.,
                     distance: 50,
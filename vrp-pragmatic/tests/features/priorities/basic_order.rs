@@ -15,7 +15,7 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None })
+    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None, max_stops_before_break: None })
 }
 
 fn create_order_objective(is_constrained: bool) -> Vec<Vec<Objective>> {
@@ -110,6 +110,7 @@ fn can_follow_orders() {
                         20
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 53.,
                     distance: 20,
@@ -185,7 +186,12 @@ fn can_handle_order_between_special_activities() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (10., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(100.), format_time(200.)]),
                         places: vec![VehicleOptionalBreakPlace {
@@ -84,6 +84,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                         3,
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 19.,
                     distance: 3,
@@ -125,6 +125,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         18
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 53.,
                     distance: 18,
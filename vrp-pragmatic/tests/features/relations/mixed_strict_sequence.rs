@@ -125,6 +125,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         22
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 61.,
                     distance: 22,
@@ -250,6 +251,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             13
                         )
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 40.,
                         distance: 13,
@@ -303,6 +305,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             13
                         )
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 40.,
                         distance: 13,
@@ -106,6 +106,7 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                         100
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 270.,
                     distance: 100,
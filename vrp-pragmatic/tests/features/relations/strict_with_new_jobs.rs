@@ -120,6 +120,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             22
                         )
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 59.,
                         distance: 22,
@@ -189,6 +190,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             20
                         )
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 55.,
                         distance: 20,
@@ -110,6 +110,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             20
                         )
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 54.,
                         distance: 20,
@@ -171,6 +172,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             14
                         )
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 42.,
                         distance: 14,
@@ -36,7 +36,12 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -111,6 +116,10 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         6
                     ),
                 ],
+                trips: Some(vec![
+                    Trip { start_stop_index: 0, end_stop_index: 2 },
+                    Trip { start_stop_index: 2, end_stop_index: 4 }
+                ]),
                 statistic: Statistic {
                     cost: 26.,
                     distance: 6,
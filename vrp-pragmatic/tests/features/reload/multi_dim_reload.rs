@@ -17,7 +17,12 @@ fn can_use_multi_dim_capacity() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
                         duration: 2.0,
@@ -91,6 +96,10 @@ fn can_use_multi_dim_capacity() {
                         6
                     ),
                 ],
+                trips: Some(vec![
+                    Trip { start_stop_index: 0, end_stop_index: 2 },
+                    Trip { start_stop_index: 2, end_stop_index: 4 }
+                ]),
                 statistic: Statistic {
                     cost: 26.,
                     distance: 6,
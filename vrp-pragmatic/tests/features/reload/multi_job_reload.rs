@@ -23,7 +23,12 @@ fn can_serve_multi_job_and_delivery_with_reload() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (10., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -15,7 +15,12 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -90,6 +95,10 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         6,
                     ),
                 ],
+                trips: Some(vec![
+                    Trip { start_stop_index: 0, end_stop_index: 2 },
+                    Trip { start_stop_index: 2, end_stop_index: 4 },
+                ]),
                 statistic: Statistic {
                     cost: 26.,
                     distance: 6,
@@ -20,7 +20,12 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (6., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (6., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -104,6 +109,10 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     6
                 ),
             ],
+            trips: Some(vec![
+                Trip { start_stop_index: 0, end_stop_index: 3 },
+                Trip { start_stop_index: 3, end_stop_index: 6 }
+            ]),
             statistic: Statistic {
                 cost: 28.,
                 distance: 6,
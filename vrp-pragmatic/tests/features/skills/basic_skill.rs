@@ -71,6 +71,7 @@ fn can_wait_for_job_start() {
                         18
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 47.,
                     distance: 18,
@@ -93,6 +93,7 @@ fn can_use_multiple_times() {
                         100
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 240.,
                     distance: 100,
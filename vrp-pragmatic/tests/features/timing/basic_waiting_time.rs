@@ -67,6 +67,7 @@ fn can_wait_for_job_start() {
                         4
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 26.,
                     distance: 4,
@@ -132,6 +133,7 @@ fn can_skip_initial_waiting() {
                         2
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 24.,
                     distance: 2,
@@ -210,6 +212,7 @@ fn can_consider_latest_departure_time() {
                         2
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 28.,
                     distance: 2,
@@ -85,6 +85,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                         80
                     ),
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 170.,
                     distance: 80,
@@ -92,7 +92,7 @@ pub fn default_shift_places_prototype() -> impl Strategy<Value = (ShiftStart, Op
     generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
         Just((
             ShiftStart { earliest: default_time_plus_offset(9), latest: None, location: location.clone() },
-            Some(ShiftEnd { earliest: None, latest: default_time_plus_offset(18), location }),
+            Some(ShiftEnd { earliest: None, latest: default_time_plus_offset(18), location, dwell: None }),
         ))
     })
 }
@@ -214,7 +214,12 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
         start: ShiftStart { earliest: format_time(0.), latest: None, location: (start.0, start.1).to_loc() },
-        end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (end.0, end.1).to_loc() }),
+        end: Some(ShiftEnd {
+            earliest: None,
+            latest: format_time(1000.),
+            location: (end.0, end.1).to_loc(),
+            dwell: None,
+        }),
         dispatch: None,
         breaks: None,
         reloads: None,
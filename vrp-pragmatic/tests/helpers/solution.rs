@@ -103,6 +103,7 @@ pub fn create_empty_tour() -> Tour {
         type_id: "".to_string(),
         shift_index: 0,
         stops: vec![],
+        trips: None,
         statistic: Default::default(),
     }
 }
@@ -127,6 +127,7 @@ fn can_handle_properly_invalid_break_removal() {
                             earliest: None,
                             latest: "2020-07-04T18:00:00Z".to_string(),
                             location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
+                            dwell: None,
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -47,6 +47,7 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
                         earliest: None,
                         latest: format_time(7200.),
                         location: Location::Reference { index: 2 },
+                        dwell: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
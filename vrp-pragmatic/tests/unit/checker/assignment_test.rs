@@ -34,6 +34,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 type_id: "my_vehicle".to_string(),
                 shift_index,
                 stops: vec![],
+                trips: None,
                 statistic: Statistic::default(),
             })
             .collect(),
@@ -168,6 +169,7 @@ fn check_jobs_impl(
                 type_id: "my_vehicle".to_string(),
                 shift_index,
                 stops: stops.into_iter().map(create_stop).collect(),
+                trips: None,
                 statistic: Statistic::default(),
             })
             .collect(),
@@ -230,6 +232,7 @@ fn can_detect_time_window_violation() {
                     2,
                 ),
             ],
+            trips: None,
             statistic: Statistic {
                 cost: 15.,
                 distance: 2,
@@ -294,6 +297,7 @@ fn can_detect_job_duration_violation() {
                     2,
                 ),
             ],
+            trips: None,
             statistic: Statistic {
                 cost: 18.,
                 distance: 2,
@@ -77,7 +77,12 @@ fn can_check_breaks_impl(
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (0., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: break_times,
@@ -161,6 +166,7 @@ fn can_check_breaks_impl(
                     4,
                 ),
             ],
+            trips: None,
             statistic: Statistic {
                 cost: 22.,
                 distance: 4,
@@ -37,7 +37,12 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (0., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     dispatch: None,
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
@@ -179,6 +184,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                     11,
                 ),
             ],
+            trips: None,
             statistic: Statistic {
                 cost: 13.,
                 distance: 1,
@@ -261,6 +267,7 @@ fn can_check_load_when_departure_has_other_activity() {
                     2,
                 ),
             ],
+            trips: None,
             statistic: Statistic {
                 cost: 6.,
                 distance: 2,
@@ -358,6 +365,7 @@ fn can_check_resource_consumption() {
                     6,
                 ),
             ],
+            trips: None,
             statistic: Statistic {
                 cost: 17.,
                 distance: 6,
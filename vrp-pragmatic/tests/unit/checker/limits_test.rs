@@ -24,6 +24,7 @@ fn create_test_solution(statistic: Statistic, stops: Vec<Stop>) -> Solution {
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
             stops,
+            trips: None,
             statistic,
         }],
         ..create_empty_solution()
@@ -60,7 +61,12 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), String>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        max_duration,
+        tour_size: None,
+        max_stops_before_break: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -72,8 +78,12 @@ pub fn can_check_shift_and_distance_limit_impl(
 
 #[test]
 pub fn can_check_tour_size_limit() {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: Some(2),
+        max_stops_before_break: None,
+    }));
     let solution = create_test_solution(
         Statistic::default(),
         vec![
@@ -141,7 +151,12 @@ fn can_check_shift_time() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(5.), location: (0., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(5.),
+                        location: (0., 0.).to_loc(),
+                        dwell: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -187,6 +202,7 @@ fn can_check_shift_time() {
                     2,
                 ),
             ],
+            trips: None,
             statistic: Statistic {
                 cost: 17.,
                 distance: 2,
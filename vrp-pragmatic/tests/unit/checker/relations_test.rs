@@ -88,7 +88,12 @@ mod single {
                     costs: create_default_vehicle_costs(),
                     shifts: vec![VehicleShift {
                         start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                        end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(1000.),
+                            location: (0., 0.).to_loc(),
+                            dwell: None,
+                        }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
                             time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
@@ -207,6 +212,7 @@ mod single {
                             16,
                         ),
                     ],
+                    trips: None,
                     statistic: Statistic {
                         cost: 51.,
                         distance: 16,
@@ -219,6 +225,7 @@ mod single {
                     type_id: "my_vehicle".to_string(),
                     shift_index: 0,
                     stops: vec![],
+                    trips: None,
                     statistic: Default::default(),
                 },
             ],
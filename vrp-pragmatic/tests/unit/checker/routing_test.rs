@@ -74,6 +74,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                     third.1,
                 ),
             ],
+            trips: None,
             statistic,
         }],
         ..create_empty_solution()
@@ -90,3 +90,45 @@ fn can_skip_merge_breaks_impl(source: Job, candidate: Job, expected: Result<(),
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_limit_stops_before_break, (index, is_break, expected), {
+    can_limit_stops_before_break_impl(index, is_break, expected);
+}}
+
+can_limit_stops_before_break! {
+    case01_between_jobs: (1, false, Some(VIOLATION_CODE)),
+    case02_before_break: (2, false, Some(VIOLATION_CODE)),
+    case03_after_break: (3, false, None),
+    case04_last: (4, false, None),
+    case05_break_itself: (1, true, None),
+}
+
+fn can_limit_stops_before_break_impl(index: usize, is_break: bool, expected: Option<ViolationCode>) {
+    let fleet = test_fleet();
+    let route_ctx = RouteContext::new_with_state(
+        create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![
+                create_activity_with_job_at_location(create_single("job1"), 1),
+                create_activity_with_job_at_location(create_single("job2"), 2),
+                create_activity_with_job_at_location(create_break("v1", None), 2),
+                create_activity_with_job_at_location(create_single("job3"), 3),
+            ],
+        ),
+        RouteState::default(),
+    );
+    let target = if is_break { create_break("v1", None) } else { create_single("job4") };
+    let target = create_activity_with_job_at_location(target, 4);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let feature = create_break_stops_limit_feature("break_stops_limit", VIOLATION_CODE, Arc::new(|_| Some(2))).unwrap();
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
@@ -1,6 +1,15 @@
 use super::*;
-use crate::helpers::{SIMPLE_MATRIX, SIMPLE_PROBLEM};
+use crate::construction::enablers::JobTie;
+use crate::format::problem::PragmaticProblem;
+use crate::format::solution::create_solution;
+use crate::helpers::*;
 use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{create_elitism_population, RefinementContext};
+use vrp_core::utils::{DefaultRandom, Environment, Parallelism};
 
 fn assert_time_windows(actual: &Option<Vec<Vec<String>>>, expected: (&str, &str)) {
     let actual = actual.as_ref().unwrap();
@@ -64,3 +73,104 @@ fn can_deserialize_matrix() {
     assert_eq!(matrix.distances.len(), 16);
     assert_eq!(matrix.travel_times.len(), 16);
 }
+
+#[test]
+fn can_create_matrix_from_custom_distance_fn() {
+    let locations =
+        vec![Location::new_coordinate(0., 0.), Location::new_coordinate(3., 1.), Location::new_coordinate(-2., 5.)];
+    let chebyshev = |from: &Location, to: &Location| {
+        let ((from_lat, from_lng), (to_lat, to_lng)) = (from.to_lat_lng(), to.to_lat_lng());
+        (from_lat - to_lat).abs().max((from_lng - to_lng).abs())
+    };
+
+    let matrix = Matrix::from_fn(locations.as_slice(), chebyshev);
+
+    assert_eq!(matrix.distances, vec![0, 3, 5, 3, 0, 5, 5, 5, 0]);
+    assert_eq!(matrix.travel_times, matrix.distances);
+    locations.iter().enumerate().for_each(|(from_idx, from)| {
+        locations.iter().enumerate().for_each(|(to_idx, to)| {
+            assert_eq!(matrix.distances[from_idx * locations.len() + to_idx], chebyshev(from, to) as i64);
+        })
+    });
+}
+
+fn solve_with_seed(problem: Problem) -> String {
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    // NOTE use one thread to keep sequence of random numbers from the seeded generator reproducible
+    let environment = Arc::new(Environment {
+        random: Arc::new(DefaultRandom::new_with_seed(0)),
+        parallelism: Parallelism::new(1, 1),
+        ..Environment::default()
+    });
+    let population = create_elitism_population(problem.goal.clone(), environment.clone());
+    let refinement_ctx =
+        RefinementContext::new(problem.clone(), Box::new(population), TelemetryMode::None, environment.clone());
+
+    // NOTE keep jobs in the same order as iteration order of unassigned jobs is not reproducible
+    let mut insertion_ctx = InsertionContext::new(problem.clone(), environment.clone());
+    let mut jobs = insertion_ctx.solution.unassigned.drain().map(|(job, _)| job).collect::<Vec<_>>();
+    jobs.sort_by(|a, b| a.dimens().get_job_id().cmp(&b.dimens().get_job_id()));
+    insertion_ctx.solution.required = jobs;
+
+    let solution = environment.parallelism.thread_pool_execute(0, || {
+        RecreateWithCheapest::new(environment.random.clone()).run(&refinement_ctx, insertion_ctx).solution.into()
+    });
+
+    serde_json::to_string(&create_solution(&problem, &solution, None)).unwrap()
+}
+
+#[test]
+fn can_write_and_read_problem_in_binary_format() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (3., 0.)),
+                create_delivery_job_with_times("job3", (5., 0.), vec![(10, 20)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let mut buffer = Vec::new();
+
+    problem.write_binary(&mut buffer).unwrap();
+    let reloaded = Problem::read_binary(&mut buffer.as_slice()).ok().unwrap();
+
+    assert_eq!(serde_json::to_string(&reloaded).unwrap(), serde_json::to_string(&problem).unwrap());
+    assert_eq!(solve_with_seed(reloaded), solve_with_seed(problem));
+}
+
+parameterized_test! {can_reject_binary_problem_with_invalid_header, (header, expected_action), {
+    can_reject_binary_problem_with_invalid_header_impl(header, expected_action);
+}}
+
+can_reject_binary_problem_with_invalid_header! {
+    case01_wrong_magic: (b"JSON\x01\x00".to_vec(), "check that input is a problem in binary format"),
+    case02_wrong_version: (b"VRPB\x02\x00".to_vec(), "binary format version 2 is not supported, expected 1"),
+}
+
+fn can_reject_binary_problem_with_invalid_header_impl(header: Vec<u8>, expected_action: &str) {
+    let problem = deserialize_problem(BufReader::new(SIMPLE_PROBLEM.as_bytes())).ok().unwrap();
+    let mut buffer = Vec::new();
+    problem.write_binary(&mut buffer).unwrap();
+    let buffer = header.into_iter().chain(buffer.into_iter().skip(6)).collect::<Vec<_>>();
+
+    let result = Problem::read_binary(&mut buffer.as_slice());
+
+    let errors = result.expect_err("should fail").into_iter().collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "E0000");
+    assert!(errors[0].action.starts_with(expected_action));
+}
+
+#[test]
+fn can_reject_truncated_binary_problem() {
+    let result = Problem::read_binary(&mut b"VRP".as_slice());
+
+    let errors = result.expect_err("should fail").into_iter().collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].action.starts_with("check binary header"));
+}
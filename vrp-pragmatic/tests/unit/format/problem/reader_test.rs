@@ -4,8 +4,11 @@ use crate::helpers::*;
 use hashbrown::HashSet;
 use std::iter::FromIterator;
 use std::sync::Arc;
+use vrp_core::construction::heuristics::try_marginal_cost;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Jobs, Multi, Place, Single};
+use vrp_core::models::solution::Registry;
+use vrp_core::utils::DefaultRandom;
 
 fn get_job(index: usize, jobs: &Jobs) -> vrp_core::models::problem::Job {
     jobs.all().collect::<Vec<_>>().get(index).unwrap().clone()
@@ -149,6 +152,7 @@ fn can_read_complex_problem() {
                         earliest: None,
                         latest: "1970-01-01T00:01:40Z".to_string(),
                         location: (52.4862, 13.45148).to_loc(),
+                        dwell: None,
                     }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
@@ -167,7 +171,12 @@ fn can_read_complex_problem() {
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    max_duration: Some(100.),
+                    tour_size: Some(3),
+                    max_stops_before_break: None,
+                }),
             }],
             ..create_default_fleet()
         },
@@ -315,3 +324,27 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+#[test]
+fn can_explain_skills_violation_of_job_insertion() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills("job1", (1., 0.), all_of_skills(vec!["unique".to_string()]))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let core_problem = Arc::new(problem.read_pragmatic().unwrap());
+    let job = core_problem.jobs.all().next().unwrap();
+    let solution = vrp_core::models::Solution {
+        registry: Registry::new(&core_problem.fleet, Arc::new(DefaultRandom::default())),
+        routes: vec![],
+        unassigned: vec![],
+        seed: None,
+    };
+
+    let result = try_marginal_cost(core_problem, &solution, &job);
+
+    assert_eq!(result.map_err(|violation| violation.code), Err(crate::format::SKILL_CONSTRAINT_CODE));
+}
@@ -0,0 +1,65 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(10, 20), (100, 200)], 1.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(5, 30)], 1.),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![3, 5])],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_times(problem: &Problem) -> Vec<Option<Vec<(f64, f64)>>> {
+    problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(get_job_tasks)
+        .flat_map(|task| task.places.iter())
+        .map(|place| {
+            place.times.as_ref().map(|times| times.iter().map(|tw| (parse_time(&tw[0]), parse_time(&tw[1]))).collect())
+        })
+        .collect()
+}
+
+#[test]
+fn can_widen_time_windows() {
+    let problem = create_test_problem();
+
+    let relaxed = relax_problem(&problem, &RelaxationStep::WidenTimeWindows { seconds: 10. });
+
+    assert_eq!(get_times(&relaxed), vec![Some(vec![(0., 30.), (90., 210.)]), Some(vec![(0., 40.)]), None]);
+    assert_eq!(relaxed.fleet.vehicles[0].capacity, vec![3, 5]);
+}
+
+#[test]
+fn can_scale_capacity() {
+    let problem = create_test_problem();
+
+    let relaxed = relax_problem(&problem, &RelaxationStep::ScaleCapacity { factor: 1.5 });
+
+    assert_eq!(relaxed.fleet.vehicles[0].capacity, vec![5, 8]);
+    assert_eq!(get_times(&relaxed), get_times(&problem));
+}
+
+#[test]
+fn can_deserialize_relaxation_steps() {
+    let steps = r#"[{"type":"widenTimeWindows","seconds":600},{"type":"scaleCapacity","factor":2}]"#;
+
+    let steps: Vec<RelaxationStep> = serde_json::from_str(steps).unwrap();
+
+    assert_eq!(
+        steps,
+        vec![RelaxationStep::WidenTimeWindows { seconds: 600. }, RelaxationStep::ScaleCapacity { factor: 2. }]
+    );
+}
@@ -169,6 +169,7 @@ fn can_read_basic_init_solution() {
                     8,
                 ),
             ],
+            trips: None,
             statistic: Statistic {
                 cost: 32.,
                 distance: 8,
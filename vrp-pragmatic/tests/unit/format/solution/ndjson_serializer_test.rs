@@ -0,0 +1,37 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format::solution::Tour;
+use crate::helpers::*;
+
+#[test]
+fn can_write_solution_as_ndjson() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", (1., 0.), vec![10]),
+                create_delivery_job_with_demand("job2", (2., 0.), vec![10]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle1"), create_default_vehicle("vehicle2")],
+            profiles: create_default_matrix_profiles(),
+            resources: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+    let mut writer = BufWriter::new(Vec::new());
+
+    write_solution_ndjson(&solution, &mut writer).unwrap();
+
+    let bytes = writer.into_inner().unwrap();
+    let tours = String::from_utf8(bytes)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<Tour>(line).expect("each line should be a tour"))
+        .collect::<Vec<_>>();
+    assert_eq!(tours.len(), 2);
+    assert_eq!(tours, solution.tours);
+}
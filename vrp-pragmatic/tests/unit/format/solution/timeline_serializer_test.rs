@@ -0,0 +1,58 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format::solution::PragmaticSolution;
+use crate::helpers::*;
+use crate::parse_time;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{create_elitism_population, RefinementContext};
+use vrp_core::utils::Environment;
+
+#[test]
+fn can_write_contiguous_timeline_intervals() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![(20, 30)], 2.),
+                create_delivery_job("job2", (10., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = RefinementContext::new(
+        core_problem.clone(),
+        Box::new(create_elitism_population(core_problem.goal.clone(), environment.clone())),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+    let core_solution: Solution = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(core_problem.clone(), environment))
+        .solution
+        .into();
+    let mut writer = BufWriter::new(Vec::new());
+
+    (&core_solution, 0.).write_timeline_json(&core_problem, &mut writer).expect("cannot write timeline");
+
+    let bytes = writer.into_inner().expect("cannot get bytes from writer");
+    let timeline: Timeline = serde_json::from_slice(bytes.as_slice()).expect("cannot read timeline");
+    assert_eq!(timeline.vehicles.len(), 1);
+    let vehicle = &timeline.vehicles[0];
+    let route = &core_solution.routes[0];
+    assert_eq!(vehicle.vehicle_id, "my_vehicle_1");
+    let intervals = &vehicle.intervals;
+    assert!(intervals.windows(2).all(|pair| parse_time(&pair[0].end) == parse_time(&pair[1].start)));
+    assert!(intervals.iter().all(|interval| parse_time(&interval.start) < parse_time(&interval.end)));
+    assert_eq!(parse_time(&intervals.first().unwrap().start), route.tour.start().unwrap().schedule.arrival);
+    assert_eq!(parse_time(&intervals.last().unwrap().end), route.tour.end().unwrap().schedule.departure);
+    let types = intervals.iter().map(|interval| interval.interval_type.as_str()).collect::<Vec<_>>();
+    assert!(types.contains(&"travel"));
+    assert!(types.contains(&"waiting"));
+    assert!(types.contains(&"service"));
+}
@@ -1,18 +1,24 @@
 use crate::format::problem::*;
-use crate::format::solution::writer::create_tour;
+use crate::format::solution::writer::{create_tour, create_trips};
 use crate::format::solution::*;
 use crate::helpers::*;
 use std::cmp::Ordering;
+use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::{TimeSpan, TimeWindow};
 use vrp_core::models::examples::create_example_problem;
-use vrp_core::utils::compare_floats;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{create_elitism_population, RefinementContext};
+use vrp_core::utils::{compare_floats, Environment};
 
 type DomainProblem = vrp_core::models::Problem;
 type DomainActivity = vrp_core::models::solution::Activity;
 type DomainCommute = vrp_core::models::solution::Commute;
 type DomainCommuteInfo = vrp_core::models::solution::CommuteInfo;
 type DomainSchedule = vrp_core::models::common::Schedule;
+type DomainSolution = vrp_core::models::Solution;
 
 fn create_test_problem_and_coord_index() -> (DomainProblem, CoordIndex) {
     let problem = {
@@ -87,6 +93,7 @@ fn can_create_solution() {
                         20
                     )
                 ],
+                trips: None,
                 statistic: Statistic {
                     cost: 52.,
                     distance: 20,
@@ -227,3 +234,80 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
 }
+
+#[test]
+fn can_round_times_in_output() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = RefinementContext::new(
+        core_problem.clone(),
+        Box::new(create_elitism_population(core_problem.goal.clone(), environment.clone())),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+    let core_solution: DomainSolution = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(core_problem.clone(), environment))
+        .solution
+        .into();
+    let mut writer = BufWriter::new(Vec::new());
+
+    (&core_solution, 0.).write_pragmatic_json_rounded(&core_problem, 10, &mut writer).expect("cannot write solution");
+
+    let bytes = writer.into_inner().expect("cannot get bytes from writer");
+    let solution = deserialize_solution(BufReader::new(bytes.as_slice())).expect("cannot read solution");
+    let schedules = solution.tours[0]
+        .stops
+        .iter()
+        .map(|stop| (stop.schedule().arrival.as_str(), stop.schedule().departure.as_str()))
+        .collect::<Vec<_>>();
+    // NOTE original schedules are: (0, 0), (10, 11), (16, 17), (22, 22)
+    assert_eq!(
+        schedules,
+        vec![
+            ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+            ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
+            ("1970-01-01T00:00:20Z", "1970-01-01T00:00:20Z"),
+            ("1970-01-01T00:00:20Z", "1970-01-01T00:00:20Z"),
+        ]
+    );
+}
+
+parameterized_test! {can_create_trips_between_depot_stops, (activity_types, expected), {
+    can_create_trips_between_depot_stops_impl(activity_types, expected);
+}}
+
+can_create_trips_between_depot_stops! {
+    case01_no_reload: (&["departure", "delivery", "arrival"], None),
+    case02_one_reload: (&["departure", "delivery", "reload", "delivery", "arrival"], Some(vec![(0, 2), (2, 4)])),
+    case03_two_reloads: (
+        &["departure", "reload", "delivery", "reload", "delivery", "arrival"],
+        Some(vec![(0, 1), (1, 3), (3, 5)])
+    ),
+    case04_open_end: (&["departure", "delivery", "reload", "delivery"], Some(vec![(0, 2), (2, 3)])),
+}
+
+fn can_create_trips_between_depot_stops_impl(activity_types: &[&str], expected: Option<Vec<(usize, usize)>>) {
+    let stops = activity_types
+        .iter()
+        .map(|activity_type| {
+            let time = ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z");
+            create_stop_with_activity(activity_type, activity_type, (0., 0.), 0, time, 0)
+        })
+        .collect::<Vec<_>>();
+
+    let trips = create_trips(stops.as_slice());
+
+    assert_eq!(
+        trips.map(|trips| trips.iter().map(|trip| (trip.start_stop_index, trip.end_stop_index)).collect::<Vec<_>>()),
+        expected
+    );
+}
@@ -181,3 +181,41 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_end_dwell, (dwell, expected), {
+    can_detect_invalid_end_dwell_impl(dwell, expected);
+}}
+
+can_detect_invalid_end_dwell! {
+    case01: (None, None),
+    case02: (Some(100.), None),
+    case03: (Some(1000.), None),
+    case04: (Some(1001.), Some("E1309".to_string())),
+    case05: (Some(-1.), Some("E1309".to_string())),
+}
+
+fn can_detect_invalid_end_dwell_impl(dwell: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (0., 0.).to_loc(),
+                        dwell,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1309_vehicle_end_dwell_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
@@ -0,0 +1,43 @@
+use super::VrpError;
+use std::io::{BufRead, BufReader, Read};
+
+/// Specifies magic bytes which start any gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// A reader which transparently decompresses its input if it is compressed.
+pub(crate) enum InputReader<R: Read> {
+    /// An input is a plain text.
+    Plain(BufReader<R>),
+    /// An input is compressed by gzip.
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::bufread::GzDecoder<BufReader<R>>),
+}
+
+impl<R: Read> Read for InputReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            InputReader::Plain(reader) => reader.read(buf),
+            #[cfg(feature = "gzip")]
+            InputReader::Gzip(reader) => reader.read(buf),
+        }
+    }
+}
+
+/// Creates a reader which decompresses the input if it starts with gzip magic bytes.
+pub(crate) fn create_input_reader<R: Read>(mut reader: BufReader<R>) -> Result<BufReader<InputReader<R>>, VrpError> {
+    let is_gzip = reader.fill_buf()?.starts_with(&GZIP_MAGIC);
+
+    if !is_gzip {
+        return Ok(BufReader::new(InputReader::Plain(reader)));
+    }
+
+    #[cfg(feature = "gzip")]
+    {
+        Ok(BufReader::new(InputReader::Gzip(flate2::bufread::GzDecoder::new(reader))))
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    {
+        Err(VrpError::Parse("gzip compressed input requires 'gzip' feature to be enabled".to_string()))
+    }
+}
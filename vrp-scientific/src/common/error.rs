@@ -0,0 +1,54 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Specifies an error which can happen while reading a scientific problem or writing its solution.
+#[derive(Clone, Debug)]
+pub enum VrpError {
+    /// An error from underlying reader or writer. The original error is kept, so its kind can be inspected.
+    Io(Arc<std::io::Error>),
+    /// An input cannot be parsed according to the format specification.
+    Parse(String),
+    /// An input is parsed, but it defines an invalid model.
+    Validation(String),
+    /// A solution cannot be represented in the format, e.g. it has unassigned jobs.
+    Infeasible(String),
+}
+
+impl Display for VrpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VrpError::Io(err) => write!(f, "io error: {err}"),
+            VrpError::Parse(msg) => write!(f, "parse error: {msg}"),
+            VrpError::Validation(msg) => write!(f, "validation error: {msg}"),
+            VrpError::Infeasible(msg) => write!(f, "infeasible: {msg}"),
+        }
+    }
+}
+
+impl PartialEq for VrpError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (VrpError::Io(a), VrpError::Io(b)) => a.kind() == b.kind() && a.to_string() == b.to_string(),
+            (VrpError::Parse(a), VrpError::Parse(b)) => a == b,
+            (VrpError::Validation(a), VrpError::Validation(b)) => a == b,
+            (VrpError::Infeasible(a), VrpError::Infeasible(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Error for VrpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            VrpError::Io(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for VrpError {
+    fn from(err: std::io::Error) -> Self {
+        VrpError::Io(Arc::new(err))
+    }
+}
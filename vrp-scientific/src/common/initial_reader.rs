@@ -2,10 +2,11 @@
 #[path = "../../tests/unit/common/init_solution_reader_test.rs"]
 mod init_solution_reader_test;
 
-use crate::common::read_line;
+use crate::common::VrpError;
 use std::collections::HashMap;
-use std::io::{BufReader, Read};
+use std::io::{BufRead, BufReader, Read};
 use std::sync::Arc;
+use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 use vrp_core::models::solution::{Activity, Registry, Route, Tour};
@@ -17,14 +18,19 @@ pub fn read_init_solution<R: Read>(
     mut reader: BufReader<R>,
     problem: Arc<Problem>,
     random: Arc<dyn Random + Send + Sync>,
-) -> Result<Solution, String> {
+) -> Result<Solution, VrpError> {
     let mut buffer = String::new();
 
-    let mut solution =
-        Solution { registry: Registry::new(&problem.fleet, random), routes: vec![], unassigned: Default::default() };
+    let mut solution = Solution {
+        registry: Registry::new(&problem.fleet, random),
+        routes: vec![],
+        unassigned: Default::default(),
+        seed: None,
+    };
 
     loop {
-        match read_line(&mut reader, &mut buffer) {
+        buffer.clear();
+        match reader.read_line(&mut buffer) {
             Ok(read) if read > 0 => {
                 let route: Vec<_> = buffer.split(':').collect();
                 if route.len() != 2 {
@@ -63,7 +69,7 @@ pub fn read_init_solution<R: Read>(
                 if buffer.is_empty() {
                     break;
                 } else {
-                    return Err(error);
+                    return Err(error.into());
                 }
             }
         }
@@ -71,3 +77,109 @@ pub fn read_init_solution<R: Read>(
 
     Ok(solution)
 }
+
+/// Reads a solution written in text format by scientific writers, so it is an inverse operation of
+/// writing: each `Route N: ids` line is assigned to the next available vehicle and sub jobs of
+/// pickup and delivery job are resolved by order of job id occurrences. Jobs which are not listed are
+/// reported as unassigned. NOTE: Solution feasibility is not checked.
+pub fn read_text_solution<R: Read>(
+    reader: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, VrpError> {
+    let jobs = problem
+        .jobs
+        .all()
+        .filter_map(|job| job.dimens().get_id().cloned().map(|id| (id, job)))
+        .collect::<HashMap<_, _>>();
+    let mut occurrences = HashMap::<String, usize>::new();
+
+    let mut solution = Solution {
+        registry: Registry::new(&problem.fleet, random),
+        routes: vec![],
+        unassigned: Default::default(),
+        seed: None,
+    };
+
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(cost) = line.strip_prefix("Cost ") {
+            cost.trim().parse::<f64>().map_err(|_| VrpError::Parse(format!("invalid cost line: '{line}'")))?;
+            continue;
+        }
+
+        let ids = line
+            .strip_prefix("Route ")
+            .and_then(|route| route.split_once(':'))
+            .map(|(_, ids)| ids)
+            .ok_or_else(|| VrpError::Parse(format!("unexpected line: '{line}'")))?;
+
+        let actor = solution
+            .registry
+            .next()
+            .next()
+            .ok_or_else(|| VrpError::Validation("not enough vehicles to read solution".to_string()))?;
+        let mut tour = Tour::new(&actor);
+
+        ids.split_whitespace().try_for_each(|id| {
+            let job = jobs.get(id).ok_or_else(|| VrpError::Parse(format!("unknown job id: '{id}'")))?;
+            let occurrence = occurrences.entry(id.to_string()).or_insert(0);
+
+            let single = match job {
+                Job::Single(single) if *occurrence == 0 => Some(single.clone()),
+                Job::Single(_) => None,
+                Job::Multi(multi) => multi.jobs.get(*occurrence).cloned(),
+            }
+            .ok_or_else(|| VrpError::Validation(format!("job '{id}' is used too many times")))?;
+            *occurrence += 1;
+
+            tour.insert_last(create_activity(single)?);
+
+            Ok::<_, VrpError>(())
+        })?;
+
+        solution.registry.use_actor(&actor);
+        solution.routes.push(Route { actor, tour });
+    }
+
+    problem.jobs.all().try_for_each(|job| {
+        let id = job.dimens().get_id().cloned().unwrap_or_default();
+
+        let used = occurrences.get(&id).copied().unwrap_or(0);
+        let is_partial = match &job {
+            Job::Single(_) => false,
+            Job::Multi(multi) => used > 0 && used < multi.jobs.len(),
+        };
+
+        if is_partial {
+            return Err(VrpError::Validation(format!("job '{id}' is not fully assigned")));
+        }
+
+        if used == 0 {
+            solution.unassigned.push((job, UnassignmentInfo::Unknown));
+        }
+
+        Ok(())
+    })?;
+
+    Ok(solution)
+}
+
+fn create_activity(single: Arc<Single>) -> Result<Activity, VrpError> {
+    let place = single.places.first().ok_or_else(|| VrpError::Validation("job without place".to_string()))?;
+    let location = place.location.ok_or_else(|| VrpError::Validation("job without location".to_string()))?;
+    let time = place.times.first().and_then(|span| span.as_time_window()).unwrap_or_else(TimeWindow::max);
+
+    Ok(Activity {
+        place: vrp_core::models::solution::Place { location, duration: place.duration, time },
+        schedule: Schedule::new(0.0, 0.0),
+        job: Some(single),
+        commute: None,
+    })
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/metrics_test.rs"]
+mod metrics_test;
+
+use super::{get_job_activities, VrpError};
+use std::io::{BufWriter, Write};
+use vrp_core::models::common::{Demand, DemandDimension, SingleDimLoad};
+use vrp_core::models::problem::{TransportCost, TravelTime};
+use vrp_core::models::solution::Route;
+use vrp_core::models::Solution;
+
+/// Specifies an output format of solution metrics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricsFormat {
+    /// A json object.
+    Json,
+    /// A single csv line with `cost,distance,routes,demand,tours` columns, where tours are
+    /// `distance:load` pairs separated by semicolon.
+    Csv,
+}
+
+/// Contains metrics of a single route.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RouteMetrics {
+    /// A tour length.
+    pub distance: f64,
+    /// A total demand served by the route.
+    pub load: i32,
+}
+
+/// Contains aggregated metrics of the solution.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SolutionMetrics {
+    /// A solution cost.
+    pub cost: f64,
+    /// A total distance of all routes.
+    pub distance: f64,
+    /// An amount of used routes.
+    pub routes: usize,
+    /// A total served demand.
+    pub demand: i32,
+    /// Metrics of each route in the same order as routes are written in text solution.
+    pub tours: Vec<RouteMetrics>,
+}
+
+impl SolutionMetrics {
+    fn to_json(&self) -> String {
+        let tours = self
+            .tours
+            .iter()
+            .map(|tour| format!("{{\"distance\":{:.2},\"load\":{}}}", tour.distance, tour.load))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            "{{\"cost\":{:.2},\"distance\":{:.2},\"routes\":{},\"demand\":{},\"tours\":[{tours}]}}",
+            self.cost, self.distance, self.routes, self.demand
+        )
+    }
+
+    fn to_csv(&self) -> String {
+        let tours =
+            self.tours.iter().map(|tour| format!("{:.2}:{}", tour.distance, tour.load)).collect::<Vec<_>>().join(";");
+
+        format!("{:.2},{:.2},{},{},{tours}", self.cost, self.distance, self.routes, self.demand)
+    }
+}
+
+/// Calculates metrics of the solution, writes them in given format and returns them. Transport is
+/// used to calculate tour lengths. An empty solution has zeroed metrics.
+pub fn write_solution_metrics<W: Write>(
+    solution: &Solution,
+    cost: f64,
+    transport: &(dyn TransportCost + Send + Sync),
+    format: MetricsFormat,
+    writer: &mut BufWriter<W>,
+) -> Result<SolutionMetrics, VrpError> {
+    let tours = solution.routes.iter().map(|route| get_route_metrics(route, transport)).collect::<Vec<_>>();
+
+    let metrics = SolutionMetrics {
+        cost,
+        // NOTE fold from zero as sum of no floats is negative zero
+        distance: tours.iter().fold(0., |acc, tour| acc + tour.distance),
+        routes: tours.len(),
+        demand: tours.iter().map(|tour| tour.load).sum(),
+        tours,
+    };
+
+    let output = match format {
+        MetricsFormat::Json => metrics.to_json(),
+        MetricsFormat::Csv => metrics.to_csv(),
+    };
+    writer.write_all(output.as_bytes())?;
+
+    Ok(metrics)
+}
+
+fn get_route_metrics(route: &Route, transport: &(dyn TransportCost + Send + Sync)) -> RouteMetrics {
+    let activities = route.tour.all_activities().collect::<Vec<_>>();
+    let distance = activities
+        .windows(2)
+        .map(|leg| {
+            let (from, to) = (leg[0], leg[1]);
+            transport.distance(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            )
+        })
+        .sum();
+
+    // NOTE dynamic delivery serves the same goods as dynamic pickup, so it is not counted
+    let load = get_job_activities(route)
+        .filter_map(|activity| activity.job.as_ref())
+        .filter_map(|job| job.dimens.get_demand())
+        .map(|demand: &Demand<SingleDimLoad>| demand.delivery.0.value + demand.pickup.0.value + demand.pickup.1.value)
+        .sum();
+
+    RouteMetrics { distance, load }
+}
@@ -1,5 +1,11 @@
 //! Contains common text reading and writing functionality.
 
+mod error;
+pub use self::error::VrpError;
+
+mod compression;
+pub(crate) use self::compression::*;
+
 mod text_reader;
 
 pub(crate) use self::text_reader::*;
@@ -8,11 +14,15 @@ use std::sync::Arc;
 mod text_writer;
 pub(crate) use self::text_writer::*;
 
+mod metrics;
+pub use self::metrics::*;
+
 mod initial_reader;
-pub use self::initial_reader::read_init_solution;
+pub use self::initial_reader::{read_init_solution, read_text_solution};
 
 mod routing;
 pub use self::routing::CoordIndex;
+pub use self::routing::RoundingMode;
 
 use vrp_core::models::Extras;
 use vrp_core::solver::{HeuristicFilter, HEURISTIC_FILTER_KEY};
@@ -23,7 +33,9 @@ pub(crate) fn get_extras(coord_index: CoordIndex) -> Extras {
     extras.insert("coord_index".to_string(), Arc::new(coord_index));
     extras.insert(
         HEURISTIC_FILTER_KEY.to_string(),
-        Arc::new(HeuristicFilter::new(|name| name != "local_reschedule_departure")),
+        Arc::new(HeuristicFilter::new(|name| {
+            !matches!(name, "local_reschedule_departure" | "local_perturb_departure")
+        })),
     );
 
     extras
@@ -3,8 +3,9 @@
 mod routing_test;
 
 use std::sync::Arc;
-use vrp_core::models::common::Location;
-use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData, TransportCost};
+use vrp_core::models::common::{Distance, Duration, Location, Profile};
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData, TransportCost, TravelTime};
+use vrp_core::models::solution::Route;
 
 /// Represents a coord index which can be used to analyze customer's locations.
 #[derive(Clone, Default)]
@@ -27,22 +28,27 @@ impl CoordIndex {
 
     /// Creates transport.
     pub fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+        let rounding = if is_rounded { RoundingMode::Nearest } else { RoundingMode::None };
+
+        self.create_transport_with_rounding(rounding, usize::MAX)
+    }
+
+    /// Creates transport with given rounding of distances. A dense matrix is precomputed once when
+    /// amount of locations does not exceed `max_matrix_locations`, otherwise distances are calculated
+    /// from coordinates on every request to bound memory usage.
+    pub fn create_transport_with_rounding(
+        &self,
+        rounding: RoundingMode,
+        max_matrix_locations: usize,
+    ) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+        if self.locations.len() > max_matrix_locations {
+            return Ok(Arc::new(CoordTransportCost { locations: self.locations.clone(), rounding }));
+        }
+
         let matrix_values = self
             .locations
             .iter()
-            .flat_map(|&(x1, y1)| {
-                self.locations.iter().map(move |&(x2, y2)| {
-                    let x = x1 as f64 - x2 as f64;
-                    let y = y1 as f64 - y2 as f64;
-                    let value = (x * x + y * y).sqrt();
-
-                    if is_rounded {
-                        value.round()
-                    } else {
-                        value
-                    }
-                })
-            })
+            .flat_map(|&from| self.locations.iter().map(move |&to| get_distance(from, to, rounding)))
             .collect::<Vec<f64>>();
 
         let matrix_data = MatrixData::new(0, None, matrix_values.clone(), matrix_values);
@@ -50,3 +56,50 @@ impl CoordIndex {
         create_matrix_transport_cost(vec![matrix_data])
     }
 }
+
+/// Specifies how distances calculated from coordinates are rounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Distances are not rounded.
+    None,
+    /// Distances are rounded to the nearest integer, e.g. tsplib95 EUC_2D convention.
+    Nearest,
+    /// Fractional part of distances is discarded.
+    Truncate,
+}
+
+/// A transport cost which calculates distances from coordinates on every request.
+struct CoordTransportCost {
+    locations: Vec<(i32, i32)>,
+    rounding: RoundingMode,
+}
+
+impl TransportCost for CoordTransportCost {
+    fn duration_approx(&self, _: &Profile, from: Location, to: Location) -> Duration {
+        get_distance(self.locations[from], self.locations[to], self.rounding)
+    }
+
+    fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        get_distance(self.locations[from], self.locations[to], self.rounding)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+}
+
+fn get_distance((x1, y1): (i32, i32), (x2, y2): (i32, i32), rounding: RoundingMode) -> f64 {
+    let x = x1 as f64 - x2 as f64;
+    let y = y1 as f64 - y2 as f64;
+    let value = (x * x + y * y).sqrt();
+
+    match rounding {
+        RoundingMode::None => value,
+        RoundingMode::Nearest => value.round(),
+        RoundingMode::Truncate => value.trunc(),
+    }
+}
@@ -1,3 +1,5 @@
+use super::VrpError;
+use std::collections::HashSet;
 use std::io::prelude::*;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
@@ -8,12 +10,13 @@ use vrp_core::models::*;
 use vrp_core::models::{Extras, Problem};
 
 pub(crate) trait TextReader {
-    fn read_problem(&mut self, is_rounded: bool) -> Result<Problem, String> {
-        let (jobs, fleet) = self.read_definitions()?;
-        let transport = self.create_transport(is_rounded)?;
+    fn read_problem(&mut self, is_rounded: bool) -> Result<Problem, VrpError> {
+        let (jobs, fleet) = self.read_definitions().map_err(VrpError::Parse)?;
+        check_unique_ids(&jobs, &fleet).map_err(VrpError::Validation)?;
+        let transport = self.create_transport(is_rounded).map_err(VrpError::Validation)?;
         let activity = Arc::new(SimpleActivityCost::default());
         let jobs = Jobs::new(&fleet, jobs, &transport);
-        let goal = self.create_goal_context(activity.clone(), transport.clone()).expect("cannot create goal context");
+        let goal = self.create_goal_context(activity.clone(), transport.clone()).map_err(VrpError::Validation)?;
 
         Ok(Problem {
             fleet: Arc::new(fleet),
@@ -39,6 +42,30 @@ pub(crate) trait TextReader {
     fn create_extras(&self) -> Extras;
 }
 
+/// Checks that jobs and vehicles have no duplicate ids as they are used to identify them in solution.
+fn check_unique_ids(jobs: &[Job], fleet: &Fleet) -> Result<(), String> {
+    let get_duplicates = |ids: Vec<Option<&String>>| {
+        let mut unique = HashSet::new();
+        let mut duplicates = ids.into_iter().flatten().filter(|id| !unique.insert(*id)).cloned().collect::<Vec<_>>();
+        duplicates.sort();
+        duplicates.dedup();
+
+        duplicates
+    };
+
+    let job_ids = get_duplicates(jobs.iter().map(|job| job.dimens().get_id()).collect());
+    let vehicle_ids = get_duplicates(fleet.vehicles.iter().map(|vehicle| vehicle.dimens.get_id()).collect());
+
+    match (job_ids.is_empty(), vehicle_ids.is_empty()) {
+        (true, true) => Ok(()),
+        (false, true) => Err(format!("duplicate job ids: {}", job_ids.join(", "))),
+        (true, false) => Err(format!("duplicate vehicle ids: {}", vehicle_ids.join(", "))),
+        (false, false) => {
+            Err(format!("duplicate job ids: {}; duplicate vehicle ids: {}", job_ids.join(", "), vehicle_ids.join(", ")))
+        }
+    }
+}
+
 pub(crate) fn create_fleet_with_distance_costs(
     number: usize,
     capacity: usize,
@@ -2,32 +2,36 @@
 #[path = "../../tests/unit/common/text_writer_test.rs"]
 mod text_writer_test;
 
-use std::io::{BufWriter, Error, ErrorKind, Write};
+use super::VrpError;
+use std::io::{BufWriter, Write};
 use vrp_core::models::common::IdDimension;
+use vrp_core::models::solution::{Activity, Route};
 use vrp_core::models::Solution;
 
 pub(crate) fn write_text_solution<W: Write>(
     solution: &Solution,
     cost: f64,
     writer: &mut BufWriter<W>,
-) -> Result<(), Error> {
+) -> Result<(), VrpError> {
     if !solution.unassigned.is_empty() {
-        return Err(Error::new(ErrorKind::Other, "cannot write text solution with unassigned jobs."));
+        return Err(VrpError::Infeasible("cannot write text solution with unassigned jobs.".to_string()));
     }
 
-    solution.routes.iter().zip(1..).for_each(|(r, i)| {
-        let customers = r
-            .tour
-            .all_activities()
-            .filter(|a| a.job.is_some())
+    solution.routes.iter().zip(1..).try_for_each(|(r, i)| {
+        let customers = get_job_activities(r)
             .map(|a| a.retrieve_job().unwrap())
             .map(|job| job.dimens().get_id().unwrap().clone())
             .collect::<Vec<String>>()
             .join(" ");
-        writer.write_all(format!("Route {i}: {customers}\n").as_bytes()).unwrap();
-    });
+        writer.write_all(format!("Route {i}: {customers}\n").as_bytes())
+    })?;
 
     writer.write_all(format!("Cost {cost:.2}").as_bytes())?;
 
     Ok(())
 }
+
+/// Returns activities of the route which serve jobs in the order they are written.
+pub(crate) fn get_job_activities(route: &Route) -> impl Iterator<Item = &Activity> + '_ {
+    route.tour.all_activities().filter(|a| a.job.is_some())
+}
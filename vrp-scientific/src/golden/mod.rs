@@ -0,0 +1,4 @@
+//! Contains functionality to read Golden and Taillard large scale CVRP problems.
+
+mod reader;
+pub use self::reader::GoldenProblem;
@@ -0,0 +1,267 @@
+#[cfg(test)]
+#[path = "../../tests/unit/golden/reader_test.rs"]
+mod reader_test;
+
+use crate::common::*;
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::features::*;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::*;
+
+/// A trait to read Golden and Taillard large scale CVRP problems. Their layout is similar to tsplib95,
+/// but header entries can come in any order, optional `DISTANCE` (max route length) and `VEHICLES`
+/// (fleet size) entries are supported and node coordinates can be real numbers. Please note that
+/// coordinates are rounded to the nearest integer values.
+pub trait GoldenProblem {
+    /// Reads Golden or Taillard problem.
+    fn read_golden(self, is_rounded: bool) -> Result<Problem, VrpError>;
+}
+
+impl<R: Read> GoldenProblem for BufReader<R> {
+    fn read_golden(self, is_rounded: bool) -> Result<Problem, VrpError> {
+        GoldenReader::new(create_input_reader(self)?).read_problem(is_rounded)
+    }
+}
+
+impl GoldenProblem for String {
+    fn read_golden(self, is_rounded: bool) -> Result<Problem, VrpError> {
+        GoldenReader::new(BufReader::new(self.as_bytes())).read_problem(is_rounded)
+    }
+}
+
+struct GoldenReader<R: Read> {
+    buffer: String,
+    reader: BufReader<R>,
+    dimension: Option<usize>,
+    vehicle_capacity: Option<usize>,
+    vehicles: Option<usize>,
+    distance_limit: Option<Distance>,
+    coord_index: CoordIndex,
+}
+
+impl<R: Read> TextReader for GoldenReader<R> {
+    fn create_goal_context(
+        &self,
+        activity: Arc<SimpleActivityCost>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+    ) -> Result<GoalContext, String> {
+        let mut features = vec![
+            create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.))?,
+            create_minimize_distance_feature("min_distance", transport.clone(), activity, 1)?,
+            create_capacity_limit_feature::<SingleDimLoad>("capacity", 2)?,
+        ];
+
+        if let Some(distance_limit) = self.distance_limit {
+            features.push(create_travel_limit_feature(
+                "distance_limit",
+                transport,
+                Arc::new(move |_: &Actor| Some(distance_limit)),
+                Arc::new(|_: &Actor| None),
+                3,
+                4,
+            )?);
+        }
+
+        let feature_map = vec![vec!["min_unassigned".to_string()], vec!["min_distance".to_string()]];
+
+        // NOTE: exclude min_unassigned from local objective
+        GoalContext::new(features.as_slice(), feature_map.as_slice(), &feature_map[1..])
+    }
+
+    fn read_definitions(&mut self) -> Result<(Vec<Job>, Fleet), String> {
+        self.read_meta()?;
+
+        let coordinates = self.read_coordinates()?;
+        let demands = self.read_demands()?;
+        let depot_id = self.read_depot_data()?;
+
+        let mut ids = coordinates.keys().cloned().filter(|id| *id != depot_id).collect::<Vec<_>>();
+        ids.sort();
+
+        let jobs = ids.into_iter().try_fold::<_, _, Result<_, String>>(
+            Vec::with_capacity(demands.len()),
+            |mut jobs, id| {
+                let demand = demands.get(&id).cloned().ok_or_else(|| format!("cannot find demand for id: '{id}'"))?;
+                let location = self.coord_index.collect(coordinates[&id]);
+
+                jobs.push(self.create_job(&(id - 1).to_string(), location, demand));
+
+                Ok(jobs)
+            },
+        )?;
+
+        let depot_coord =
+            *coordinates.get(&depot_id).ok_or_else(|| format!("cannot find coordinate for depot id: '{depot_id}'"))?;
+        let depot_location = self.coord_index.collect(depot_coord);
+
+        let fleet = create_fleet_with_distance_costs(
+            self.vehicles.unwrap_or(self.dimension.unwrap()),
+            self.vehicle_capacity.unwrap(),
+            depot_location,
+            TimeWindow::max(),
+        );
+
+        Ok((jobs, fleet))
+    }
+
+    fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+        self.coord_index.create_transport(is_rounded)
+    }
+
+    fn create_extras(&self) -> Extras {
+        get_extras(self.coord_index.clone())
+    }
+}
+
+impl<R: Read> GoldenReader<R> {
+    fn new(reader: BufReader<R>) -> Self {
+        Self {
+            buffer: String::new(),
+            reader,
+            dimension: None,
+            vehicle_capacity: None,
+            vehicles: None,
+            distance_limit: None,
+            coord_index: CoordIndex::default(),
+        }
+    }
+
+    /// Reads header entries until node coordinates section is reached.
+    fn read_meta(&mut self) -> Result<(), String> {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Err("unexpected end of file: expecting NODE_COORD_SECTION".to_string());
+            }
+
+            let line = self.buffer.trim().to_string();
+            if line == "NODE_COORD_SECTION" {
+                break;
+            }
+
+            if line.is_empty() {
+                continue;
+            }
+
+            // NOTE comment can contain colons, so only the first one separates key from value
+            let (key, value) =
+                line.split_once(':').ok_or_else(|| format!("expected colon separated string, got: '{line}'"))?;
+            let value = value.trim();
+
+            match key.trim() {
+                "NAME" | "COMMENT" => {}
+                "TYPE" if value != "CVRP" => return Err(format!("expecting 'CVRP' as TYPE, got '{value}'")),
+                "TYPE" => {}
+                "EDGE_WEIGHT_TYPE" if value != "EUC_2D" => {
+                    return Err(format!("expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got '{value}'"))
+                }
+                "EDGE_WEIGHT_TYPE" => {}
+                "DIMENSION" => self.dimension = Some(parse_int(value, "cannot parse DIMENSION")? as usize),
+                "CAPACITY" => self.vehicle_capacity = Some(parse_int(value, "cannot parse CAPACITY")? as usize),
+                "VEHICLES" => self.vehicles = Some(parse_int(value, "cannot parse VEHICLES")? as usize),
+                "DISTANCE" => {
+                    let distance = value.parse::<f64>().map_err(|err| format!("cannot parse DISTANCE: '{err}'"))?;
+                    self.distance_limit = Some(distance);
+                }
+                key => return Err(format!("unexpected key: '{key}'")),
+            }
+        }
+
+        if self.dimension.is_none() {
+            return Err("missing DIMENSION".to_string());
+        }
+
+        if self.vehicle_capacity.is_none() {
+            return Err("missing CAPACITY".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn read_coordinates(&mut self) -> Result<HashMap<i32, (i32, i32)>, String> {
+        let dimension = self.dimension.unwrap();
+
+        let mut coordinates = HashMap::with_capacity(dimension);
+        for _ in 0..dimension {
+            let line = self.read_line()?.trim();
+            let data = line.split_whitespace().collect::<Vec<_>>();
+
+            if data.len() != 3 {
+                return Err(format!("unexpected coord data: '{line}'"));
+            }
+
+            let coord = (parse_int(data[1], "cannot parse coord.0")?, parse_int(data[2], "cannot parse coord.1")?);
+
+            coordinates.insert(parse_int(data[0], "cannot parse id")?, coord);
+        }
+
+        Ok(coordinates)
+    }
+
+    fn read_demands(&mut self) -> Result<HashMap<i32, i32>, String> {
+        let dimension = self.dimension.unwrap();
+
+        self.read_expected_line("DEMAND_SECTION")?;
+
+        let mut demands = HashMap::with_capacity(dimension);
+        for _ in 0..dimension {
+            let line = self.read_line()?.trim();
+            let data = line.split_whitespace().collect::<Vec<_>>();
+
+            if data.len() != 2 {
+                return Err(format!("unexpected demand data: '{line}'"));
+            }
+
+            demands.insert(parse_int(data[0], "cannot parse id")?, parse_int(data[1], "cannot parse demand")?);
+        }
+
+        Ok(demands)
+    }
+
+    fn read_depot_data(&mut self) -> Result<i32, String> {
+        self.read_expected_line("DEPOT_SECTION")?;
+        let depot_id = parse_int(self.read_line()?.trim(), "cannot parse depot id")?;
+        self.read_expected_line("-1")?;
+
+        Ok(depot_id)
+    }
+
+    fn read_expected_line(&mut self, expected: &str) -> Result<(), String> {
+        let line = self.read_line()?.trim();
+        if line != expected {
+            Err(format!("expecting {expected}, got: '{line}'"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn read_line(&mut self) -> Result<&String, String> {
+        read_line(&mut self.reader, &mut self.buffer)?;
+        Ok(&self.buffer)
+    }
+
+    fn create_job(&mut self, id: &str, location: Location, demand: i32) -> Job {
+        let mut dimens = create_dimens_with_id("", id);
+        dimens.set_demand(Demand::<SingleDimLoad> {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+            delivery: (SingleDimLoad::new(demand), SingleDimLoad::default()),
+        });
+        Job::Single(Arc::new(Single {
+            places: vec![Place {
+                location: Some(location),
+                duration: 0.,
+                times: vec![TimeSpan::Window(TimeWindow::max())],
+            }],
+            dimens,
+        }))
+    }
+}
+
+fn parse_int(data: &str, err_msg: &str) -> Result<i32, String> {
+    data.parse::<f64>()
+        // NOTE coordinates of Golden instances are real numbers
+        .map(|value| value.round() as i32)
+        .map_err(|err| format!("{err_msg}: '{err}'"))
+}
@@ -22,6 +22,7 @@ mod known_problems_test;
 pub use vrp_core as core;
 
 pub mod common;
+pub mod golden;
 pub mod lilim;
 pub mod solomon;
 pub mod tsplib;
@@ -14,17 +14,18 @@ use vrp_core::models::{Extras, Problem};
 /// A trait to read lilim problem.
 pub trait LilimProblem {
     /// Reads lilim problem.
-    fn read_lilim(self, is_rounded: bool) -> Result<Problem, String>;
+    fn read_lilim(self, is_rounded: bool) -> Result<Problem, VrpError>;
 }
 
 impl<R: Read> LilimProblem for BufReader<R> {
-    fn read_lilim(self, is_rounded: bool) -> Result<Problem, String> {
-        LilimReader { buffer: String::new(), reader: self, coord_index: CoordIndex::default() }.read_problem(is_rounded)
+    fn read_lilim(self, is_rounded: bool) -> Result<Problem, VrpError> {
+        let reader = create_input_reader(self)?;
+        LilimReader { buffer: String::new(), reader, coord_index: CoordIndex::default() }.read_problem(is_rounded)
     }
 }
 
 impl LilimProblem for String {
-    fn read_lilim(self, is_rounded: bool) -> Result<Problem, String> {
+    fn read_lilim(self, is_rounded: bool) -> Result<Problem, VrpError> {
         BufReader::new(self.as_bytes()).read_lilim(is_rounded)
     }
 }
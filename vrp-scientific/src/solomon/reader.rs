@@ -13,22 +13,22 @@ use vrp_core::models::{Extras, Problem};
 /// A trait read write solomon problem.
 pub trait SolomonProblem {
     /// Reads solomon problem.
-    fn read_solomon(self, is_rounded: bool) -> Result<Problem, String>;
+    fn read_solomon(self, is_rounded: bool) -> Result<Problem, VrpError>;
 }
 
 impl<R: Read> SolomonProblem for BufReader<R> {
-    fn read_solomon(self, is_rounded: bool) -> Result<Problem, String> {
-        read_solomon_format(self, is_rounded)
+    fn read_solomon(self, is_rounded: bool) -> Result<Problem, VrpError> {
+        read_solomon_format(create_input_reader(self)?, is_rounded)
     }
 }
 
 impl SolomonProblem for String {
-    fn read_solomon(self, is_rounded: bool) -> Result<Problem, String> {
+    fn read_solomon(self, is_rounded: bool) -> Result<Problem, VrpError> {
         read_solomon_format(BufReader::new(self.as_bytes()), is_rounded)
     }
 }
 
-fn read_solomon_format<R: Read>(reader: BufReader<R>, is_rounded: bool) -> Result<Problem, String> {
+fn read_solomon_format<R: Read>(reader: BufReader<R>, is_rounded: bool) -> Result<Problem, VrpError> {
     SolomonReader { buffer: String::new(), reader, coord_index: CoordIndex::default() }.read_problem(is_rounded)
 }
 
@@ -13,26 +13,37 @@ use vrp_core::models::*;
 /// A trait to read tsplib95 problem. Please note that it is very basic implementation of the format specification.
 pub trait TsplibProblem {
     /// Reads tsplib95 problem.
-    fn read_tsplib(self, is_rounded: bool) -> Result<Problem, String>;
+    fn read_tsplib(self, is_rounded: bool) -> Result<Problem, VrpError>;
 }
 
 impl<R: Read> TsplibProblem for BufReader<R> {
-    fn read_tsplib(self, is_rounded: bool) -> Result<Problem, String> {
-        TsplibReader::new(self).read_problem(is_rounded)
+    fn read_tsplib(self, is_rounded: bool) -> Result<Problem, VrpError> {
+        TsplibReader::new(create_input_reader(self)?).read_problem(is_rounded)
     }
 }
 
 impl TsplibProblem for String {
-    fn read_tsplib(self, is_rounded: bool) -> Result<Problem, String> {
+    fn read_tsplib(self, is_rounded: bool) -> Result<Problem, VrpError> {
         TsplibReader::new(BufReader::new(self.as_bytes())).read_problem(is_rounded)
     }
 }
 
+/// Specifies supported formats of explicit edge weights.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EdgeWeightFormat {
+    /// A lower triangular matrix including diagonal, given row-wise.
+    LowerDiagRow,
+    /// An upper triangular matrix without diagonal, given row-wise.
+    UpperRow,
+}
+
 struct TsplibReader<R: Read> {
     buffer: String,
     reader: BufReader<R>,
     dimension: Option<usize>,
     vehicle_capacity: Option<usize>,
+    edge_weight_format: Option<EdgeWeightFormat>,
+    matrix: Option<Vec<f64>>,
     coord_index: CoordIndex,
 }
 
@@ -54,24 +65,33 @@ impl<R: Read> TextReader for TsplibReader<R> {
 
         let dimension = self.dimension.unwrap();
 
-        let jobs = coordinates.iter().filter(|(id, _)| **id != depot_id).try_fold::<_, _, Result<_, String>>(
+        // NOTE explicit edge weights come without coordinates, so jobs are defined by demands
+        let ids = if self.matrix.is_some() {
+            demands.keys().cloned().collect::<Vec<_>>()
+        } else {
+            coordinates.keys().cloned().collect::<Vec<_>>()
+        };
+
+        let jobs = ids.into_iter().filter(|id| *id != depot_id).try_fold::<_, _, Result<_, String>>(
             Vec::with_capacity(dimension),
-            |mut jobs, (id, (x, y))| {
-                let demand = demands.get(id).cloned().ok_or_else(|| format!("cannot find demand for id: '{id}'"))?;
+            |mut jobs, id| {
+                let demand = demands.get(&id).cloned().ok_or_else(|| format!("cannot find demand for id: '{id}'"))?;
+                let location = self.get_location(id, &coordinates)?;
 
-                jobs.push(self.create_job(&(*id - 1).to_string(), (*x, *y), demand));
+                jobs.push(self.create_job(&(id - 1).to_string(), location, demand));
 
                 Ok(jobs)
             },
         )?;
 
-        let depot_coord =
-            *coordinates.get(&depot_id).ok_or_else(|| format!("cannot find coordinate for depot id: '{depot_id}'"))?;
+        let depot_location = self
+            .get_location(depot_id, &coordinates)
+            .map_err(|_| format!("cannot find coordinate for depot id: '{depot_id}'"))?;
 
         let fleet = create_fleet_with_distance_costs(
             dimension,
             self.vehicle_capacity.unwrap(),
-            self.coord_index.collect(depot_coord),
+            depot_location,
             TimeWindow::max(),
         );
 
@@ -79,7 +99,13 @@ impl<R: Read> TextReader for TsplibReader<R> {
     }
 
     fn create_transport(&self, is_rounded: bool) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
-        self.coord_index.create_transport(is_rounded)
+        if let Some(matrix) = self.matrix.as_ref() {
+            let matrix = matrix.iter().map(|&value| if is_rounded { value.round() } else { value }).collect::<Vec<_>>();
+
+            create_matrix_transport_cost(vec![MatrixData::new(0, None, matrix.clone(), matrix)])
+        } else {
+            self.coord_index.create_transport(is_rounded)
+        }
     }
 
     fn create_extras(&self) -> Extras {
@@ -96,6 +122,8 @@ impl<R: Read> TsplibReader<R> {
             reader,
             dimension: None,
             vehicle_capacity: None,
+            edge_weight_format: None,
+            matrix: None,
             coord_index: CoordIndex::default(),
         }
     }
@@ -114,9 +142,19 @@ impl<R: Read> TsplibReader<R> {
         );
 
         let edge_type = self.read_key_value("EDGE_WEIGHT_TYPE")?;
-        if edge_type != "EUC_2D" {
-            return Err(format!("expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got '{edge_type}'"));
-        }
+        self.edge_weight_format = match edge_type.as_str() {
+            "EUC_2D" => None,
+            "EXPLICIT" => match self.read_key_value("EDGE_WEIGHT_FORMAT")?.as_str() {
+                "LOWER_DIAG_ROW" => Some(EdgeWeightFormat::LowerDiagRow),
+                "UPPER_ROW" => Some(EdgeWeightFormat::UpperRow),
+                format => {
+                    return Err(format!(
+                        "expecting 'LOWER_DIAG_ROW' or 'UPPER_ROW' as EDGE_WEIGHT_FORMAT, got '{format}'"
+                    ))
+                }
+            },
+            _ => return Err(format!("expecting 'EUC_2D' or 'EXPLICIT' as EDGE_WEIGHT_TYPE, got '{edge_type}'")),
+        };
 
         self.vehicle_capacity = Some(
             self.read_key_value("CAPACITY")
@@ -129,10 +167,37 @@ impl<R: Read> TsplibReader<R> {
     fn read_customer_data(&mut self) -> Result<ProblemData, String> {
         let dimension = self.dimension.unwrap();
 
-        // read coordinates
+        let mut coordinates = HashMap::with_capacity(self.dimension.unwrap());
+
+        if let Some(format) = self.edge_weight_format {
+            self.matrix = Some(self.read_edge_weights(format)?);
+        } else {
+            self.read_coordinates(&mut coordinates)?;
+        }
+
+        // read demand
+        self.read_expected_line("DEMAND_SECTION")?;
+
+        let mut demands = HashMap::with_capacity(self.dimension.unwrap());
+        for _ in 0..dimension {
+            let line = self.read_line()?.trim();
+            let data = line.split_whitespace().collect::<Vec<_>>();
+
+            if data.len() != 2 {
+                return Err(format!("unexpected demand data: '{line}'"));
+            }
+
+            demands.insert(parse_int(data[0], "cannot parse id")?, parse_int(data[1], "cannot parse demand")?);
+        }
+
+        Ok((coordinates, demands))
+    }
+
+    fn read_coordinates(&mut self, coordinates: &mut HashMap<i32, (i32, i32)>) -> Result<(), String> {
+        let dimension = self.dimension.unwrap();
+
         self.read_expected_line("NODE_COORD_SECTION")?;
 
-        let mut coordinates = HashMap::with_capacity(self.dimension.unwrap());
         for _ in 0..dimension {
             let line = self.read_line()?.trim();
             let data = line.split_whitespace().collect::<Vec<_>>();
@@ -146,22 +211,53 @@ impl<R: Read> TsplibReader<R> {
             coordinates.insert(parse_int(data[0], "cannot parse id")?, coord);
         }
 
-        // read demand
-        self.read_expected_line("DEMAND_SECTION")?;
+        Ok(())
+    }
 
-        let mut demands = HashMap::with_capacity(self.dimension.unwrap());
-        for _ in 0..dimension {
-            let line = self.read_line()?.trim();
-            let data = line.split_whitespace().collect::<Vec<_>>();
+    /// Reads edge weights given in triangular form and reconstructs a full symmetric matrix.
+    fn read_edge_weights(&mut self, format: EdgeWeightFormat) -> Result<Vec<f64>, String> {
+        let dimension = self.dimension.unwrap();
 
-            if data.len() != 2 {
-                return Err(format!("unexpected demand data: '{line}'"));
+        self.read_expected_line("EDGE_WEIGHT_SECTION")?;
+
+        let expected = match format {
+            EdgeWeightFormat::LowerDiagRow => dimension * (dimension + 1) / 2,
+            EdgeWeightFormat::UpperRow => dimension * (dimension - 1) / 2,
+        };
+
+        // NOTE values are not bound to matrix rows and can be split across lines arbitrarily
+        let mut values = Vec::with_capacity(expected);
+        while values.len() < expected {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Err(format!("unexpected end of EDGE_WEIGHT_SECTION: expecting {expected} values"));
             }
 
-            demands.insert(parse_int(data[0], "cannot parse id")?, parse_int(data[1], "cannot parse demand")?);
+            self.buffer.split_whitespace().try_for_each(|value| {
+                values.push(value.parse::<f64>().map_err(|err| format!("cannot parse edge weight: '{err}'"))?);
+                Ok::<_, String>(())
+            })?;
         }
 
-        Ok((coordinates, demands))
+        if values.len() != expected {
+            return Err(format!("unexpected amount of edge weights: expecting {expected}, got {}", values.len()));
+        }
+
+        let cells = (0..dimension).flat_map(|row| {
+            let columns = match format {
+                EdgeWeightFormat::LowerDiagRow => 0..(row + 1),
+                EdgeWeightFormat::UpperRow => (row + 1)..dimension,
+            };
+
+            columns.map(move |column| (row, column))
+        });
+
+        let mut matrix = vec![0.; dimension * dimension];
+        cells.zip(values).for_each(|((row, column), value)| {
+            matrix[row * dimension + column] = value;
+            matrix[column * dimension + row] = value;
+        });
+
+        Ok(matrix)
     }
 
     fn read_depot_data(&mut self) -> Result<i32, String> {
@@ -206,7 +302,22 @@ impl<R: Read> TsplibReader<R> {
         skip_lines(count, &mut self.reader, &mut self.buffer)
     }
 
-    fn create_job(&mut self, id: &str, location: (i32, i32), demand: i32) -> Job {
+    fn get_location(&mut self, id: i32, coordinates: &HashMap<i32, (i32, i32)>) -> Result<Location, String> {
+        if self.matrix.is_some() {
+            let dimension = self.dimension.unwrap() as i32;
+            if id < 1 || id > dimension {
+                return Err(format!("node id is out of range: '{id}'"));
+            }
+
+            Ok((id - 1) as Location)
+        } else {
+            let coord = *coordinates.get(&id).ok_or_else(|| format!("cannot find coordinate for id: '{id}'"))?;
+
+            Ok(self.coord_index.collect(coord))
+        }
+    }
+
+    fn create_job(&mut self, id: &str, location: Location, demand: i32) -> Job {
         let mut dimens = create_dimens_with_id("", id);
         dimens.set_demand(Demand::<SingleDimLoad> {
             pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
@@ -214,7 +325,7 @@ impl<R: Read> TsplibReader<R> {
         });
         Job::Single(Arc::new(Single {
             places: vec![Place {
-                location: Some(self.coord_index.collect(location)),
+                location: Some(location),
                 duration: 0.,
                 times: vec![TimeSpan::Window(TimeWindow::max())],
             }],
@@ -1,16 +1,15 @@
-use crate::common::write_text_solution;
+use crate::common::{write_text_solution, VrpError};
 use std::io::{BufWriter, Write};
 use vrp_core::models::Solution;
 
 /// A trait to write tsplib95 solution.
 pub trait TsplibSolution<W: Write> {
     /// Writes tsplib95 solution.
-    fn write_tsplib(&self, writer: &mut BufWriter<W>) -> Result<(), String>;
+    fn write_tsplib(&self, writer: &mut BufWriter<W>) -> Result<(), VrpError>;
 }
 
 impl<W: Write> TsplibSolution<W> for (&Solution, f64) {
-    fn write_tsplib(&self, writer: &mut BufWriter<W>) -> Result<(), String> {
-        write_text_solution(self.0, self.1, writer).map_err(|err| err.to_string())?;
-        Ok(())
+    fn write_tsplib(&self, writer: &mut BufWriter<W>) -> Result<(), VrpError> {
+        write_text_solution(self.0, self.1, writer)
     }
 }
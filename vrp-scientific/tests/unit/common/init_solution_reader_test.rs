@@ -1,8 +1,21 @@
 use super::*;
-use crate::helpers::{create_c101_100_problem, get_test_resource};
+use crate::common::{get_job_activities, write_text_solution};
+use crate::helpers::{create_c101_100_problem, create_c101_25_problem, create_lc101_problem, get_test_resource};
+use std::io::BufWriter;
 use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{ElitismPopulation, RefinementContext};
 use vrp_core::utils::Environment;
 
+fn get_route_jobs(solution: &Solution) -> Vec<Vec<*const Single>> {
+    solution
+        .routes
+        .iter()
+        .map(|route| get_job_activities(route).map(|activity| Arc::as_ptr(activity.job.as_ref().unwrap())).collect())
+        .collect()
+}
+
 #[test]
 pub fn can_read_init_solution() {
     let environment = Arc::new(Environment::default());
@@ -16,3 +29,50 @@ pub fn can_read_init_solution() {
     let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
     assert_eq!(insertion_ctx.solution.get_total_cost().round(), 828.936f64.round());
 }
+
+parameterized_test! {can_read_text_solution_written_by_writer, problem, {
+    can_read_text_solution_written_by_writer_impl(problem);
+}}
+
+can_read_text_solution_written_by_writer! {
+    case01_solomon: create_c101_25_problem(),
+    case02_lilim: create_lc101_problem(),
+}
+
+fn can_read_text_solution_written_by_writer_impl(problem: Problem) {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(problem);
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(ElitismPopulation::new(problem.goal.clone(), environment.random.clone(), 1, 1)),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+    let solution: Solution = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem.clone(), environment.clone()))
+        .solution
+        .into();
+    let mut writer = BufWriter::new(Vec::new());
+    write_text_solution(&solution, 42., &mut writer).expect("cannot write solution");
+    let bytes = writer.into_inner().expect("cannot get bytes from writer");
+
+    let result = read_text_solution(BufReader::new(bytes.as_slice()), problem, environment.random.clone())
+        .expect("cannot read solution");
+
+    assert!(result.unassigned.is_empty());
+    assert_eq!(get_route_jobs(&result), get_route_jobs(&solution));
+}
+
+#[test]
+fn can_detect_unknown_job_in_text_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_c101_25_problem());
+
+    let result = read_text_solution(
+        BufReader::new("Route 1: 1 unknown\nCost 1.00".as_bytes()),
+        problem,
+        environment.random.clone(),
+    );
+
+    assert_eq!(result.err(), Some(VrpError::Parse("unknown job id: 'unknown'".to_string())));
+}
@@ -0,0 +1,98 @@
+use super::*;
+use crate::helpers::SolomonBuilder;
+use crate::solomon::SolomonProblem;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::solution::Registry;
+use vrp_core::models::Problem;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{ElitismPopulation, RefinementContext};
+use vrp_core::utils::Environment;
+
+fn create_two_routes_problem() -> Arc<Problem> {
+    Arc::new(
+        SolomonBuilder::default()
+            .set_title("Two routes problem")
+            .set_vehicle((2, 1))
+            .add_customer((0, 0, 0, 0, 0, 1000, 1))
+            .add_customer((1, 1, 0, 1, 5, 1000, 5))
+            .add_customer((2, 2, 0, 1, 5, 1000, 5))
+            .build()
+            .read_solomon(false)
+            .unwrap(),
+    )
+}
+
+fn create_solution(problem: Arc<Problem>) -> Solution {
+    let environment = Arc::new(Environment::default());
+    let refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(ElitismPopulation::new(problem.goal.clone(), environment.random.clone(), 1, 1)),
+        TelemetryMode::None,
+        environment.clone(),
+    );
+
+    RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(problem, environment))
+        .solution
+        .into()
+}
+
+fn write_metrics(problem: &Problem, solution: &Solution, format: MetricsFormat) -> (SolutionMetrics, String) {
+    let mut writer = BufWriter::new(Vec::new());
+
+    let metrics = write_solution_metrics(solution, 6., problem.transport.as_ref(), format, &mut writer).unwrap();
+
+    (metrics, String::from_utf8(writer.into_inner().unwrap()).unwrap())
+}
+
+#[test]
+fn can_calculate_metrics_for_two_routes_solution() {
+    let problem = create_two_routes_problem();
+    let solution = create_solution(problem.clone());
+
+    let (metrics, _) = write_metrics(problem.as_ref(), &solution, MetricsFormat::Json);
+
+    let mut tours = metrics.tours.iter().map(|tour| (tour.distance, tour.load)).collect::<Vec<_>>();
+    tours.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    assert_eq!(metrics.distance, 6.);
+    assert_eq!(metrics.routes, 2);
+    assert_eq!(metrics.demand, 2);
+    assert_eq!(tours, vec![(2., 1), (4., 1)]);
+}
+
+parameterized_test! {can_write_metrics_in_format, (format, expected_prefix), {
+    can_write_metrics_in_format_impl(format, expected_prefix);
+}}
+
+can_write_metrics_in_format! {
+    case_01_json: (MetricsFormat::Json, "{\"cost\":6.00,\"distance\":6.00,\"routes\":2,\"demand\":2,\"tours\":[{"),
+    case_02_csv: (MetricsFormat::Csv, "6.00,6.00,2,2,"),
+}
+
+fn can_write_metrics_in_format_impl(format: MetricsFormat, expected_prefix: &str) {
+    let problem = create_two_routes_problem();
+    let solution = create_solution(problem.clone());
+
+    let (_, output) = write_metrics(problem.as_ref(), &solution, format);
+
+    assert!(output.starts_with(expected_prefix), "unexpected output: {output}");
+    assert!(!output.contains('\n'));
+}
+
+#[test]
+fn can_return_zeroed_metrics_for_empty_solution() {
+    let problem = create_two_routes_problem();
+    let solution = Solution {
+        registry: Registry::new(&problem.fleet, Environment::default().random),
+        routes: vec![],
+        unassigned: vec![],
+        seed: None,
+    };
+
+    let (metrics, output) = write_metrics(problem.as_ref(), &solution, MetricsFormat::Csv);
+
+    assert_eq!(metrics, SolutionMetrics { cost: 6., ..SolutionMetrics::default() });
+    assert_eq!(output, "6.00,0.00,0,0,");
+}
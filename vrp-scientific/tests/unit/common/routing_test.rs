@@ -26,3 +26,40 @@ fn can_create_transport_with_rounding() {
 
     assert_eq!(transport.distance_approx(&Profile::new(0, None), 0, 1), 2.);
 }
+
+parameterized_test! {can_use_the_same_costs_with_matrix_and_coordinates, rounding, {
+    can_use_the_same_costs_with_matrix_and_coordinates_impl(rounding);
+}}
+
+can_use_the_same_costs_with_matrix_and_coordinates! {
+    case01_none: RoundingMode::None,
+    case02_nearest: RoundingMode::Nearest,
+    case03_truncate: RoundingMode::Truncate,
+}
+
+fn can_use_the_same_costs_with_matrix_and_coordinates_impl(rounding: RoundingMode) {
+    let mut index = get_index();
+    index.collect((-3, 7));
+    index.collect((5, 5));
+    let profile = Profile::new(0, None);
+
+    let matrix = index.create_transport_with_rounding(rounding, 4).unwrap();
+    let coordinates = index.create_transport_with_rounding(rounding, 3).unwrap();
+
+    (0..4).flat_map(|from| (0..4).map(move |to| (from, to))).for_each(|(from, to)| {
+        assert_eq!(matrix.distance_approx(&profile, from, to), coordinates.distance_approx(&profile, from, to));
+        assert_eq!(matrix.duration_approx(&profile, from, to), coordinates.duration_approx(&profile, from, to));
+    });
+}
+
+#[test]
+fn can_create_transport_with_truncation() {
+    let mut index = get_index();
+    index.collect((4, 3));
+
+    let transport = index.create_transport_with_rounding(RoundingMode::Truncate, usize::MAX).unwrap();
+
+    assert_eq!(transport.distance_approx(&Profile::new(0, None), 0, 1), 2.);
+    assert_eq!(transport.distance_approx(&Profile::new(0, None), 1, 2), 2.);
+    assert_eq!(transport.distance_approx(&Profile::new(0, None), 0, 2), 5.);
+}
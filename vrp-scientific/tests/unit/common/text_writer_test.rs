@@ -1,6 +1,9 @@
 use super::*;
 use crate::helpers::SolomonBuilder;
+use crate::lilim::LilimSolution;
 use crate::solomon::{SolomonProblem, SolomonSolution};
+use crate::tsplib::TsplibSolution;
+use std::io::{Error, ErrorKind};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::rosomaxa::evolution::TelemetryMode;
@@ -8,19 +11,9 @@ use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
 use vrp_core::solver::{ElitismPopulation, RefinementContext};
 use vrp_core::utils::Environment;
 
-#[test]
-fn can_write_solomon_solution() {
+fn create_solution(builder: &mut SolomonBuilder) -> Solution {
     let environment = Arc::new(Environment::default());
-    let problem = Arc::new(
-        SolomonBuilder::default()
-            .set_title("Trivial problem")
-            .set_vehicle((1, 10))
-            .add_customer((0, 0, 0, 0, 0, 1000, 1))
-            .add_customer((1, 1, 0, 1, 5, 1000, 5))
-            .build()
-            .read_solomon(false)
-            .unwrap(),
-    );
+    let problem = Arc::new(builder.build().read_solomon(false).unwrap());
 
     let refinement_ctx = RefinementContext::new(
         problem.clone(),
@@ -29,13 +22,76 @@ fn can_write_solomon_solution() {
         environment.clone(),
     );
 
-    let mut writer = BufWriter::new(Vec::new());
-    let solution = RecreateWithCheapest::new(environment.random.clone())
+    RecreateWithCheapest::new(environment.random.clone())
         .run(&refinement_ctx, InsertionContext::new(problem, environment))
         .solution
-        .into();
+        .into()
+}
+
+#[test]
+fn can_write_solomon_solution() {
+    let solution = create_solution(
+        SolomonBuilder::default()
+            .set_title("Trivial problem")
+            .set_vehicle((1, 10))
+            .add_customer((0, 0, 0, 0, 0, 1000, 1))
+            .add_customer((1, 1, 0, 1, 5, 1000, 5)),
+    );
+    let mut writer = BufWriter::new(Vec::new());
+
     (&solution, 3.123456).write_solomon(&mut writer).unwrap();
     let result = String::from_utf8(writer.into_inner().unwrap()).unwrap();
 
     assert_eq!(result, "Route 1: 1\nCost 3.12");
 }
+
+#[test]
+fn can_write_lilim_and_tsplib_solutions_with_the_same_writer() {
+    let solution = create_solution(
+        SolomonBuilder::default()
+            .set_title("Two routes problem")
+            .set_vehicle((2, 1))
+            .add_customer((0, 0, 0, 0, 0, 1000, 1))
+            .add_customer((1, 1, 0, 1, 5, 1000, 5))
+            .add_customer((2, 2, 0, 1, 5, 1000, 5)),
+    );
+    let mut writer = BufWriter::new(Vec::new());
+
+    (&solution, 4.).write_lilim(&mut writer).unwrap();
+    (&solution, 4.).write_tsplib(&mut writer).unwrap();
+    let result = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+
+    assert!(result.starts_with("Route 1: "));
+    assert_eq!(result.matches("Route 1: ").count(), 2);
+    assert_eq!(result.matches("Route 2: ").count(), 2);
+}
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(Error::new(ErrorKind::BrokenPipe, "broken pipe"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn can_return_io_error_with_original_kind() {
+    let solution = create_solution(
+        SolomonBuilder::default()
+            .set_title("Trivial problem")
+            .set_vehicle((1, 10))
+            .add_customer((0, 0, 0, 0, 0, 1000, 1))
+            .add_customer((1, 1, 0, 1, 5, 1000, 5)),
+    );
+    // NOTE zero capacity makes buffered writer to pass data to the underlying writer immediately
+    let mut writer = BufWriter::with_capacity(0, FailingWriter);
+
+    let result = (&solution, 1.).write_solomon(&mut writer);
+
+    assert!(matches!(&result, Err(VrpError::Io(err)) if err.kind() == ErrorKind::BrokenPipe));
+    assert_eq!(result.unwrap_err().to_string(), "io error: broken pipe");
+}
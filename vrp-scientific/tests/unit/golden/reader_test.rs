@@ -0,0 +1,86 @@
+use crate::common::CoordIndex;
+use crate::golden::reader::GoldenReader;
+use crate::golden::GoldenProblem;
+use crate::helpers::{get_job_demands, get_job_ids, get_test_resource, get_vehicle_capacity};
+use std::fs::File;
+use std::io::{BufReader, Read};
+
+fn get_example_problem_string() -> String {
+    let mut buffer = "".to_string();
+
+    get_test_resource("../../examples/data/scientific/golden/example.vrp")
+        .expect("cannot open file")
+        .read_to_string(&mut buffer)
+        .expect("cannot read file");
+
+    buffer
+}
+
+fn get_example_problem_reader() -> BufReader<File> {
+    BufReader::new(get_test_resource("../../examples/data/scientific/golden/example.vrp").expect("cannot open file"))
+}
+
+#[test]
+fn can_read_meta_in_any_order() {
+    let mut reader = GoldenReader::new(get_example_problem_reader());
+
+    reader.read_meta().expect("cannot read meta");
+
+    assert_eq!(reader.dimension, Some(7));
+    assert_eq!(reader.vehicle_capacity, Some(40));
+    assert_eq!(reader.distance_limit, Some(120.));
+    assert_eq!(reader.vehicles, None);
+}
+
+#[test]
+fn can_read_meta_errors() {
+    for (from, to, error) in &[
+        ("TYPE : CVRP", "TYPE : ASD", "expecting 'CVRP' as TYPE, got 'ASD'"),
+        ("EUC_2D", "GEO", "expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got 'GEO'"),
+        ("CAPACITY : 40", "CAPACITY : asd", "cannot parse CAPACITY: 'invalid float literal'"),
+        ("CAPACITY : 40\n", "", "missing CAPACITY"),
+        ("DISTANCE : 120", "SERVICE_TIME : 10", "unexpected key: 'SERVICE_TIME'"),
+        ("NODE_COORD_SECTION", "NODE_SECTION", "expected colon separated string, got: 'NODE_SECTION'"),
+    ] {
+        let content = get_example_problem_string().replace(from, to);
+        let mut reader = GoldenReader::new(BufReader::new(content.as_bytes()));
+
+        let result = reader.read_meta();
+
+        assert_eq!(result, Err(error.to_string()));
+    }
+}
+
+#[test]
+fn can_read_problem() {
+    let problem = get_example_problem_reader().read_golden(false).expect("cannot read problem");
+
+    assert_eq!(get_job_ids(&problem), vec!["1", "2", "3", "4", "5", "6"]);
+    assert_eq!(get_job_demands(&problem), vec![10, 12, 8, 15, 9, 11]);
+    assert_eq!(problem.fleet.vehicles.len(), 7);
+    assert_eq!(get_vehicle_capacity(&problem), 40);
+    let coord_index = problem.extras.get("coord_index").and_then(|index| index.downcast_ref::<CoordIndex>()).unwrap();
+    assert_eq!(coord_index.locations, vec![(10, 5), (-10, 5), (20, -5), (-20, -5), (16, 25), (-15, 24), (0, 0)]);
+}
+
+#[test]
+fn can_read_fleet_size() {
+    let content = get_example_problem_string().replace("DISTANCE : 120", "VEHICLES : 2");
+
+    let problem = content.read_golden(false).expect("cannot read problem");
+
+    assert_eq!(problem.jobs.size(), 6);
+    assert_eq!(problem.fleet.vehicles.len(), 2);
+}
+
+#[test]
+fn can_read_tsplib_layout() {
+    let reader = BufReader::new(
+        get_test_resource("../../examples/data/scientific/tsplib/example.txt").expect("cannot open file"),
+    );
+
+    let problem = reader.read_golden(false).expect("cannot read problem");
+
+    assert_eq!(problem.jobs.size(), 5);
+    assert_eq!(get_vehicle_capacity(&problem), 30);
+}
@@ -1,5 +1,8 @@
+use crate::common::VrpError;
 use crate::helpers::*;
 use crate::solomon::SolomonProblem;
+use std::fs::File;
+use std::io::BufReader;
 
 #[test]
 fn can_read_solomon_built_from_builder() {
@@ -37,3 +40,45 @@ fn can_read_solomon_format_from_test_file() {
     assert_eq!(problem.fleet.vehicles.len(), 25);
     assert_eq!(get_vehicle_capacity(&problem), 200);
 }
+
+#[test]
+fn can_reject_problem_with_duplicate_job_ids() {
+    let result = SolomonBuilder::default()
+        .set_vehicle((2, 10))
+        .add_customer((0, 0, 0, 0, 0, 1000, 1))
+        .add_customer((1, 1, 0, 1, 5, 1000, 5))
+        .add_customer((2, 3, 0, 2, 0, 1002, 11))
+        .add_customer((1, 7, 0, 1, 0, 1000, 12))
+        .add_customer((2, 9, 0, 1, 0, 1000, 12))
+        .build()
+        .read_solomon(false);
+
+    assert!(matches!(result, Err(VrpError::Validation(msg)) if msg == "duplicate job ids: 1, 2"));
+}
+
+fn get_gzipped_c101_25_reader() -> BufReader<File> {
+    BufReader::new(get_test_resource("../../examples/data/scientific/solomon/C101.25.txt.gz").unwrap())
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn can_read_solomon_format_from_gzipped_test_file() {
+    let expected = create_c101_25_problem();
+
+    let problem = get_gzipped_c101_25_reader().read_solomon(false).unwrap();
+
+    assert_eq!(get_job_ids(&problem), get_job_ids(&expected));
+    assert_eq!(get_job_demands(&problem), get_job_demands(&expected));
+    assert_eq!(get_job_time_windows(&problem), get_job_time_windows(&expected));
+    assert_eq!(get_job_durations(&problem), get_job_durations(&expected));
+    assert_eq!(problem.fleet.vehicles.len(), expected.fleet.vehicles.len());
+    assert_eq!(get_vehicle_capacity(&problem), get_vehicle_capacity(&expected));
+}
+
+#[cfg(not(feature = "gzip"))]
+#[test]
+fn can_return_parse_error_for_gzipped_file_without_gzip_feature() {
+    let result = get_gzipped_c101_25_reader().read_solomon(false);
+
+    assert!(matches!(result, Err(VrpError::Parse(msg)) if msg.contains("'gzip' feature")));
+}
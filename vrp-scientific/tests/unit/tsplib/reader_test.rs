@@ -1,8 +1,10 @@
-use crate::helpers::get_test_resource;
+use crate::common::{CoordIndex, VrpError};
+use crate::helpers::{get_test_resource, get_vehicle_capacity};
 use crate::tsplib::reader::TsplibReader;
 use crate::tsplib::TsplibProblem;
 use std::fs::File;
 use std::io::{BufReader, Read};
+use vrp_core::models::common::Profile;
 
 fn get_example_problem_string() -> String {
     let mut buffer = "".to_string();
@@ -24,7 +26,7 @@ fn can_read_meta_errors() {
     for (from, to, error) in &[
         ("CVRP", "ASD", "expecting 'CVRP' as TYPE, got 'ASD'"),
         ("DIMENSION : 6", "DIMENSION : asd", "cannot parse DIMENSION: 'invalid float literal'"),
-        ("EUC_2D", "ASD", "expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got 'ASD'"),
+        ("EUC_2D", "ASD", "expecting 'EUC_2D' or 'EXPLICIT' as EDGE_WEIGHT_TYPE, got 'ASD'"),
         ("CAPACITY : 30", "CAPACITY : asd", "cannot parse CAPACITY: 'invalid float literal'"),
     ] {
         let content = get_example_problem_string().replace(from, to);
@@ -36,6 +38,15 @@ fn can_read_meta_errors() {
     }
 }
 
+#[test]
+fn can_return_parse_error_for_malformed_problem() {
+    let content = get_example_problem_string().replace("CVRP", "ASD");
+
+    let result = content.read_tsplib(false);
+
+    assert!(matches!(result, Err(VrpError::Parse(msg)) if msg == "expecting 'CVRP' as TYPE, got 'ASD'"));
+}
+
 #[test]
 fn can_read_meta_capacity_and_dimension() {
     let mut reader = TsplibReader::new(get_example_problem_reader());
@@ -75,3 +86,73 @@ fn can_read_problem() {
     assert_eq!(problem.jobs.size(), 5);
     assert_eq!(problem.fleet.actors.len(), 6);
 }
+
+#[test]
+fn can_read_standard_instance() {
+    let reader = BufReader::new(
+        get_test_resource("../../examples/data/scientific/tsplib/A-n32-k5.vrp").expect("cannot open file"),
+    );
+
+    let problem = reader.read_tsplib(true).expect("cannot read problem");
+
+    assert_eq!(problem.jobs.size(), 31);
+    assert_eq!(problem.fleet.vehicles.len(), 32);
+    assert_eq!(get_vehicle_capacity(&problem), 100);
+    let coord_index = problem.extras.get("coord_index").and_then(|index| index.downcast_ref::<CoordIndex>()).unwrap();
+    let depot = problem.fleet.vehicles[0].details[0].start.as_ref().unwrap().location;
+    assert_eq!(coord_index.locations[depot], (82, 76));
+    let customer = coord_index.locations.iter().position(|&coord| coord == (96, 44)).unwrap();
+    assert_eq!(problem.transport.distance_approx(&Profile::default(), depot, customer), 35.);
+}
+
+fn get_explicit_problem_reader(format: &str) -> BufReader<File> {
+    let path = format!("../../examples/data/scientific/tsplib/example_{format}.txt");
+    BufReader::new(get_test_resource(path.as_str()).expect("cannot open file"))
+}
+
+parameterized_test! {can_read_explicit_edge_weights, format, {
+    can_read_explicit_edge_weights_impl(format);
+}}
+
+can_read_explicit_edge_weights! {
+    case_01_lower_diag_row: "lower_diag_row",
+    case_02_upper_row: "upper_row",
+}
+
+fn can_read_explicit_edge_weights_impl(format: &str) {
+    let problem = get_explicit_problem_reader(format).read_tsplib(false).expect("cannot read problem");
+    let profile = Profile::default();
+
+    assert_eq!(problem.jobs.size(), 3);
+    assert_eq!(problem.fleet.actors.len(), 4);
+    for (from, to, expected) in [(0, 0, 0.), (0, 1, 3.), (2, 0, 5.), (0, 3, 7.), (1, 2, 4.), (3, 1, 6.), (2, 3, 2.)] {
+        assert_eq!(problem.transport.distance_approx(&profile, from, to), expected);
+        assert_eq!(problem.transport.distance_approx(&profile, to, from), expected);
+    }
+}
+
+#[test]
+fn can_read_explicit_meta_errors() {
+    let content = "NAME : toy\nCOMMENT : toy\nTYPE : CVRP\nDIMENSION : 4\nEDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : FULL_MATRIX\n";
+    let mut reader = TsplibReader::new(BufReader::new(content.as_bytes()));
+
+    let result = reader.read_meta();
+
+    assert_eq!(
+        result,
+        Err("expecting 'LOWER_DIAG_ROW' or 'UPPER_ROW' as EDGE_WEIGHT_FORMAT, got 'FULL_MATRIX'".to_string())
+    );
+}
+
+#[test]
+fn can_detect_missing_edge_weights() {
+    let mut buffer = String::new();
+    get_explicit_problem_reader("upper_row").read_to_string(&mut buffer).expect("cannot read file");
+    let content = buffer.replace("3 5 7 4\n6 2\n", "3 5 7 4\n");
+    let mut reader = TsplibReader::new(BufReader::new(content.as_bytes()));
+    reader.read_meta().expect("cannot read meta");
+
+    let result = reader.read_customer_data().map(|_| ());
+
+    assert_eq!(result, Err("cannot parse edge weight: 'invalid float literal'".to_string()));
+}